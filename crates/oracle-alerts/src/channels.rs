@@ -0,0 +1,77 @@
+//! Alert destinations.
+
+use anyhow::{bail, Result};
+use serde::Deserialize;
+use serde_json::json;
+
+/// One destination, entitled to alerts up to its subscription tier
+#[derive(Debug, Deserialize)]
+pub struct Channel {
+    #[serde(flatten)]
+    pub target: Target,
+    /// Subscription tier the channel's audience holds; alerts that need a
+    /// higher tier are not posted here
+    #[serde(default)]
+    pub tier: u8,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Target {
+    /// Telegram chat id (e.g. `-1001234567890` or `@channel`)
+    Telegram(String),
+    /// Discord webhook URL
+    Discord(String),
+}
+
+impl std::fmt::Display for Target {
+    // Webhook URLs embed their secret, so never print them
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Target::Telegram(chat_id) => write!(f, "telegram {chat_id}"),
+            Target::Discord(_) => f.write_str("discord webhook"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Config {
+    /// Bot token for Telegram targets; `TELEGRAM_BOT_TOKEN` overrides it
+    #[serde(default)]
+    pub telegram_bot_token: Option<String>,
+    pub channels: Vec<Channel>,
+}
+
+pub struct Poster {
+    http: reqwest::Client,
+    telegram_bot_token: Option<String>,
+}
+
+impl Poster {
+    pub fn new(telegram_bot_token: Option<String>) -> Self {
+        Poster {
+            http: reqwest::Client::new(),
+            telegram_bot_token,
+        }
+    }
+
+    pub async fn post(&self, target: &Target, text: &str) -> Result<()> {
+        let response = match target {
+            Target::Telegram(chat_id) => {
+                let Some(token) = &self.telegram_bot_token else {
+                    bail!("no Telegram bot token configured");
+                };
+                self.http
+                    .post(format!("https://api.telegram.org/bot{token}/sendMessage"))
+                    .json(&json!({ "chat_id": chat_id, "text": text, "disable_web_page_preview": true }))
+                    .send()
+                    .await?
+            }
+            Target::Discord(webhook) => self.http.post(webhook).json(&json!({ "content": text })).send().await?,
+        };
+        if !response.status().is_success() {
+            bail!("{} {}", response.status(), response.text().await.unwrap_or_default());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+//! Alert text.
+
+use oracle::{PRICE_DECIMALS, RISK_LEVEL_COUNT};
+use oracle_client::types::{Signal, SignalDirection, SignalStatus};
+
+/// On-chain price as a decimal string, trailing zeros trimmed
+pub fn price(raw: u64) -> String {
+    let scale = 10u64.pow(u32::from(PRICE_DECIMALS));
+    let frac = format!("{:0width$}", raw % scale, width = PRICE_DECIMALS as usize);
+    let frac = frac.trim_end_matches('0');
+    if frac.is_empty() {
+        (raw / scale).to_string()
+    } else {
+        format!("{}.{frac}", raw / scale)
+    }
+}
+
+/// Basis points as a signed percentage
+pub fn roi(bps: i64) -> String {
+    let sign = if bps < 0 { '-' } else { '+' };
+    format!("{sign}{}.{:02}%", bps.unsigned_abs() / 100, bps.unsigned_abs() % 100)
+}
+
+fn direction(signal: &Signal) -> &'static str {
+    match signal.direction {
+        SignalDirection::Long => "LONG",
+        SignalDirection::Short => "SHORT",
+    }
+}
+
+pub fn published(signal: &Signal) -> String {
+    format!(
+        "🟢 New signal #{id}: {symbol} {direction}\n\
+         Score: {score} · Risk: {risk}/{max_risk}\n\
+         Entry: {entry}\n\
+         Token: {token}",
+        id = signal.id,
+        symbol = signal.symbol,
+        direction = direction(signal),
+        score = signal.score,
+        risk = signal.risk_level,
+        max_risk = RISK_LEVEL_COUNT - 1,
+        entry = price(signal.entry_price),
+        token = signal.token,
+    )
+}
+
+pub fn closed(signal: &Signal, status: SignalStatus, roi_bps: i64) -> String {
+    let (icon, outcome) = match status {
+        SignalStatus::Win => ("✅", "WIN"),
+        SignalStatus::Loss => ("❌", "LOSS"),
+        SignalStatus::BreakEven => ("➖", "BREAK-EVEN"),
+        _ => ("⚪", "CLOSED"),
+    };
+    format!(
+        "{icon} Signal #{id} closed: {symbol} {direction} — {outcome}\n\
+         ROI: {roi}\n\
+         Entry: {entry} · Exit: {exit}",
+        id = signal.id,
+        symbol = signal.symbol,
+        direction = direction(signal),
+        roi = roi(roi_bps),
+        entry = price(signal.entry_price),
+        exit = price(signal.exit_price),
+    )
+}
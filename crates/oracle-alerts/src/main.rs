@@ -0,0 +1,89 @@
+//! `oracle-alerts`: post published and closed signals to Telegram and Discord.
+//!
+//! Each channel in the config carries the subscription tier its audience
+//! holds. A signal's alert tier is the one the program assigns it
+//! (`oracle::alert_tier`, by score), and an alert is only posted to
+//! channels at that tier or above.
+//!
+//! Example config:
+//!
+//! ```json
+//! {
+//!   "telegram_bot_token": "123456:ABC...",
+//!   "channels": [
+//!     { "telegram": "-1001234567890", "tier": 2 },
+//!     { "discord": "https://discord.com/api/webhooks/...", "tier": 0 }
+//!   ]
+//! }
+//! ```
+
+mod channels;
+mod format;
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use oracle_client::fetch;
+use oracle_client::stream::{SignalEvent, SignalStream};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+
+use channels::{Config, Poster};
+
+#[derive(Parser)]
+#[command(name = "oracle-alerts", version, about = "Post ORACLE Alpha signals to Telegram and Discord")]
+struct Cli {
+    /// RPC endpoint, for reading signal details
+    #[arg(long, short = 'u', env = "ORACLE_RPC_URL", default_value = "https://api.mainnet-beta.solana.com")]
+    url: String,
+
+    /// RPC websocket endpoint to subscribe to
+    #[arg(long, env = "ORACLE_WS_URL", default_value = "wss://api.mainnet-beta.solana.com")]
+    ws_url: String,
+
+    /// JSON channel config
+    #[arg(long, short = 'c', env = "ORACLE_ALERTS_CONFIG")]
+    config: PathBuf,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let raw = std::fs::read_to_string(&cli.config).with_context(|| format!("reading {}", cli.config.display()))?;
+    let config: Config = serde_json::from_str(&raw).context("parsing config")?;
+    let token = std::env::var("TELEGRAM_BOT_TOKEN").ok().or(config.telegram_bot_token);
+    let poster = Poster::new(token);
+
+    let rpc = RpcClient::new_with_commitment(cli.url, CommitmentConfig::confirmed());
+    let mut events = SignalStream::new(cli.ws_url).spawn(256);
+    eprintln!("oracle-alerts posting to {} channel(s)", config.channels.len());
+
+    while let Some(item) = events.recv().await {
+        let (id, text) = match &item.event {
+            SignalEvent::Published(e) => (e.id, None),
+            SignalEvent::PublishedWithProof(e) => (e.id, None),
+            SignalEvent::Closed(e) => (e.id, Some((e.status, e.roi_bps))),
+        };
+        // The event is confirmed, so the account already reflects it
+        let signal = match fetch::fetch_signal(&rpc, id).await {
+            Ok(signal) => signal,
+            Err(err) => {
+                eprintln!("signal #{id}: {err}");
+                continue;
+            }
+        };
+        let text = match text {
+            None => format::published(&signal),
+            Some((status, roi_bps)) => format::closed(&signal, status, roi_bps),
+        };
+
+        let tier = oracle::alert_tier(signal.score);
+        for channel in config.channels.iter().filter(|channel| channel.tier >= tier) {
+            if let Err(err) = poster.post(&channel.target, &text).await {
+                eprintln!("signal #{id}: posting to {} failed: {err:#}", channel.target);
+            }
+        }
+    }
+    Ok(())
+}
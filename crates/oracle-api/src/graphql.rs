@@ -0,0 +1,233 @@
+//! GraphQL schema over the same tables as the REST routes.
+//!
+//! `signals` pages newest-first with Relay-style cursors (`first`/`after`,
+//! the cursor being the signal id) and takes a `filter` on status, score,
+//! token and publish time. Each signal resolves its settlement and its
+//! token's aggregate stats through data loaders, so a page costs three
+//! queries however many nested fields are requested.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_graphql::connection::{Connection, Edge};
+use async_graphql::dataloader::{DataLoader, Loader};
+use async_graphql::http::GraphiQLSource;
+use async_graphql::{ComplexObject, Context, EmptyMutation, EmptySubscription, InputObject, Object, Result, Schema, SimpleObject};
+use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
+use axum::extract::State;
+use axum::response::{Html, IntoResponse};
+
+use crate::routes::{self, Settlement, SignalRow, Stats, SIGNAL_COLUMNS};
+use crate::AppState;
+
+const DEFAULT_PAGE: i32 = 50;
+const MAX_PAGE: i32 = 500;
+/// Deepest query accepted, to bound nested-field fan-out
+const MAX_DEPTH: usize = 8;
+
+pub type OracleSchema = Schema<Query, EmptyMutation, EmptySubscription>;
+
+pub fn schema(db: Arc<tokio_postgres::Client>) -> OracleSchema {
+    Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(DataLoader::new(Db(db.clone()), tokio::spawn))
+        .data(db)
+        .limit_depth(MAX_DEPTH)
+        .finish()
+}
+
+pub async fn handler(State(state): State<AppState>, request: GraphQLRequest) -> GraphQLResponse {
+    state.schema.execute(request.into_inner()).await.into()
+}
+
+pub async fn graphiql() -> impl IntoResponse {
+    Html(GraphiQLSource::build().endpoint("/graphql").finish())
+}
+
+/// Aggregate track record of one token
+#[derive(Clone, SimpleObject)]
+pub struct TokenStats {
+    mint: String,
+    signals: i64,
+    open: i64,
+    wins: i64,
+    losses: i64,
+    win_rate_bps: i64,
+    /// Mean ROI of settled signals
+    avg_roi_bps: i64,
+    best_roi_bps: Option<i64>,
+    last_published_at: i64,
+}
+
+#[derive(InputObject, Default)]
+pub struct SignalFilter {
+    /// Any of `Open`, `Win`, `Loss`, `Closed`, `BreakEven`
+    status: Option<Vec<String>>,
+    min_score: Option<i16>,
+    max_score: Option<i16>,
+    token: Option<String>,
+    /// Unix seconds, inclusive
+    published_after: Option<i64>,
+    /// Unix seconds, exclusive
+    published_before: Option<i64>,
+}
+
+pub struct Query;
+
+#[Object]
+impl Query {
+    /// Signals, newest first
+    async fn signals(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        filter: Option<SignalFilter>,
+    ) -> Result<Connection<i64, SignalRow>> {
+        let db = ctx.data::<Arc<tokio_postgres::Client>>()?;
+        let filter = filter.unwrap_or_default();
+        let limit = i64::from(first.unwrap_or(DEFAULT_PAGE).clamp(1, MAX_PAGE));
+        let after = match after {
+            Some(cursor) => cursor.parse::<i64>().map_err(|_| "invalid cursor")?,
+            None => i64::MAX,
+        };
+        let query = format!(
+            "SELECT {SIGNAL_COLUMNS} FROM signals s
+             WHERE s.id < $1
+               AND ($2::TEXT[] IS NULL OR s.status = ANY($2))
+               AND ($3::SMALLINT IS NULL OR s.score >= $3)
+               AND ($4::SMALLINT IS NULL OR s.score <= $4)
+               AND ($5::TEXT IS NULL OR s.token = $5)
+               AND ($6::BIGINT IS NULL OR s.published_at >= $6)
+               AND ($7::BIGINT IS NULL OR s.published_at < $7)
+             ORDER BY s.id DESC LIMIT $8"
+        );
+        let rows = db
+            .query(
+                &query,
+                &[
+                    &after,
+                    &filter.status,
+                    &filter.min_score,
+                    &filter.max_score,
+                    &filter.token,
+                    &filter.published_after,
+                    &filter.published_before,
+                    &(limit + 1),
+                ],
+            )
+            .await?;
+
+        let has_next = rows.len() as i64 > limit;
+        let mut connection = Connection::new(after != i64::MAX, has_next);
+        connection.edges.extend(
+            rows.iter()
+                .take(limit as usize)
+                .map(SignalRow::from)
+                .map(|signal| Edge::new(signal.id, signal)),
+        );
+        Ok(connection)
+    }
+
+    async fn signal(&self, ctx: &Context<'_>, id: i64) -> Result<Option<SignalRow>> {
+        let db = ctx.data::<Arc<tokio_postgres::Client>>()?;
+        let query = format!("SELECT {SIGNAL_COLUMNS} FROM signals s WHERE s.id = $1");
+        Ok(db.query_opt(&query, &[&id]).await?.as_ref().map(SignalRow::from))
+    }
+
+    async fn token(&self, ctx: &Context<'_>, mint: String) -> Result<Option<TokenStats>> {
+        Ok(ctx.data::<DataLoader<Db>>()?.load_one(mint).await?)
+    }
+
+    async fn stats(&self, ctx: &Context<'_>) -> Result<Option<Stats>> {
+        Ok(routes::load_stats(ctx.data::<Arc<tokio_postgres::Client>>()?).await?)
+    }
+}
+
+#[ComplexObject]
+impl SignalRow {
+    async fn settlement(&self, ctx: &Context<'_>) -> Result<Option<Settlement>> {
+        Ok(ctx.data::<DataLoader<Db>>()?.load_one(SettlementOf(self.id)).await?)
+    }
+
+    async fn token_stats(&self, ctx: &Context<'_>) -> Result<Option<TokenStats>> {
+        Ok(ctx.data::<DataLoader<Db>>()?.load_one(self.token.clone()).await?)
+    }
+}
+
+/// Batch loader over the indexer database
+pub struct Db(Arc<tokio_postgres::Client>);
+
+/// Loader key for a signal's settlement
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SettlementOf(i64);
+
+impl Loader<String> for Db {
+    type Value = TokenStats;
+    type Error = Arc<tokio_postgres::Error>;
+
+    async fn load(&self, mints: &[String]) -> Result<HashMap<String, TokenStats>, Self::Error> {
+        let rows = self
+            .0
+            .query(
+                "SELECT token,
+                        COUNT(*) AS signals,
+                        COUNT(*) FILTER (WHERE status = 'Open') AS open,
+                        COUNT(*) FILTER (WHERE status = 'Win') AS wins,
+                        COUNT(*) FILTER (WHERE status = 'Loss') AS losses,
+                        COALESCE(AVG(roi_bps) FILTER (WHERE status <> 'Open'), 0)::BIGINT AS avg_roi_bps,
+                        MAX(roi_bps) FILTER (WHERE status <> 'Open') AS best_roi_bps,
+                        MAX(published_at) AS last_published_at
+                 FROM signals WHERE token = ANY($1) GROUP BY token",
+                &[&mints],
+            )
+            .await?;
+        Ok(rows
+            .iter()
+            .map(|row| {
+                let wins: i64 = row.get("wins");
+                let losses: i64 = row.get("losses");
+                let stats = TokenStats {
+                    mint: row.get("token"),
+                    signals: row.get("signals"),
+                    open: row.get("open"),
+                    wins,
+                    losses,
+                    win_rate_bps: oracle_math::win_rate_bps(wins as u64, losses as u64) as i64,
+                    avg_roi_bps: row.get("avg_roi_bps"),
+                    best_roi_bps: row.get("best_roi_bps"),
+                    last_published_at: row.get("last_published_at"),
+                };
+                (stats.mint.clone(), stats)
+            })
+            .collect())
+    }
+}
+
+impl Loader<SettlementOf> for Db {
+    type Value = Settlement;
+    type Error = Arc<tokio_postgres::Error>;
+
+    async fn load(&self, keys: &[SettlementOf]) -> Result<HashMap<SettlementOf, Settlement>, Self::Error> {
+        let ids: Vec<i64> = keys.iter().map(|key| key.0).collect();
+        let rows = self
+            .0
+            .query(
+                "SELECT DISTINCT ON (signal_id) signal_id, signature, slot, block_time
+                 FROM settlements WHERE signal_id = ANY($1)
+                 ORDER BY signal_id, slot DESC",
+                &[&ids],
+            )
+            .await?;
+        Ok(rows
+            .iter()
+            .map(|row| {
+                let settlement = Settlement {
+                    signature: row.get("signature"),
+                    slot: row.get("slot"),
+                    block_time: row.get("block_time"),
+                };
+                (SettlementOf(row.get("signal_id")), settlement)
+            })
+            .collect())
+    }
+}
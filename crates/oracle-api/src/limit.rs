@@ -0,0 +1,90 @@
+//! API-key checks and per-caller rate limiting.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::{header, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde_json::json;
+
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Token bucket refilled continuously at `rate` per minute
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+#[derive(Clone)]
+pub struct Limiter {
+    keys: Arc<Vec<String>>,
+    per_minute: f64,
+    buckets: Arc<Mutex<HashMap<String, Bucket>>>,
+}
+
+impl Limiter {
+    pub fn new(keys: Vec<String>, per_minute: u32) -> Self {
+        Limiter {
+            keys: Arc::new(keys.into_iter().filter(|key| !key.is_empty()).collect()),
+            per_minute: f64::from(per_minute.max(1)),
+            buckets: Arc::default(),
+        }
+    }
+
+    /// Take a token for `caller`; on refusal, seconds until one is available
+    fn take(&self, caller: &str) -> Result<(), u64> {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.entry(caller.to_string()).or_insert(Bucket {
+            tokens: self.per_minute,
+            updated: now,
+        });
+        let refill = now.duration_since(bucket.updated).as_secs_f64() * self.per_minute / 60.0;
+        bucket.tokens = (bucket.tokens + refill).min(self.per_minute);
+        bucket.updated = now;
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(((1.0 - bucket.tokens) * 60.0 / self.per_minute).ceil() as u64)
+        }
+    }
+}
+
+pub async fn enforce(
+    State(limiter): State<Limiter>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let key = request
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok());
+    let caller = if limiter.keys.is_empty() {
+        peer.ip().to_string()
+    } else {
+        match key {
+            Some(key) if limiter.keys.iter().any(|k| k == key) => format!("key:{key}"),
+            _ => {
+                return (StatusCode::UNAUTHORIZED, Json(json!({ "error": "missing or invalid API key" })))
+                    .into_response()
+            }
+        }
+    };
+
+    match limiter.take(&caller) {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(header::RETRY_AFTER, retry_after.to_string())],
+            Json(json!({ "error": "rate limit exceeded" })),
+        )
+            .into_response(),
+    }
+}
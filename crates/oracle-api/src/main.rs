@@ -0,0 +1,87 @@
+//! `oracle-api`: read-only REST API over the `oracle-indexer` database.
+//!
+//! Routes:
+//! - `GET /signals` — all signals, newest first
+//! - `GET /signals/open` — open signals, newest first
+//! - `GET /stats` — the oracle's track record
+//! - `GET /tokens/{mint}/history` — signals on a token with their settlements
+//! - `POST /graphql` — GraphQL over the same data (GraphiQL on `GET`)
+//!
+//! List routes page with `?limit=` (max 500) and `?before=<signal id>`; the
+//! response's `next` is the `before` value for the following page. When API
+//! keys are configured every request needs an `x-api-key` header, and each
+//! key (or each client IP, without keys) is rate limited.
+
+mod error;
+mod graphql;
+mod limit;
+mod routes;
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use axum::routing::get;
+use axum::{middleware, Router};
+use clap::Parser;
+use tokio_postgres::NoTls;
+
+#[derive(Parser)]
+#[command(name = "oracle-api", version, about = "Serve indexed ORACLE Alpha data over HTTP")]
+struct Cli {
+    /// Postgres connection string (the indexer's database)
+    #[arg(long, env = "DATABASE_URL")]
+    database_url: String,
+
+    /// Address to listen on
+    #[arg(long, env = "ORACLE_API_LISTEN", default_value = "0.0.0.0:8080")]
+    listen: SocketAddr,
+
+    /// Accepted API keys, comma-separated; empty leaves the API open
+    #[arg(long, env = "ORACLE_API_KEYS", value_delimiter = ',')]
+    api_keys: Vec<String>,
+
+    /// Requests per minute allowed per key (or per IP without keys)
+    #[arg(long, default_value_t = 120)]
+    rate_limit: u32,
+}
+
+#[derive(Clone)]
+pub struct AppState {
+    db: Arc<tokio_postgres::Client>,
+    schema: graphql::OracleSchema,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    let (client, connection) = tokio_postgres::connect(&cli.database_url, NoTls)
+        .await
+        .context("connecting to Postgres")?;
+    tokio::spawn(async move {
+        if let Err(err) = connection.await {
+            eprintln!("postgres connection closed: {err}");
+        }
+    });
+
+    let db = Arc::new(client);
+    let state = AppState {
+        schema: graphql::schema(db.clone()),
+        db,
+    };
+    let limiter = limit::Limiter::new(cli.api_keys, cli.rate_limit);
+    let app = Router::new()
+        .route("/signals", get(routes::signals))
+        .route("/signals/open", get(routes::open_signals))
+        .route("/stats", get(routes::stats))
+        .route("/tokens/{mint}/history", get(routes::token_history))
+        .route("/graphql", get(graphql::graphiql).post(graphql::handler))
+        .layer(middleware::from_fn_with_state(limiter, limit::enforce))
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(cli.listen).await?;
+    eprintln!("oracle-api listening on {}", cli.listen);
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>()).await?;
+    Ok(())
+}
@@ -0,0 +1,204 @@
+//! Route handlers.
+
+use async_graphql::SimpleObject;
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use serde::{Deserialize, Serialize};
+use tokio_postgres::Row;
+
+use crate::error::ApiError;
+use crate::AppState;
+
+const DEFAULT_LIMIT: i64 = 50;
+const MAX_LIMIT: i64 = 500;
+
+pub(crate) const SIGNAL_COLUMNS: &str = "s.address, s.id, s.token, s.symbol, s.score, s.risk_level, s.sources_bitmap,
+    s.mcap_at_signal, s.entry_price, s.direction, s.ath_price, s.exit_price, s.roi_bps, s.published_at,
+    s.status, s.reasoning_revealed, s.price_pool, s.entry_verified, s.exit_verified";
+
+#[derive(Deserialize)]
+pub struct PageParams {
+    limit: Option<i64>,
+    /// Only signals with an id below this
+    before: Option<i64>,
+}
+
+impl PageParams {
+    fn limit(&self) -> i64 {
+        self.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT)
+    }
+
+    fn before(&self) -> i64 {
+        self.before.unwrap_or(i64::MAX)
+    }
+}
+
+#[derive(Serialize)]
+pub struct Page<T> {
+    data: Vec<T>,
+    /// `before` for the next page; absent on the last page
+    next: Option<i64>,
+}
+
+#[derive(Serialize, SimpleObject)]
+#[graphql(name = "Signal", complex)]
+pub struct SignalRow {
+    address: String,
+    pub(crate) id: i64,
+    pub(crate) token: String,
+    symbol: String,
+    score: i16,
+    risk_level: i16,
+    sources_bitmap: i16,
+    mcap_at_signal: i64,
+    entry_price: i64,
+    direction: String,
+    ath_price: i64,
+    exit_price: i64,
+    roi_bps: i64,
+    published_at: i64,
+    status: String,
+    reasoning_revealed: bool,
+    price_pool: String,
+    entry_verified: bool,
+    exit_verified: bool,
+}
+
+impl From<&Row> for SignalRow {
+    fn from(row: &Row) -> Self {
+        SignalRow {
+            address: row.get("address"),
+            id: row.get("id"),
+            token: row.get("token"),
+            symbol: row.get("symbol"),
+            score: row.get("score"),
+            risk_level: row.get("risk_level"),
+            sources_bitmap: row.get("sources_bitmap"),
+            mcap_at_signal: row.get("mcap_at_signal"),
+            entry_price: row.get("entry_price"),
+            direction: row.get("direction"),
+            ath_price: row.get("ath_price"),
+            exit_price: row.get("exit_price"),
+            roi_bps: row.get("roi_bps"),
+            published_at: row.get("published_at"),
+            status: row.get("status"),
+            reasoning_revealed: row.get("reasoning_revealed"),
+            price_pool: row.get("price_pool"),
+            entry_verified: row.get("entry_verified"),
+            exit_verified: row.get("exit_verified"),
+        }
+    }
+}
+
+#[derive(Clone, Serialize, SimpleObject)]
+pub struct Settlement {
+    pub(crate) signature: String,
+    pub(crate) slot: i64,
+    pub(crate) block_time: Option<i64>,
+}
+
+#[derive(Serialize)]
+pub struct HistoryRow {
+    #[serde(flatten)]
+    signal: SignalRow,
+    settlement: Option<Settlement>,
+}
+
+fn page<T>(data: Vec<T>, limit: i64, id: impl Fn(&T) -> i64) -> Page<T> {
+    let next = match data.last() {
+        Some(last) if data.len() as i64 == limit => Some(id(last)),
+        _ => None,
+    };
+    Page { data, next }
+}
+
+pub async fn signals(State(state): State<AppState>, Query(params): Query<PageParams>) -> Result<Json<Page<SignalRow>>, ApiError> {
+    list_signals(&state, &params, false).await.map(Json)
+}
+
+pub async fn open_signals(
+    State(state): State<AppState>,
+    Query(params): Query<PageParams>,
+) -> Result<Json<Page<SignalRow>>, ApiError> {
+    list_signals(&state, &params, true).await.map(Json)
+}
+
+async fn list_signals(state: &AppState, params: &PageParams, open_only: bool) -> Result<Page<SignalRow>, ApiError> {
+    let limit = params.limit();
+    let query = format!(
+        "SELECT {SIGNAL_COLUMNS} FROM signals s
+         WHERE s.id < $1 AND ($2 = FALSE OR s.status = 'Open')
+         ORDER BY s.id DESC LIMIT $3"
+    );
+    let rows = state.db.query(&query, &[&params.before(), &open_only, &limit]).await?;
+    Ok(page(rows.iter().map(SignalRow::from).collect(), limit, |s| s.id))
+}
+
+#[derive(Serialize, SimpleObject)]
+#[graphql(name = "OracleStats")]
+pub struct Stats {
+    authority: String,
+    total_signals: i64,
+    total_wins: i64,
+    total_losses: i64,
+    compressed_signals: i64,
+    win_rate_bps: i64,
+    slot: i64,
+}
+
+/// Latest indexed `OracleState` snapshot
+pub(crate) async fn load_stats(db: &tokio_postgres::Client) -> Result<Option<Stats>, tokio_postgres::Error> {
+    let row = db
+        .query_opt(
+            "SELECT authority, total_signals, total_wins, total_losses, compressed_signals, slot
+             FROM stats ORDER BY slot DESC LIMIT 1",
+            &[],
+        )
+        .await?;
+    Ok(row.map(|row| {
+        let wins: i64 = row.get("total_wins");
+        let losses: i64 = row.get("total_losses");
+        Stats {
+            authority: row.get("authority"),
+            total_signals: row.get("total_signals"),
+            total_wins: wins,
+            total_losses: losses,
+            compressed_signals: row.get("compressed_signals"),
+            win_rate_bps: oracle_math::win_rate_bps(wins as u64, losses as u64) as i64,
+            slot: row.get("slot"),
+        }
+    }))
+}
+
+pub async fn stats(State(state): State<AppState>) -> Result<Json<Stats>, ApiError> {
+    let stats = load_stats(&state.db).await?.ok_or(ApiError::NotFound("stats"))?;
+    Ok(Json(stats))
+}
+
+pub async fn token_history(
+    State(state): State<AppState>,
+    Path(mint): Path<String>,
+    Query(params): Query<PageParams>,
+) -> Result<Json<Page<HistoryRow>>, ApiError> {
+    let limit = params.limit();
+    let query = format!(
+        "SELECT {SIGNAL_COLUMNS}, t.signature, t.slot AS settled_slot, t.block_time
+         FROM signals s
+         LEFT JOIN settlements t ON t.signal_id = s.id
+         WHERE s.token = $1 AND s.id < $2
+         ORDER BY s.id DESC LIMIT $3"
+    );
+    let rows = state.db.query(&query, &[&mint, &params.before(), &limit]).await?;
+    let history = rows
+        .iter()
+        .map(|row| HistoryRow {
+            signal: SignalRow::from(row),
+            settlement: row.get::<_, Option<String>>("signature").map(|signature| Settlement {
+                signature,
+                slot: row.get("settled_slot"),
+                block_time: row.get("block_time"),
+            }),
+        })
+        .collect();
+    Ok(Json(page(history, limit, |h| h.signal.id)))
+}
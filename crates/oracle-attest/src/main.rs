@@ -0,0 +1,209 @@
+//! `oracle-attest`: holds the model-attestation key and signs signals for
+//! the publisher, so the key never lives in the publisher bot itself.
+//!
+//! Routes (every request needs `Authorization: Bearer <token>`):
+//! - `GET /pubkey` — `{"pubkey"}` of the attestation key
+//! - `POST /attest` — sign a signal; the body is the signal's contents:
+//!
+//! ```text
+//! {"signal_id": 42, "token": "<mint>", "symbol": "BONK", "score": 82,
+//!  "risk_level": 1, "sources_bitmap": 5, "mcap": 0, "entry_price": 1200,
+//!  "direction": "long", "reasoning_hash": "<hex, optional>",
+//!  "quote_mint": "<mint, optional, defaults to USDC>"}
+//! ```
+//!
+//! and the response carries the signed `message` (base64), the
+//! `signature` (base58) and the Ed25519 precompile `instruction` to place
+//! before the publish instruction.
+//!
+//! The daemon only listens on loopback unless `--allow-remote` is given.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use anyhow::{bail, Context, Result};
+use axum::extract::{Request, State};
+use axum::http::{header, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use clap::Parser;
+use oracle::SignalDirection;
+use oracle_client::attestation;
+use oracle_client::instructions::SignalParams;
+use oracle_client::prices::USDC_MINT;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Keypair};
+use solana_sdk::signer::Signer;
+
+#[derive(Parser)]
+#[command(name = "oracle-attest", version, about = "Sign ORACLE Alpha model attestations")]
+struct Cli {
+    /// Attestation keypair file
+    #[arg(long, short = 'k', env = "ORACLE_ATTESTATION_KEYPAIR")]
+    keypair: String,
+
+    /// Address to listen on
+    #[arg(long, env = "ORACLE_ATTEST_LISTEN", default_value = "127.0.0.1:7401")]
+    listen: SocketAddr,
+
+    /// Bearer token callers must present
+    #[arg(long, env = "ORACLE_ATTEST_TOKEN", hide_env_values = true)]
+    token: String,
+
+    /// Allow listening on a non-loopback address
+    #[arg(long)]
+    allow_remote: bool,
+}
+
+#[derive(Clone)]
+struct AppState {
+    keypair: Arc<Keypair>,
+    token: Arc<String>,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    if !cli.listen.ip().is_loopback() && !cli.allow_remote {
+        bail!("refusing to listen on {} (not loopback) without --allow-remote", cli.listen);
+    }
+    if cli.token.len() < 16 {
+        bail!("the bearer token must be at least 16 characters");
+    }
+
+    let path = expand_home(&cli.keypair);
+    let keypair = read_keypair_file(&path).map_err(|err| anyhow::anyhow!("reading keypair {path}: {err}"))?;
+    eprintln!("attestation key: {}", keypair.pubkey());
+
+    let state = AppState {
+        keypair: Arc::new(keypair),
+        token: Arc::new(cli.token),
+    };
+    let app = Router::new()
+        .route("/pubkey", get(pubkey))
+        .route("/attest", post(attest))
+        .layer(middleware::from_fn_with_state(state.clone(), authenticate))
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(cli.listen)
+        .await
+        .with_context(|| format!("binding {}", cli.listen))?;
+    eprintln!("listening on {}", cli.listen);
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+async fn authenticate(State(state): State<AppState>, request: Request, next: Next) -> Response {
+    let presented = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    match presented {
+        Some(token) if constant_time_eq(token.as_bytes(), state.token.as_bytes()) => next.run(request).await,
+        _ => error(StatusCode::UNAUTHORIZED, "missing or invalid bearer token"),
+    }
+}
+
+/// Compare without leaking the length of the matching prefix
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+async fn pubkey(State(state): State<AppState>) -> Json<Value> {
+    Json(json!({ "pubkey": state.keypair.pubkey().to_string() }))
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Direction {
+    Long,
+    Short,
+}
+
+#[derive(Deserialize)]
+struct AttestRequest {
+    signal_id: u64,
+    token: String,
+    symbol: String,
+    score: u8,
+    #[serde(default)]
+    risk_level: u8,
+    #[serde(default)]
+    sources_bitmap: u8,
+    #[serde(default)]
+    mcap: u64,
+    entry_price: u64,
+    direction: Direction,
+    reasoning_hash: Option<String>,
+    /// Mint the entry price is quoted in; USDC if left out
+    quote_mint: Option<String>,
+}
+
+async fn attest(State(state): State<AppState>, Json(request): Json<AttestRequest>) -> Response {
+    let Ok(token) = request.token.parse::<Pubkey>() else {
+        return error(StatusCode::BAD_REQUEST, "token is not a valid address");
+    };
+    let reasoning_hash = match request.reasoning_hash.as_deref().map(parse_hash) {
+        Some(Some(hash)) => hash,
+        Some(None) => return error(StatusCode::BAD_REQUEST, "reasoning_hash must be 32 bytes of hex"),
+        None => [0; 32],
+    };
+    let quote_mint = match request.quote_mint.as_deref().map(str::parse::<Pubkey>) {
+        Some(Ok(mint)) => mint,
+        Some(Err(_)) => return error(StatusCode::BAD_REQUEST, "quote_mint is not a valid address"),
+        None => USDC_MINT,
+    };
+    let params = SignalParams {
+        token,
+        symbol: request.symbol,
+        score: request.score,
+        risk_level: request.risk_level,
+        sources_bitmap: request.sources_bitmap,
+        mcap: request.mcap,
+        entry_price: request.entry_price,
+        direction: match request.direction {
+            Direction::Long => SignalDirection::Long,
+            Direction::Short => SignalDirection::Short,
+        },
+        quote_mint,
+        parent_signal: None,
+    };
+
+    let attester = state.keypair.pubkey();
+    let message = attestation::message(request.signal_id, &params, &reasoning_hash);
+    let signature = state.keypair.sign_message(&message);
+    let instruction = attestation::verify_instruction(&attester, &signature, &message);
+    eprintln!("attested signal {} ({} {})", request.signal_id, params.symbol, params.token);
+    Json(json!({
+        "pubkey": attester.to_string(),
+        "message": BASE64.encode(&message),
+        "signature": signature.to_string(),
+        "instruction": {
+            "program_id": instruction.program_id.to_string(),
+            "data": BASE64.encode(&instruction.data),
+        },
+    }))
+    .into_response()
+}
+
+fn parse_hash(hex_hash: &str) -> Option<[u8; 32]> {
+    <[u8; 32]>::try_from(hex::decode(hex_hash.trim_start_matches("0x")).ok()?).ok()
+}
+
+fn error(status: StatusCode, message: &str) -> Response {
+    (status, Json(json!({ "error": message }))).into_response()
+}
+
+fn expand_home(path: &str) -> String {
+    match (path.strip_prefix("~/"), std::env::var("HOME")) {
+        (Some(rest), Ok(home)) => format!("{home}/{rest}"),
+        _ => path.to_string(),
+    }
+}
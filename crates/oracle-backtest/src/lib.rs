@@ -0,0 +1,233 @@
+//! Replay historical signals against price checkpoints under configurable
+//! trading rules.
+//!
+//! Each signal is entered at its published entry price and exited at the
+//! first checkpoint that hits take-profit, stop-loss or the holding limit;
+//! otherwise at its on-chain close, or at the last checkpoint if it is still
+//! open. Every fill pays `fee_bps + slippage_bps` of its notional. Equity
+//! only moves when a trade exits, so the curve and drawdown are on realized
+//! P&L.
+
+use std::collections::HashMap;
+
+use oracle_math::{classify, roi_bps, Direction, Outcome, BPS};
+use serde::Serialize;
+
+/// A historical signal as the engine sees it
+#[derive(Debug, Clone)]
+pub struct SignalRecord {
+    pub id: u64,
+    pub token: String,
+    pub score: u8,
+    pub direction: Direction,
+    pub entry_price: u64,
+    pub published_at: i64,
+    /// On-chain close, if the signal was closed: (time, exit price)
+    pub closed: Option<(i64, u64)>,
+}
+
+/// Observed price of a token at a time
+#[derive(Debug, Clone, Copy)]
+pub struct Checkpoint {
+    pub ts: i64,
+    pub price: u64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Sizing {
+    /// The same notional on every trade
+    Fixed(f64),
+    /// A share of realized equity at entry, in basis points
+    Fraction(u32),
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Rules {
+    pub take_profit_bps: Option<i64>,
+    pub stop_loss_bps: Option<i64>,
+    pub max_hold_secs: Option<i64>,
+    pub min_score: u8,
+    pub sizing: Sizing,
+    pub fee_bps: u32,
+    pub slippage_bps: u32,
+    pub initial_equity: f64,
+    /// Break-even band trades are classified with, as in the oracle config
+    pub break_even_bps: u16,
+    /// ROI a trade needs to count as a win
+    pub win_threshold_bps: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExitReason {
+    TakeProfit,
+    StopLoss,
+    MaxHold,
+    OnChainClose,
+    /// Still open; marked to the last checkpoint
+    MarkToMarket,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Trade {
+    pub signal_id: u64,
+    pub entry_ts: i64,
+    pub exit_ts: i64,
+    pub exit_price: u64,
+    pub reason: ExitReason,
+    pub gross_bps: i64,
+    pub net_bps: i64,
+    pub notional: f64,
+    pub pnl: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub trades: Vec<Trade>,
+    /// (exit time, realized equity after the exit)
+    pub equity_curve: Vec<(i64, f64)>,
+    pub final_equity: f64,
+    pub total_return_bps: i64,
+    pub max_drawdown_bps: i64,
+    /// Oracle win rate (`oracle_math::classify` on gross ROI)
+    pub win_rate_bps: u64,
+    /// Share of trades with positive net return
+    pub hit_rate_bps: u64,
+    pub avg_net_bps: i64,
+    pub exits: HashMap<ExitReason, usize>,
+    /// Signals with no price to exit at
+    pub skipped: usize,
+}
+
+/// Where and why the trade on `signal` exits under `rules`
+fn exit(signal: &SignalRecord, prices: &[Checkpoint], rules: &Rules) -> Option<(i64, u64, ExitReason)> {
+    let mut after = prices.iter().filter(|c| c.ts > signal.published_at);
+    for checkpoint in after.clone() {
+        if let Some((closed_at, _)) = signal.closed {
+            if checkpoint.ts > closed_at {
+                break;
+            }
+        }
+        let roi = roi_bps(signal.entry_price, checkpoint.price, signal.direction)?;
+        if rules.take_profit_bps.is_some_and(|tp| roi >= tp) {
+            return Some((checkpoint.ts, checkpoint.price, ExitReason::TakeProfit));
+        }
+        if rules.stop_loss_bps.is_some_and(|sl| roi <= -sl) {
+            return Some((checkpoint.ts, checkpoint.price, ExitReason::StopLoss));
+        }
+        if rules.max_hold_secs.is_some_and(|hold| checkpoint.ts - signal.published_at >= hold) {
+            return Some((checkpoint.ts, checkpoint.price, ExitReason::MaxHold));
+        }
+    }
+    match signal.closed {
+        Some((closed_at, price)) => Some((closed_at, price, ExitReason::OnChainClose)),
+        None => after.next_back().map(|c| (c.ts, c.price, ExitReason::MarkToMarket)),
+    }
+}
+
+/// Run the backtest; `prices` maps token to checkpoints sorted by time
+pub fn run(signals: &[SignalRecord], prices: &HashMap<String, Vec<Checkpoint>>, rules: &Rules) -> Report {
+    let cost_bps = 2 * i64::from(rules.fee_bps + rules.slippage_bps);
+    let mut planned = Vec::new();
+    let mut skipped = 0;
+    for signal in signals.iter().filter(|s| s.score >= rules.min_score) {
+        let checkpoints = prices.get(&signal.token).map_or(&[][..], Vec::as_slice);
+        match exit(signal, checkpoints, rules) {
+            Some((exit_ts, exit_price, reason)) => {
+                let gross = roi_bps(signal.entry_price, exit_price, signal.direction).unwrap_or(0);
+                planned.push((signal, exit_ts, exit_price, reason, gross, gross - cost_bps));
+            }
+            None => skipped += 1,
+        }
+    }
+
+    // Entries size off realized equity, so replay entries and exits in time order
+    enum Step {
+        Exit(usize),
+        Entry(usize),
+    }
+    let mut steps: Vec<(i64, Step)> = Vec::new();
+    for (i, plan) in planned.iter().enumerate() {
+        steps.push((plan.0.published_at, Step::Entry(i)));
+        steps.push((plan.1, Step::Exit(i)));
+    }
+    // Exits before entries at the same instant
+    steps.sort_by_key(|(ts, step)| (*ts, matches!(step, Step::Entry(_))));
+
+    let mut equity = rules.initial_equity;
+    let mut notional = vec![0.0; planned.len()];
+    let mut trades = Vec::with_capacity(planned.len());
+    let mut curve = Vec::new();
+    for (ts, step) in steps {
+        match step {
+            Step::Entry(i) => {
+                notional[i] = match rules.sizing {
+                    Sizing::Fixed(amount) => amount,
+                    Sizing::Fraction(bps) => equity.max(0.0) * f64::from(bps) / BPS as f64,
+                };
+            }
+            Step::Exit(i) => {
+                let (signal, _, exit_price, reason, gross, net) = planned[i];
+                let pnl = notional[i] * net as f64 / BPS as f64;
+                equity += pnl;
+                curve.push((ts, equity));
+                trades.push(Trade {
+                    signal_id: signal.id,
+                    entry_ts: signal.published_at,
+                    exit_ts: ts,
+                    exit_price,
+                    reason,
+                    gross_bps: gross,
+                    net_bps: net,
+                    notional: notional[i],
+                    pnl,
+                });
+            }
+        }
+    }
+
+    let mut exits = HashMap::new();
+    for trade in &trades {
+        *exits.entry(trade.reason).or_insert(0) += 1;
+    }
+    let outcome = |trade: &Trade| classify(trade.gross_bps, rules.win_threshold_bps, rules.break_even_bps);
+    let wins = trades.iter().filter(|t| outcome(t) == Outcome::Win).count() as u64;
+    let losses = trades.iter().filter(|t| outcome(t) == Outcome::Loss).count() as u64;
+    let hits = trades.iter().filter(|t| t.net_bps > 0).count() as u64;
+    Report {
+        final_equity: equity,
+        total_return_bps: ratio_bps(equity - rules.initial_equity, rules.initial_equity),
+        max_drawdown_bps: max_drawdown_bps(rules.initial_equity, &curve),
+        win_rate_bps: oracle_math::win_rate_bps(wins, losses),
+        hit_rate_bps: if trades.is_empty() { 0 } else { hits * BPS as u64 / trades.len() as u64 },
+        avg_net_bps: if trades.is_empty() {
+            0
+        } else {
+            trades.iter().map(|t| t.net_bps).sum::<i64>() / trades.len() as i64
+        },
+        exits,
+        skipped,
+        trades,
+        equity_curve: curve,
+    }
+}
+
+fn ratio_bps(numerator: f64, denominator: f64) -> i64 {
+    if denominator == 0.0 {
+        0
+    } else {
+        (numerator / denominator * BPS as f64).round() as i64
+    }
+}
+
+/// Largest peak-to-trough fall of the curve, in basis points of the peak
+fn max_drawdown_bps(initial: f64, curve: &[(i64, f64)]) -> i64 {
+    let mut peak = initial;
+    let mut worst = 0;
+    for &(_, equity) in curve {
+        peak = peak.max(equity);
+        worst = worst.max(ratio_bps(peak - equity, peak));
+    }
+    worst
+}
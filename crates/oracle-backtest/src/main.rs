@@ -0,0 +1,163 @@
+//! `oracle-backtest`: backtest ORACLE Alpha signals from the indexer database.
+//!
+//! Signals come from `signals` (with close times from `settlements`) and
+//! prices from `price_checkpoints`. Prints a summary, or the full report
+//! with `--json`; `--curve` writes the equity curve as CSV.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use oracle_backtest::{Checkpoint, Rules, SignalRecord, Sizing};
+use oracle_math::Direction;
+use tokio_postgres::NoTls;
+
+#[derive(Parser)]
+#[command(name = "oracle-backtest", version, about = "Backtest ORACLE Alpha signals")]
+struct Cli {
+    /// Postgres connection string (the indexer's database)
+    #[arg(long, env = "DATABASE_URL")]
+    database_url: String,
+
+    /// Take profit at this ROI, in basis points
+    #[arg(long)]
+    take_profit_bps: Option<i64>,
+
+    /// Stop out at this loss, in basis points
+    #[arg(long)]
+    stop_loss_bps: Option<i64>,
+
+    /// Exit after holding this many seconds
+    #[arg(long)]
+    max_hold_secs: Option<i64>,
+
+    /// Only trade signals scoring at least this
+    #[arg(long, default_value_t = 0)]
+    min_score: u8,
+
+    /// Fixed notional per trade (overrides --fraction-bps)
+    #[arg(long)]
+    fixed: Option<f64>,
+
+    /// Share of equity per trade, in basis points
+    #[arg(long, default_value_t = 1_000)]
+    fraction_bps: u32,
+
+    /// Fee per fill, in basis points
+    #[arg(long, default_value_t = 30)]
+    fee_bps: u32,
+
+    /// Slippage per fill, in basis points
+    #[arg(long, default_value_t = 50)]
+    slippage_bps: u32,
+
+    #[arg(long, default_value_t = 10_000.0)]
+    initial_equity: f64,
+
+    /// ROI band either side of zero counted as break-even, in basis points
+    #[arg(long, default_value_t = 0)]
+    break_even_bps: u16,
+
+    /// ROI a trade needs to count as a win, in basis points
+    #[arg(long, default_value_t = oracle_math::WIN_THRESHOLD_BPS)]
+    win_threshold_bps: u32,
+
+    /// Only signals published at or after this unix time
+    #[arg(long)]
+    since: Option<i64>,
+
+    /// Write the equity curve here as CSV
+    #[arg(long)]
+    curve: Option<PathBuf>,
+
+    /// Print the full report as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let (db, connection) = tokio_postgres::connect(&cli.database_url, NoTls)
+        .await
+        .context("connecting to Postgres")?;
+    tokio::spawn(async move {
+        if let Err(err) = connection.await {
+            eprintln!("postgres connection closed: {err}");
+        }
+    });
+
+    let rows = db
+        .query(
+            "SELECT s.id, s.token, s.score, s.direction, s.entry_price, s.published_at, s.status,
+                    s.exit_price, t.block_time
+             FROM signals s LEFT JOIN settlements t ON t.signal_id = s.id
+             WHERE s.published_at >= $1 ORDER BY s.published_at",
+            &[&cli.since.unwrap_or(i64::MIN)],
+        )
+        .await?;
+    let signals: Vec<SignalRecord> = rows
+        .iter()
+        .map(|row| {
+            let closed_at: Option<i64> = row.get("block_time");
+            let status: String = row.get("status");
+            SignalRecord {
+                id: row.get::<_, i64>("id") as u64,
+                token: row.get("token"),
+                score: row.get::<_, i16>("score") as u8,
+                direction: if row.get::<_, String>("direction") == "Short" { Direction::Short } else { Direction::Long },
+                entry_price: row.get::<_, i64>("entry_price") as u64,
+                published_at: row.get("published_at"),
+                closed: closed_at
+                    .filter(|_| status != "Open")
+                    .map(|ts| (ts, row.get::<_, i64>("exit_price") as u64)),
+            }
+        })
+        .collect();
+
+    let mut prices: HashMap<String, Vec<Checkpoint>> = HashMap::new();
+    for row in db.query("SELECT token, ts, price FROM price_checkpoints ORDER BY ts", &[]).await? {
+        prices.entry(row.get("token")).or_default().push(Checkpoint {
+            ts: row.get("ts"),
+            price: row.get::<_, i64>("price") as u64,
+        });
+    }
+
+    let rules = Rules {
+        take_profit_bps: cli.take_profit_bps,
+        stop_loss_bps: cli.stop_loss_bps,
+        max_hold_secs: cli.max_hold_secs,
+        min_score: cli.min_score,
+        sizing: cli.fixed.map_or(Sizing::Fraction(cli.fraction_bps), Sizing::Fixed),
+        fee_bps: cli.fee_bps,
+        slippage_bps: cli.slippage_bps,
+        initial_equity: cli.initial_equity,
+        break_even_bps: cli.break_even_bps,
+        win_threshold_bps: cli.win_threshold_bps,
+    };
+    let report = oracle_backtest::run(&signals, &prices, &rules);
+
+    if let Some(path) = &cli.curve {
+        let csv: String = std::iter::once("ts,equity\n".to_string())
+            .chain(report.equity_curve.iter().map(|(ts, equity)| format!("{ts},{equity:.2}\n")))
+            .collect();
+        std::fs::write(path, csv).with_context(|| format!("writing {}", path.display()))?;
+    }
+
+    if cli.json {
+        println!("{}", serde_json::to_string_pretty(&serde_json::json!({ "rules": rules, "report": report }))?);
+    } else {
+        println!("trades: {} (skipped {})", report.trades.len(), report.skipped);
+        println!("final_equity: {:.2}", report.final_equity);
+        println!("total_return_bps: {}", report.total_return_bps);
+        println!("max_drawdown_bps: {}", report.max_drawdown_bps);
+        println!("win_rate_bps: {}", report.win_rate_bps);
+        println!("hit_rate_bps: {}", report.hit_rate_bps);
+        println!("avg_net_bps: {}", report.avg_net_bps);
+        for (reason, count) in &report.exits {
+            println!("exits.{reason:?}: {count}");
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,1202 @@
+//! `oracle-cli`: operate the ORACLE Alpha program from the command line.
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use oracle::{BasketMemberParams, SignalDirection, TakeProfitParams};
+use oracle_client::fees::{FeeConfig, Urgency};
+use oracle_client::instructions::{self, SignalParams};
+use oracle_client::prices::USDC_MINT;
+use oracle_client::rationale::{Ipfs, Pinner, Rationale};
+use oracle_client::submit::{SubmitConfig, Submitter};
+use oracle_client::types::{FeaturedAuction, SignalStatus};
+use oracle_client::{fetch, math, pda, preview, prices, signer, ClientError};
+use serde_json::{json, Value};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+
+#[derive(Parser)]
+#[command(name = "oracle-cli", version, about = "Operate the ORACLE Alpha program")]
+struct Cli {
+    /// RPC endpoint
+    #[arg(long, short = 'u', env = "ORACLE_RPC_URL", default_value = "https://api.mainnet-beta.solana.com")]
+    url: String,
+
+    /// Keypair file, `usb://ledger` for a Ledger device, or a remote
+    /// signer URL (token in ORACLE_SIGNER_TOKEN)
+    #[arg(long, short = 'k', env = "ORACLE_KEYPAIR", default_value = "~/.config/solana/id.json")]
+    keypair: String,
+
+    /// Derivation path for Ledger keys (e.g. `0/0`)
+    #[arg(long)]
+    derivation_path: Option<String>,
+
+    /// Priority-fee urgency
+    #[arg(long, value_enum, default_value_t = FeeUrgency::Normal)]
+    urgency: FeeUrgency,
+
+    /// Print results as JSON
+    #[arg(long)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum FeeUrgency {
+    Low,
+    Normal,
+    High,
+    Urgent,
+}
+
+impl From<FeeUrgency> for Urgency {
+    fn from(urgency: FeeUrgency) -> Self {
+        match urgency {
+            FeeUrgency::Low => Urgency::Low,
+            FeeUrgency::Normal => Urgency::Normal,
+            FeeUrgency::High => Urgency::High,
+            FeeUrgency::Urgent => Urgency::Urgent,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Publish a new signal
+    Publish {
+        #[arg(long)]
+        token: Pubkey,
+        #[arg(long)]
+        symbol: String,
+        #[arg(long)]
+        score: u8,
+        #[arg(long, default_value_t = 0)]
+        risk_level: u8,
+        #[arg(long, default_value_t = 0)]
+        sources: u8,
+        #[arg(long, default_value_t = 0)]
+        mcap: u64,
+        #[arg(long)]
+        entry_price: u64,
+        /// Call the signal short instead of long
+        #[arg(long)]
+        short: bool,
+        /// Mint the entry price is quoted in
+        #[arg(long, default_value_t = USDC_MINT)]
+        quote_mint: Pubkey,
+        /// Hex sha256 of the reasoning, committed before the outcome
+        #[arg(long, conflicts_with = "reasoning_file")]
+        reasoning_hash: Option<String>,
+        /// Reasoning document to hash (and pin, with --ipfs-api) instead
+        /// of passing --reasoning-hash
+        #[arg(long)]
+        reasoning_file: Option<PathBuf>,
+        /// Kubo-compatible IPFS API to pin --reasoning-file to (token in
+        /// ORACLE_IPFS_TOKEN)
+        #[arg(long, env = "ORACLE_IPFS_API")]
+        ipfs_api: Option<String>,
+        /// Attach the signer's publisher profile (and its verified domain)
+        #[arg(long)]
+        with_profile: bool,
+        /// Id of the token's closed signal this one follows up on, needed
+        /// to re-publish within the republish cooldown
+        #[arg(long)]
+        parent_signal: Option<u64>,
+    },
+    /// Re-enter a token as the next attempt after its last closed signal
+    Reopen {
+        /// Id of the token's last closed signal
+        #[arg(long)]
+        parent: u64,
+        /// Defaults to the parent's symbol
+        #[arg(long)]
+        symbol: Option<String>,
+        #[arg(long)]
+        score: u8,
+        #[arg(long, default_value_t = 0)]
+        risk_level: u8,
+        #[arg(long, default_value_t = 0)]
+        sources: u8,
+        #[arg(long, default_value_t = 0)]
+        mcap: u64,
+        #[arg(long)]
+        entry_price: u64,
+        /// Call the signal short instead of long
+        #[arg(long)]
+        short: bool,
+        /// Mint the entry price is quoted in; defaults to the parent's
+        #[arg(long)]
+        quote_mint: Option<Pubkey>,
+        /// Hex sha256 of the reasoning, committed before the outcome
+        #[arg(long)]
+        reasoning_hash: Option<String>,
+        /// Attach the signer's publisher profile (and its verified domain)
+        #[arg(long)]
+        with_profile: bool,
+    },
+    /// Publish a basket signal on several weighted tokens
+    PublishBasket {
+        #[arg(long)]
+        name: String,
+        /// MINT:WEIGHT_BPS:ENTRY_PRICE, repeated; weights must sum to 10000
+        #[arg(long = "member", value_parser = parse_member, required = true)]
+        members: Vec<BasketMemberParams>,
+    },
+    /// Close a basket with one exit price per member, in publish order
+    CloseBasket {
+        #[arg(long)]
+        id: u64,
+        #[arg(long = "exit-price", required = true)]
+        exit_prices: Vec<u64>,
+    },
+    /// Publish a pair signal: --long will outperform --short
+    PublishPair {
+        #[arg(long)]
+        long: Pubkey,
+        #[arg(long)]
+        long_entry_price: u64,
+        #[arg(long)]
+        short: Pubkey,
+        #[arg(long)]
+        short_entry_price: u64,
+    },
+    /// Close a pair signal at both legs' exit prices
+    ClosePair {
+        #[arg(long)]
+        id: u64,
+        #[arg(long)]
+        long_exit_price: u64,
+        #[arg(long)]
+        short_exit_price: u64,
+    },
+    /// Close a signal at an exit price
+    Close {
+        #[arg(long)]
+        id: u64,
+        #[arg(long)]
+        exit_price: u64,
+        /// Mint the exit price is quoted in
+        #[arg(long, default_value_t = USDC_MINT)]
+        quote_mint: Pubkey,
+        /// Show the resulting status, ROI and win rate without sending
+        #[arg(long)]
+        preview: bool,
+    },
+    /// Record a new best price for an open signal
+    Ath {
+        #[arg(long)]
+        id: u64,
+        #[arg(long)]
+        price: u64,
+        /// Mint the price is quoted in
+        #[arg(long, default_value_t = USDC_MINT)]
+        quote_mint: Pubkey,
+    },
+    /// Check a fresh signal's claimed mcap against mint supply times its verified pool's price
+    VerifyMcap {
+        #[arg(long)]
+        id: u64,
+    },
+    /// Snapshot the token supply of a fresh signal; the exit supply is taken as it settles
+    SnapshotSupply {
+        #[arg(long)]
+        id: u64,
+    },
+    /// Commit the hash of a fresh signal's top-holder snapshot
+    CommitHolders {
+        #[arg(long)]
+        id: u64,
+        /// Hex SHA256 of the canonical snapshot text
+        #[arg(long)]
+        snapshot_hash: String,
+        /// Share of supply the snapshotted holders held
+        #[arg(long)]
+        top_holders_bps: u16,
+    },
+    /// Record a further entry an open signal scaled into
+    AddEntry {
+        #[arg(long)]
+        id: u64,
+        #[arg(long)]
+        price: u64,
+        /// Share of the final position bought at this price
+        #[arg(long)]
+        weight_bps: u16,
+    },
+    /// Declare an open signal's entry as a DCA schedule
+    Dca {
+        #[arg(long)]
+        id: u64,
+        #[arg(long)]
+        tranches: u8,
+        /// Seconds between tranches
+        #[arg(long)]
+        interval_secs: i64,
+        /// Share of the position each tranche buys
+        #[arg(long)]
+        tranche_bps: u16,
+    },
+    /// Ladder an open signal's exit over take-profit levels
+    TakeProfits {
+        #[arg(long)]
+        id: u64,
+        /// PRICE:EXIT_BPS, repeated, nearest the entry first
+        #[arg(long = "level", value_parser = parse_take_profit, required = true)]
+        levels: Vec<TakeProfitParams>,
+    },
+    /// Settle a take-profit level against the signal's verified pool
+    SettleTakeProfit {
+        #[arg(long)]
+        id: u64,
+        #[arg(long)]
+        level: u8,
+    },
+    /// Set the price past which anyone may close a signal as a loss
+    Invalidation {
+        #[arg(long)]
+        id: u64,
+        #[arg(long)]
+        price: u64,
+    },
+    /// Close a signal whose verified pool price crossed its invalidation price
+    Invalidate {
+        #[arg(long)]
+        id: u64,
+    },
+    /// Mark open signals to their verified pools' prices
+    Mark {
+        /// Signal ids, repeated
+        #[arg(long = "id", required = true)]
+        ids: Vec<u64>,
+    },
+    /// Record the configured benchmarks' prices next to a signal and show its alpha
+    Benchmark {
+        #[arg(long)]
+        id: u64,
+    },
+    /// Start the model portfolio that follows every signal published from now on
+    InitPortfolio {
+        /// Virtual starting cash
+        #[arg(long)]
+        bankroll: u64,
+        #[arg(long)]
+        max_positions: u8,
+    },
+    /// Freeze your remaining subscription time while you're away
+    PauseSubscription,
+    /// Restart a paused subscription
+    ResumeSubscription,
+    /// Gift or sell your remaining subscription time to another wallet
+    TransferSubscription {
+        #[arg(long)]
+        to: Pubkey,
+    },
+    /// Restrict a subscription tier to allowlisted wallets, or change its capacity
+    SetAllowlist {
+        #[arg(long)]
+        tier: u8,
+        #[arg(long)]
+        capacity: u32,
+    },
+    /// Add a wallet to, or remove it from, an allowlisted tier
+    Allowlist {
+        #[arg(long)]
+        tier: u8,
+        #[arg(long)]
+        wallet: Pubkey,
+        #[arg(long)]
+        remove: bool,
+    },
+    /// Recommend perps leverage for an open signal (10000 = 1x, 0 clears)
+    /// and the Drift market the vault shorts it on
+    Leverage {
+        #[arg(long)]
+        id: u64,
+        #[arg(long)]
+        leverage_bps: u32,
+        #[arg(long, default_value_t = 0)]
+        market_index: u16,
+    },
+    /// Append a note to a signal, opening its notes account if needed
+    Note {
+        #[arg(long)]
+        id: u64,
+        #[arg(long)]
+        text: String,
+    },
+    /// Rate a closed signal 1-5 (needs an active subscription)
+    Rate {
+        #[arg(long)]
+        id: u64,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=5))]
+        rating: u8,
+    },
+    /// Record that you hold a signal now, before its reasoning is revealed (needs an active subscription)
+    ClaimAccess {
+        #[arg(long)]
+        id: u64,
+    },
+    /// Log a reasoning release to an institutional subscriber for their audit trail
+    LogAccess {
+        #[arg(long)]
+        id: u64,
+        /// Hex SHA256 identifying the subscriber, e.g. of their internal account id
+        #[arg(long)]
+        subscriber_hash: String,
+    },
+    /// Pay into the treasury to boost an open signal toward a featured slot
+    Boost {
+        #[arg(long)]
+        id: u64,
+        #[arg(long)]
+        lamports: u64,
+    },
+    /// Bid for a featured slot in the next auction epoch
+    Bid {
+        #[arg(long)]
+        id: u64,
+        #[arg(long)]
+        lamports: u64,
+    },
+    /// Install an auction epoch's winners once it has begun
+    SettleAuction {
+        #[arg(long)]
+        epoch: u64,
+    },
+    /// Show a signal's score decayed by its age
+    Score {
+        #[arg(long)]
+        id: u64,
+    },
+    /// Show the model portfolio's NAV, drawdown and open positions
+    Portfolio,
+    /// Show the oracle's track record
+    Stats {
+        /// Show this publisher's stats instead of the global counters
+        #[arg(long)]
+        publisher: Option<Pubkey>,
+    },
+    /// Pay for a subscription tier
+    Subscribe {
+        #[arg(long)]
+        tier: u8,
+        #[arg(long, default_value_t = 1)]
+        periods: u32,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let rpc = RpcClient::new_with_commitment(cli.url.clone(), CommitmentConfig::confirmed());
+
+    let output = match cli.command {
+        Command::Stats { publisher: None } => stats(&rpc).await?,
+        Command::Stats {
+            publisher: Some(wallet),
+        } => publisher_stats(&rpc, &wallet).await?,
+        Command::Score { id } => effective_score(&rpc, id).await?,
+        Command::Portfolio => portfolio(&rpc).await?,
+        Command::Close {
+            id,
+            exit_price,
+            preview: true,
+            ..
+        } => preview_close(&rpc, id, exit_price).await?,
+        ref command => {
+            let signer = signer::load_signer(&cli.keypair, cli.derivation_path.as_deref())?;
+            let fee_config = FeeConfig {
+                urgency: cli.urgency.into(),
+                ..FeeConfig::default()
+            };
+            run(&rpc, signer.as_ref(), &fee_config, command).await?
+        }
+    };
+
+    if cli.json {
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else if let Value::Object(fields) = output {
+        for (key, value) in fields {
+            match value {
+                Value::String(s) => println!("{key}: {s}"),
+                other => println!("{key}: {other}"),
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn run(rpc: &RpcClient, signer: &dyn Signer, fee_config: &FeeConfig, command: &Command) -> Result<Value> {
+    let authority = signer.pubkey();
+    match command {
+        Command::Publish {
+            token,
+            symbol,
+            score,
+            risk_level,
+            sources,
+            mcap,
+            entry_price,
+            short,
+            quote_mint,
+            reasoning_hash,
+            reasoning_file,
+            ipfs_api,
+            with_profile,
+            parent_signal,
+        } => {
+            let id = fetch::next_signal_id(rpc).await?;
+            let params = SignalParams {
+                token: *token,
+                symbol: symbol.clone(),
+                score: *score,
+                risk_level: *risk_level,
+                sources_bitmap: *sources,
+                mcap: *mcap,
+                entry_price: *entry_price,
+                direction: if *short { SignalDirection::Short } else { SignalDirection::Long },
+                quote_mint: *quote_mint,
+                parent_signal: parent_signal.map(pda::signal),
+            };
+            let rationale = match (reasoning_hash, reasoning_file) {
+                (Some(hash), _) => Some(Rationale {
+                    hash: parse_hash(hash)?,
+                    uri: None,
+                }),
+                (None, Some(path)) => {
+                    let document = std::fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+                    let name = path.file_name().map_or("rationale".into(), |name| name.to_string_lossy());
+                    let ipfs = ipfs_api.as_ref().map(|api| Ipfs::new(api, std::env::var("ORACLE_IPFS_TOKEN").ok()));
+                    Some(Rationale::prepare(&name, &document, ipfs.as_ref().map(|ipfs| ipfs as &dyn Pinner)).await?)
+                }
+                (None, None) => None,
+            };
+            let ix = match &rationale {
+                Some(rationale) => rationale.publish_instruction(&authority, id, *with_profile, params),
+                None => instructions::publish_signal(&authority, id, *with_profile, params),
+            };
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            let sizing = fetch::fetch_signal_sizing(rpc, id).await?;
+            let mut output = json!({
+                "signature": signature,
+                "id": id,
+                "signal": pda::signal(id).to_string(),
+                "size_bps": sizing.size_bps,
+            });
+            if let Some(rationale) = rationale {
+                output["reasoning_hash"] = json!(hex::encode(rationale.hash));
+                output["reasoning_uri"] = json!(rationale.uri);
+            }
+            Ok(output)
+        }
+        Command::Reopen {
+            parent,
+            symbol,
+            score,
+            risk_level,
+            sources,
+            mcap,
+            entry_price,
+            short,
+            quote_mint,
+            reasoning_hash,
+            with_profile,
+        } => {
+            let previous = fetch::fetch_signal(rpc, *parent).await?;
+            let quote_mint = match quote_mint {
+                Some(quote_mint) => *quote_mint,
+                None if previous.quote_mint != Pubkey::default() => previous.quote_mint,
+                None => USDC_MINT,
+            };
+            let id = fetch::next_signal_id(rpc).await?;
+            let params = SignalParams {
+                token: previous.token,
+                symbol: symbol.clone().unwrap_or(previous.symbol),
+                score: *score,
+                risk_level: *risk_level,
+                sources_bitmap: *sources,
+                mcap: *mcap,
+                entry_price: *entry_price,
+                direction: if *short { SignalDirection::Short } else { SignalDirection::Long },
+                quote_mint,
+                parent_signal: None,
+            };
+            let reasoning_hash = reasoning_hash.as_deref().map(parse_hash).transpose()?.unwrap_or_default();
+            let ix = instructions::reopen_signal(&authority, id, *with_profile, *parent, params, reasoning_hash);
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            let lineage = fetch::fetch_signal_lineage(rpc, id).await?;
+            Ok(json!({
+                "signature": signature,
+                "id": id,
+                "signal": pda::signal(id).to_string(),
+                "root": lineage.root.to_string(),
+                "attempt": lineage.attempt,
+                "prior_roi_bps": lineage.prior_roi_bps,
+            }))
+        }
+        Command::Close {
+            id,
+            exit_price,
+            quote_mint,
+            ..
+        } => {
+            let open = fetch::fetch_signal(rpc, *id).await?;
+            let has_snapshot = match fetch::fetch_supply_snapshot(rpc, *id).await {
+                Ok(_) => true,
+                Err(ClientError::AccountNotFound(_)) => false,
+                Err(err) => return Err(err.into()),
+            };
+            let signal = pda::signal(*id);
+            let ix = instructions::close_signal(
+                &authority,
+                &signal,
+                *exit_price,
+                quote_mint,
+                &open.publisher,
+                &open.token,
+                has_snapshot,
+            );
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            let signal = fetch::fetch_signal(rpc, *id).await?;
+            Ok(json!({
+                "signature": signature,
+                "id": id,
+                "status": format!("{:?}", signal.status),
+                "roi_bps": signal.roi_bps,
+                "net_roi_bps": signal.net_roi_bps,
+                "roi_annualized_bps": signal.roi_annualized_bps,
+            }))
+        }
+        Command::Note { id, text } => {
+            let signal = pda::signal(*id);
+            let mut ixs = Vec::new();
+            match fetch::fetch_signal_notes(rpc, *id).await {
+                Ok(_) => {}
+                Err(ClientError::AccountNotFound(_)) => ixs.push(instructions::init_signal_notes(&authority, &signal)),
+                Err(err) => return Err(err.into()),
+            }
+            ixs.push(instructions::append_signal_note(&authority, &signal, text.clone()));
+            let signature = send(rpc, signer, fee_config, ixs).await?;
+            let notes = fetch::fetch_signal_notes(rpc, *id).await?;
+            Ok(json!({ "signature": signature, "id": id, "notes": notes.notes.len() }))
+        }
+        Command::Rate { id, rating } => {
+            let ix = instructions::rate_signal(&authority, &pda::signal(*id), *rating);
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            let ratings = fetch::fetch_signal_rating(rpc, *id).await?;
+            Ok(json!({
+                "signature": signature,
+                "id": id,
+                "ratings": ratings.count,
+                "mean_rating": ratings.sum as f64 / ratings.count.max(1) as f64,
+            }))
+        }
+        Command::ClaimAccess { id } => {
+            let ix = instructions::claim_access(&authority, &pda::signal(*id));
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            let claim = fetch::fetch_access_claim(rpc, *id, &authority).await?;
+            Ok(json!({
+                "signature": signature,
+                "id": id,
+                "claim": pda::access_claim(&pda::signal(*id), &authority).to_string(),
+                "slot": claim.slot,
+                "timestamp": claim.timestamp,
+            }))
+        }
+        Command::LogAccess { id, subscriber_hash } => {
+            let subscriber_hash = parse_hash(subscriber_hash)?;
+            let ix = instructions::log_access(&authority, &pda::signal(*id), subscriber_hash);
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            Ok(json!({
+                "signature": signature,
+                "id": id,
+                "subscriber_hash": hex::encode(subscriber_hash),
+            }))
+        }
+        Command::PublishBasket { name, members } => {
+            let id = fetch::fetch_oracle_state(rpc).await?.total_baskets;
+            let ix = instructions::publish_basket(&authority, id, name.clone(), members.clone());
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            Ok(json!({ "signature": signature, "id": id, "basket": pda::basket(id).to_string() }))
+        }
+        Command::CloseBasket { id, exit_prices } => {
+            let ix = instructions::close_basket(&authority, *id, exit_prices.clone());
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            let basket = fetch::fetch_basket(rpc, *id).await?;
+            let members: Vec<Value> = basket
+                .members
+                .iter()
+                .map(|member| json!({ "token": member.token.to_string(), "roi_bps": member.roi_bps }))
+                .collect();
+            Ok(json!({
+                "signature": signature,
+                "id": id,
+                "status": format!("{:?}", basket.status),
+                "roi_bps": basket.roi_bps,
+                "members": members,
+            }))
+        }
+        Command::PublishPair {
+            long,
+            long_entry_price,
+            short,
+            short_entry_price,
+        } => {
+            let id = fetch::fetch_oracle_state(rpc).await?.total_pairs;
+            let (long, short) = ((*long, *long_entry_price), (*short, *short_entry_price));
+            let ix = instructions::publish_pair(&authority, id, long, short);
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            Ok(json!({ "signature": signature, "id": id, "pair": pda::pair_signal(id).to_string() }))
+        }
+        Command::ClosePair {
+            id,
+            long_exit_price,
+            short_exit_price,
+        } => {
+            let ix = instructions::close_pair(&authority, *id, *long_exit_price, *short_exit_price);
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            let pair = fetch::fetch_pair_signal(rpc, *id).await?;
+            Ok(json!({
+                "signature": signature,
+                "id": id,
+                "status": format!("{:?}", pair.status),
+                "roi_bps": pair.roi_bps,
+            }))
+        }
+        Command::Boost { id, lamports } => {
+            let ix = instructions::boost_signal(&authority, &pda::signal(*id), *lamports);
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            let boost = fetch::fetch_signal_boost(rpc, *id).await?;
+            let discovery = fetch::fetch_discovery(rpc).await?;
+            let signal = pda::signal(*id);
+            Ok(json!({
+                "signature": signature,
+                "id": id,
+                "heat": boost.heat,
+                "expires_at": boost.expires_at,
+                "featured": discovery.featured.iter().any(|slot| slot.signal == signal),
+            }))
+        }
+        Command::Bid { id, lamports } => {
+            let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?.as_secs() as i64;
+            let epoch = (now / oracle::AUCTION_EPOCH_SECS + 1) as u64;
+            let signal = pda::signal(*id);
+            let outbid = match fetch::fetch_auction(rpc, epoch).await {
+                Ok(auction) => outbid_bidder(&auction, &signal).filter(|bidder| *bidder != authority),
+                Err(ClientError::AccountNotFound(_)) => None,
+                Err(err) => return Err(err.into()),
+            };
+            let ix = instructions::bid_featured(&authority, &signal, epoch, *lamports, outbid);
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            Ok(json!({
+                "signature": signature,
+                "id": id,
+                "epoch": epoch,
+                "lamports": lamports,
+                "refunded": outbid.map(|bidder| bidder.to_string()),
+            }))
+        }
+        Command::SettleAuction { epoch } => {
+            let ix = instructions::settle_auction(&authority, *epoch);
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            let discovery = fetch::fetch_discovery(rpc).await?;
+            let featured: Vec<String> = discovery
+                .featured
+                .iter()
+                .filter(|slot| slot.signal != Pubkey::default())
+                .map(|slot| slot.signal.to_string())
+                .collect();
+            Ok(json!({ "signature": signature, "epoch": epoch, "featured": featured }))
+        }
+        Command::Ath { id, price, quote_mint } => {
+            let ix = instructions::update_ath(&authority, &pda::signal(*id), *price, quote_mint);
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            Ok(json!({ "signature": signature, "id": id, "price": price }))
+        }
+        Command::VerifyMcap { id } => {
+            let signal = fetch::fetch_signal(rpc, *id).await?;
+            if signal.price_pool == Pubkey::default() {
+                bail!("signal #{id} has no verified price pool");
+            }
+            let pool = prices::fetch_pool_accounts(rpc, &signal.price_pool).await?;
+            let ix = instructions::verify_market_cap(&authority, &pda::signal(*id), &pool);
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            let market_cap = fetch::fetch_signal_market_cap(rpc, *id).await?;
+            Ok(json!({
+                "signature": signature,
+                "id": id,
+                "claimed_mcap": market_cap.claimed_mcap,
+                "verified_mcap": market_cap.verified_mcap,
+                "quote_mint": market_cap.quote_mint.to_string(),
+                "discrepancy_bps": market_cap.discrepancy_bps,
+                "flagged": market_cap.flagged,
+            }))
+        }
+        Command::SnapshotSupply { id } => {
+            let signal = fetch::fetch_signal(rpc, *id).await?;
+            let ix = instructions::snapshot_entry_supply(&authority, &pda::signal(*id), &signal.token);
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            let snapshot = fetch::fetch_supply_snapshot(rpc, *id).await?;
+            Ok(json!({
+                "signature": signature,
+                "id": id,
+                "entry_supply": snapshot.entry_supply,
+                "exit_supply": snapshot.exit_supply,
+                "supply_change_bps": snapshot.supply_change_bps,
+                "flagged": snapshot.flagged,
+            }))
+        }
+        Command::CommitHolders {
+            id,
+            snapshot_hash,
+            top_holders_bps,
+        } => {
+            let snapshot_hash = parse_hash(snapshot_hash)?;
+            let signal = pda::signal(*id);
+            let ix = instructions::commit_holder_distribution(&authority, &signal, snapshot_hash, *top_holders_bps);
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            Ok(json!({
+                "signature": signature,
+                "id": id,
+                "snapshot_hash": hex::encode(snapshot_hash),
+                "top_holders_bps": top_holders_bps,
+            }))
+        }
+        Command::AddEntry { id, price, weight_bps } => {
+            let ix = instructions::add_entry(&authority, &pda::signal(*id), *price, *weight_bps);
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            let signal = fetch::fetch_signal(rpc, *id).await?;
+            Ok(json!({
+                "signature": signature,
+                "id": id,
+                "entry_price": signal.entry_price,
+                "ath_price": signal.ath_price,
+            }))
+        }
+        Command::Dca {
+            id,
+            tranches,
+            interval_secs,
+            tranche_bps,
+        } => {
+            let ix = instructions::set_dca_plan(&authority, &pda::signal(*id), *tranches, *interval_secs, *tranche_bps);
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            let plan = fetch::fetch_dca_plan(rpc, *id).await?;
+            Ok(json!({
+                "signature": signature,
+                "id": id,
+                "dca_plan": pda::dca_plan(&pda::signal(*id)).to_string(),
+                "start": plan.start,
+            }))
+        }
+        Command::TakeProfits { id, levels } => {
+            let ix = instructions::set_take_profits(&authority, &pda::signal(*id), levels.clone());
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            Ok(json!({
+                "signature": signature,
+                "id": id,
+                "ladder": pda::take_profit(&pda::signal(*id)).to_string(),
+            }))
+        }
+        Command::SettleTakeProfit { id, level } => {
+            let signal = fetch::fetch_signal(rpc, *id).await?;
+            if signal.price_pool == Pubkey::default() {
+                bail!("signal #{id} has no verified price pool");
+            }
+            let pool = prices::fetch_pool_accounts(rpc, &signal.price_pool).await?;
+            let address = pda::signal(*id);
+            let (publisher, token) = (signal.publisher, signal.token);
+            let ix = instructions::settle_take_profit(&authority, &address, &pool, *level, &publisher, &token);
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            let ladder = fetch::fetch_take_profit_ladder(rpc, *id).await?;
+            let signal = fetch::fetch_signal(rpc, *id).await?;
+            Ok(json!({
+                "signature": signature,
+                "id": id,
+                "level": level,
+                "realized_roi_bps": ladder.realized_roi_bps,
+                "exited_bps": ladder.exited_bps,
+                "status": format!("{:?}", signal.status),
+            }))
+        }
+        Command::Invalidation { id, price } => {
+            let ix = instructions::set_invalidation_price(&authority, &pda::signal(*id), *price);
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            Ok(json!({
+                "signature": signature,
+                "id": id,
+                "invalidation_price": price,
+            }))
+        }
+        Command::Invalidate { id } => {
+            let signal = fetch::fetch_signal(rpc, *id).await?;
+            if signal.price_pool == Pubkey::default() {
+                bail!("signal #{id} has no verified price pool");
+            }
+            let pool = prices::fetch_pool_accounts(rpc, &signal.price_pool).await?;
+            let address = pda::signal(*id);
+            let ix = instructions::invalidate_signal(&authority, &address, &pool, &signal.publisher, &signal.token);
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            let signal = fetch::fetch_signal(rpc, *id).await?;
+            Ok(json!({
+                "signature": signature,
+                "id": id,
+                "status": format!("{:?}", signal.status),
+                "exit_price": signal.exit_price,
+                "roi_bps": signal.roi_bps,
+                "net_roi_bps": signal.net_roi_bps,
+                "roi_annualized_bps": signal.roi_annualized_bps,
+            }))
+        }
+        Command::Mark { ids } => {
+            let mut marks = Vec::new();
+            for id in ids {
+                let signal = fetch::fetch_signal(rpc, *id).await?;
+                if signal.price_pool == Pubkey::default() {
+                    bail!("signal #{id} has no verified price pool");
+                }
+                marks.push((pda::signal(*id), prices::fetch_pool_accounts(rpc, &signal.price_pool).await?));
+            }
+            let signature = send(rpc, signer, fee_config, vec![instructions::mark_signals(&marks)]).await?;
+            let state = fetch::fetch_oracle_state(rpc).await?;
+            Ok(json!({
+                "signature": signature,
+                "ids": ids,
+                "unrealized_roi_bps": state.unrealized_roi_bps,
+            }))
+        }
+        Command::Benchmark { id } => {
+            let signal = fetch::fetch_signal(rpc, *id).await?;
+            let address = pda::signal(*id);
+            let recorded = match fetch::fetch_signal_benchmark(rpc, *id).await {
+                Ok(benchmark) => Some(benchmark),
+                Err(ClientError::AccountNotFound(_)) => None,
+                Err(err) => return Err(err.into()),
+            };
+            let at_exit = signal.status != SignalStatus::Open;
+            let mut ixs = Vec::new();
+            for (index, pool) in fetch::fetch_config(rpc).await?.benchmark_pools.iter().enumerate() {
+                let price = recorded.as_ref().map_or(0, |b| {
+                    if at_exit { b.exit_prices[index] } else { b.entry_prices[index] }
+                });
+                if *pool == Pubkey::default() || price != 0 {
+                    continue;
+                }
+                let pool = prices::fetch_pool_accounts(rpc, pool).await?;
+                ixs.push(instructions::record_benchmark(&authority, &address, index as u8, &pool));
+            }
+            if ixs.is_empty() {
+                bail!("no benchmark left to record for signal #{id}");
+            }
+            let signature = send(rpc, signer, fee_config, ixs).await?;
+            let benchmark = fetch::fetch_signal_benchmark(rpc, *id).await?;
+            let alpha: Vec<_> = benchmark
+                .entry_prices
+                .iter()
+                .zip(&benchmark.exit_prices)
+                .map(|(entry, exit)| math::alpha_bps(signal.roi_bps, *entry, *exit))
+                .collect();
+            Ok(json!({
+                "signature": signature,
+                "id": id,
+                "entry_prices": benchmark.entry_prices,
+                "exit_prices": benchmark.exit_prices,
+                "alpha_bps": alpha,
+            }))
+        }
+        Command::InitPortfolio { bankroll, max_positions } => {
+            let ix = instructions::init_model_portfolio(&authority, *bankroll, *max_positions);
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            Ok(json!({
+                "signature": signature,
+                "portfolio": pda::model_portfolio().to_string(),
+                "first_signal": fetch::fetch_model_portfolio(rpc).await?.next_id,
+            }))
+        }
+        Command::PauseSubscription | Command::ResumeSubscription => {
+            // Once passes exist, pausing burns the pass and resuming mints it back
+            let with_pass = rpc.get_account(&pda::pass_mint()).await.is_ok();
+            let ix = if matches!(command, Command::PauseSubscription) {
+                instructions::pause_subscription(&authority, with_pass)
+            } else {
+                instructions::resume_subscription(&authority, with_pass)
+            };
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            let subscription = fetch::fetch_subscription(rpc, &authority).await?;
+            Ok(json!({
+                "signature": signature,
+                "paused": subscription.paused_at != 0,
+                "expires_at": subscription.expires_at,
+                "paused_remaining_secs": subscription.paused_remaining_secs,
+                "pauses": subscription.pauses,
+            }))
+        }
+        Command::TransferSubscription { to } => {
+            let tier = fetch::fetch_subscription(rpc, &authority).await?.tier;
+            let allowlisted = match fetch::fetch_tier_allowlist(rpc, tier).await {
+                Ok(_) => true,
+                Err(ClientError::AccountNotFound(_)) => false,
+                Err(err) => return Err(err.into()),
+            };
+            // Once passes exist, the owner's pass is burned and the recipient's minted
+            let with_pass = rpc.get_account(&pda::pass_mint()).await.is_ok();
+            let mut ixs = Vec::new();
+            if with_pass {
+                ixs.push(instructions::create_subscriber_pass_account(&authority, to));
+            }
+            ixs.push(instructions::transfer_subscription(&authority, to, tier, allowlisted, with_pass));
+            let signature = send(rpc, signer, fee_config, ixs).await?;
+            let subscription = fetch::fetch_subscription(rpc, to).await?;
+            Ok(json!({
+                "signature": signature,
+                "to": to.to_string(),
+                "tier": subscription.tier,
+                "expires_at": subscription.expires_at,
+                "transferable_at": subscription.transferable_at,
+            }))
+        }
+        Command::SetAllowlist { tier, capacity } => {
+            let ix = instructions::set_tier_allowlist(&authority, *tier, *capacity);
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            let allowlist = fetch::fetch_tier_allowlist(rpc, *tier).await?;
+            Ok(json!({
+                "signature": signature,
+                "tier": tier,
+                "capacity": allowlist.capacity,
+                "count": allowlist.count,
+            }))
+        }
+        Command::Allowlist { tier, wallet, remove } => {
+            let ix = if *remove {
+                instructions::allowlist_remove(&authority, *tier, *wallet)
+            } else {
+                instructions::allowlist_add(&authority, *tier, *wallet)
+            };
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            let allowlist = fetch::fetch_tier_allowlist(rpc, *tier).await?;
+            Ok(json!({
+                "signature": signature,
+                "tier": tier,
+                "wallet": wallet.to_string(),
+                "allowlisted": !remove,
+                "count": allowlist.count,
+            }))
+        }
+        Command::Leverage {
+            id,
+            leverage_bps,
+            market_index,
+        } => {
+            let signal = pda::signal(*id);
+            let ix = instructions::set_perp_params(&authority, &signal, *leverage_bps, *market_index);
+            let signature = send(rpc, signer, fee_config, vec![ix]).await?;
+            let params = fetch::fetch_perp_params(rpc, &signal).await?;
+            Ok(json!({
+                "signature": signature,
+                "id": id,
+                "leverage_bps": params.leverage_bps,
+                "market_index": params.market_index,
+                "liquidation_price": params.liquidation_price,
+            }))
+        }
+        Command::Subscribe { tier, periods } => {
+            let allowlisted = match fetch::fetch_tier_allowlist(rpc, *tier).await {
+                Ok(_) => true,
+                Err(ClientError::AccountNotFound(_)) => false,
+                Err(err) => return Err(err.into()),
+            };
+            // Once passes exist, subscribing mints one into the subscriber's pass account
+            let with_pass = rpc.get_account(&pda::pass_mint()).await.is_ok();
+            let mut ixs = Vec::new();
+            if with_pass {
+                ixs.push(instructions::create_subscriber_pass_account(&authority, &authority));
+            }
+            ixs.push(instructions::subscribe(&authority, *tier, *periods, allowlisted, with_pass));
+            let signature = send(rpc, signer, fee_config, ixs).await?;
+            let subscription = fetch::fetch_subscription(rpc, &authority).await?;
+            Ok(json!({
+                "signature": signature,
+                "tier": subscription.tier,
+                "expires_at": subscription.expires_at,
+            }))
+        }
+        Command::Stats { .. } | Command::Score { .. } | Command::Portfolio => {
+            unreachable!("read-only commands need no signer")
+        }
+    }
+}
+
+/// Bidder a new bid on `signal` would displace, mirroring
+/// `FeaturedAuction::place`
+fn outbid_bidder(auction: &FeaturedAuction, signal: &Pubkey) -> Option<Pubkey> {
+    let bids = &auction.bids;
+    let bid = bids
+        .iter()
+        .find(|bid| bid.lamports > 0 && bid.signal == *signal)
+        .or_else(|| bids.iter().find(|bid| bid.lamports == 0))
+        .or_else(|| bids.iter().min_by_key(|bid| bid.lamports))?;
+    (bid.lamports > 0).then_some(bid.bidder)
+}
+
+async fn stats(rpc: &RpcClient) -> Result<Value> {
+    let state = fetch::fetch_oracle_state(rpc).await?;
+    let moments = math::RoiMoments {
+        count: state.roi_moments.count,
+        mean: state.roi_moments.mean,
+        m2: state.roi_moments.m2,
+    };
+    let annualized = math::RoiMoments {
+        count: state.roi_annualized_moments.count,
+        mean: state.roi_annualized_moments.mean,
+        m2: state.roi_annualized_moments.m2,
+    };
+    Ok(json!({
+        "authority": state.authority.to_string(),
+        "total_signals": state.total_signals,
+        "total_wins": state.total_wins,
+        "total_losses": state.total_losses,
+        "win_rate_bps": math::win_rate_bps(state.total_wins, state.total_losses),
+        "win_streak": state.streaks.win,
+        "loss_streak": state.streaks.loss,
+        "max_win_streak": state.streaks.max_win,
+        "max_loss_streak": state.streaks.max_loss,
+        "mean_roi_bps": moments.mean_bps(),
+        "roi_stddev_bps": moments.stddev_bps(),
+        "sharpe_bps": moments.sharpe_bps(),
+        "mean_roi_annualized_bps": annualized.mean_bps(),
+        "median_roi_bps": math::percentile_bucket(&state.roi_histogram, 5_000).map(bucket_range),
+        "p90_roi_bps": math::percentile_bucket(&state.roi_histogram, 9_000).map(bucket_range),
+        "roi_histogram": state.roi_histogram,
+        "unrealized_roi_bps": state.unrealized_roi_bps,
+        "marked_at": state.marked_at,
+        "performance_index": state.performance_index,
+    }))
+}
+
+async fn portfolio(rpc: &RpcClient) -> Result<Value> {
+    let portfolio = fetch::fetch_model_portfolio(rpc).await?;
+    let committed: u64 = portfolio.positions.iter().map(|position| position.size).sum();
+    let positions: Vec<Value> = portfolio
+        .positions
+        .iter()
+        .map(|position| json!({ "signal": position.signal.to_string(), "size": position.size }))
+        .collect();
+    Ok(json!({
+        "nav": portfolio.nav,
+        "cash": portfolio.cash,
+        "peak_nav": portfolio.peak_nav,
+        "max_drawdown_bps": portfolio.max_drawdown_bps,
+        "exposure_bps": (committed as u128 * 10_000 / portfolio.nav.max(1) as u128) as u64,
+        "max_positions": portfolio.max_positions,
+        "positions": positions,
+        "next_id": portfolio.next_id,
+        "skipped": portfolio.skipped,
+    }))
+}
+
+/// ROI range of a histogram bucket, open-ended at both extremes
+fn bucket_range(bucket: usize) -> String {
+    let floors = math::ROI_BUCKET_FLOORS_BPS;
+    match (bucket, floors.get(bucket + 1)) {
+        (0, Some(ceiling)) => format!("..{ceiling}"),
+        (_, Some(ceiling)) => format!("{}..{ceiling}", floors[bucket]),
+        (_, None) => format!("{}..", floors[bucket]),
+    }
+}
+
+async fn effective_score(rpc: &RpcClient, id: u64) -> Result<Value> {
+    let config = fetch::fetch_config(rpc).await?;
+    let signal = fetch::fetch_signal(rpc, id).await?;
+    let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?.as_secs() as i64;
+    let age = now - signal.timestamp;
+    Ok(json!({
+        "id": id,
+        "score": signal.score,
+        "age_secs": age,
+        "half_life_secs": config.score_half_life_secs,
+        "effective_score": math::effective_score(signal.score, age, config.score_half_life_secs),
+    }))
+}
+
+async fn publisher_stats(rpc: &RpcClient, wallet: &Pubkey) -> Result<Value> {
+    let stats = fetch::fetch_publisher_stats(rpc, wallet).await?;
+    Ok(json!({
+        "publisher": wallet.to_string(),
+        "signals": stats.signals,
+        "wins": stats.wins,
+        "losses": stats.losses,
+        "win_rate_bps": math::win_rate_bps(stats.wins, stats.losses),
+        "cumulative_roi_bps": stats.cumulative_roi_bps,
+        "cumulative_roi_annualized_bps": stats.cumulative_roi_annualized_bps,
+        "last_active": stats.last_active,
+        "win_streak": stats.streaks.win,
+        "loss_streak": stats.streaks.loss,
+        "max_win_streak": stats.streaks.max_win,
+        "max_loss_streak": stats.streaks.max_loss,
+    }))
+}
+
+async fn preview_close(rpc: &RpcClient, id: u64, exit_price: u64) -> Result<Value> {
+    let state = fetch::fetch_oracle_state(rpc).await?;
+    let preview = preview::fetch_preview_close(rpc, id, exit_price).await?;
+    let (win_rate_before, win_rate_after) = preview.win_rate_bps(&state);
+    Ok(json!({
+        "id": id,
+        "exit_price": exit_price,
+        "status": format!("{:?}", preview.status),
+        "roi_bps": preview.roi_bps,
+        "net_roi_bps": preview.net_roi_bps,
+        "win_rate_bps_before": win_rate_before,
+        "win_rate_bps_after": win_rate_after,
+    }))
+}
+
+/// Price, sign and confirm `instructions`, returning the signature.
+/// Retries re-sign only once the previous attempt has expired, so a slow
+/// publish is never sent twice.
+async fn send(rpc: &RpcClient, signer: &dyn Signer, fee_config: &FeeConfig, instructions: Vec<Instruction>) -> Result<String> {
+    let config = SubmitConfig {
+        fee: *fee_config,
+        ..SubmitConfig::default()
+    };
+    let submitted = Submitter::new(rpc, config)
+        .submit("oracle-cli", &signer.pubkey(), &[signer], &instructions)
+        .await
+        .context("transaction failed")?;
+    Ok(submitted.signature.to_string())
+}
+
+fn parse_member(member: &str) -> Result<BasketMemberParams> {
+    let [token, weight, price] = member.split(':').collect::<Vec<_>>()[..] else {
+        bail!("basket member must be MINT:WEIGHT_BPS:ENTRY_PRICE");
+    };
+    Ok(BasketMemberParams {
+        token: token.parse().context("invalid member mint")?,
+        weight_bps: weight.parse().context("invalid member weight")?,
+        entry_price: price.parse().context("invalid member entry price")?,
+    })
+}
+
+fn parse_take_profit(level: &str) -> Result<TakeProfitParams> {
+    let Some((price, exit_bps)) = level.split_once(':') else {
+        bail!("take-profit level must be PRICE:EXIT_BPS");
+    };
+    Ok(TakeProfitParams {
+        price: price.parse().context("invalid take-profit price")?,
+        exit_bps: exit_bps.parse().context("invalid take-profit exit share")?,
+    })
+}
+
+fn parse_hash(hex_hash: &str) -> Result<[u8; 32]> {
+    let bytes = hex::decode(hex_hash.trim_start_matches("0x")).context("hash is not hex")?;
+    match <[u8; 32]>::try_from(bytes) {
+        Ok(hash) => Ok(hash),
+        Err(_) => bail!("hash must be 32 bytes"),
+    }
+}
@@ -0,0 +1,54 @@
+//! Model attestations.
+//!
+//! An attestation is an Ed25519 signature by the scoring model's key over
+//! a signal's contents, showing the signal came out of the model rather
+//! than being typed in by hand. It travels with the publish transaction as
+//! an Ed25519 precompile instruction ([`verify_instruction`]), so the
+//! runtime checks the signature and anyone reading the transaction can see
+//! which key attested what.
+//!
+//! The attestation key is kept out of the publisher: `oracle-attest` holds
+//! it and signs [`message`]s on request.
+
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+
+use crate::instructions::SignalParams;
+
+/// Prefix of every attestation message, so the key's signatures can't be
+/// replayed as anything else
+pub const DOMAIN: &[u8] = b"oracle-alpha:attestation:v1";
+
+/// Bytes the attestation key signs for signal `signal_id`.
+///
+/// Layout (integers little-endian): domain, signal id, token, symbol
+/// (u32 length + UTF-8), score, risk level, sources bitmap, mcap, entry
+/// price, direction, reasoning hash, quote mint.
+pub fn message(signal_id: u64, params: &SignalParams, reasoning_hash: &[u8; 32]) -> Vec<u8> {
+    let mut message = Vec::with_capacity(DOMAIN.len() + 128 + params.symbol.len());
+    message.extend_from_slice(DOMAIN);
+    message.extend_from_slice(&signal_id.to_le_bytes());
+    message.extend_from_slice(params.token.as_ref());
+    message.extend_from_slice(&(params.symbol.len() as u32).to_le_bytes());
+    message.extend_from_slice(params.symbol.as_bytes());
+    message.extend_from_slice(&[params.score, params.risk_level, params.sources_bitmap]);
+    message.extend_from_slice(&params.mcap.to_le_bytes());
+    message.extend_from_slice(&params.entry_price.to_le_bytes());
+    message.push(params.direction as u8);
+    message.extend_from_slice(reasoning_hash);
+    message.extend_from_slice(params.quote_mint.as_ref());
+    message
+}
+
+/// Ed25519 precompile instruction checking `signature` by `attester`
+/// over `message`; place it before the publish instruction
+pub fn verify_instruction(attester: &Pubkey, signature: &Signature, message: &[u8]) -> Instruction {
+    let signature: [u8; 64] = (*signature).into();
+    solana_ed25519_program::new_ed25519_instruction_with_signature(message, &signature, &attester.to_bytes())
+}
+
+/// Check an attestation off-chain
+pub fn verify(attester: &Pubkey, signature: &Signature, message: &[u8]) -> bool {
+    signature.verify(attester.as_ref(), message)
+}
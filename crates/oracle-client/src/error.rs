@@ -0,0 +1,70 @@
+use solana_pubsub_client::nonblocking::pubsub_client::PubsubClientError;
+use solana_rpc_client_api::client_error::Error as RpcError;
+use solana_rpc_client_nonce_utils::Error as NonceError;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::message::CompileError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::signer::SignerError;
+use solana_sdk::transaction::TransactionError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ClientError {
+    #[error("rpc error: {0}")]
+    Rpc(#[from] Box<RpcError>),
+    #[error("websocket error: {0}")]
+    Pubsub(#[from] Box<PubsubClientError>),
+    #[error("nonce account error: {0}")]
+    Nonce(#[from] NonceError),
+    #[error("signing failed: {0}")]
+    Signing(SignerError),
+    #[error("failed to compile message: {0}")]
+    Compile(#[from] CompileError),
+    #[error("account {0} not found")]
+    AccountNotFound(Pubkey),
+    #[error("account {0} is not an address lookup table")]
+    InvalidLookupTable(Pubkey),
+    #[error("account {0} could not be decoded")]
+    InvalidAccountData(Pubkey),
+    #[error("transaction {signature} failed: {err}")]
+    TransactionFailed { signature: Signature, err: TransactionError },
+    #[error("signal #{0} is already closed")]
+    SignalClosed(u64),
+    #[error("blockhash expired before the transaction landed")]
+    Expired,
+    #[error("upload failed: {0}")]
+    Upload(String),
+    #[error("program error: {0}")]
+    Program(#[from] anchor_lang::error::Error),
+}
+
+impl ClientError {
+    /// Whether the program rejected the transaction (or its simulation)
+    /// with `error`
+    pub fn is_oracle_error(&self, error: oracle::OracleError) -> bool {
+        let err = match self {
+            ClientError::TransactionFailed { err, .. } => Some(err.clone()),
+            ClientError::Rpc(err) => err.get_transaction_error(),
+            _ => None,
+        };
+        matches!(
+            err,
+            Some(TransactionError::InstructionError(_, InstructionError::Custom(code))) if code == u32::from(error)
+        )
+    }
+}
+
+impl From<RpcError> for ClientError {
+    fn from(err: RpcError) -> Self {
+        ClientError::Rpc(Box::new(err))
+    }
+}
+
+impl From<PubsubClientError> for ClientError {
+    fn from(err: PubsubClientError) -> Self {
+        ClientError::Pubsub(Box::new(err))
+    }
+}
+
+pub type Result<T> = std::result::Result<T, ClientError>;
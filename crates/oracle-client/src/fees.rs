@@ -0,0 +1,118 @@
+//! Priority-fee estimation.
+//!
+//! Every publish write-locks the oracle state PDA, so under congestion the
+//! fee that lands a transaction is set by recent bids on the accounts it
+//! locks, not by the global fee market. Estimates sample
+//! `getRecentPrioritizationFees` for exactly those accounts.
+
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::Result;
+
+/// Default compute budget for a single oracle instruction plus overhead
+pub const DEFAULT_COMPUTE_UNIT_LIMIT: u32 = 200_000;
+
+/// How aggressively to bid against recent fees
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Urgency {
+    Low,
+    #[default]
+    Normal,
+    High,
+    Urgent,
+}
+
+impl Urgency {
+    /// Percentile of recent per-slot fees to match
+    pub fn percentile(self) -> u64 {
+        match self {
+            Urgency::Low => 25,
+            Urgency::Normal => 50,
+            Urgency::High => 75,
+            Urgency::Urgent => 95,
+        }
+    }
+}
+
+/// Fee policy applied when building transactions
+#[derive(Debug, Clone, Copy)]
+pub struct FeeConfig {
+    pub urgency: Urgency,
+    pub compute_unit_limit: u32,
+    pub min_micro_lamports: u64, // Floor on the compute-unit price
+    pub max_micro_lamports: u64, // Cap so a fee spike can't drain the payer
+}
+
+impl Default for FeeConfig {
+    fn default() -> Self {
+        FeeConfig {
+            urgency: Urgency::Normal,
+            compute_unit_limit: DEFAULT_COMPUTE_UNIT_LIMIT,
+            min_micro_lamports: 1_000,
+            max_micro_lamports: 5_000_000,
+        }
+    }
+}
+
+/// Writable accounts of `instructions`, deduplicated
+pub fn write_locked_accounts(instructions: &[Instruction]) -> Vec<Pubkey> {
+    let mut accounts: Vec<Pubkey> = instructions
+        .iter()
+        .flat_map(|ix| ix.accounts.iter())
+        .filter(|meta| meta.is_writable)
+        .map(|meta| meta.pubkey)
+        .collect();
+    accounts.sort();
+    accounts.dedup();
+    accounts
+}
+
+/// `pct`-th percentile of `fees` (nearest rank), 0 when there are no samples
+pub fn percentile(fees: &mut [u64], pct: u64) -> u64 {
+    if fees.is_empty() {
+        return 0;
+    }
+    fees.sort_unstable();
+    let rank = (pct.min(100) as usize * fees.len()).div_ceil(100);
+    fees[rank.saturating_sub(1)]
+}
+
+/// Compute-unit price (micro-lamports) recently paid to lock `write_accounts`
+pub async fn estimate_unit_price(
+    rpc: &RpcClient,
+    write_accounts: &[Pubkey],
+    urgency: Urgency,
+) -> Result<u64> {
+    let mut fees: Vec<u64> = rpc
+        .get_recent_prioritization_fees(write_accounts)
+        .await?
+        .into_iter()
+        .map(|fee| fee.prioritization_fee)
+        .collect();
+    Ok(percentile(&mut fees, urgency.percentile()))
+}
+
+/// Compute-budget instructions setting `unit_limit` and `unit_price`
+pub fn compute_budget_instructions(unit_limit: u32, unit_price: u64) -> [Instruction; 2] {
+    [
+        ComputeBudgetInstruction::set_compute_unit_limit(unit_limit),
+        ComputeBudgetInstruction::set_compute_unit_price(unit_price),
+    ]
+}
+
+/// Prepend compute-budget instructions priced for the accounts `instructions` lock
+pub async fn with_priority_fee(
+    rpc: &RpcClient,
+    config: &FeeConfig,
+    instructions: Vec<Instruction>,
+) -> Result<Vec<Instruction>> {
+    let estimate = estimate_unit_price(rpc, &write_locked_accounts(&instructions), config.urgency).await?;
+    let unit_price = estimate.clamp(config.min_micro_lamports, config.max_micro_lamports);
+
+    let mut out = compute_budget_instructions(config.compute_unit_limit, unit_price).to_vec();
+    out.extend(instructions);
+    Ok(out)
+}
@@ -0,0 +1,207 @@
+//! Account fetching and decoding.
+
+use borsh::BorshDeserialize;
+use oracle_types::{
+    decode_account, AccessClaim, Basket, DcaPlan, Discovery, Discriminator, ExecutionTicket, FeaturedAuction,
+    HolderCommitment, Invalidation, ModelPortfolio, OracleConfig, OracleState, PairSignal, PerpParams, Publisher,
+    PublisherStats, Signal, SignalBenchmark, SignalBoost, SignalEntries, SignalLineage, SignalMarketCap, SignalNotes,
+    SignalRating, SignalSizing, Subscription, SupplySnapshot, TakeProfitLadder, TierAllowlist, TokenHistory, Vault,
+    VaultDepositor, VaultEpoch, VaultPosition,
+};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{pda, ClientError, Result};
+
+/// Decode `data` of the account at `address`
+pub fn decode<T: Discriminator + BorshDeserialize>(address: &Pubkey, data: &[u8]) -> Result<T> {
+    decode_account(data).ok_or(ClientError::InvalidAccountData(*address))
+}
+
+/// Fetch and decode the account at `address`
+pub async fn fetch<T: Discriminator + BorshDeserialize>(rpc: &RpcClient, address: &Pubkey) -> Result<T> {
+    let account = rpc
+        .get_account_with_commitment(address, rpc.commitment())
+        .await?
+        .value
+        .ok_or(ClientError::AccountNotFound(*address))?;
+    decode(address, &account.data)
+}
+
+pub async fn fetch_oracle_state(rpc: &RpcClient) -> Result<OracleState> {
+    fetch(rpc, &pda::oracle_state()).await
+}
+
+pub async fn fetch_config(rpc: &RpcClient) -> Result<OracleConfig> {
+    fetch(rpc, &pda::config()).await
+}
+
+pub async fn fetch_signal(rpc: &RpcClient, id: u64) -> Result<Signal> {
+    fetch(rpc, &pda::signal(id)).await
+}
+
+pub async fn fetch_basket(rpc: &RpcClient, id: u64) -> Result<Basket> {
+    fetch(rpc, &pda::basket(id)).await
+}
+
+pub async fn fetch_pair_signal(rpc: &RpcClient, id: u64) -> Result<PairSignal> {
+    fetch(rpc, &pda::pair_signal(id)).await
+}
+
+/// Id the next published signal will get
+pub async fn next_signal_id(rpc: &RpcClient) -> Result<u64> {
+    Ok(fetch_oracle_state(rpc).await?.total_signals)
+}
+
+pub async fn fetch_vault(rpc: &RpcClient) -> Result<Vault> {
+    fetch(rpc, &pda::vault()).await
+}
+
+pub async fn fetch_position(rpc: &RpcClient, signal: &Pubkey) -> Result<VaultPosition> {
+    fetch(rpc, &pda::position(signal)).await
+}
+
+pub async fn fetch_ticket(rpc: &RpcClient, signal: &Pubkey) -> Result<ExecutionTicket> {
+    fetch(rpc, &pda::ticket(signal)).await
+}
+
+pub async fn fetch_perp_params(rpc: &RpcClient, signal: &Pubkey) -> Result<PerpParams> {
+    fetch(rpc, &pda::perp_params(signal)).await
+}
+
+/// `owner`'s vault shares and queued flows
+pub async fn fetch_vault_depositor(rpc: &RpcClient, owner: &Pubkey) -> Result<VaultDepositor> {
+    fetch(rpc, &pda::vault_depositor(owner)).await
+}
+
+/// How processed vault epoch `epoch` priced its flows
+pub async fn fetch_vault_epoch(rpc: &RpcClient, epoch: u64) -> Result<VaultEpoch> {
+    fetch(rpc, &pda::vault_epoch(epoch)).await
+}
+
+pub async fn fetch_subscription(rpc: &RpcClient, subscriber: &Pubkey) -> Result<Subscription> {
+    fetch(rpc, &pda::subscription(subscriber)).await
+}
+
+/// Allowlist of `tier`, if it is in allowlist mode
+pub async fn fetch_tier_allowlist(rpc: &RpcClient, tier: u8) -> Result<TierAllowlist> {
+    fetch(rpc, &pda::tier_allowlist(tier)).await
+}
+
+pub async fn fetch_publisher(rpc: &RpcClient, wallet: &Pubkey) -> Result<Publisher> {
+    fetch(rpc, &pda::publisher(wallet)).await
+}
+
+/// Position sizing suggested for signal `id` at publish
+pub async fn fetch_signal_sizing(rpc: &RpcClient, id: u64) -> Result<SignalSizing> {
+    fetch(rpc, &pda::signal_sizing(&pda::signal(id))).await
+}
+
+/// Claimed and verified market cap of signal `id`
+pub async fn fetch_signal_market_cap(rpc: &RpcClient, id: u64) -> Result<SignalMarketCap> {
+    fetch(rpc, &pda::signal_market_cap(&pda::signal(id))).await
+}
+
+/// Mint supply at the open and close of signal `id`
+pub async fn fetch_supply_snapshot(rpc: &RpcClient, id: u64) -> Result<SupplySnapshot> {
+    fetch(rpc, &pda::supply_snapshot(&pda::signal(id))).await
+}
+
+/// Top-holder distribution committed for signal `id`
+pub async fn fetch_holder_commitment(rpc: &RpcClient, id: u64) -> Result<HolderCommitment> {
+    fetch(rpc, &pda::holder_commitment(&pda::signal(id))).await
+}
+
+/// Last close of any signal on `token`
+pub async fn fetch_token_history(rpc: &RpcClient, token: &Pubkey) -> Result<TokenHistory> {
+    fetch(rpc, &pda::token_history(token)).await
+}
+
+/// Chain of attempts signal `id` reopened, if it was a reopen
+pub async fn fetch_signal_lineage(rpc: &RpcClient, id: u64) -> Result<SignalLineage> {
+    fetch(rpc, &pda::signal_lineage(&pda::signal(id))).await
+}
+
+/// Entries signal `id` scaled into after publish, if it added any
+pub async fn fetch_signal_entries(rpc: &RpcClient, id: u64) -> Result<SignalEntries> {
+    fetch(rpc, &pda::signal_entries(&pda::signal(id))).await
+}
+
+/// DCA plan of signal `id`, if it declared one
+pub async fn fetch_dca_plan(rpc: &RpcClient, id: u64) -> Result<DcaPlan> {
+    fetch(rpc, &pda::dca_plan(&pda::signal(id))).await
+}
+
+/// Take-profit ladder of signal `id`, if it set one
+pub async fn fetch_take_profit_ladder(rpc: &RpcClient, id: u64) -> Result<TakeProfitLadder> {
+    fetch(rpc, &pda::take_profit(&pda::signal(id))).await
+}
+
+/// Invalidation price of signal `id`, if it set one
+pub async fn fetch_invalidation(rpc: &RpcClient, id: u64) -> Result<Invalidation> {
+    fetch(rpc, &pda::invalidation(&pda::signal(id))).await
+}
+
+/// The model portfolio, if one was started
+pub async fn fetch_model_portfolio(rpc: &RpcClient) -> Result<ModelPortfolio> {
+    fetch(rpc, &pda::model_portfolio()).await
+}
+
+/// Benchmark prices recorded next to signal `id`
+pub async fn fetch_signal_benchmark(rpc: &RpcClient, id: u64) -> Result<SignalBenchmark> {
+    fetch(rpc, &pda::signal_benchmark(&pda::signal(id))).await
+}
+
+/// `subscriber`'s access claim on signal `id`, if they made one
+pub async fn fetch_access_claim(rpc: &RpcClient, id: u64, subscriber: &Pubkey) -> Result<AccessClaim> {
+    fetch(rpc, &pda::access_claim(&pda::signal(id), subscriber)).await
+}
+
+/// Notes appended to signal `id`
+pub async fn fetch_signal_notes(rpc: &RpcClient, id: u64) -> Result<SignalNotes> {
+    fetch(rpc, &pda::signal_notes(&pda::signal(id))).await
+}
+
+/// Subscriber ratings of signal `id`
+pub async fn fetch_signal_rating(rpc: &RpcClient, id: u64) -> Result<SignalRating> {
+    fetch(rpc, &pda::signal_rating(&pda::signal(id))).await
+}
+
+/// Paid boosts of signal `id`
+pub async fn fetch_signal_boost(rpc: &RpcClient, id: u64) -> Result<SignalBoost> {
+    fetch(rpc, &pda::signal_boost(&pda::signal(id))).await
+}
+
+pub async fn fetch_discovery(rpc: &RpcClient) -> Result<Discovery> {
+    fetch(rpc, &pda::discovery()).await
+}
+
+/// Featured-slot auction of `epoch`
+pub async fn fetch_auction(rpc: &RpcClient, epoch: u64) -> Result<FeaturedAuction> {
+    fetch(rpc, &pda::auction(epoch)).await
+}
+
+pub async fn fetch_publisher_stats(rpc: &RpcClient, wallet: &Pubkey) -> Result<PublisherStats> {
+    fetch(rpc, &pda::publisher_stats(wallet)).await
+}
+
+/// Offset of `amount` in an SPL Token / Token-2022 token account
+const TOKEN_AMOUNT_OFFSET: usize = 64;
+
+/// Largest batch `getMultipleAccounts` accepts
+pub(crate) const MULTIPLE_ACCOUNTS_LIMIT: usize = 100;
+
+/// Subscriber-pass balance of each subscriber, 0 where the account is missing
+pub async fn fetch_pass_balances(rpc: &RpcClient, subscribers: &[Pubkey]) -> Result<Vec<u64>> {
+    let mut balances = Vec::with_capacity(subscribers.len());
+    for chunk in subscribers.chunks(MULTIPLE_ACCOUNTS_LIMIT) {
+        let passes: Vec<Pubkey> = chunk.iter().map(pda::subscriber_pass).collect();
+        for account in rpc.get_multiple_accounts(&passes).await? {
+            let amount = account
+                .and_then(|account| <[u8; 8]>::try_from(account.data.get(TOKEN_AMOUNT_OFFSET..TOKEN_AMOUNT_OFFSET + 8)?).ok())
+                .map_or(0, u64::from_le_bytes);
+            balances.push(amount);
+        }
+    }
+    Ok(balances)
+}
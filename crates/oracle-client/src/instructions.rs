@@ -0,0 +1,1683 @@
+//! Typed instruction builders.
+//!
+//! Each builder fills in the PDAs and program ids an instruction needs and
+//! encodes its arguments with the program's own (Anchor-generated) account
+//! and instruction types, so layouts cannot drift from the program.
+
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use oracle::automation::ThreadInstruction;
+use oracle::{
+    accounts, instruction, BasketMemberParams, ConfigUpdate, ExecutionSide, Signal, SignalDirection, TakeProfitParams,
+};
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{pda, PROGRAM_ID};
+
+/// Fields shared by every publish instruction
+#[derive(Debug, Clone)]
+pub struct SignalParams {
+    pub token: Pubkey,
+    pub symbol: String,
+    pub score: u8,
+    pub risk_level: u8,
+    pub sources_bitmap: u8,
+    pub mcap: u64,
+    pub entry_price: u64,
+    pub direction: SignalDirection,
+    /// Mint the entry price is quoted in; later ATH and exit prices must be
+    /// quoted in it too
+    pub quote_mint: Pubkey,
+    /// Closed signal on the same token this one follows up on, letting it
+    /// through the republish cooldown (account-stored signals only)
+    pub parent_signal: Option<Pubkey>,
+}
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    build_with(accounts, data, Vec::new())
+}
+
+fn build_with(accounts: impl ToAccountMetas, data: impl InstructionData, remaining: Vec<AccountMeta>) -> Instruction {
+    let mut metas = accounts.to_account_metas(None);
+    metas.extend(remaining);
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: metas,
+        data: data.data(),
+    }
+}
+
+/// Publisher profile account, if the authority has registered one
+fn publisher_profile(authority: &Pubkey, has_profile: bool) -> Option<Pubkey> {
+    has_profile.then(|| pda::publisher(authority))
+}
+
+pub fn initialize(authority: &Pubkey) -> Instruction {
+    build(
+        accounts::Initialize {
+            oracle_state: pda::oracle_state(),
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::Initialize {},
+    )
+}
+
+/// Publish signal `signal_id` (the oracle's current `total_signals`)
+pub fn publish_signal(authority: &Pubkey, signal_id: u64, has_profile: bool, params: SignalParams) -> Instruction {
+    build(
+        publish_accounts(authority, signal_id, has_profile, &params),
+        instruction::PublishSignal {
+            token: params.token,
+            symbol: params.symbol,
+            score: params.score,
+            risk_level: params.risk_level,
+            sources_bitmap: params.sources_bitmap,
+            mcap: params.mcap,
+            entry_price: params.entry_price,
+            direction: params.direction,
+            quote_mint: params.quote_mint,
+        },
+    )
+}
+
+/// Publish signal `signal_id` with a commitment to its reasoning
+pub fn publish_signal_with_proof(
+    authority: &Pubkey,
+    signal_id: u64,
+    has_profile: bool,
+    params: SignalParams,
+    reasoning_hash: [u8; 32],
+) -> Instruction {
+    build(
+        publish_accounts(authority, signal_id, has_profile, &params),
+        instruction::PublishSignalWithProof {
+            token: params.token,
+            symbol: params.symbol,
+            score: params.score,
+            risk_level: params.risk_level,
+            sources_bitmap: params.sources_bitmap,
+            mcap: params.mcap,
+            entry_price: params.entry_price,
+            direction: params.direction,
+            reasoning_hash,
+            quote_mint: params.quote_mint,
+        },
+    )
+}
+
+fn publish_accounts(
+    authority: &Pubkey,
+    signal_id: u64,
+    has_profile: bool,
+    params: &SignalParams,
+) -> accounts::PublishSignal {
+    accounts::PublishSignal {
+        oracle_state: pda::oracle_state(),
+        config: pda::config(),
+        signal: pda::signal(signal_id),
+        sizing: pda::signal_sizing(&pda::signal(signal_id)),
+        token_history: pda::token_history(&params.token),
+        parent_signal: params.parent_signal,
+        authority: *authority,
+        publisher: publisher_profile(authority, has_profile),
+        system_program: system_program::ID,
+    }
+}
+
+/// Publish signal `signal_id` as the next attempt after signal `parent_id`,
+/// the last closed signal on `params.token` (`params.parent_signal` is
+/// ignored)
+pub fn reopen_signal(
+    authority: &Pubkey,
+    signal_id: u64,
+    has_profile: bool,
+    parent_id: u64,
+    params: SignalParams,
+    reasoning_hash: [u8; 32],
+) -> Instruction {
+    let parent_signal = pda::signal(parent_id);
+    let signal = pda::signal(signal_id);
+    build(
+        accounts::ReopenSignal {
+            oracle_state: pda::oracle_state(),
+            config: pda::config(),
+            signal,
+            sizing: pda::signal_sizing(&signal),
+            token_history: pda::token_history(&params.token),
+            parent_signal,
+            parent_lineage: pda::signal_lineage(&parent_signal),
+            lineage: pda::signal_lineage(&signal),
+            authority: *authority,
+            publisher: publisher_profile(authority, has_profile),
+            system_program: system_program::ID,
+        },
+        instruction::ReopenSignal {
+            symbol: params.symbol,
+            score: params.score,
+            risk_level: params.risk_level,
+            sources_bitmap: params.sources_bitmap,
+            mcap: params.mcap,
+            entry_price: params.entry_price,
+            direction: params.direction,
+            reasoning_hash,
+            quote_mint: params.quote_mint,
+        },
+    )
+}
+
+fn update_accounts(authority: &Pubkey, signal: &Pubkey) -> accounts::UpdateSignal {
+    accounts::UpdateSignal {
+        oracle_state: pda::oracle_state(),
+        config: pda::config(),
+        signal: *signal,
+        ladder: pda::take_profit(signal),
+        authority: *authority,
+    }
+}
+
+pub fn reveal_reasoning(authority: &Pubkey, signal: &Pubkey) -> Instruction {
+    build(update_accounts(authority, signal), instruction::RevealReasoning {})
+}
+
+/// Log the release of `signal`'s reasoning to the subscriber behind `subscriber_hash`
+pub fn log_access(authority: &Pubkey, signal: &Pubkey, subscriber_hash: [u8; 32]) -> Instruction {
+    build(
+        accounts::LogAccess {
+            oracle_state: pda::oracle_state(),
+            signal: *signal,
+            authority: *authority,
+        },
+        instruction::LogAccess { subscriber_hash },
+    )
+}
+
+/// Raise a signal's ATH to `new_ath`, quoted in `quote_mint`
+pub fn update_ath(authority: &Pubkey, signal: &Pubkey, new_ath: u64, quote_mint: &Pubkey) -> Instruction {
+    build(
+        update_accounts(authority, signal),
+        instruction::UpdateAth {
+            new_ath,
+            quote_mint: *quote_mint,
+        },
+    )
+}
+
+/// Close a signal at `exit_price`, quoted in `quote_mint`, recording it in
+/// the stats of its `publisher` and in the history of its `token`. A signal
+/// that `has_snapshot` of its entry supply takes the exit supply from the
+/// token's mint.
+pub fn close_signal(
+    authority: &Pubkey,
+    signal: &Pubkey,
+    exit_price: u64,
+    quote_mint: &Pubkey,
+    publisher: &Pubkey,
+    token: &Pubkey,
+    has_snapshot: bool,
+) -> Instruction {
+    build(
+        accounts::CloseSignal {
+            oracle_state: pda::oracle_state(),
+            config: pda::config(),
+            signal: *signal,
+            ladder: pda::take_profit(signal),
+            authority: *authority,
+            publisher_stats: pda::publisher_stats(publisher),
+            token_history: pda::token_history(token),
+            supply_snapshot: pda::supply_snapshot(signal),
+            mint: has_snapshot.then_some(*token),
+            system_program: system_program::ID,
+        },
+        instruction::CloseSignal {
+            exit_price,
+            quote_mint: *quote_mint,
+        },
+    )
+}
+
+/// View returning `signal`'s age-decayed score as return data; simulate it
+/// rather than sending it
+pub fn effective_score(signal: &Pubkey) -> Instruction {
+    build(
+        accounts::ViewSignal {
+            config: pda::config(),
+            signal: *signal,
+        },
+        instruction::EffectiveScore {},
+    )
+}
+
+/// Grow `signal`'s account to fit `symbol` and store it
+pub fn realloc_signal(authority: &Pubkey, signal: &Pubkey, symbol: String) -> Instruction {
+    build(
+        accounts::ReallocSignal {
+            oracle_state: pda::oracle_state(),
+            config: pda::config(),
+            signal: *signal,
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::ReallocSignal { symbol },
+    )
+}
+
+/// Create `signal`'s notes account; needed once before the first note
+pub fn init_signal_notes(authority: &Pubkey, signal: &Pubkey) -> Instruction {
+    build(
+        accounts::InitSignalNotes {
+            oracle_state: pda::oracle_state(),
+            signal: *signal,
+            signal_notes: pda::signal_notes(signal),
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::InitSignalNotes {},
+    )
+}
+
+pub fn append_signal_note(authority: &Pubkey, signal: &Pubkey, text: String) -> Instruction {
+    build(
+        accounts::AppendSignalNote {
+            oracle_state: pda::oracle_state(),
+            signal: *signal,
+            signal_notes: pda::signal_notes(signal),
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::AppendSignalNote { text },
+    )
+}
+
+/// Rewrite a legacy-layout signal into the current layout
+pub fn migrate_signal(authority: &Pubkey, signal: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateSignal {
+            oracle_state: pda::oracle_state(),
+            signal: *signal,
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateSignal {},
+    )
+}
+
+/// DEX pool a price is checked against, with its two mints and their
+/// reserve vaults in pool order
+#[derive(Debug, Clone, Copy)]
+pub struct PoolAccounts {
+    pub pool: Pubkey,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub vault_a: Pubkey,
+    pub vault_b: Pubkey,
+}
+
+pub fn verify_entry_price(authority: &Pubkey, signal: &Pubkey, pool: &PoolAccounts) -> Instruction {
+    build(
+        accounts::VerifyPoolPrice {
+            oracle_state: pda::oracle_state(),
+            config: pda::config(),
+            signal: *signal,
+            ladder: pda::take_profit(signal),
+            pool: pool.pool,
+            mint_a: pool.mint_a,
+            mint_b: pool.mint_b,
+            vault_a: pool.vault_a,
+            vault_b: pool.vault_b,
+            authority: *authority,
+        },
+        instruction::VerifyEntryPrice {},
+    )
+}
+
+/// Close a signal at `exit_price` checked against `pool`, recording it in
+/// the stats of its `publisher` and in the history of its `token`
+pub fn close_signal_verified(
+    authority: &Pubkey,
+    signal: &Pubkey,
+    pool: &PoolAccounts,
+    exit_price: u64,
+    publisher: &Pubkey,
+    token: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::CloseSignalVerified {
+            oracle_state: pda::oracle_state(),
+            config: pda::config(),
+            signal: *signal,
+            ladder: pda::take_profit(signal),
+            pool: pool.pool,
+            mint_a: pool.mint_a,
+            mint_b: pool.mint_b,
+            vault_a: pool.vault_a,
+            vault_b: pool.vault_b,
+            authority: *authority,
+            publisher_stats: pda::publisher_stats(publisher),
+            token_history: pda::token_history(token),
+            supply_snapshot: pda::supply_snapshot(signal),
+            system_program: system_program::ID,
+        },
+        instruction::CloseSignalVerified { exit_price },
+    )
+}
+
+/// Compute `signal`'s market cap from its mint supply and the price of
+/// `pool`, the pool its entry was verified against
+pub fn verify_market_cap(authority: &Pubkey, signal: &Pubkey, pool: &PoolAccounts) -> Instruction {
+    build(
+        accounts::VerifyMarketCap {
+            oracle_state: pda::oracle_state(),
+            config: pda::config(),
+            signal: *signal,
+            market_cap: pda::signal_market_cap(signal),
+            pool: pool.pool,
+            mint_a: pool.mint_a,
+            mint_b: pool.mint_b,
+            vault_a: pool.vault_a,
+            vault_b: pool.vault_b,
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::VerifyMarketCap {},
+    )
+}
+
+/// Record the supply of `mint` (`signal`'s token) as the signal opens; the
+/// exit supply is taken when it settles
+pub fn snapshot_entry_supply(authority: &Pubkey, signal: &Pubkey, mint: &Pubkey) -> Instruction {
+    build(
+        accounts::SnapshotEntrySupply {
+            oracle_state: pda::oracle_state(),
+            signal: *signal,
+            mint: *mint,
+            snapshot: pda::supply_snapshot(signal),
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::SnapshotEntrySupply {},
+    )
+}
+
+/// Commit the hash of `signal`'s top-holder snapshot
+pub fn commit_holder_distribution(
+    authority: &Pubkey,
+    signal: &Pubkey,
+    snapshot_hash: [u8; 32],
+    top_holders_bps: u16,
+) -> Instruction {
+    build(
+        accounts::CommitHolderDistribution {
+            oracle_state: pda::oracle_state(),
+            signal: *signal,
+            commitment: pda::holder_commitment(signal),
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::CommitHolderDistribution {
+            snapshot_hash,
+            top_holders_bps,
+        },
+    )
+}
+
+/// Scale `signal` into a further entry at `price`, sized at `weight_bps`
+/// of the final position
+pub fn add_entry(authority: &Pubkey, signal: &Pubkey, price: u64, weight_bps: u16) -> Instruction {
+    build(
+        accounts::AddEntry {
+            oracle_state: pda::oracle_state(),
+            signal: *signal,
+            entries: pda::signal_entries(signal),
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::AddEntry { price, weight_bps },
+    )
+}
+
+/// Declare `signal`'s entry as `tranches` tranches of `tranche_bps` each,
+/// one every `interval_secs`
+pub fn set_dca_plan(
+    authority: &Pubkey,
+    signal: &Pubkey,
+    tranches: u8,
+    interval_secs: i64,
+    tranche_bps: u16,
+) -> Instruction {
+    build(
+        accounts::SetDcaPlan {
+            oracle_state: pda::oracle_state(),
+            signal: *signal,
+            dca_plan: pda::dca_plan(signal),
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::SetDcaPlan {
+            tranches,
+            interval_secs,
+            tranche_bps,
+        },
+    )
+}
+
+/// Ladder `signal`'s exit over take-profit `levels`, nearest the entry first
+pub fn set_take_profits(authority: &Pubkey, signal: &Pubkey, levels: Vec<TakeProfitParams>) -> Instruction {
+    build(
+        accounts::SetTakeProfits {
+            oracle_state: pda::oracle_state(),
+            signal: *signal,
+            ladder: pda::take_profit(signal),
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::SetTakeProfits { levels },
+    )
+}
+
+/// Settle take-profit `level` of `signal` against its verified `pool`;
+/// anyone can send it. `publisher` is the signal's publisher, whose stats
+/// record the close if the level exits the rest of the position.
+pub fn settle_take_profit(
+    payer: &Pubkey,
+    signal: &Pubkey,
+    pool: &PoolAccounts,
+    level: u8,
+    publisher: &Pubkey,
+    token: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::SettleTakeProfit {
+            oracle_state: pda::oracle_state(),
+            config: pda::config(),
+            signal: *signal,
+            ladder: pda::take_profit(signal),
+            pool: pool.pool,
+            mint_a: pool.mint_a,
+            mint_b: pool.mint_b,
+            vault_a: pool.vault_a,
+            vault_b: pool.vault_b,
+            publisher_stats: pda::publisher_stats(publisher),
+            token_history: pda::token_history(token),
+            supply_snapshot: pda::supply_snapshot(signal),
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        instruction::SettleTakeProfit { level },
+    )
+}
+
+/// Set the price past which `signal` is invalidated
+pub fn set_invalidation_price(authority: &Pubkey, signal: &Pubkey, price: u64) -> Instruction {
+    build(
+        accounts::SetInvalidationPrice {
+            oracle_state: pda::oracle_state(),
+            config: pda::config(),
+            signal: *signal,
+            invalidation: pda::invalidation(signal),
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::SetInvalidationPrice { price },
+    )
+}
+
+/// Close `signal` as invalidated against its verified `pool`; anyone can
+/// send it. `publisher` is the signal's publisher, whose stats take the loss.
+pub fn invalidate_signal(
+    payer: &Pubkey,
+    signal: &Pubkey,
+    pool: &PoolAccounts,
+    publisher: &Pubkey,
+    token: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::InvalidateSignal {
+            oracle_state: pda::oracle_state(),
+            config: pda::config(),
+            signal: *signal,
+            ladder: pda::take_profit(signal),
+            invalidation: pda::invalidation(signal),
+            pool: pool.pool,
+            mint_a: pool.mint_a,
+            mint_b: pool.mint_b,
+            vault_a: pool.vault_a,
+            vault_b: pool.vault_b,
+            publisher_stats: pda::publisher_stats(publisher),
+            token_history: pda::token_history(token),
+            supply_snapshot: pda::supply_snapshot(signal),
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        instruction::InvalidateSignal {},
+    )
+}
+
+/// Mark each `(signal, pool)` to the pool price; every pool must be its
+/// signal's verified pool. Anyone can send it.
+pub fn mark_signals(marks: &[(Pubkey, PoolAccounts)]) -> Instruction {
+    let remaining = marks
+        .iter()
+        .flat_map(|(signal, pool)| {
+            [
+                AccountMeta::new(*signal, false),
+                AccountMeta::new_readonly(pool.pool, false),
+                AccountMeta::new_readonly(pool.mint_a, false),
+                AccountMeta::new_readonly(pool.mint_b, false),
+                AccountMeta::new_readonly(pool.vault_a, false),
+                AccountMeta::new_readonly(pool.vault_b, false),
+            ]
+        })
+        .collect();
+    build_with(
+        accounts::MarkSignals {
+            oracle_state: pda::oracle_state(),
+        },
+        instruction::MarkSignals {},
+        remaining,
+    )
+}
+
+/// Start the model portfolio with `bankroll` over `max_positions` slots
+pub fn init_model_portfolio(authority: &Pubkey, bankroll: u64, max_positions: u8) -> Instruction {
+    build(
+        accounts::InitModelPortfolio {
+            oracle_state: pda::oracle_state(),
+            portfolio: pda::model_portfolio(),
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::InitModelPortfolio { bankroll, max_positions },
+    )
+}
+
+/// Have the model portfolio consider signal `next_id`, its
+/// `ModelPortfolio::next_id`; anyone can send it
+pub fn portfolio_enter(next_id: u64) -> Instruction {
+    build(
+        accounts::PortfolioEnter {
+            oracle_state: pda::oracle_state(),
+            portfolio: pda::model_portfolio(),
+            signal: pda::signal(next_id),
+        },
+        instruction::PortfolioEnter {},
+    )
+}
+
+/// Close the model portfolio's position in settled `signal`; anyone can
+/// send it
+pub fn portfolio_exit(signal: &Pubkey) -> Instruction {
+    build(
+        accounts::PortfolioExit {
+            portfolio: pda::model_portfolio(),
+            signal: *signal,
+        },
+        instruction::PortfolioExit {},
+    )
+}
+
+/// Record configured benchmark `benchmark`'s price from `pool` next to
+/// `signal`, paid by `payer`; anyone can send it
+pub fn record_benchmark(payer: &Pubkey, signal: &Pubkey, benchmark: u8, pool: &PoolAccounts) -> Instruction {
+    build(
+        accounts::RecordBenchmark {
+            config: pda::config(),
+            signal: *signal,
+            benchmark: pda::signal_benchmark(signal),
+            pool: pool.pool,
+            mint_a: pool.mint_a,
+            mint_b: pool.mint_b,
+            vault_a: pool.vault_a,
+            vault_b: pool.vault_b,
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        instruction::RecordBenchmark { benchmark },
+    )
+}
+
+pub fn initialize_config(authority: &Pubkey) -> Instruction {
+    build(
+        accounts::InitializeConfig {
+            oracle_state: pda::oracle_state(),
+            config: pda::config(),
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeConfig {},
+    )
+}
+
+pub fn update_config(authority: &Pubkey, update: ConfigUpdate) -> Instruction {
+    build(
+        accounts::UpdateConfig {
+            oracle_state: pda::oracle_state(),
+            config: pda::config(),
+            authority: *authority,
+        },
+        instruction::UpdateConfig { update },
+    )
+}
+
+pub fn initialize_vault(authority: &Pubkey, base_mint: &Pubkey, executor: Pubkey) -> Instruction {
+    build(
+        accounts::InitializeVault {
+            oracle_state: pda::oracle_state(),
+            vault: pda::vault(),
+            base_mint: *base_mint,
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeVault { executor },
+    )
+}
+
+/// Vault token accounts used by a swap execution
+#[derive(Debug, Clone, Copy)]
+pub struct SwapAccounts {
+    pub vault_base_account: Pubkey,
+    pub vault_token_account: Pubkey,
+}
+
+/// Execute a scheduled swap, floored at the signal's verified `pool`;
+/// `route_accounts` are the Jupiter route's accounts. Signals with a DCA
+/// plan (`has_dca_plan`) fill its next due tranche on entry.
+pub fn execute_signal(
+    executor: &Pubkey,
+    signal: &Pubkey,
+    swap: &SwapAccounts,
+    pool: &PoolAccounts,
+    side: ExecutionSide,
+    amount_in: u64,
+    quoted_out: u64,
+    salt: [u8; 32],
+    route_data: Vec<u8>,
+    route_accounts: Vec<AccountMeta>,
+    has_dca_plan: bool,
+) -> Instruction {
+    build_with(
+        accounts::ExecuteSignal {
+            config: pda::config(),
+            vault: pda::vault(),
+            signal: *signal,
+            ticket: pda::ticket(signal),
+            dca_plan: has_dca_plan.then(|| pda::dca_plan(signal)),
+            position: pda::position(signal),
+            vault_base_account: swap.vault_base_account,
+            vault_token_account: swap.vault_token_account,
+            pool: pool.pool,
+            mint_a: pool.mint_a,
+            mint_b: pool.mint_b,
+            vault_a: pool.vault_a,
+            vault_b: pool.vault_b,
+            jupiter_program: oracle::vault::JUPITER_PROGRAM_ID,
+            executor: *executor,
+            system_program: system_program::ID,
+        },
+        instruction::ExecuteSignal {
+            side,
+            amount_in,
+            quoted_out,
+            salt,
+            route_data,
+        },
+        route_accounts,
+    )
+}
+
+/// Open or reduce a Drift perp short on the signal's perp params market,
+/// priced off its verified `pool`; `drift_accounts` are the order's
+/// accounts
+pub fn execute_short(
+    executor: &Pubkey,
+    signal: &Pubkey,
+    base_mint: &Pubkey,
+    vault_base_account: &Pubkey,
+    pool: &PoolAccounts,
+    side: ExecutionSide,
+    base_asset_amount: u64,
+    drift_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    build_with(
+        accounts::ExecuteShort {
+            config: pda::config(),
+            vault: pda::vault(),
+            signal: *signal,
+            position: pda::position(signal),
+            base_mint: *base_mint,
+            vault_base_account: *vault_base_account,
+            perp_params: pda::perp_params(signal),
+            pool: pool.pool,
+            mint_a: pool.mint_a,
+            mint_b: pool.mint_b,
+            vault_a: pool.vault_a,
+            vault_b: pool.vault_b,
+            drift_program: oracle::vault::DRIFT_PROGRAM_ID,
+            executor: *executor,
+            system_program: system_program::ID,
+        },
+        instruction::ExecuteShort { side, base_asset_amount },
+        drift_accounts,
+    )
+}
+
+fn lending_accounts(executor: &Pubkey, vault_base_account: &Pubkey) -> accounts::ManageLending {
+    accounts::ManageLending {
+        config: pda::config(),
+        vault: pda::vault(),
+        vault_base_account: *vault_base_account,
+        marginfi_program: oracle::vault::MARGINFI_PROGRAM_ID,
+        executor: *executor,
+    }
+}
+
+/// Deposit idle vault base into marginfi; `marginfi_accounts` are the deposit's accounts
+pub fn lend_idle(
+    executor: &Pubkey,
+    vault_base_account: &Pubkey,
+    amount: u64,
+    marginfi_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    build_with(
+        lending_accounts(executor, vault_base_account),
+        instruction::LendIdle { amount },
+        marginfi_accounts,
+    )
+}
+
+/// Withdraw lent vault base from marginfi; `marginfi_accounts` are the withdrawal's accounts
+pub fn recall_lent(
+    executor: &Pubkey,
+    vault_base_account: &Pubkey,
+    amount: u64,
+    withdraw_all: bool,
+    marginfi_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    build_with(
+        lending_accounts(executor, vault_base_account),
+        instruction::RecallLent { amount, withdraw_all },
+        marginfi_accounts,
+    )
+}
+
+fn staking_accounts(executor: &Pubkey, vault_lst_account: &Pubkey, stake_pool: &Pubkey) -> accounts::ManageStaking {
+    accounts::ManageStaking {
+        config: pda::config(),
+        vault: pda::vault(),
+        vault_sol: pda::vault_sol(),
+        vault_lst_account: *vault_lst_account,
+        stake_pool: *stake_pool,
+        stake_pool_program: oracle::vault::STAKE_POOL_PROGRAM_ID,
+        executor: *executor,
+    }
+}
+
+/// Stake idle vault SOL for the configured pool's LST; `pool_accounts` are the `DepositSol` accounts
+pub fn stake_idle_sol(
+    executor: &Pubkey,
+    vault_lst_account: &Pubkey,
+    stake_pool: &Pubkey,
+    lamports: u64,
+    pool_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    build_with(
+        staking_accounts(executor, vault_lst_account, stake_pool),
+        instruction::StakeIdleSol { lamports },
+        pool_accounts,
+    )
+}
+
+/// Redeem vault LST back to idle SOL; `pool_accounts` are the `WithdrawSol` accounts
+pub fn unstake_lst(
+    executor: &Pubkey,
+    vault_lst_account: &Pubkey,
+    stake_pool: &Pubkey,
+    pool_tokens: u64,
+    pool_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    build_with(
+        staking_accounts(executor, vault_lst_account, stake_pool),
+        instruction::UnstakeLst { pool_tokens },
+        pool_accounts,
+    )
+}
+
+/// Re-value the vault's LST at its stake pool's exchange rate; anyone can send it
+pub fn sync_vault_lst(vault_lst_account: &Pubkey, stake_pool: &Pubkey) -> Instruction {
+    build(
+        accounts::SyncVaultLst {
+            config: pda::config(),
+            vault: pda::vault(),
+            vault_lst_account: *vault_lst_account,
+            stake_pool: *stake_pool,
+        },
+        instruction::SyncVaultLst {},
+    )
+}
+
+/// Token accounts moving vault base between a follower and the vault
+#[derive(Debug, Clone, Copy)]
+pub struct DepositAccounts {
+    pub base_mint: Pubkey,
+    pub owner_base_account: Pubkey,
+    pub vault_base_account: Pubkey,
+    /// Token program owning the base mint
+    pub token_program: Pubkey,
+}
+
+/// Queue `amount` of base to buy vault shares at the end of the epoch
+pub fn request_vault_deposit(owner: &Pubkey, base: &DepositAccounts, amount: u64) -> Instruction {
+    build(
+        accounts::RequestVaultDeposit {
+            vault: pda::vault(),
+            depositor: pda::vault_depositor(owner),
+            base_mint: base.base_mint,
+            owner_base_account: base.owner_base_account,
+            vault_base_account: base.vault_base_account,
+            owner: *owner,
+            token_program: base.token_program,
+            system_program: system_program::ID,
+        },
+        instruction::RequestVaultDeposit { amount },
+    )
+}
+
+/// Queue `shares` for redemption at the end of the epoch
+pub fn request_vault_withdrawal(owner: &Pubkey, shares: u64) -> Instruction {
+    build(
+        accounts::RequestVaultWithdrawal {
+            vault: pda::vault(),
+            depositor: pda::vault_depositor(owner),
+            owner: *owner,
+        },
+        instruction::RequestVaultWithdrawal { shares },
+    )
+}
+
+/// Close vault epoch `epoch` (the vault's current one); anyone can send it
+pub fn process_vault_epoch(payer: &Pubkey, epoch: u64) -> Instruction {
+    build(
+        accounts::ProcessVaultEpoch {
+            config: pda::config(),
+            vault: pda::vault(),
+            epoch: pda::vault_epoch(epoch),
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        instruction::ProcessVaultEpoch {},
+    )
+}
+
+/// Charge the current epoch's performance fee before it is processed;
+/// `treasury_base.owner_base_account` is the treasury's base account.
+/// Anyone can send it.
+pub fn crystallize_vault_fees(treasury_base: &DepositAccounts) -> Instruction {
+    build(
+        accounts::CrystallizeVaultFees {
+            config: pda::config(),
+            vault: pda::vault(),
+            base_mint: treasury_base.base_mint,
+            vault_base_account: treasury_base.vault_base_account,
+            treasury: pda::treasury(),
+            treasury_base_account: treasury_base.owner_base_account,
+            token_program: treasury_base.token_program,
+        },
+        instruction::CrystallizeVaultFees {},
+    )
+}
+
+/// Settle `base`'s owner's flows queued in processed epoch `epoch`;
+/// anyone can send it
+pub fn settle_vault_depositor(base: &DepositAccounts, owner: &Pubkey, epoch: u64) -> Instruction {
+    build(
+        accounts::SettleVaultDepositor {
+            vault: pda::vault(),
+            depositor: pda::vault_depositor(owner),
+            epoch: pda::vault_epoch(epoch),
+            base_mint: base.base_mint,
+            owner_base_account: base.owner_base_account,
+            vault_base_account: base.vault_base_account,
+            token_program: base.token_program,
+        },
+        instruction::SettleVaultDepositor {},
+    )
+}
+
+/// Bring `owner`'s vault entry and realized-PnL totals up to date; anyone
+/// can send it
+pub fn accrue_vault_depositor(owner: &Pubkey) -> Instruction {
+    build(
+        accounts::AccrueVaultDepositor {
+            vault: pda::vault(),
+            depositor: pda::vault_depositor(owner),
+        },
+        instruction::AccrueVaultDepositor {},
+    )
+}
+
+/// Report the vault's off-chain valued NAV in base mint units. Every open
+/// position is passed as `(signal, pool)` with its signal's verified pool;
+/// `sol_pool` is the SOL benchmark pool, needed while the vault holds SOL.
+pub fn report_vault_nav(
+    executor: &Pubkey,
+    vault_base_account: &Pubkey,
+    sol_pool: Option<&PoolAccounts>,
+    positions: &[(Pubkey, PoolAccounts)],
+    nav: u64,
+) -> Instruction {
+    let mut positions: Vec<_> = positions.iter().map(|(signal, pool)| (pda::position(signal), signal, pool)).collect();
+    positions.sort_by_key(|(position, ..)| *position);
+    let remaining = positions
+        .into_iter()
+        .flat_map(|(position, signal, pool)| {
+            [
+                AccountMeta::new_readonly(position, false),
+                AccountMeta::new_readonly(*signal, false),
+                AccountMeta::new_readonly(pool.pool, false),
+                AccountMeta::new_readonly(pool.mint_a, false),
+                AccountMeta::new_readonly(pool.mint_b, false),
+                AccountMeta::new_readonly(pool.vault_a, false),
+                AccountMeta::new_readonly(pool.vault_b, false),
+            ]
+        })
+        .collect();
+    build_with(
+        accounts::ReportVaultNav {
+            config: pda::config(),
+            vault: pda::vault(),
+            vault_base_account: *vault_base_account,
+            vault_sol: pda::vault_sol(),
+            sol_pool: sol_pool.map(|pool| pool.pool),
+            sol_mint_a: sol_pool.map(|pool| pool.mint_a),
+            sol_mint_b: sol_pool.map(|pool| pool.mint_b),
+            sol_vault_a: sol_pool.map(|pool| pool.vault_a),
+            sol_vault_b: sol_pool.map(|pool| pool.vault_b),
+            executor: *executor,
+        },
+        instruction::ReportVaultNav { nav },
+        remaining,
+    )
+}
+
+/// Lift the vault's drawdown de-risking
+pub fn reset_vault_derisk(authority: &Pubkey) -> Instruction {
+    build(
+        accounts::ResetVaultDerisk {
+            oracle_state: pda::oracle_state(),
+            vault: pda::vault(),
+            authority: *authority,
+        },
+        instruction::ResetVaultDerisk {},
+    )
+}
+
+/// Trim or top up a signal's position toward its target weight, valued
+/// at the signal's verified `pool`; `route_accounts` are the Jupiter
+/// route's accounts
+pub fn rebalance(
+    executor: &Pubkey,
+    signal: &Pubkey,
+    swap: &SwapAccounts,
+    pool: &PoolAccounts,
+    side: ExecutionSide,
+    amount_in: u64,
+    quoted_out: u64,
+    route_data: Vec<u8>,
+    route_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    build_with(
+        accounts::Rebalance {
+            config: pda::config(),
+            vault: pda::vault(),
+            signal: *signal,
+            sizing: pda::signal_sizing(signal),
+            position: pda::position(signal),
+            vault_base_account: swap.vault_base_account,
+            vault_token_account: swap.vault_token_account,
+            pool: pool.pool,
+            mint_a: pool.mint_a,
+            mint_b: pool.mint_b,
+            vault_a: pool.vault_a,
+            vault_b: pool.vault_b,
+            jupiter_program: oracle::vault::JUPITER_PROGRAM_ID,
+            executor: *executor,
+        },
+        instruction::Rebalance {
+            side,
+            amount_in,
+            quoted_out,
+            route_data,
+        },
+        route_accounts,
+    )
+}
+
+/// Put the vault into emergency exit mode
+pub fn declare_vault_emergency(authority: &Pubkey) -> Instruction {
+    build(
+        accounts::DeclareVaultEmergency {
+            oracle_state: pda::oracle_state(),
+            vault: pda::vault(),
+            authority: *authority,
+        },
+        instruction::DeclareVaultEmergency {},
+    )
+}
+
+/// Sell part of a signal's position during an emergency, floored at the
+/// signal's verified `pool`; `route_accounts` are the Jupiter route's
+/// accounts
+pub fn emergency_unwind(
+    operator: &Pubkey,
+    signal: &Pubkey,
+    swap: &SwapAccounts,
+    pool: &PoolAccounts,
+    amount_in: u64,
+    quoted_out: u64,
+    route_data: Vec<u8>,
+    route_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    build_with(
+        accounts::EmergencyUnwind {
+            config: pda::config(),
+            vault: pda::vault(),
+            signal: *signal,
+            position: pda::position(signal),
+            vault_base_account: swap.vault_base_account,
+            vault_token_account: swap.vault_token_account,
+            pool: pool.pool,
+            mint_a: pool.mint_a,
+            mint_b: pool.mint_b,
+            vault_a: pool.vault_a,
+            vault_b: pool.vault_b,
+            jupiter_program: oracle::vault::JUPITER_PROGRAM_ID,
+            operator: *operator,
+        },
+        instruction::EmergencyUnwind {
+            amount_in,
+            quoted_out,
+            route_data,
+        },
+        route_accounts,
+    )
+}
+
+/// Redeem `shares` from an emergency-mode vault at its post-emergency NAV,
+/// along with anything queued this epoch; `shares` of 0 collects what is
+/// still owed
+pub fn emergency_withdraw(owner: &Pubkey, base: &DepositAccounts, shares: u64) -> Instruction {
+    build(
+        accounts::EmergencyWithdraw {
+            vault: pda::vault(),
+            depositor: pda::vault_depositor(owner),
+            base_mint: base.base_mint,
+            owner_base_account: base.owner_base_account,
+            vault_base_account: base.vault_base_account,
+            owner: *owner,
+            token_program: base.token_program,
+        },
+        instruction::EmergencyWithdraw { shares },
+    )
+}
+
+/// Compressed NFT leaf being proven, and its proof path
+#[derive(Debug, Clone)]
+pub struct LeafProof {
+    pub root: [u8; 32],
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    pub nonce: u64,
+    pub index: u32,
+    pub proof: Vec<Pubkey>,
+}
+
+fn proof_accounts(proof: &[Pubkey]) -> Vec<AccountMeta> {
+    proof.iter().map(|node| AccountMeta::new_readonly(*node, false)).collect()
+}
+
+pub fn verify_access_pass(owner: &Pubkey, delegate: &Pubkey, merkle_tree: &Pubkey, leaf: LeafProof) -> Instruction {
+    build_with(
+        accounts::VerifyAccessPass {
+            config: pda::config(),
+            merkle_tree: *merkle_tree,
+            owner: *owner,
+            delegate: *delegate,
+            compression_program: oracle::compression::COMPRESSION_PROGRAM_ID,
+        },
+        instruction::VerifyAccessPass {
+            root: leaf.root,
+            data_hash: leaf.data_hash,
+            creator_hash: leaf.creator_hash,
+            nonce: leaf.nonce,
+            index: leaf.index,
+        },
+        proof_accounts(&leaf.proof),
+    )
+}
+
+/// Subscribe to `tier` for `periods`; `allowlisted` passes the
+/// subscriber's allowlist entry, which allowlisted tiers require.
+/// `with_pass` passes the subscriber's pass account (see
+/// `create_subscriber_pass_account`), required once the pass mint exists.
+pub fn subscribe(subscriber: &Pubkey, tier: u8, periods: u32, allowlisted: bool, with_pass: bool) -> Instruction {
+    build(
+        accounts::Subscribe {
+            config: pda::config(),
+            tier_allowlist: pda::tier_allowlist(tier),
+            allowlist_entry: allowlisted.then(|| pda::allowlist_entry(tier, subscriber)),
+            subscription: pda::subscription(subscriber),
+            treasury: pda::treasury(),
+            pass_mint: pda::pass_mint(),
+            subscriber_pass: with_pass.then(|| pda::subscriber_pass(subscriber)),
+            token_program: with_pass.then_some(anchor_spl::token_2022::ID),
+            subscriber: *subscriber,
+            system_program: system_program::ID,
+        },
+        instruction::Subscribe { tier, periods },
+    )
+}
+
+fn manage_subscription_accounts(subscriber: &Pubkey, with_pass: bool) -> accounts::ManageSubscription {
+    accounts::ManageSubscription {
+        subscription: pda::subscription(subscriber),
+        pass_mint: pda::pass_mint(),
+        subscriber_pass: with_pass.then(|| pda::subscriber_pass(subscriber)),
+        token_program: with_pass.then_some(anchor_spl::token_2022::ID),
+        subscriber: *subscriber,
+    }
+}
+
+/// Freeze `subscriber`'s remaining subscription time, burning the pass
+/// when `with_pass` (required once the pass mint exists)
+pub fn pause_subscription(subscriber: &Pubkey, with_pass: bool) -> Instruction {
+    build(manage_subscription_accounts(subscriber, with_pass), instruction::PauseSubscription {})
+}
+
+/// Restart `subscriber`'s paused subscription, minting the pass back when
+/// `with_pass` (required once the pass mint exists)
+pub fn resume_subscription(subscriber: &Pubkey, with_pass: bool) -> Instruction {
+    build(manage_subscription_accounts(subscriber, with_pass), instruction::ResumeSubscription {})
+}
+
+/// Move `owner`'s remaining time on `tier` to `recipient`; `allowlisted`
+/// passes the recipient's allowlist entry, which allowlisted tiers require.
+/// `with_pass` passes both pass accounts, needed once the pass mint exists,
+/// so the owner's pass is burned and the recipient's minted.
+pub fn transfer_subscription(
+    owner: &Pubkey,
+    recipient: &Pubkey,
+    tier: u8,
+    allowlisted: bool,
+    with_pass: bool,
+) -> Instruction {
+    build(
+        accounts::TransferSubscription {
+            config: pda::config(),
+            from: pda::subscription(owner),
+            to: pda::subscription(recipient),
+            tier_allowlist: pda::tier_allowlist(tier),
+            recipient_entry: allowlisted.then(|| pda::allowlist_entry(tier, recipient)),
+            recipient: *recipient,
+            pass_mint: pda::pass_mint(),
+            owner_pass: with_pass.then(|| pda::subscriber_pass(owner)),
+            recipient_pass: with_pass.then(|| pda::subscriber_pass(recipient)),
+            token_program: with_pass.then_some(anchor_spl::token_2022::ID),
+            owner: *owner,
+            system_program: system_program::ID,
+        },
+        instruction::TransferSubscription {},
+    )
+}
+
+/// Put `tier` in allowlist mode with room for `capacity` wallets, or resize it
+pub fn set_tier_allowlist(authority: &Pubkey, tier: u8, capacity: u32) -> Instruction {
+    build(
+        accounts::SetTierAllowlist {
+            oracle_state: pda::oracle_state(),
+            allowlist: pda::tier_allowlist(tier),
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::SetTierAllowlist { tier, capacity },
+    )
+}
+
+/// Let `wallet` subscribe to allowlisted `tier`
+pub fn allowlist_add(authority: &Pubkey, tier: u8, wallet: Pubkey) -> Instruction {
+    build(
+        accounts::AllowlistAdd {
+            oracle_state: pda::oracle_state(),
+            allowlist: pda::tier_allowlist(tier),
+            entry: pda::allowlist_entry(tier, &wallet),
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::AllowlistAdd { tier, wallet },
+    )
+}
+
+/// Take `wallet` off allowlisted `tier`
+pub fn allowlist_remove(authority: &Pubkey, tier: u8, wallet: Pubkey) -> Instruction {
+    build(
+        accounts::AllowlistRemove {
+            oracle_state: pda::oracle_state(),
+            allowlist: pda::tier_allowlist(tier),
+            entry: pda::allowlist_entry(tier, &wallet),
+            authority: *authority,
+        },
+        instruction::AllowlistRemove { tier, wallet },
+    )
+}
+
+pub fn initialize_pass_mint(authority: &Pubkey) -> Instruction {
+    build(
+        accounts::InitializePassMint {
+            oracle_state: pda::oracle_state(),
+            pass_mint: pda::pass_mint(),
+            authority: *authority,
+            token_program: anchor_spl::token_2022::ID,
+            system_program: system_program::ID,
+        },
+        instruction::InitializePassMint {},
+    )
+}
+
+/// Create `subscriber`'s Token-2022 pass account, paid by `payer`, if it
+/// doesn't exist yet
+pub fn create_subscriber_pass_account(payer: &Pubkey, subscriber: &Pubkey) -> Instruction {
+    anchor_spl::associated_token::spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+        payer,
+        subscriber,
+        &pda::pass_mint(),
+        &anchor_spl::token_2022::ID,
+    )
+}
+
+/// Mint the pass into the subscriber's (already created) Token-2022 account
+pub fn mint_subscriber_pass(subscriber: &Pubkey) -> Instruction {
+    build(
+        accounts::MintSubscriberPass {
+            subscription: pda::subscription(subscriber),
+            pass_mint: pda::pass_mint(),
+            subscriber_pass: pda::subscriber_pass(subscriber),
+            subscriber: *subscriber,
+            token_program: anchor_spl::token_2022::ID,
+        },
+        instruction::MintSubscriberPass {},
+    )
+}
+
+/// Burn an expired subscriber's pass (permissionless)
+pub fn burn_expired_pass(subscriber: &Pubkey) -> Instruction {
+    build(
+        accounts::BurnExpiredPass {
+            subscription: pda::subscription(subscriber),
+            pass_mint: pda::pass_mint(),
+            subscriber_pass: pda::subscriber_pass(subscriber),
+            token_program: anchor_spl::token_2022::ID,
+        },
+        instruction::BurnExpiredPass {},
+    )
+}
+
+/// Rate closed signal `signal` 1-5 as `subscriber`
+pub fn rate_signal(subscriber: &Pubkey, signal: &Pubkey, rating: u8) -> Instruction {
+    build(
+        accounts::RateSignal {
+            signal: *signal,
+            subscription: pda::subscription(subscriber),
+            signal_rating: pda::signal_rating(signal),
+            receipt: pda::rating_receipt(signal, subscriber),
+            subscriber: *subscriber,
+            system_program: system_program::ID,
+        },
+        instruction::RateSignal { rating },
+    )
+}
+
+/// Record that `subscriber` holds `signal` now, before its reveal
+pub fn claim_access(subscriber: &Pubkey, signal: &Pubkey) -> Instruction {
+    build(
+        accounts::ClaimAccess {
+            signal: *signal,
+            subscription: pda::subscription(subscriber),
+            claim: pda::access_claim(signal, subscriber),
+            subscriber: *subscriber,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimAccess {},
+    )
+}
+
+/// Pay `lamports` into the treasury to boost `signal`
+pub fn boost_signal(booster: &Pubkey, signal: &Pubkey, lamports: u64) -> Instruction {
+    build(
+        accounts::BoostSignal {
+            signal: *signal,
+            signal_boost: pda::signal_boost(signal),
+            discovery: pda::discovery(),
+            treasury: pda::treasury(),
+            booster: *booster,
+            system_program: system_program::ID,
+        },
+        instruction::BoostSignal { lamports },
+    )
+}
+
+/// Bid `lamports` to feature `signal` during `epoch`; `outbid` is the
+/// bidder of the bid this one replaces, if that's not `bidder`
+pub fn bid_featured(
+    bidder: &Pubkey,
+    signal: &Pubkey,
+    epoch: u64,
+    lamports: u64,
+    outbid: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::BidFeatured {
+            signal: *signal,
+            auction: pda::auction(epoch),
+            outbid,
+            bidder: *bidder,
+            system_program: system_program::ID,
+        },
+        instruction::BidFeatured { epoch, lamports },
+    )
+}
+
+pub fn settle_auction(payer: &Pubkey, epoch: u64) -> Instruction {
+    build(
+        accounts::SettleAuction {
+            auction: pda::auction(epoch),
+            discovery: pda::discovery(),
+            treasury: pda::treasury(),
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        instruction::SettleAuction { epoch },
+    )
+}
+
+/// Publish basket `basket_id` (the oracle's current `total_baskets`)
+pub fn publish_basket(
+    authority: &Pubkey,
+    basket_id: u64,
+    name: String,
+    members: Vec<BasketMemberParams>,
+) -> Instruction {
+    build(
+        accounts::PublishBasket {
+            oracle_state: pda::oracle_state(),
+            basket: pda::basket(basket_id),
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::PublishBasket { name, members },
+    )
+}
+
+/// Close basket `basket_id` with one exit price per member
+pub fn close_basket(authority: &Pubkey, basket_id: u64, exit_prices: Vec<u64>) -> Instruction {
+    build(
+        accounts::CloseBasket {
+            oracle_state: pda::oracle_state(),
+            config: pda::config(),
+            basket: pda::basket(basket_id),
+            authority: *authority,
+        },
+        instruction::CloseBasket { exit_prices },
+    )
+}
+
+/// Publish pair `pair_id` (the oracle's current `total_pairs`), long
+/// `long.0` against `short.0`, each leg given as `(token, entry_price)`
+pub fn publish_pair(authority: &Pubkey, pair_id: u64, long: (Pubkey, u64), short: (Pubkey, u64)) -> Instruction {
+    build(
+        accounts::PublishPair {
+            oracle_state: pda::oracle_state(),
+            pair: pda::pair_signal(pair_id),
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::PublishPair {
+            long_token: long.0,
+            long_entry_price: long.1,
+            short_token: short.0,
+            short_entry_price: short.1,
+        },
+    )
+}
+
+pub fn close_pair(authority: &Pubkey, pair_id: u64, long_exit_price: u64, short_exit_price: u64) -> Instruction {
+    build(
+        accounts::ClosePair {
+            oracle_state: pda::oracle_state(),
+            config: pda::config(),
+            pair: pda::pair_signal(pair_id),
+            authority: *authority,
+        },
+        instruction::ClosePair {
+            long_exit_price,
+            short_exit_price,
+        },
+    )
+}
+
+/// Recommend `leverage_bps` (0 clears it) for executing `signal` on perps,
+/// shorted on Drift market `market_index`
+pub fn set_perp_params(authority: &Pubkey, signal: &Pubkey, leverage_bps: u32, market_index: u16) -> Instruction {
+    build(
+        accounts::SetPerpParams {
+            oracle_state: pda::oracle_state(),
+            config: pda::config(),
+            signal: *signal,
+            perp_params: pda::perp_params(signal),
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::SetPerpParams {
+            leverage_bps,
+            market_index,
+        },
+    )
+}
+
+pub fn register_publisher(wallet: &Pubkey) -> Instruction {
+    build(
+        accounts::RegisterPublisher {
+            publisher: pda::publisher(wallet),
+            wallet: *wallet,
+            system_program: system_program::ID,
+        },
+        instruction::RegisterPublisher {},
+    )
+}
+
+/// Create the settlement statistics account of `wallet`'s publisher profile
+pub fn init_publisher_stats(wallet: &Pubkey) -> Instruction {
+    build(
+        accounts::InitPublisherStats {
+            publisher: pda::publisher(wallet),
+            publisher_stats: pda::publisher_stats(wallet),
+            wallet: *wallet,
+            system_program: system_program::ID,
+        },
+        instruction::InitPublisherStats {},
+    )
+}
+
+/// Link `<domain>.sol` (owned by `wallet`) to its publisher profile
+pub fn link_domain(wallet: &Pubkey, domain: String) -> Instruction {
+    build(
+        accounts::LinkDomain {
+            publisher: pda::publisher(wallet),
+            name_account: oracle::sns::domain_account(&domain),
+            wallet: *wallet,
+        },
+        instruction::LinkDomain { domain },
+    )
+}
+
+fn thread_accounts(authority: &Pubkey, thread_id: &[u8]) -> accounts::ManageThread {
+    accounts::ManageThread {
+        oracle_state: pda::oracle_state(),
+        treasury: pda::treasury(),
+        thread: pda::thread(thread_id),
+        thread_program: oracle::automation::THREAD_PROGRAM_ID,
+        authority: *authority,
+        system_program: system_program::ID,
+    }
+}
+
+pub fn create_thread(
+    authority: &Pubkey,
+    thread_id: Vec<u8>,
+    instructions: Vec<ThreadInstruction>,
+    schedule: String,
+    amount: u64,
+) -> Instruction {
+    build(
+        thread_accounts(authority, &thread_id),
+        instruction::CreateThread {
+            thread_id,
+            instructions,
+            schedule,
+            amount,
+        },
+    )
+}
+
+pub fn fund_thread(authority: &Pubkey, thread_id: &[u8], amount: u64) -> Instruction {
+    build(thread_accounts(authority, thread_id), instruction::FundThread { amount })
+}
+
+pub fn close_thread(authority: &Pubkey, thread_id: &[u8]) -> Instruction {
+    build(thread_accounts(authority, thread_id), instruction::CloseThread {})
+}
+
+/// Commit to an execution of `signal`; see `oracle::vault::execution_commitment`
+pub fn schedule_execution(
+    authority: &Pubkey,
+    signal: &Pubkey,
+    commit_hash: [u8; 32],
+    valid_from_slot: u64,
+    valid_until_slot: u64,
+) -> Instruction {
+    build(
+        accounts::ScheduleExecution {
+            vault: pda::vault(),
+            signal: *signal,
+            ticket: pda::ticket(signal),
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::ScheduleExecution {
+            commit_hash,
+            valid_from_slot,
+            valid_until_slot,
+        },
+    )
+}
+
+pub fn init_signal_tree(authority: &Pubkey, merkle_tree: &Pubkey, max_depth: u32, max_buffer_size: u32) -> Instruction {
+    build(
+        accounts::InitSignalTree {
+            oracle_state: pda::oracle_state(),
+            config: pda::config(),
+            merkle_tree: *merkle_tree,
+            tree_authority: pda::tree_authority(),
+            compression_program: oracle::compression::COMPRESSION_PROGRAM_ID,
+            noop_program: oracle::compression::NOOP_PROGRAM_ID,
+            authority: *authority,
+        },
+        instruction::InitSignalTree {
+            max_depth,
+            max_buffer_size,
+        },
+    )
+}
+
+fn tree_accounts(authority: &Pubkey, merkle_tree: &Pubkey) -> accounts::CompressedSignalTree {
+    accounts::CompressedSignalTree {
+        oracle_state: pda::oracle_state(),
+        config: pda::config(),
+        merkle_tree: *merkle_tree,
+        tree_authority: pda::tree_authority(),
+        compression_program: oracle::compression::COMPRESSION_PROGRAM_ID,
+        noop_program: oracle::compression::NOOP_PROGRAM_ID,
+        authority: *authority,
+    }
+}
+
+pub fn publish_signal_compressed(
+    authority: &Pubkey,
+    merkle_tree: &Pubkey,
+    params: SignalParams,
+    reasoning_hash: [u8; 32],
+) -> Instruction {
+    build(
+        tree_accounts(authority, merkle_tree),
+        instruction::PublishSignalCompressed {
+            token: params.token,
+            symbol: params.symbol,
+            score: params.score,
+            risk_level: params.risk_level,
+            sources_bitmap: params.sources_bitmap,
+            mcap: params.mcap,
+            entry_price: params.entry_price,
+            direction: params.direction,
+            reasoning_hash,
+            quote_mint: params.quote_mint,
+        },
+    )
+}
+
+/// Close a compressed signal; `signal` is its current leaf data, and its
+/// publisher's stats record the close
+pub fn close_compressed_signal(
+    authority: &Pubkey,
+    merkle_tree: &Pubkey,
+    signal: Signal,
+    root: [u8; 32],
+    leaf_index: u32,
+    exit_price: u64,
+    quote_mint: &Pubkey,
+    proof: &[Pubkey],
+) -> Instruction {
+    build_with(
+        accounts::CloseCompressedSignal {
+            oracle_state: pda::oracle_state(),
+            config: pda::config(),
+            merkle_tree: *merkle_tree,
+            tree_authority: pda::tree_authority(),
+            compression_program: oracle::compression::COMPRESSION_PROGRAM_ID,
+            noop_program: oracle::compression::NOOP_PROGRAM_ID,
+            authority: *authority,
+            publisher_stats: pda::publisher_stats(&signal.publisher),
+            system_program: system_program::ID,
+        },
+        instruction::CloseCompressedSignal {
+            signal,
+            root,
+            leaf_index,
+            exit_price,
+            quote_mint: *quote_mint,
+        },
+        proof_accounts(proof),
+    )
+}
+
+pub fn mint_signal_asset(authority: &Pubkey, signal: &Pubkey, uri: String) -> Instruction {
+    build(
+        accounts::MintSignalAsset {
+            oracle_state: pda::oracle_state(),
+            config: pda::config(),
+            signal: *signal,
+            asset: pda::signal_asset(signal),
+            core_program: oracle::collectibles::CORE_PROGRAM_ID,
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::MintSignalAsset { uri },
+    )
+}
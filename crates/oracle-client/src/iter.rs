@@ -0,0 +1,112 @@
+//! Walking signal accounts in id order.
+//!
+//! Signal PDAs are derived from their id, so the full history can be read
+//! with `getMultipleAccounts` batches instead of one `getProgramAccounts`
+//! scan that some providers refuse or time out on. Ids whose account is
+//! missing (compressed-mode signals) are skipped.
+
+use std::collections::VecDeque;
+
+use futures_util::stream::{self, Stream};
+use oracle_types::Signal;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::fetch::{self, decode, MULTIPLE_ACCOUNTS_LIMIT};
+use crate::{pda, Result};
+
+/// Async iterator over signals, `batch` accounts per request
+///
+/// ```ignore
+/// let mut signals = SignalIterator::new(&rpc).starting_at(1_000);
+/// while let Some(item) = signals.next().await {
+///     let (address, signal) = item?;
+/// }
+/// ```
+pub struct SignalIterator<'a> {
+    rpc: &'a RpcClient,
+    next_id: u64,
+    end: Option<u64>,
+    batch: usize,
+    buffered: VecDeque<(Pubkey, Signal)>,
+}
+
+impl<'a> SignalIterator<'a> {
+    /// Every signal published so far, from id 0
+    pub fn new(rpc: &'a RpcClient) -> Self {
+        SignalIterator {
+            rpc,
+            next_id: 0,
+            end: None,
+            batch: MULTIPLE_ACCOUNTS_LIMIT,
+            buffered: VecDeque::new(),
+        }
+    }
+
+    /// Start at `id` instead of 0 (e.g. to resume a sync)
+    pub fn starting_at(mut self, id: u64) -> Self {
+        self.next_id = id;
+        self
+    }
+
+    /// Stop before `id`. Defaults to the signal count when iteration starts.
+    pub fn ending_before(mut self, id: u64) -> Self {
+        self.end = Some(id);
+        self
+    }
+
+    /// Accounts per request, at most 100
+    pub fn batch_size(mut self, batch: usize) -> Self {
+        self.batch = batch.clamp(1, MULTIPLE_ACCOUNTS_LIMIT);
+        self
+    }
+
+    /// Id the next batch will start from
+    pub fn next_id(&self) -> u64 {
+        self.next_id
+    }
+
+    /// The next signal. A failed batch is returned as an error and retried
+    /// by the following call.
+    pub async fn next(&mut self) -> Option<Result<(Pubkey, Signal)>> {
+        loop {
+            if let Some(item) = self.buffered.pop_front() {
+                return Some(Ok(item));
+            }
+            let end = match self.end {
+                Some(end) => end,
+                None => match fetch::next_signal_id(self.rpc).await {
+                    Ok(end) => *self.end.insert(end),
+                    Err(err) => return Some(Err(err)),
+                },
+            };
+            if self.next_id >= end {
+                return None;
+            }
+            if let Err(err) = self.fill(end).await {
+                return Some(Err(err));
+            }
+        }
+    }
+
+    async fn fill(&mut self, end: u64) -> Result<()> {
+        let last = end.min(self.next_id.saturating_add(self.batch as u64));
+        let addresses: Vec<Pubkey> = (self.next_id..last).map(pda::signal).collect();
+        let accounts = self.rpc.get_multiple_accounts(&addresses).await?;
+        for (address, account) in addresses.iter().zip(accounts) {
+            if let Some(account) = account {
+                self.buffered.push_back((*address, decode::<Signal>(address, &account.data)?));
+            }
+        }
+        self.next_id = last;
+        Ok(())
+    }
+
+    /// As a `Stream`, for use with `futures` combinators
+    pub fn into_stream(self) -> impl Stream<Item = Result<(Pubkey, Signal)>> + 'a {
+        stream::unfold(self, |mut signals| async move {
+            let item = signals.next().await?;
+            Some((item, signals))
+        })
+    }
+}
@@ -0,0 +1,26 @@
+//! Rust client for the ORACLE Alpha program.
+
+#![allow(clippy::too_many_arguments)]
+
+pub mod attestation;
+pub mod error;
+pub mod fees;
+pub mod fetch;
+pub mod instructions;
+pub mod iter;
+pub mod lookup;
+pub mod nonce;
+pub mod pda;
+pub mod pool;
+pub mod preview;
+pub mod prices;
+pub mod query;
+pub mod rationale;
+pub mod signer;
+pub mod stream;
+pub mod submit;
+
+pub use error::{ClientError, Result};
+pub use oracle::ID as PROGRAM_ID;
+pub use oracle_math as math;
+pub use oracle_types as types;
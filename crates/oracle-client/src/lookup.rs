@@ -0,0 +1,134 @@
+//! Versioned (v0) transactions with address lookup tables.
+//!
+//! Batch publishes and vault executions reference more accounts than fit in
+//! a legacy transaction. Building v0 messages against the oracle's lookup
+//! tables moves every non-signer account that a table covers into a one-byte
+//! index.
+
+use solana_address_lookup_table_interface::instruction::{create_lookup_table, extend_lookup_table};
+use solana_address_lookup_table_interface::state::AddressLookupTable;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::{v0, AddressLookupTableAccount, VersionedMessage};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::VersionedTransaction;
+use std::collections::HashSet;
+
+use crate::{pda, ClientError, Result};
+
+/// Max addresses per `extend_lookup_table` instruction that fit in one transaction
+pub const MAX_EXTEND_ADDRESSES: usize = 20;
+
+/// Accounts shared by most oracle transactions, worth keeping in a table
+pub fn oracle_common_accounts() -> Vec<Pubkey> {
+    vec![
+        crate::PROGRAM_ID,
+        pda::oracle_state(),
+        pda::config(),
+        pda::vault(),
+        pda::treasury(),
+        solana_system_interface::program::ID,
+        oracle::vault::JUPITER_PROGRAM_ID,
+        oracle::vault::DRIFT_PROGRAM_ID,
+        oracle::vault::MARGINFI_PROGRAM_ID,
+    ]
+}
+
+/// Instructions creating a lookup table and filling it with `addresses`
+///
+/// Returns the table address. Extends are chunked so each one fits in its
+/// own transaction; the table is usable one slot after the last extend.
+pub fn create_table(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    recent_slot: u64,
+    addresses: &[Pubkey],
+) -> (Pubkey, Vec<Instruction>) {
+    let (create, table) = create_lookup_table(*authority, *payer, recent_slot);
+    let mut instructions = vec![create];
+    instructions.extend(addresses.chunks(MAX_EXTEND_ADDRESSES).map(|chunk| {
+        extend_lookup_table(table, *authority, Some(*payer), chunk.to_vec())
+    }));
+    (table, instructions)
+}
+
+/// Fetch and decode lookup tables
+pub async fn fetch_tables(rpc: &RpcClient, tables: &[Pubkey]) -> Result<Vec<AddressLookupTableAccount>> {
+    let accounts = rpc.get_multiple_accounts(tables).await?;
+    tables
+        .iter()
+        .zip(accounts)
+        .map(|(key, account)| {
+            let account = account.ok_or(ClientError::AccountNotFound(*key))?;
+            let table = AddressLookupTable::deserialize(&account.data)
+                .map_err(|_| ClientError::InvalidLookupTable(*key))?;
+            Ok(AddressLookupTableAccount {
+                key: *key,
+                addresses: table.addresses.to_vec(),
+            })
+        })
+        .collect()
+}
+
+/// Tables from `candidates` that cover any account `instructions` could load
+/// from a table, picked greedily by coverage
+pub fn select_tables(
+    instructions: &[Instruction],
+    candidates: &[AddressLookupTableAccount],
+) -> Vec<AddressLookupTableAccount> {
+    // Signers and invoked programs must be static keys
+    let invoked: HashSet<Pubkey> = instructions.iter().map(|ix| ix.program_id).collect();
+    let mut uncovered: HashSet<Pubkey> = instructions
+        .iter()
+        .flat_map(|ix| ix.accounts.iter())
+        .filter(|meta| !meta.is_signer && !invoked.contains(&meta.pubkey))
+        .map(|meta| meta.pubkey)
+        .collect();
+
+    let mut remaining: Vec<&AddressLookupTableAccount> = candidates.iter().collect();
+    let mut selected = Vec::new();
+    loop {
+        let best = remaining
+            .iter()
+            .enumerate()
+            .map(|(i, table)| (i, table.addresses.iter().filter(|a| uncovered.contains(a)).count()))
+            .max_by_key(|(_, covered)| *covered);
+        match best {
+            Some((i, covered)) if covered > 0 => {
+                let table = remaining.swap_remove(i);
+                for address in &table.addresses {
+                    uncovered.remove(address);
+                }
+                selected.push(table.clone());
+            }
+            _ => break,
+        }
+    }
+    selected
+}
+
+/// Compile a v0 message, resolving accounts through whichever `tables` help
+pub fn v0_message(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    tables: &[AddressLookupTableAccount],
+    blockhash: Hash,
+) -> Result<VersionedMessage> {
+    let tables = select_tables(instructions, tables);
+    let message = v0::Message::try_compile(payer, instructions, &tables, blockhash)?;
+    Ok(VersionedMessage::V0(message))
+}
+
+/// Build and sign a v0 transaction
+pub fn v0_transaction<T: Signer>(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    tables: &[AddressLookupTableAccount],
+    blockhash: Hash,
+    signers: &[&T],
+) -> Result<VersionedTransaction> {
+    let message = v0_message(payer, instructions, tables, blockhash)?;
+    VersionedTransaction::try_new(message, signers).map_err(ClientError::Signing)
+}
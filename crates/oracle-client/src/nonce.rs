@@ -0,0 +1,88 @@
+//! Durable-nonce transactions.
+//!
+//! Signals are often signed by an offline model-signer and submitted later,
+//! long after a recent blockhash would have expired. Transactions built here
+//! use a nonce account's stored blockhash instead, and stay valid until the
+//! nonce is advanced (which the transaction itself does on landing).
+
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_nonce_utils::nonblocking::{data_from_account, get_account_with_commitment};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::Message;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::Transaction;
+use solana_system_interface::instruction as system_instruction;
+
+use crate::{ClientError, Result};
+
+/// Current state of a durable nonce account
+#[derive(Debug, Clone, Copy)]
+pub struct NonceInfo {
+    pub address: Pubkey,
+    pub authority: Pubkey,
+    pub blockhash: Hash,
+}
+
+/// Instructions creating `nonce` as a nonce account controlled by `authority`
+pub fn create_nonce_account(
+    payer: &Pubkey,
+    nonce: &Pubkey,
+    authority: &Pubkey,
+    lamports: u64,
+) -> Vec<Instruction> {
+    system_instruction::create_nonce_account(payer, nonce, authority, lamports)
+}
+
+/// Read the stored blockhash and authority of `nonce`
+pub async fn fetch_nonce(rpc: &RpcClient, nonce: &Pubkey) -> Result<NonceInfo> {
+    let account = get_account_with_commitment(rpc, nonce, CommitmentConfig::confirmed()).await?;
+    let data = data_from_account(&account)?;
+    Ok(NonceInfo {
+        address: *nonce,
+        authority: data.authority,
+        blockhash: data.blockhash(),
+    })
+}
+
+/// Message that advances `nonce` first and uses its blockhash
+pub fn nonce_message(instructions: &[Instruction], payer: &Pubkey, nonce: &NonceInfo) -> Message {
+    let mut message = Message::new_with_nonce(
+        instructions.to_vec(),
+        Some(payer),
+        &nonce.address,
+        &nonce.authority,
+    );
+    message.recent_blockhash = nonce.blockhash;
+    message
+}
+
+/// Sign a nonce transaction with whichever of its signers are available
+///
+/// The result can be serialized and handed to other signers (or stored)
+/// before submission; it does not expire until the nonce advances.
+pub fn sign_partial<T: Signer>(
+    instructions: &[Instruction],
+    payer: &Pubkey,
+    nonce: &NonceInfo,
+    signers: &[&T],
+) -> Result<Transaction> {
+    let mut tx = Transaction::new_unsigned(nonce_message(instructions, payer, nonce));
+    tx.try_partial_sign(signers, nonce.blockhash)
+        .map_err(ClientError::Signing)?;
+    Ok(tx)
+}
+
+/// Add `signers` to a partially signed nonce transaction
+pub fn add_signatures<T: Signer>(tx: &mut Transaction, signers: &[&T]) -> Result<()> {
+    let blockhash = tx.message.recent_blockhash;
+    tx.try_partial_sign(signers, blockhash)
+        .map_err(ClientError::Signing)
+}
+
+/// Check that `tx` is still bound to the nonce's current blockhash
+pub async fn is_nonce_current(rpc: &RpcClient, tx: &Transaction, nonce: &Pubkey) -> Result<bool> {
+    Ok(fetch_nonce(rpc, nonce).await?.blockhash == tx.message.recent_blockhash)
+}
@@ -0,0 +1,22 @@
+//! PDA derivations.
+//!
+//! Program-owned addresses come from `oracle_types::pda` (usable without
+//! this crate); addresses that need other programs' derivations live here.
+
+use solana_sdk::pubkey::Pubkey;
+
+pub use oracle_types::pda::*;
+
+/// Clockwork thread owned by the treasury under `thread_id`
+pub fn thread(thread_id: &[u8]) -> Pubkey {
+    oracle::automation::thread_address(&treasury(), thread_id)
+}
+
+/// Token-2022 account holding `subscriber`'s pass
+pub fn subscriber_pass(subscriber: &Pubkey) -> Pubkey {
+    anchor_spl::associated_token::get_associated_token_address_with_program_id(
+        subscriber,
+        &pass_mint(),
+        &anchor_spl::token_2022::ID,
+    )
+}
@@ -0,0 +1,274 @@
+//! A pool of RPC endpoints with health checks and failover.
+//!
+//! Calls go to the first healthy endpoint with budget to spare, in the
+//! order the endpoints were given (so list the preferred provider first).
+//! Transport failures, HTTP errors and "node unhealthy" responses mark the
+//! endpoint unhealthy and move on to the next one; any other error is the
+//! call's own and is returned as is. Unhealthy endpoints are still tried
+//! after the healthy ones, and [`RpcPool::check_health`] brings them back
+//! once they answer and have caught up.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures_util::future::{join_all, BoxFuture};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::client_error::{Error as RpcError, ErrorKind};
+use solana_rpc_client_api::custom_error::JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY;
+use solana_rpc_client_api::request::RpcError as RpcRequestError;
+use solana_sdk::commitment_config::CommitmentConfig;
+
+use crate::{ClientError, Result};
+
+/// How far behind the best endpoint's slot an endpoint may be and still
+/// count as healthy
+pub const DEFAULT_MAX_SLOT_LAG: u64 = 50;
+
+/// One RPC provider
+#[derive(Debug, Clone)]
+pub struct EndpointConfig {
+    pub url: String,
+    /// Requests per second this endpoint may be sent (0 = unlimited)
+    pub requests_per_sec: u32,
+}
+
+impl EndpointConfig {
+    pub fn new(url: impl Into<String>) -> Self {
+        EndpointConfig {
+            url: url.into(),
+            requests_per_sec: 0,
+        }
+    }
+
+    pub fn with_rate(mut self, requests_per_sec: u32) -> Self {
+        self.requests_per_sec = requests_per_sec;
+        self
+    }
+}
+
+/// Point-in-time view of an endpoint, for logging and metrics
+#[derive(Debug, Clone)]
+pub struct EndpointStatus {
+    pub url: String,
+    pub healthy: bool,
+    pub consecutive_failures: u32,
+    pub slot: Option<u64>,
+}
+
+struct State {
+    healthy: bool,
+    consecutive_failures: u32,
+    slot: Option<u64>,
+    tokens: f64,
+    updated: Instant,
+}
+
+struct Endpoint {
+    url: String,
+    client: RpcClient,
+    rate: f64,
+    state: Mutex<State>,
+}
+
+impl Endpoint {
+    /// Take one request from the budget; on refusal, how long until one is free
+    fn take(&self) -> std::result::Result<(), Duration> {
+        if self.rate == 0.0 {
+            return Ok(());
+        }
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        let refill = now.duration_since(state.updated).as_secs_f64() * self.rate;
+        state.tokens = (state.tokens + refill).min(self.rate);
+        state.updated = now;
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - state.tokens) / self.rate))
+        }
+    }
+
+    fn is_healthy(&self) -> bool {
+        self.state.lock().unwrap().healthy
+    }
+
+    fn record(&self, ok: bool) {
+        let mut state = self.state.lock().unwrap();
+        state.healthy = ok;
+        state.consecutive_failures = if ok { 0 } else { state.consecutive_failures + 1 };
+    }
+}
+
+/// RPC endpoints behind one failover interface
+pub struct RpcPool {
+    endpoints: Vec<Endpoint>,
+    max_slot_lag: u64,
+}
+
+impl RpcPool {
+    pub fn new(endpoints: Vec<EndpointConfig>, commitment: CommitmentConfig) -> Self {
+        assert!(!endpoints.is_empty(), "an RPC pool needs at least one endpoint");
+        let now = Instant::now();
+        let endpoints = endpoints
+            .into_iter()
+            .map(|config| Endpoint {
+                client: RpcClient::new_with_commitment(config.url.clone(), commitment),
+                url: config.url,
+                rate: f64::from(config.requests_per_sec),
+                state: Mutex::new(State {
+                    healthy: true,
+                    consecutive_failures: 0,
+                    slot: None,
+                    tokens: f64::from(config.requests_per_sec),
+                    updated: now,
+                }),
+            })
+            .collect();
+        RpcPool {
+            endpoints,
+            max_slot_lag: DEFAULT_MAX_SLOT_LAG,
+        }
+    }
+
+    /// Pool from plain URLs, without rate budgets
+    pub fn from_urls<I, S>(urls: I, commitment: CommitmentConfig) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::new(urls.into_iter().map(EndpointConfig::new).collect(), commitment)
+    }
+
+    pub fn with_max_slot_lag(mut self, max_slot_lag: u64) -> Self {
+        self.max_slot_lag = max_slot_lag;
+        self
+    }
+
+    /// Run `call` against the best available endpoint, failing over to the
+    /// next one when the endpoint itself is at fault.
+    ///
+    /// ```ignore
+    /// let signals = pool.call(|rpc| Box::pin(query::fetch_open_signals(rpc))).await?;
+    /// ```
+    pub async fn call<T, F>(&self, call: F) -> Result<T>
+    where
+        F: for<'a> Fn(&'a RpcClient) -> BoxFuture<'a, Result<T>>,
+    {
+        let mut order: Vec<&Endpoint> = self.endpoints.iter().filter(|e| e.is_healthy()).collect();
+        order.extend(self.endpoints.iter().filter(|e| !e.is_healthy()));
+
+        let mut last_err = None;
+        let mut remaining = order;
+        while !remaining.is_empty() {
+            let mut throttled = Vec::new();
+            let mut wait = Duration::MAX;
+            for endpoint in remaining {
+                if let Err(until) = endpoint.take() {
+                    wait = wait.min(until);
+                    throttled.push(endpoint);
+                    continue;
+                }
+                match call(&endpoint.client).await {
+                    Err(err) if is_endpoint_fault(&err) => {
+                        endpoint.record(false);
+                        last_err = Some(err);
+                    }
+                    result => {
+                        endpoint.record(true);
+                        return result;
+                    }
+                }
+            }
+            // Everything left is only out of budget: wait for the first refill
+            if !throttled.is_empty() {
+                tokio::time::sleep(wait).await;
+            }
+            remaining = throttled;
+        }
+        Err(last_err.expect("every endpoint was tried"))
+    }
+
+    /// Client of the first healthy endpoint, for calls that need an
+    /// `RpcClient` directly (no failover or budget accounting)
+    pub fn primary(&self) -> &RpcClient {
+        let endpoint = self.endpoints.iter().find(|e| e.is_healthy()).unwrap_or(&self.endpoints[0]);
+        &endpoint.client
+    }
+
+    /// Probe every endpoint with `getHealth` and `getSlot`. An endpoint is
+    /// healthy if both succeed and its slot is within the pool's maximum
+    /// lag of the best slot seen.
+    pub async fn check_health(&self) -> Vec<EndpointStatus> {
+        let slots = join_all(self.endpoints.iter().map(|endpoint| async move {
+            endpoint.client.get_health().await.ok()?;
+            endpoint.client.get_slot().await.ok()
+        }))
+        .await;
+        let best = slots.iter().flatten().copied().max().unwrap_or(0);
+
+        self.endpoints
+            .iter()
+            .zip(slots)
+            .map(|(endpoint, slot)| {
+                let healthy = slot.is_some_and(|slot| slot + self.max_slot_lag >= best);
+                endpoint.record(healthy);
+                let mut state = endpoint.state.lock().unwrap();
+                state.slot = slot;
+                EndpointStatus {
+                    url: endpoint.url.clone(),
+                    healthy,
+                    consecutive_failures: state.consecutive_failures,
+                    slot,
+                }
+            })
+            .collect()
+    }
+
+    /// Run [`RpcPool::check_health`] every `interval` in the background
+    pub fn spawn_health_checks(self: &Arc<Self>, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let pool = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                pool.check_health().await;
+            }
+        })
+    }
+
+    pub fn status(&self) -> Vec<EndpointStatus> {
+        self.endpoints
+            .iter()
+            .map(|endpoint| {
+                let state = endpoint.state.lock().unwrap();
+                EndpointStatus {
+                    url: endpoint.url.clone(),
+                    healthy: state.healthy,
+                    consecutive_failures: state.consecutive_failures,
+                    slot: state.slot,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Whether an error says the endpoint is down or overloaded rather than
+/// the request being bad
+fn is_endpoint_fault(err: &ClientError) -> bool {
+    let ClientError::Rpc(err) = err else {
+        return false;
+    };
+    is_transport_error(err)
+}
+
+pub(crate) fn is_transport_error(err: &RpcError) -> bool {
+    match err.kind() {
+        ErrorKind::Io(_) | ErrorKind::Reqwest(_) | ErrorKind::Middleware(_) => true,
+        ErrorKind::RpcError(RpcRequestError::RpcRequestError(_)) => true,
+        ErrorKind::RpcError(RpcRequestError::RpcResponseError { code, .. }) => {
+            *code == JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY
+        }
+        _ => false,
+    }
+}
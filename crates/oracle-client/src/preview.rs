@@ -0,0 +1,98 @@
+//! Settlement previews.
+//!
+//! [`preview_close`] runs the same `oracle_math` ROI and outcome functions
+//! `close_signal` does, so what it reports is what the program would write:
+//! useful to sanity-check an exit price before submitting, or to show
+//! "if we closed now" numbers for an open signal.
+
+use oracle_types::{OracleConfig, OracleState, Signal, SignalDirection, SignalStatus};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+
+use crate::{fetch, math, ClientError, Result};
+
+/// What closing a signal at a given price would do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClosePreview {
+    pub exit_price: u64,
+    pub status: SignalStatus,
+    pub roi_bps: i64,
+    /// `roi_bps` after the config's friction
+    pub net_roi_bps: i64,
+    /// Added to `OracleState::total_wins`
+    pub wins_delta: u64,
+    /// Added to `OracleState::total_losses`
+    pub losses_delta: u64,
+}
+
+impl ClosePreview {
+    /// `state` as it would be after the close
+    pub fn apply(&self, state: &OracleState) -> OracleState {
+        OracleState {
+            total_wins: state.total_wins + self.wins_delta,
+            total_losses: state.total_losses + self.losses_delta,
+            ..state.clone()
+        }
+    }
+
+    /// Oracle win rate before and after the close, in basis points
+    pub fn win_rate_bps(&self, state: &OracleState) -> (u64, u64) {
+        let after = self.apply(state);
+        (
+            math::win_rate_bps(state.total_wins, state.total_losses),
+            math::win_rate_bps(after.total_wins, after.total_losses),
+        )
+    }
+}
+
+/// Outcome of closing `signal` at `exit_price` under `config`, computed as
+/// the program would; fails if the signal is already settled
+pub fn preview_close(signal: &Signal, config: &OracleConfig, exit_price: u64) -> Result<ClosePreview> {
+    if signal.status != SignalStatus::Open {
+        return Err(ClientError::SignalClosed(signal.id));
+    }
+    // Like the program, keep the stored ROI when there is no entry price
+    let roi_bps = math::roi_bps(signal.entry_price, exit_price, direction(signal.direction)).unwrap_or(signal.roi_bps);
+    let net_roi_bps = math::net_roi_bps(signal.entry_price, exit_price, direction(signal.direction), friction(config))
+        .unwrap_or(roi_bps);
+    let win_threshold_bps = config
+        .win_threshold_bps
+        .get(signal.risk_level as usize)
+        .copied()
+        .unwrap_or(math::WIN_THRESHOLD_BPS);
+    let outcome = math::classify(roi_bps, win_threshold_bps, config.break_even_bps);
+    let (status, wins_delta, losses_delta) = match outcome {
+        math::Outcome::Win => (SignalStatus::Win, 1, 0),
+        math::Outcome::Loss => (SignalStatus::Loss, 0, 1),
+        math::Outcome::Closed => (SignalStatus::Closed, 0, 0),
+        math::Outcome::BreakEven => (SignalStatus::BreakEven, 0, 0),
+    };
+    Ok(ClosePreview {
+        exit_price,
+        status,
+        roi_bps,
+        net_roi_bps,
+        wins_delta,
+        losses_delta,
+    })
+}
+
+/// Fetch signal `id` and preview closing it at `exit_price`
+pub async fn fetch_preview_close(rpc: &RpcClient, id: u64, exit_price: u64) -> Result<ClosePreview> {
+    let config = fetch::fetch_config(rpc).await?;
+    preview_close(&fetch::fetch_signal(rpc, id).await?, &config, exit_price)
+}
+
+fn friction(config: &OracleConfig) -> math::Friction {
+    math::Friction {
+        entry_slippage_bps: config.friction.entry_slippage_bps,
+        exit_slippage_bps: config.friction.exit_slippage_bps,
+        swap_fee_bps: config.friction.swap_fee_bps,
+    }
+}
+
+fn direction(direction: SignalDirection) -> math::Direction {
+    match direction {
+        SignalDirection::Long => math::Direction::Long,
+        SignalDirection::Short => math::Direction::Short,
+    }
+}
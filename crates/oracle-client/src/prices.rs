@@ -0,0 +1,45 @@
+//! Off-chain pool prices, computed exactly as the program computes them.
+
+use oracle::pools::{decode_pool, token_price, PoolPrice};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::instructions::PoolAccounts;
+use crate::{ClientError, Result};
+
+/// Offset of `decimals` in an SPL Token / Token-2022 mint
+const MINT_DECIMALS_OFFSET: usize = 44;
+
+/// USDC mint; USD prices from the aggregator are submitted as quoted in it
+pub const USDC_MINT: Pubkey = solana_sdk::pubkey!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+
+/// Spot price of `token` in `pool`, scaled by 10^PRICE_DECIMALS
+pub async fn fetch_pool_price(rpc: &RpcClient, pool: &Pubkey, token: &Pubkey) -> Result<PoolPrice> {
+    let account = rpc.get_account(pool).await?;
+    let raw = decode_pool(&account.owner, &account.data)?;
+
+    let mints = rpc.get_multiple_accounts(&[raw.mint_a, raw.mint_b]).await?;
+    let decimals = |mint: &Pubkey, account: Option<&solana_sdk::account::Account>| {
+        account
+            .and_then(|account| account.data.get(MINT_DECIMALS_OFFSET).copied())
+            .ok_or(ClientError::InvalidAccountData(*mint))
+    };
+    let decimals_a = decimals(&raw.mint_a, mints[0].as_ref())?;
+    let decimals_b = decimals(&raw.mint_b, mints[1].as_ref())?;
+
+    Ok(token_price(&raw, token, decimals_a, decimals_b)?)
+}
+
+/// `pool` with its two mints and reserve vaults in pool order, as the
+/// verified-price instructions take it
+pub async fn fetch_pool_accounts(rpc: &RpcClient, pool: &Pubkey) -> Result<PoolAccounts> {
+    let account = rpc.get_account(pool).await?;
+    let raw = decode_pool(&account.owner, &account.data)?;
+    Ok(PoolAccounts {
+        pool: *pool,
+        mint_a: raw.mint_a,
+        mint_b: raw.mint_b,
+        vault_a: raw.vault_a,
+        vault_b: raw.vault_b,
+    })
+}
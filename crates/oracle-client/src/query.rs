@@ -0,0 +1,148 @@
+//! Filtered `getProgramAccounts` queries over signals.
+//!
+//! The discriminator and token sit at fixed offsets and are matched by the
+//! RPC node. Status and timestamp come after the variable-length symbol,
+//! so those filters are applied after decoding. Signal accounts vary in
+//! size (longer symbols, reserved tail), so legacy-layout accounts, which
+//! share the discriminator, are told apart by their exact size.
+
+use borsh::BorshDeserialize;
+use oracle_types::{DcaPlan, Discriminator, Invalidation, Signal, SignalStatus, Subscription, TakeProfitLadder};
+use solana_account_decoder_client_types::{UiAccountEncoding, UiDataSliceConfig};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::fetch::decode;
+use crate::{ClientError, Result, PROGRAM_ID};
+
+/// Size of signal accounts still in the pre-`direction` layout
+pub const LEGACY_SIGNAL_ACCOUNT_SIZE: u64 = oracle::LegacySignal::SPACE as u64;
+
+/// Filters matching signal accounts, legacy-layout ones included
+pub fn signal_filters() -> Vec<RpcFilterType> {
+    vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, Signal::DISCRIMINATOR.to_vec()))]
+}
+
+/// Filter matching signals on `mint`
+pub fn token_filter(mint: &Pubkey) -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+        oracle::SIGNAL_TOKEN_OFFSET as usize,
+        mint.to_bytes().to_vec(),
+    ))
+}
+
+/// Signals matching `signal_filters()` plus `extra`, ordered by id
+pub async fn fetch_signals(rpc: &RpcClient, extra: Vec<RpcFilterType>) -> Result<Vec<(Pubkey, Signal)>> {
+    let mut filters = signal_filters();
+    filters.extend(extra);
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            commitment: Some(rpc.commitment()),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+    let mut signals = rpc
+        .get_program_accounts_with_config(&PROGRAM_ID, config)
+        .await?
+        .into_iter()
+        .filter(|(_, account)| account.data.len() as u64 != LEGACY_SIGNAL_ACCOUNT_SIZE)
+        .map(|(address, account)| Ok((address, decode::<Signal>(&address, &account.data)?)))
+        .collect::<Result<Vec<_>>>()?;
+    signals.sort_by_key(|(_, signal)| signal.id);
+    Ok(signals)
+}
+
+/// Signals that have not been closed yet
+pub async fn fetch_open_signals(rpc: &RpcClient) -> Result<Vec<(Pubkey, Signal)>> {
+    let mut signals = fetch_signals(rpc, Vec::new()).await?;
+    signals.retain(|(_, signal)| signal.status == SignalStatus::Open);
+    Ok(signals)
+}
+
+/// Every signal called on `mint`
+pub async fn fetch_signals_for_token(rpc: &RpcClient, mint: &Pubkey) -> Result<Vec<(Pubkey, Signal)>> {
+    fetch_signals(rpc, vec![token_filter(mint)]).await
+}
+
+/// Signals published at or after unix time `since`
+pub async fn fetch_signals_since(rpc: &RpcClient, since: i64) -> Result<Vec<(Pubkey, Signal)>> {
+    let mut signals = fetch_signals(rpc, Vec::new()).await?;
+    signals.retain(|(_, signal)| signal.timestamp >= since);
+    Ok(signals)
+}
+
+/// Ids and addresses of signals that still need `migrate_signal`,
+/// ordered by id
+pub async fn fetch_legacy_signals(rpc: &RpcClient) -> Result<Vec<(u64, Pubkey)>> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![
+            RpcFilterType::DataSize(LEGACY_SIGNAL_ACCOUNT_SIZE),
+            RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, Signal::DISCRIMINATOR.to_vec())),
+        ]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            // Only the id is needed
+            data_slice: Some(UiDataSliceConfig {
+                offset: oracle::SIGNAL_ID_OFFSET as usize,
+                length: 8,
+            }),
+            commitment: Some(rpc.commitment()),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+    let mut signals = rpc
+        .get_program_accounts_with_config(&PROGRAM_ID, config)
+        .await?
+        .into_iter()
+        .map(|(address, account)| {
+            let id = <[u8; 8]>::try_from(account.data.as_slice()).map_err(|_| ClientError::InvalidAccountData(address))?;
+            Ok((u64::from_le_bytes(id), address))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    signals.sort();
+    Ok(signals)
+}
+
+/// Every subscription account, in no particular order
+pub async fn fetch_subscriptions(rpc: &RpcClient) -> Result<Vec<(Pubkey, Subscription)>> {
+    fetch_all(rpc).await
+}
+
+/// Every DCA plan, whether or not its signal is still open
+pub async fn fetch_dca_plans(rpc: &RpcClient) -> Result<Vec<(Pubkey, DcaPlan)>> {
+    fetch_all(rpc).await
+}
+
+/// Every take-profit ladder, whether or not its signal is still open
+pub async fn fetch_take_profit_ladders(rpc: &RpcClient) -> Result<Vec<(Pubkey, TakeProfitLadder)>> {
+    fetch_all(rpc).await
+}
+
+/// Every invalidation price, whether or not its signal is still open
+pub async fn fetch_invalidations(rpc: &RpcClient) -> Result<Vec<(Pubkey, Invalidation)>> {
+    fetch_all(rpc).await
+}
+
+/// Every account of type `T`, in no particular order
+async fn fetch_all<T: Discriminator + BorshDeserialize>(rpc: &RpcClient) -> Result<Vec<(Pubkey, T)>> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, T::DISCRIMINATOR.to_vec()))]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            commitment: Some(rpc.commitment()),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+    rpc.get_program_accounts_with_config(&PROGRAM_ID, config)
+        .await?
+        .into_iter()
+        .map(|(address, account)| Ok((address, decode(&address, &account.data)?)))
+        .collect()
+}
@@ -0,0 +1,111 @@
+//! Rationale documents behind `publish_signal_with_proof`.
+//!
+//! The program stores only the SHA256 of a signal's reasoning, committed
+//! at publish time and checkable once the document is revealed. This
+//! module hashes the document, optionally pins it to content-addressed
+//! storage so the reveal is a link rather than a copy-paste, and builds
+//! the publish instruction around the hash. The storage URI is returned
+//! to the caller (for logs, the reveal post, the API); there is no
+//! on-chain field for it.
+
+use futures_util::future::BoxFuture;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::instructions::{self, SignalParams};
+use crate::{ClientError, Result};
+
+/// SHA256 of the reasoning document, as committed on-chain
+pub fn reasoning_hash(document: &[u8]) -> [u8; 32] {
+    Sha256::digest(document).into()
+}
+
+/// Content-addressed storage for rationale documents
+pub trait Pinner: Send + Sync {
+    /// Store `document` and return its URI (e.g. `ipfs://<cid>`)
+    fn pin<'a>(&'a self, name: &'a str, document: &'a [u8]) -> BoxFuture<'a, Result<String>>;
+}
+
+/// IPFS node or pinning service speaking the Kubo RPC API
+/// (`POST /api/v0/add`)
+pub struct Ipfs {
+    api_url: String,
+    token: Option<String>,
+    http: reqwest::Client,
+}
+
+impl Ipfs {
+    /// `api_url` is the API root, e.g. `http://127.0.0.1:5001`; `token`
+    /// is sent as a bearer token for hosted pinning services
+    pub fn new(api_url: impl Into<String>, token: Option<String>) -> Self {
+        Ipfs {
+            api_url: api_url.into().trim_end_matches('/').to_string(),
+            token,
+            http: reqwest::Client::new(),
+        }
+    }
+}
+
+impl Pinner for Ipfs {
+    fn pin<'a>(&'a self, name: &'a str, document: &'a [u8]) -> BoxFuture<'a, Result<String>> {
+        Box::pin(async move {
+            let upload = |err: reqwest::Error| ClientError::Upload(err.without_url().to_string());
+            let part = reqwest::multipart::Part::bytes(document.to_vec()).file_name(name.to_string());
+            let mut request = self
+                .http
+                .post(format!("{}/api/v0/add", self.api_url))
+                .query(&[("pin", "true"), ("cid-version", "1")])
+                .multipart(reqwest::multipart::Form::new().part("file", part));
+            if let Some(token) = &self.token {
+                request = request.bearer_auth(token);
+            }
+            let response: Value = request
+                .send()
+                .await
+                .and_then(|response| response.error_for_status())
+                .map_err(upload)?
+                .json()
+                .await
+                .map_err(upload)?;
+            let cid = response
+                .get("Hash")
+                .and_then(Value::as_str)
+                .ok_or_else(|| ClientError::Upload("response has no `Hash`".into()))?;
+            Ok(format!("ipfs://{cid}"))
+        })
+    }
+}
+
+/// A hashed (and possibly pinned) rationale document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rationale {
+    pub hash: [u8; 32],
+    pub uri: Option<String>,
+}
+
+impl Rationale {
+    /// Hash `document`, pinning it first when a `pinner` is given
+    pub async fn prepare(name: &str, document: &[u8], pinner: Option<&dyn Pinner>) -> Result<Self> {
+        let uri = match pinner {
+            Some(pinner) => Some(pinner.pin(name, document).await?),
+            None => None,
+        };
+        Ok(Rationale {
+            hash: reasoning_hash(document),
+            uri,
+        })
+    }
+
+    /// `publish_signal_with_proof` committing to this rationale
+    pub fn publish_instruction(
+        &self,
+        authority: &Pubkey,
+        signal_id: u64,
+        has_profile: bool,
+        params: SignalParams,
+    ) -> Instruction {
+        instructions::publish_signal_with_proof(authority, signal_id, has_profile, params, self.hash)
+    }
+}
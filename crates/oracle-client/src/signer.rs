@@ -0,0 +1,175 @@
+//! Transaction signers: local keypairs, Ledger devices and remote signing
+//! services.
+//!
+//! Everything here implements the SDK's [`Signer`] trait, so any of them
+//! can be passed wherever the client signs (`Submitter::submit`,
+//! `Transaction::try_sign`). [`load_signer`] picks one from a single
+//! string, which is what the binaries expose as `--keypair`:
+//!
+//! - a keypair file path (`~` is expanded);
+//! - `usb://ledger[/<pubkey>]` for a Ledger (build with the `ledger`
+//!   feature);
+//! - an `http(s)://` URL of a remote signer, e.g. a service fronting an
+//!   HSM or cloud KMS.
+//!
+//! A remote signer answers two requests, authenticated with
+//! `Authorization: Bearer <token>` when a token is configured:
+//!
+//! ```text
+//! GET  {url}/pubkey  -> {"pubkey": "<base58>"}
+//! POST {url}/sign    {"pubkey": "<base58>", "message": "<base64>"}
+//!                    -> {"signature": "<base58>"}
+//! ```
+
+use std::time::Duration;
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde_json::{json, Value};
+use solana_derivation_path::DerivationPath;
+use solana_remote_wallet::locator::Locator;
+use solana_remote_wallet::remote_keypair::generate_remote_keypair;
+use solana_remote_wallet::remote_wallet::initialize_wallet_manager;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Signature};
+pub use solana_sdk::signer::Signer;
+use solana_sdk::signer::SignerError;
+
+use crate::{ClientError, Result};
+
+/// Environment variable holding the bearer token for remote signers
+pub const SIGNER_TOKEN_ENV: &str = "ORACLE_SIGNER_TOKEN";
+
+/// Resolve a keypair path, Ledger locator or remote signer URL.
+///
+/// `derivation_path` only applies to Ledger keys; remote signers use the
+/// token from `ORACLE_SIGNER_TOKEN`, if set.
+pub fn load_signer(source: &str, derivation_path: Option<&str>) -> Result<Box<dyn Signer>> {
+    if source.starts_with("usb://") {
+        return Ok(Box::new(load_ledger(source, derivation_path)?));
+    }
+    if source.starts_with("https://") || source.starts_with("http://") {
+        let token = std::env::var(SIGNER_TOKEN_ENV).ok();
+        return Ok(Box::new(RemoteSigner::connect(source, token)?));
+    }
+
+    let path = expand_home(source);
+    let keypair = read_keypair_file(&path)
+        .map_err(|err| ClientError::Signing(SignerError::Custom(format!("reading keypair {path}: {err}"))))?;
+    Ok(Box::new(keypair))
+}
+
+fn load_ledger(locator: &str, derivation_path: Option<&str>) -> Result<impl Signer> {
+    let invalid = |err: String| ClientError::Signing(SignerError::InvalidInput(err));
+    let locator = Locator::new_from_path(locator).map_err(|err| invalid(format!("invalid locator {locator}: {err}")))?;
+    let derivation_path = match derivation_path {
+        Some(key) => DerivationPath::from_key_str(key).map_err(|err| invalid(format!("invalid derivation path {key}: {err}")))?,
+        None => DerivationPath::default(),
+    };
+    let manager = initialize_wallet_manager()
+        .map_err(|err| invalid(format!("Ledger support unavailable (build with --features ledger): {err}")))?;
+    manager.update_devices().map_err(|err| ClientError::Signing(err.into()))?;
+    generate_remote_keypair(locator, derivation_path, &manager, true, "oracle")
+        .map_err(|err| ClientError::Signing(err.into()))
+}
+
+/// Key held by a remote signing service
+#[derive(Debug)]
+pub struct RemoteSigner {
+    url: String,
+    pubkey: Pubkey,
+    token: Option<String>,
+    timeout: Duration,
+}
+
+impl RemoteSigner {
+    /// Signer for `pubkey` at `url`, without contacting the service
+    pub fn new(url: impl Into<String>, pubkey: Pubkey, token: Option<String>) -> Self {
+        RemoteSigner {
+            url: url.into().trim_end_matches('/').to_string(),
+            pubkey,
+            token,
+            timeout: Duration::from_secs(10),
+        }
+    }
+
+    /// Ask the service at `url` which key it holds
+    pub fn connect(url: &str, token: Option<String>) -> Result<Self> {
+        let mut signer = RemoteSigner::new(url, Pubkey::default(), token);
+        let response = signer.request(reqwest::Method::GET, "pubkey", None).map_err(ClientError::Signing)?;
+        signer.pubkey = parse_field(&response, "pubkey").map_err(ClientError::Signing)?;
+        Ok(signer)
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// One blocking request, made on its own thread so it's safe to call
+    /// from inside an async runtime (signing is synchronous)
+    fn request(&self, method: reqwest::Method, path: &str, body: Option<Value>) -> std::result::Result<Value, SignerError> {
+        std::thread::scope(|scope| {
+            scope
+                .spawn(|| {
+                    let client = reqwest::blocking::Client::builder()
+                        .timeout(self.timeout)
+                        .build()
+                        .map_err(|err| SignerError::Connection(err.to_string()))?;
+                    let mut request = client.request(method, format!("{}/{path}", self.url));
+                    if let Some(token) = &self.token {
+                        request = request.bearer_auth(token);
+                    }
+                    if let Some(body) = body {
+                        request = request.json(&body);
+                    }
+                    let response = request
+                        .send()
+                        .and_then(|response| response.error_for_status())
+                        .map_err(|err| SignerError::Connection(err.without_url().to_string()))?;
+                    response.json().map_err(|err| SignerError::Protocol(err.to_string()))
+                })
+                .join()
+                .map_err(|_| SignerError::Custom("remote signer request panicked".into()))?
+        })
+    }
+}
+
+impl Signer for RemoteSigner {
+    fn try_pubkey(&self) -> std::result::Result<Pubkey, SignerError> {
+        Ok(self.pubkey)
+    }
+
+    fn try_sign_message(&self, message: &[u8]) -> std::result::Result<Signature, SignerError> {
+        let body = json!({
+            "pubkey": self.pubkey.to_string(),
+            "message": BASE64.encode(message),
+        });
+        let response = self.request(reqwest::Method::POST, "sign", Some(body))?;
+        let signature: Signature = parse_field(&response, "signature")?;
+        // Never hand back a signature the network would reject
+        if !signature.verify(self.pubkey.as_ref(), message) {
+            return Err(SignerError::Protocol(format!("remote signature does not verify for {}", self.pubkey)));
+        }
+        Ok(signature)
+    }
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
+}
+
+fn parse_field<T: std::str::FromStr>(response: &Value, field: &str) -> std::result::Result<T, SignerError> {
+    response
+        .get(field)
+        .and_then(Value::as_str)
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| SignerError::Protocol(format!("response has no valid `{field}`")))
+}
+
+fn expand_home(path: &str) -> String {
+    match (path.strip_prefix("~/"), std::env::var("HOME")) {
+        (Some(rest), Ok(home)) => format!("{home}/{rest}"),
+        _ => path.to_string(),
+    }
+}
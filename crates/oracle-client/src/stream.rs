@@ -0,0 +1,199 @@
+//! Live signal event stream over websocket log subscriptions.
+//!
+//! `SignalStream` subscribes to the program's transaction logs, decodes
+//! publish/close events into `oracle-types` structs, and delivers each
+//! transaction's events at most once, reconnecting with backoff whenever the
+//! websocket drops.
+
+use std::collections::{HashSet, VecDeque};
+use std::time::Duration;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use futures_util::StreamExt;
+use oracle_types::{decode_event, SignalClosed, SignalPublished, SignalPublishedWithProof};
+use solana_pubsub_client::nonblocking::pubsub_client::PubsubClient;
+use solana_rpc_client_api::config::{RpcTransactionLogsConfig, RpcTransactionLogsFilter};
+use solana_sdk::commitment_config::CommitmentConfig;
+use tokio::sync::mpsc;
+
+use crate::{Result, PROGRAM_ID};
+
+/// Signatures remembered for deduplication across reconnects
+const DEDUP_CAPACITY: usize = 4_096;
+
+/// Decoded signal lifecycle event
+#[derive(Debug, Clone, PartialEq)]
+pub enum SignalEvent {
+    Published(SignalPublished),
+    PublishedWithProof(SignalPublishedWithProof),
+    Closed(SignalClosed),
+}
+
+/// Event together with the transaction that emitted it
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamItem {
+    pub signature: String,
+    pub slot: u64,
+    pub event: SignalEvent,
+}
+
+/// Decode one `Program data:` payload, if it is a signal event
+pub fn decode_signal_event(data: &[u8]) -> Option<SignalEvent> {
+    decode_event(data)
+        .map(SignalEvent::Published)
+        .or_else(|| decode_event(data).map(SignalEvent::PublishedWithProof))
+        .or_else(|| decode_event(data).map(SignalEvent::Closed))
+}
+
+/// Decoded `Program data:` payloads the oracle program logged in a
+/// transaction, in order
+///
+/// Only lines logged while the oracle program is the innermost invocation
+/// are considered, so other programs in the same transaction cannot inject
+/// events.
+pub fn program_data(logs: &[String]) -> Vec<Vec<u8>> {
+    let program = PROGRAM_ID.to_string();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut payloads = Vec::new();
+    for line in logs {
+        let Some(rest) = line.strip_prefix("Program ") else {
+            continue;
+        };
+        if let Some(data) = rest.strip_prefix("data: ") {
+            if stack.last() == Some(&program.as_str()) {
+                if let Ok(payload) = STANDARD.decode(data) {
+                    payloads.push(payload);
+                }
+            }
+        } else if let Some((id, tail)) = rest.split_once(' ') {
+            if tail.starts_with("invoke [") {
+                stack.push(id);
+            } else if tail == "success" || tail.starts_with("failed") {
+                stack.pop();
+            }
+        }
+    }
+    payloads
+}
+
+/// Signal events emitted by the oracle program in a transaction's logs
+pub fn parse_logs(logs: &[String]) -> Vec<SignalEvent> {
+    program_data(logs).iter().filter_map(|data| decode_signal_event(data)).collect()
+}
+
+/// Bounded set of recently seen signatures
+#[derive(Debug, Default)]
+struct Dedup {
+    seen: HashSet<String>,
+    order: VecDeque<String>,
+}
+
+impl Dedup {
+    /// Record `signature`, returning false if it was already seen
+    fn insert(&mut self, signature: &str) -> bool {
+        if self.seen.contains(signature) {
+            return false;
+        }
+        if self.order.len() == DEDUP_CAPACITY {
+            if let Some(oldest) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+        self.seen.insert(signature.to_string());
+        self.order.push_back(signature.to_string());
+        true
+    }
+}
+
+/// Reconnecting subscriber to the oracle's signal events
+#[derive(Debug)]
+pub struct SignalStream {
+    ws_url: String,
+    commitment: CommitmentConfig,
+    min_backoff: Duration,
+    max_backoff: Duration,
+    dedup: Dedup,
+}
+
+impl SignalStream {
+    pub fn new(ws_url: impl Into<String>) -> Self {
+        SignalStream {
+            ws_url: ws_url.into(),
+            commitment: CommitmentConfig::confirmed(),
+            min_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            dedup: Dedup::default(),
+        }
+    }
+
+    pub fn with_commitment(mut self, commitment: CommitmentConfig) -> Self {
+        self.commitment = commitment;
+        self
+    }
+
+    /// Delay before the first reconnect, doubled up to `max` on repeated failures
+    pub fn with_backoff(mut self, min: Duration, max: Duration) -> Self {
+        self.min_backoff = min;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Run in the background, delivering events until the receiver is dropped
+    pub fn spawn(self, buffer: usize) -> mpsc::Receiver<StreamItem> {
+        let (tx, rx) = mpsc::channel(buffer);
+        tokio::spawn(self.run(tx));
+        rx
+    }
+
+    /// Deliver events to `tx`, reconnecting until it is closed
+    pub async fn run(mut self, tx: mpsc::Sender<StreamItem>) {
+        let mut backoff = self.min_backoff;
+        while !tx.is_closed() {
+            // A session that delivered notifications was healthy: reset the backoff
+            if let Ok(true) = self.run_once(&tx).await {
+                backoff = self.min_backoff;
+            }
+            if tx.is_closed() {
+                break;
+            }
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(self.max_backoff);
+        }
+    }
+
+    /// One websocket session; returns whether any notification was received
+    async fn run_once(&mut self, tx: &mpsc::Sender<StreamItem>) -> Result<bool> {
+        let client = PubsubClient::new(&self.ws_url).await?;
+        let (mut notifications, unsubscribe) = client
+            .logs_subscribe(
+                RpcTransactionLogsFilter::Mentions(vec![PROGRAM_ID.to_string()]),
+                RpcTransactionLogsConfig {
+                    commitment: Some(self.commitment),
+                },
+            )
+            .await?;
+
+        let mut received = false;
+        while let Some(notification) = notifications.next().await {
+            received = true;
+            let logs = notification.value;
+            if logs.err.is_some() || !self.dedup.insert(&logs.signature) {
+                continue;
+            }
+            for event in parse_logs(&logs.logs) {
+                let item = StreamItem {
+                    signature: logs.signature.clone(),
+                    slot: notification.context.slot,
+                    event,
+                };
+                if tx.send(item).await.is_err() {
+                    unsubscribe().await;
+                    return Ok(received);
+                }
+            }
+        }
+        unsubscribe().await;
+        Ok(received)
+    }
+}
@@ -0,0 +1,348 @@
+//! Transaction submission with confirmation tracking and idempotency.
+//!
+//! A publish that times out may still land, and re-sending it under a
+//! fresh blockhash while the first copy is alive can publish the signal
+//! twice. [`Submitter`] prevents that by tying every attempt to an
+//! idempotency key:
+//!
+//! - every signature sent under a key is recorded in an
+//!   [`IdempotencyStore`] *before* it is broadcast;
+//! - a key whose earlier attempt landed returns that signature instead of
+//!   sending again;
+//! - a new attempt (new blockhash, escalated fee) is only signed once the
+//!   previous attempt's blockhash has expired, so at most one attempt can
+//!   ever land.
+//!
+//! Keep the store across restarts (e.g. back it with a file or database)
+//! to extend the guarantee past the process lifetime.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::client_error::{Error as RpcError, ErrorKind};
+use solana_rpc_client_api::config::RpcSendTransactionConfig;
+use solana_rpc_client_api::request::RpcError as RpcRequestError;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::signers::Signers;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use crate::fees::{self, FeeConfig, Urgency};
+use crate::pool::is_transport_error;
+use crate::{ClientError, Result};
+
+/// How an error should be handled by whoever is submitting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorClass {
+    /// Network, rate limiting or a lagging node: try again
+    Transient,
+    /// The blockhash ran out before the transaction landed: re-sign and
+    /// try again
+    Expired,
+    /// The transaction executed (or simulated) and failed; retrying the
+    /// same instructions will fail the same way
+    Rejected,
+    /// Anything else (bad input, signing, decoding): don't retry
+    Fatal,
+}
+
+/// Classify an error from this crate
+pub fn classify(err: &ClientError) -> ErrorClass {
+    match err {
+        ClientError::Rpc(err) => classify_rpc(err),
+        ClientError::Pubsub(_) => ErrorClass::Transient,
+        ClientError::TransactionFailed { .. } => ErrorClass::Rejected,
+        ClientError::Expired => ErrorClass::Expired,
+        _ => ErrorClass::Fatal,
+    }
+}
+
+fn classify_rpc(err: &RpcError) -> ErrorClass {
+    if is_transport_error(err) {
+        return ErrorClass::Transient;
+    }
+    match err.get_transaction_error() {
+        Some(TransactionError::BlockhashNotFound) => ErrorClass::Expired,
+        // Already processed means an identical copy is in flight: keep
+        // watching for it
+        Some(TransactionError::AlreadyProcessed)
+        | Some(TransactionError::WouldExceedMaxBlockCostLimit)
+        | Some(TransactionError::WouldExceedMaxAccountCostLimit)
+        | Some(TransactionError::WouldExceedMaxVoteCostLimit)
+        | Some(TransactionError::WouldExceedAccountDataBlockLimit)
+        | Some(TransactionError::TooManyAccountLocks) => ErrorClass::Transient,
+        Some(_) => ErrorClass::Rejected,
+        None => match err.kind() {
+            ErrorKind::RpcError(RpcRequestError::RpcResponseError { .. }) => ErrorClass::Transient,
+            _ => ErrorClass::Fatal,
+        },
+    }
+}
+
+/// Signatures sent under one idempotency key
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Attempts {
+    pub signatures: Vec<Signature>,
+    /// The attempt that landed, once known
+    pub landed: Option<Signature>,
+    /// Block height after which the newest attempt can no longer land
+    pub last_valid_block_height: u64,
+}
+
+/// Where attempts are remembered between calls (and, for persistent
+/// implementations, between runs)
+pub trait IdempotencyStore: Send + Sync {
+    fn load(&self, key: &str) -> Option<Attempts>;
+
+    fn save(&self, key: &str, attempts: &Attempts);
+}
+
+/// In-process store; forgets everything on restart
+#[derive(Default)]
+pub struct MemoryStore(Mutex<HashMap<String, Attempts>>);
+
+impl IdempotencyStore for MemoryStore {
+    fn load(&self, key: &str) -> Option<Attempts> {
+        self.0.lock().unwrap().get(key).cloned()
+    }
+
+    fn save(&self, key: &str, attempts: &Attempts) {
+        self.0.lock().unwrap().insert(key.to_string(), attempts.clone());
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SubmitConfig {
+    /// Level an attempt must reach to count as landed
+    pub commitment: CommitmentConfig,
+    /// Fee policy of the first attempt; later attempts escalate urgency
+    pub fee: FeeConfig,
+    /// Attempts (each with a fresh blockhash) before giving up
+    pub max_attempts: u32,
+    /// Status polling and rebroadcast interval
+    pub poll_interval: Duration,
+}
+
+impl Default for SubmitConfig {
+    fn default() -> Self {
+        SubmitConfig {
+            commitment: CommitmentConfig::confirmed(),
+            fee: FeeConfig::default(),
+            max_attempts: 3,
+            poll_interval: Duration::from_secs(2),
+        }
+    }
+}
+
+/// Result of a submission
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Submitted {
+    pub signature: Signature,
+    /// Whether an earlier call under the same key had already landed it
+    pub deduplicated: bool,
+}
+
+pub struct Submitter<'a, S: IdempotencyStore> {
+    rpc: &'a RpcClient,
+    store: S,
+    config: SubmitConfig,
+}
+
+impl<'a> Submitter<'a, MemoryStore> {
+    pub fn new(rpc: &'a RpcClient, config: SubmitConfig) -> Self {
+        Submitter {
+            rpc,
+            store: MemoryStore::default(),
+            config,
+        }
+    }
+}
+
+impl<'a, S: IdempotencyStore> Submitter<'a, S> {
+    pub fn with_store(rpc: &'a RpcClient, config: SubmitConfig, store: S) -> Self {
+        Submitter { rpc, store, config }
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Land `instructions` at most once for `key`, paid by `payer`
+    /// (which must be among `signers`).
+    ///
+    /// Calling again with the same key after a success returns the landed
+    /// signature without sending anything; after a failure it first checks
+    /// whether a previous attempt landed late, and waits out any attempt
+    /// that could still land before signing a new one.
+    pub async fn submit<T: Signers + ?Sized>(
+        &self,
+        key: &str,
+        payer: &Pubkey,
+        signers: &T,
+        instructions: &[Instruction],
+    ) -> Result<Submitted> {
+        let mut attempts = self.store.load(key).unwrap_or_default();
+        if let Some(signature) = attempts.landed {
+            return Ok(Submitted {
+                signature,
+                deduplicated: true,
+            });
+        }
+        if let Some(signature) = self.find_landed(&attempts.signatures).await? {
+            attempts.landed = Some(signature);
+            self.store.save(key, &attempts);
+            return Ok(Submitted {
+                signature,
+                deduplicated: true,
+            });
+        }
+        if !attempts.signatures.is_empty() {
+            match self.await_outcome(key, &mut attempts, None).await {
+                Ok(signature) => {
+                    return Ok(Submitted {
+                        signature,
+                        deduplicated: true,
+                    })
+                }
+                Err(ClientError::Expired) => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        let mut fee = self.config.fee;
+        let mut attempt = 1;
+        loop {
+            match self.attempt(key, &mut attempts, &fee, payer, signers, instructions).await {
+                Ok(signature) => {
+                    return Ok(Submitted {
+                        signature,
+                        deduplicated: false,
+                    })
+                }
+                Err(err) if attempt < self.config.max_attempts && is_retryable(&err) => {
+                    fee.urgency = escalate(fee.urgency);
+                    attempt += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// One blockhash's worth of trying: sign, record, broadcast, then wait
+    /// for the outcome
+    async fn attempt<T: Signers + ?Sized>(
+        &self,
+        key: &str,
+        attempts: &mut Attempts,
+        fee: &FeeConfig,
+        payer: &Pubkey,
+        signers: &T,
+        instructions: &[Instruction],
+    ) -> Result<Signature> {
+        let instructions = fees::with_priority_fee(self.rpc, fee, instructions.to_vec()).await?;
+        let (blockhash, last_valid_block_height) = self
+            .rpc
+            .get_latest_blockhash_with_commitment(self.config.commitment)
+            .await?;
+        let mut tx = Transaction::new_with_payer(&instructions, Some(payer));
+        tx.try_sign(signers, blockhash).map_err(ClientError::Signing)?;
+        let signature = tx.signatures[0];
+
+        attempts.signatures.push(signature);
+        attempts.last_valid_block_height = last_valid_block_height;
+        self.store.save(key, attempts);
+
+        let send_config = RpcSendTransactionConfig {
+            skip_preflight: false,
+            preflight_commitment: Some(self.config.commitment.commitment),
+            max_retries: Some(0),
+            ..RpcSendTransactionConfig::default()
+        };
+        if let Err(err) = self.rpc.send_transaction_with_config(&tx, send_config).await {
+            let err = ClientError::from(err);
+            if classify(&err) != ErrorClass::Transient {
+                return Err(err);
+            }
+        }
+        self.await_outcome(key, attempts, Some((&tx, send_config))).await
+    }
+
+    /// Poll every attempt under the key (rebroadcasting `rebroadcast`)
+    /// until one lands or fails, or the newest blockhash expires.
+    ///
+    /// Only expiry or a definite outcome ends the wait; transient errors
+    /// are retried in place, since a new attempt must not be signed while
+    /// an old one can still land.
+    async fn await_outcome(
+        &self,
+        key: &str,
+        attempts: &mut Attempts,
+        rebroadcast: Option<(&Transaction, RpcSendTransactionConfig)>,
+    ) -> Result<Signature> {
+        loop {
+            tokio::time::sleep(self.config.poll_interval).await;
+            // Every signature under the key is checked, so a late-landing
+            // earlier attempt is still caught
+            let statuses = match self.rpc.get_signature_statuses(&attempts.signatures).await {
+                Ok(response) => response.value,
+                Err(err) if classify_rpc(&err) == ErrorClass::Transient => continue,
+                Err(err) => return Err(err.into()),
+            };
+            for (sent, status) in attempts.signatures.iter().zip(statuses) {
+                let Some(status) = status else { continue };
+                if let Some(err) = status.err {
+                    return Err(ClientError::TransactionFailed { signature: *sent, err });
+                }
+                if status.satisfies_commitment(self.config.commitment) {
+                    attempts.landed = Some(*sent);
+                    self.store.save(key, attempts);
+                    return Ok(*sent);
+                }
+            }
+            match self.rpc.get_block_height().await {
+                Ok(height) if height > attempts.last_valid_block_height => return Err(ClientError::Expired),
+                Ok(_) => {}
+                Err(err) if classify_rpc(&err) == ErrorClass::Transient => continue,
+                Err(err) => return Err(err.into()),
+            }
+            if let Some((tx, config)) = rebroadcast {
+                // Best effort: the next poll decides what happened
+                let config = RpcSendTransactionConfig {
+                    skip_preflight: true,
+                    ..config
+                };
+                let _ = self.rpc.send_transaction_with_config(tx, config).await;
+            }
+        }
+    }
+
+    /// An earlier attempt that reached the configured commitment
+    async fn find_landed(&self, signatures: &[Signature]) -> Result<Option<Signature>> {
+        if signatures.is_empty() {
+            return Ok(None);
+        }
+        let statuses = self.rpc.get_signature_statuses_with_history(signatures).await?.value;
+        Ok(signatures.iter().zip(statuses).find_map(|(signature, status)| {
+            let status = status?;
+            (status.err.is_none() && status.satisfies_commitment(self.config.commitment)).then_some(*signature)
+        }))
+    }
+}
+
+/// Whether a failed attempt may be followed by another one
+fn is_retryable(err: &ClientError) -> bool {
+    matches!(classify(err), ErrorClass::Transient | ErrorClass::Expired)
+}
+
+/// One urgency level up, saturating at `Urgent`
+pub fn escalate(urgency: Urgency) -> Urgency {
+    match urgency {
+        Urgency::Low => Urgency::Normal,
+        Urgency::Normal => Urgency::High,
+        Urgency::High | Urgency::Urgent => Urgency::Urgent,
+    }
+}
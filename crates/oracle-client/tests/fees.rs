@@ -0,0 +1,19 @@
+//! Table tests for priority-fee percentiles.
+
+use oracle_client::fees::percentile;
+
+#[test]
+fn percentile_is_nearest_rank() {
+    let cases: [(&[u64], u64, u64); 7] = [
+        (&[], 50, 0),
+        (&[5, 1, 3, 2, 4], 0, 1),
+        (&[5, 1, 3, 2, 4], 50, 3),
+        (&[5, 1, 3, 2, 4], 100, 5),
+        (&[5, 1, 3, 2, 4], 150, 5),
+        (&[10, 20, 30, 40], 75, 30),
+        (&[10, 20, 30, 40], 76, 40),
+    ];
+    for (fees, pct, expected) in cases {
+        assert_eq!(percentile(&mut fees.to_vec(), pct), expected, "p{pct} of {fees:?}");
+    }
+}
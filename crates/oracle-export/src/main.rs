@@ -0,0 +1,102 @@
+//! `oracle-export`: dump the indexed signal history to CSV or Parquet.
+//!
+//! Reads the `oracle-indexer` database and writes `signals` and
+//! `settlements` files into `--out`. See `schema` for the columns; they
+//! are stable, so notebooks can rely on them across releases. Rows are
+//! streamed through a server-side cursor in `--batch` sized chunks (one
+//! Parquet row group each), so the history never has to fit in memory.
+
+mod schema;
+mod write;
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use clap::{Parser, ValueEnum};
+use tokio_postgres::NoTls;
+
+use schema::Table;
+use write::{Sink, Values};
+
+#[derive(Parser)]
+#[command(name = "oracle-export", version, about = "Export ORACLE Alpha signal history")]
+struct Cli {
+    /// Postgres connection string (the indexer's database)
+    #[arg(long, env = "DATABASE_URL")]
+    database_url: String,
+
+    /// Output directory
+    #[arg(long, short, default_value = ".")]
+    out: PathBuf,
+
+    #[arg(long, value_enum, default_value_t = Format::Parquet)]
+    format: Format,
+
+    /// Rows fetched (and, for Parquet, written per row group) at a time
+    #[arg(long, default_value_t = 50_000)]
+    batch: u32,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Csv,
+    Parquet,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let (mut db, connection) = tokio_postgres::connect(&cli.database_url, NoTls)
+        .await
+        .context("connecting to Postgres")?;
+    tokio::spawn(async move {
+        if let Err(err) = connection.await {
+            eprintln!("postgres connection closed: {err}");
+        }
+    });
+    std::fs::create_dir_all(&cli.out).with_context(|| format!("creating {}", cli.out.display()))?;
+
+    for table in schema::TABLES {
+        let (rows, path) = export(&mut db, table, &cli).await.with_context(|| format!("exporting {}", table.name))?;
+        eprintln!("wrote {rows} row(s) to {}", path.display());
+    }
+    Ok(())
+}
+
+/// Write `table` to its file in `--out`, returning the row count and path
+async fn export(db: &mut tokio_postgres::Client, table: &Table, cli: &Cli) -> Result<(u64, PathBuf)> {
+    let path;
+    let mut sink: Box<dyn Sink> = match cli.format {
+        Format::Csv => {
+            path = cli.out.join(format!("{}.csv", table.name));
+            Box::new(write::Csv::create(&path, table)?)
+        }
+        Format::Parquet => {
+            path = cli.out.join(format!("{}.parquet", table.name));
+            Box::new(write::Parquet::create(&path, table)?)
+        }
+    };
+
+    // One snapshot for the whole table, read through a cursor
+    let tx = db.build_transaction().read_only(true).start().await?;
+    tx.batch_execute(&format!("DECLARE export NO SCROLL CURSOR FOR {}", table.query)).await?;
+    let fetch = format!("FETCH {} FROM export", cli.batch.max(1));
+    let mut total = 0;
+    loop {
+        let rows = tx.query(&fetch, &[]).await?;
+        if rows.is_empty() {
+            break;
+        }
+        let mut batch: Vec<Values> = table.columns.iter().map(|column| Values::new(column.kind)).collect();
+        for row in &rows {
+            for (idx, values) in batch.iter_mut().enumerate() {
+                values.push(row, idx);
+            }
+        }
+        sink.write(&batch)?;
+        total += rows.len() as u64;
+    }
+    tx.commit().await?;
+    sink.finish()?;
+    Ok((total, path))
+}
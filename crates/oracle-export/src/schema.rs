@@ -0,0 +1,79 @@
+//! The exported tables and their columns.
+//!
+//! This is the export's contract: column names, order and types only ever
+//! change by appending. Prices are scaled by 10^9, times are unix
+//! seconds, pubkeys are base58 and hashes are hex.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Int32,
+    Int64,
+    /// Nullable BIGINT
+    OptInt64,
+    Bool,
+    Text,
+}
+
+pub struct Column {
+    pub name: &'static str,
+    pub kind: Kind,
+}
+
+pub struct Table {
+    pub name: &'static str,
+    /// Selects exactly `columns`, in order
+    pub query: &'static str,
+    pub columns: &'static [Column],
+}
+
+const fn col(name: &'static str, kind: Kind) -> Column {
+    Column { name, kind }
+}
+
+pub const SIGNALS: Table = Table {
+    name: "signals",
+    query: "SELECT id, address, token, symbol, score::INT, risk_level::INT, sources_bitmap::INT,
+                   mcap_at_signal, entry_price, direction, ath_price, exit_price, roi_bps,
+                   published_at, status, encode(reasoning_hash, 'hex'), reasoning_revealed,
+                   price_pool, entry_verified, exit_verified, slot
+            FROM signals ORDER BY id",
+    columns: &[
+        col("id", Kind::Int64),
+        col("address", Kind::Text),
+        col("token", Kind::Text),
+        col("symbol", Kind::Text),
+        col("score", Kind::Int32),
+        col("risk_level", Kind::Int32),
+        col("sources_bitmap", Kind::Int32),
+        col("mcap_at_signal", Kind::Int64),
+        col("entry_price", Kind::Int64),
+        col("direction", Kind::Text),
+        col("ath_price", Kind::Int64),
+        col("exit_price", Kind::Int64),
+        col("roi_bps", Kind::Int64),
+        col("published_at", Kind::Int64),
+        col("status", Kind::Text),
+        col("reasoning_hash", Kind::Text),
+        col("reasoning_revealed", Kind::Bool),
+        col("price_pool", Kind::Text),
+        col("entry_verified", Kind::Bool),
+        col("exit_verified", Kind::Bool),
+        col("slot", Kind::Int64),
+    ],
+};
+
+pub const SETTLEMENTS: Table = Table {
+    name: "settlements",
+    query: "SELECT signal_id, signature, status, roi_bps, slot, block_time
+            FROM settlements ORDER BY signal_id, slot",
+    columns: &[
+        col("signal_id", Kind::Int64),
+        col("signature", Kind::Text),
+        col("status", Kind::Text),
+        col("roi_bps", Kind::Int64),
+        col("slot", Kind::Int64),
+        col("block_time", Kind::OptInt64),
+    ],
+};
+
+pub const TABLES: [&Table; 2] = [&SIGNALS, &SETTLEMENTS];
@@ -0,0 +1,166 @@
+//! CSV and Parquet writers, fed one batch of rows at a time.
+
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::Result;
+use parquet::basic::Compression;
+use parquet::data_type::{BoolType, ByteArray, ByteArrayType, Int32Type, Int64Type};
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::parser::parse_message_type;
+
+use crate::schema::{Kind, Table};
+
+/// One batch of rows, stored column by column
+pub enum Values {
+    Int32(Vec<i32>),
+    Int64(Vec<i64>),
+    OptInt64(Vec<Option<i64>>),
+    Bool(Vec<bool>),
+    Text(Vec<String>),
+}
+
+impl Values {
+    pub fn new(kind: Kind) -> Self {
+        match kind {
+            Kind::Int32 => Values::Int32(Vec::new()),
+            Kind::Int64 => Values::Int64(Vec::new()),
+            Kind::OptInt64 => Values::OptInt64(Vec::new()),
+            Kind::Bool => Values::Bool(Vec::new()),
+            Kind::Text => Values::Text(Vec::new()),
+        }
+    }
+
+    pub fn push(&mut self, row: &tokio_postgres::Row, idx: usize) {
+        match self {
+            Values::Int32(v) => v.push(row.get(idx)),
+            Values::Int64(v) => v.push(row.get(idx)),
+            Values::OptInt64(v) => v.push(row.get(idx)),
+            Values::Bool(v) => v.push(row.get(idx)),
+            Values::Text(v) => v.push(row.get(idx)),
+        }
+    }
+
+    fn cell(&self, row: usize) -> String {
+        match self {
+            Values::Int32(v) => v[row].to_string(),
+            Values::Int64(v) => v[row].to_string(),
+            Values::OptInt64(v) => v[row].map(|x| x.to_string()).unwrap_or_default(),
+            Values::Bool(v) => v[row].to_string(),
+            Values::Text(v) => v[row].clone(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Values::Int32(v) => v.len(),
+            Values::Int64(v) => v.len(),
+            Values::OptInt64(v) => v.len(),
+            Values::Bool(v) => v.len(),
+            Values::Text(v) => v.len(),
+        }
+    }
+}
+
+pub trait Sink {
+    fn write(&mut self, batch: &[Values]) -> Result<()>;
+
+    fn finish(self: Box<Self>) -> Result<()>;
+}
+
+pub struct Csv(csv::Writer<File>);
+
+impl Csv {
+    pub fn create(path: &Path, table: &Table) -> Result<Self> {
+        let mut writer = csv::Writer::from_path(path)?;
+        writer.write_record(table.columns.iter().map(|column| column.name))?;
+        Ok(Csv(writer))
+    }
+}
+
+impl Sink for Csv {
+    fn write(&mut self, batch: &[Values]) -> Result<()> {
+        let rows = batch.first().map_or(0, Values::len);
+        for row in 0..rows {
+            self.0.write_record(batch.iter().map(|column| column.cell(row)))?;
+        }
+        Ok(())
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<()> {
+        self.0.flush()?;
+        Ok(())
+    }
+}
+
+/// Each batch becomes one row group
+pub struct Parquet(SerializedFileWriter<File>);
+
+impl Parquet {
+    pub fn create(path: &Path, table: &Table) -> Result<Self> {
+        let schema = Arc::new(parse_message_type(&message_type(table))?);
+        let props = Arc::new(WriterProperties::builder().set_compression(Compression::SNAPPY).build());
+        Ok(Parquet(SerializedFileWriter::new(File::create(path)?, schema, props)?))
+    }
+}
+
+fn message_type(table: &Table) -> String {
+    let fields: String = table
+        .columns
+        .iter()
+        .map(|column| {
+            let ty = match column.kind {
+                Kind::Int32 => "REQUIRED INT32",
+                Kind::Int64 => "REQUIRED INT64",
+                Kind::OptInt64 => "OPTIONAL INT64",
+                Kind::Bool => "REQUIRED BOOLEAN",
+                Kind::Text => "REQUIRED BYTE_ARRAY",
+            };
+            let annotation = if column.kind == Kind::Text { " (UTF8)" } else { "" };
+            format!("  {ty} {}{annotation};\n", column.name)
+        })
+        .collect();
+    format!("message {} {{\n{fields}}}", table.name)
+}
+
+impl Sink for Parquet {
+    fn write(&mut self, batch: &[Values]) -> Result<()> {
+        if batch.first().is_none_or(|column| column.len() == 0) {
+            return Ok(());
+        }
+        let mut row_group = self.0.next_row_group()?;
+        for values in batch {
+            let mut column = row_group.next_column()?.expect("batch matches the schema");
+            match values {
+                Values::Int32(v) => {
+                    column.typed::<Int32Type>().write_batch(v, None, None)?;
+                }
+                Values::Int64(v) => {
+                    column.typed::<Int64Type>().write_batch(v, None, None)?;
+                }
+                Values::OptInt64(v) => {
+                    let present: Vec<i64> = v.iter().flatten().copied().collect();
+                    let levels: Vec<i16> = v.iter().map(|x| i16::from(x.is_some())).collect();
+                    column.typed::<Int64Type>().write_batch(&present, Some(&levels), None)?;
+                }
+                Values::Bool(v) => {
+                    column.typed::<BoolType>().write_batch(v, None, None)?;
+                }
+                Values::Text(v) => {
+                    let bytes: Vec<ByteArray> = v.iter().map(|s| ByteArray::from(s.as_str())).collect();
+                    column.typed::<ByteArrayType>().write_batch(&bytes, None, None)?;
+                }
+            }
+            column.close()?;
+        }
+        row_group.close()?;
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<()> {
+        self.0.close()?;
+        Ok(())
+    }
+}
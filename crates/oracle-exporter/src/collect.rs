@@ -0,0 +1,121 @@
+//! Metric collection from the chain and the indexer database.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use oracle_client::{fetch, math, query, PROGRAM_ID};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use tokio_postgres::Client;
+
+use crate::metrics::Exposition;
+
+pub struct Settings {
+    /// Settlements in the rolling win rate
+    pub win_rate_window: i64,
+    /// Seconds of transaction history for the failure rate
+    pub failure_window: i64,
+}
+
+pub async fn collect(rpc: &RpcClient, db: &Client, settings: &Settings) -> Result<String> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+    let mut out = Exposition::default();
+    chain(rpc, now, settings, &mut out).await?;
+    indexer(rpc, db, now, settings, &mut out).await?;
+    Ok(out.finish())
+}
+
+async fn chain(rpc: &RpcClient, now: i64, settings: &Settings, out: &mut Exposition) -> Result<()> {
+    let state = fetch::fetch_oracle_state(rpc).await?;
+    out.counter("oracle_signals_total", "Signals published (on-chain counter)", state.total_signals as f64);
+    out.counter("oracle_wins_total", "Signals settled as wins", state.total_wins as f64);
+    out.counter("oracle_losses_total", "Signals settled as losses", state.total_losses as f64);
+    out.gauge(
+        "oracle_win_rate_bps",
+        "Lifetime win rate in basis points",
+        math::win_rate_bps(state.total_wins, state.total_losses) as f64,
+    );
+
+    // Failure rate over the most recent page of program transactions
+    let config = GetConfirmedSignaturesForAddress2Config {
+        commitment: Some(rpc.commitment()),
+        ..GetConfirmedSignaturesForAddress2Config::default()
+    };
+    let recent: Vec<_> = rpc
+        .get_signatures_for_address_with_config(&PROGRAM_ID, config)
+        .await?
+        .into_iter()
+        .filter(|status| status.block_time.is_some_and(|t| t >= now - settings.failure_window))
+        .collect();
+    let failed = recent.iter().filter(|status| status.err.is_some()).count();
+    out.gauge("oracle_transactions_recent", "Program transactions in the failure window", recent.len() as f64);
+    out.gauge(
+        "oracle_failed_transaction_ratio",
+        "Share of program transactions in the failure window that failed",
+        if recent.is_empty() { 0.0 } else { failed as f64 / recent.len() as f64 },
+    );
+
+    // Keeper lag: how long the oldest lapsed pass has waited to be burned
+    let expired: Vec<_> = query::fetch_subscriptions(rpc)
+        .await?
+        .into_iter()
+        .map(|(_, subscription)| subscription)
+        .filter(|subscription| subscription.expires_at <= now)
+        .collect();
+    let subscribers: Vec<_> = expired.iter().map(|subscription| subscription.subscriber).collect();
+    let balances = fetch::fetch_pass_balances(rpc, &subscribers).await?;
+    let oldest_unburned = expired
+        .iter()
+        .zip(balances)
+        .filter(|(_, balance)| *balance > 0)
+        .map(|(subscription, _)| subscription.expires_at)
+        .min();
+    out.gauge(
+        "oracle_keeper_lag_seconds",
+        "Age of the oldest expired subscriber pass not yet burned by a keeper",
+        oldest_unburned.map_or(0, |expires_at| now - expires_at) as f64,
+    );
+    Ok(())
+}
+
+async fn indexer(rpc: &RpcClient, db: &Client, now: i64, settings: &Settings, out: &mut Exposition) -> Result<()> {
+    let row = db
+        .query_one(
+            "SELECT
+                 COUNT(*) FILTER (WHERE published_at >= $1) AS last_hour,
+                 COUNT(*) FILTER (WHERE status = 'Open') AS open,
+                 MAX(published_at) AS latest
+             FROM signals",
+            &[&(now - 3_600)],
+        )
+        .await?;
+    out.gauge("oracle_signals_last_hour", "Signals published in the last hour", row.get::<_, i64>("last_hour") as f64);
+    out.gauge("oracle_open_signals", "Signals currently open", row.get::<_, i64>("open") as f64);
+    if let Some(latest) = row.get::<_, Option<i64>>("latest") {
+        out.gauge("oracle_last_signal_age_seconds", "Seconds since the newest signal was published", (now - latest) as f64);
+    }
+
+    let row = db
+        .query_one(
+            "SELECT COUNT(*) FILTER (WHERE status = 'Win') AS wins, COUNT(*) FILTER (WHERE status = 'Loss') AS losses
+             FROM (SELECT status FROM settlements ORDER BY slot DESC LIMIT $1) recent",
+            &[&settings.win_rate_window],
+        )
+        .await?;
+    let wins = row.get::<_, i64>("wins") as u64;
+    let losses = row.get::<_, i64>("losses") as u64;
+    out.gauge(
+        "oracle_rolling_win_rate_bps",
+        "Win rate over the most recent settlements, in basis points",
+        math::win_rate_bps(wins, losses) as f64,
+    );
+
+    let cursor_slot: Option<i64> = db.query_opt("SELECT slot FROM cursor", &[]).await?.map(|row| row.get(0));
+    let slot = rpc.get_slot().await?;
+    out.gauge(
+        "oracle_indexer_lag_slots",
+        "Slots between the chain tip and the newest indexed transaction",
+        cursor_slot.map_or(slot as f64, |cursor| slot.saturating_sub(cursor as u64) as f64),
+    );
+    Ok(())
+}
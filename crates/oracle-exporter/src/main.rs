@@ -0,0 +1,112 @@
+//! `oracle-exporter`: Prometheus metrics for ORACLE Alpha operators.
+//!
+//! Collects from the chain and the `oracle-indexer` database every
+//! `--interval` seconds and serves the latest snapshot on `/metrics`, so
+//! scrapes never wait on RPC. Alert on `oracle_last_signal_age_seconds`
+//! for a stalled publisher, `oracle_keeper_lag_seconds` for a dead keeper,
+//! and `oracle_exporter_last_success_timestamp_seconds` for the exporter
+//! itself.
+
+mod collect;
+mod metrics;
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use axum::extract::State;
+use axum::routing::get;
+use axum::Router;
+use clap::Parser;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use tokio::sync::RwLock;
+use tokio_postgres::NoTls;
+
+#[derive(Parser)]
+#[command(name = "oracle-exporter", version, about = "Export ORACLE Alpha metrics to Prometheus")]
+struct Cli {
+    /// RPC endpoint
+    #[arg(long, short = 'u', env = "ORACLE_RPC_URL", default_value = "https://api.mainnet-beta.solana.com")]
+    url: String,
+
+    /// Postgres connection string (the indexer's database)
+    #[arg(long, env = "DATABASE_URL")]
+    database_url: String,
+
+    /// Address to serve `/metrics` on
+    #[arg(long, env = "ORACLE_EXPORTER_LISTEN", default_value = "0.0.0.0:9464")]
+    listen: SocketAddr,
+
+    /// Seconds between collections
+    #[arg(long, default_value_t = 30)]
+    interval: u64,
+
+    /// Settlements in the rolling win rate
+    #[arg(long, default_value_t = 100)]
+    win_rate_window: i64,
+
+    /// Seconds of transaction history for the failure rate
+    #[arg(long, default_value_t = 3_600)]
+    failure_window: i64,
+}
+
+#[derive(Default)]
+struct Snapshot {
+    metrics: String,
+    last_success: Option<u64>,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let rpc = RpcClient::new_with_commitment(cli.url.clone(), CommitmentConfig::confirmed());
+    let (db, connection) = tokio_postgres::connect(&cli.database_url, NoTls)
+        .await
+        .context("connecting to Postgres")?;
+    tokio::spawn(async move {
+        if let Err(err) = connection.await {
+            eprintln!("postgres connection closed: {err}");
+        }
+    });
+
+    let snapshot = Arc::new(RwLock::new(Snapshot::default()));
+    let settings = collect::Settings {
+        win_rate_window: cli.win_rate_window.max(1),
+        failure_window: cli.failure_window.max(1),
+    };
+    let collector = snapshot.clone();
+    let interval = Duration::from_secs(cli.interval.max(1));
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match collect::collect(&rpc, &db, &settings).await {
+                Ok(metrics) => {
+                    let mut snapshot = collector.write().await;
+                    snapshot.metrics = metrics;
+                    snapshot.last_success = SystemTime::now().duration_since(UNIX_EPOCH).ok().map(|d| d.as_secs());
+                }
+                Err(err) => eprintln!("collection failed: {err:#}"),
+            }
+        }
+    });
+
+    let app = Router::new().route("/metrics", get(serve)).with_state(snapshot);
+    let listener = tokio::net::TcpListener::bind(cli.listen).await?;
+    eprintln!("oracle-exporter listening on {}", cli.listen);
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+async fn serve(State(snapshot): State<Arc<RwLock<Snapshot>>>) -> String {
+    let snapshot = snapshot.read().await;
+    let mut out = metrics::Exposition::default();
+    out.gauge(
+        "oracle_exporter_last_success_timestamp_seconds",
+        "Unix time of the last successful collection",
+        snapshot.last_success.unwrap_or(0) as f64,
+    );
+    out.finish() + &snapshot.metrics
+}
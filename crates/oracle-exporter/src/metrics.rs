@@ -0,0 +1,27 @@
+//! Prometheus text exposition.
+
+use std::fmt::Write;
+
+#[derive(Default)]
+pub struct Exposition {
+    text: String,
+}
+
+impl Exposition {
+    pub fn gauge(&mut self, name: &str, help: &str, value: f64) {
+        self.metric(name, "gauge", help, value);
+    }
+
+    pub fn counter(&mut self, name: &str, help: &str, value: f64) {
+        self.metric(name, "counter", help, value);
+    }
+
+    fn metric(&mut self, name: &str, kind: &str, help: &str, value: f64) {
+        // Writing to a String cannot fail
+        let _ = writeln!(self.text, "# HELP {name} {help}\n# TYPE {name} {kind}\n{name} {value}");
+    }
+
+    pub fn finish(self) -> String {
+        self.text
+    }
+}
@@ -0,0 +1,230 @@
+//! Geyser plugin forwarding ORACLE Alpha account writes and events to NATS.
+//!
+//! Runs inside the validator, so consumers see each write as it is
+//! processed rather than after an RPC round trip. Publishes JSON to:
+//!
+//! - `<prefix>.accounts.<Kind>` for every oracle-owned account write
+//!   (`Signal`, `OracleState`, ...): `{ slot, address, write_version,
+//!   signature, data }`
+//! - `<prefix>.events.<Name>` for every event in a successful transaction:
+//!   `{ slot, signature, index, data }`
+//!
+//! Validator callbacks never block on the network: messages go through a
+//! bounded queue to a publisher task, and are dropped (and counted) when
+//! the queue is full.
+//!
+//! Example config:
+//!
+//! ```json
+//! {
+//!   "libpath": "/path/to/liboracle_geyser.so",
+//!   "nats_url": "nats://127.0.0.1:4222",
+//!   "subject_prefix": "oracle",
+//!   "queue_capacity": 65536,
+//!   "include_startup": false
+//! }
+//! ```
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use agave_geyser_plugin_interface::geyser_plugin_interface::{
+    GeyserPlugin, GeyserPluginError, ReplicaAccountInfoVersions, ReplicaTransactionInfoVersions, Result,
+};
+use oracle_client::stream::program_data;
+use oracle_client::PROGRAM_ID;
+use oracle_types::{decode_any_account, decode_any_event};
+use serde::Deserialize;
+use serde_json::json;
+use solana_sdk::clock::Slot;
+use solana_sdk::pubkey::Pubkey;
+use tokio::runtime::Runtime;
+use tokio::sync::mpsc;
+
+/// Log the drop count every this many dropped messages
+const DROP_LOG_INTERVAL: u64 = 1_000;
+
+fn default_prefix() -> String {
+    "oracle".to_string()
+}
+
+fn default_capacity() -> usize {
+    65_536
+}
+
+#[derive(Debug, Deserialize)]
+struct Config {
+    nats_url: String,
+    #[serde(default = "default_prefix")]
+    subject_prefix: String,
+    #[serde(default = "default_capacity")]
+    queue_capacity: usize,
+    /// Also forward the accounts replayed from the snapshot at startup
+    #[serde(default)]
+    include_startup: bool,
+}
+
+#[derive(Debug)]
+struct Running {
+    runtime: Runtime,
+    queue: mpsc::Sender<(String, Vec<u8>)>,
+    prefix: String,
+    include_startup: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct OracleGeyser {
+    running: Option<Running>,
+    dropped: AtomicU64,
+}
+
+impl OracleGeyser {
+    fn publish(&self, subject: String, payload: serde_json::Value) {
+        let Some(running) = &self.running else {
+            return;
+        };
+        if running.queue.try_send((subject, payload.to_string().into_bytes())).is_err() {
+            let dropped = self.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+            if dropped % DROP_LOG_INTERVAL == 1 {
+                log::warn!("oracle-geyser: publish queue full, {dropped} message(s) dropped so far");
+            }
+        }
+    }
+}
+
+fn custom(err: impl std::error::Error + Send + Sync + 'static) -> GeyserPluginError {
+    GeyserPluginError::Custom(Box::new(err))
+}
+
+impl GeyserPlugin for OracleGeyser {
+    fn setup_logger(&self, logger: &'static dyn log::Log, level: log::LevelFilter) -> Result<()> {
+        log::set_max_level(level);
+        log::set_logger(logger).map_err(custom)
+    }
+
+    fn name(&self) -> &'static str {
+        "oracle-geyser"
+    }
+
+    fn on_load(&mut self, config_file: &str, _is_reload: bool) -> Result<()> {
+        let raw = std::fs::read_to_string(config_file)?;
+        let config: Config =
+            serde_json::from_str(&raw).map_err(|err| GeyserPluginError::ConfigFileReadError { msg: err.to_string() })?;
+
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("oracle-geyser")
+            .enable_all()
+            .build()?;
+        let client = runtime.block_on(async_nats::connect(&config.nats_url)).map_err(custom)?;
+
+        let (queue, mut pending) = mpsc::channel::<(String, Vec<u8>)>(config.queue_capacity.max(1));
+        runtime.spawn(async move {
+            while let Some((subject, payload)) = pending.recv().await {
+                if let Err(err) = client.publish(subject, payload.into()).await {
+                    log::error!("oracle-geyser: publish failed: {err}");
+                }
+            }
+        });
+
+        log::info!("oracle-geyser: forwarding to {} under {}", config.nats_url, config.subject_prefix);
+        self.running = Some(Running {
+            runtime,
+            queue,
+            prefix: config.subject_prefix,
+            include_startup: config.include_startup,
+        });
+        Ok(())
+    }
+
+    fn on_unload(&mut self) {
+        if let Some(running) = self.running.take() {
+            drop(running.queue);
+            running.runtime.shutdown_timeout(std::time::Duration::from_secs(5));
+        }
+    }
+
+    fn update_account(&self, account: ReplicaAccountInfoVersions, slot: Slot, is_startup: bool) -> Result<()> {
+        let Some(running) = &self.running else {
+            return Ok(());
+        };
+        if is_startup && !running.include_startup {
+            return Ok(());
+        }
+        let (pubkey, owner, data, write_version, signature) = match account {
+            ReplicaAccountInfoVersions::V0_0_1(a) => (a.pubkey, a.owner, a.data, a.write_version, None),
+            ReplicaAccountInfoVersions::V0_0_2(a) => (a.pubkey, a.owner, a.data, a.write_version, a.txn_signature.copied()),
+            ReplicaAccountInfoVersions::V0_0_3(a) => {
+                (a.pubkey, a.owner, a.data, a.write_version, a.txn.map(|txn| *txn.signature()))
+            }
+        };
+        if owner != PROGRAM_ID.as_ref() {
+            return Ok(());
+        }
+        let Some(decoded) = decode_any_account(data) else {
+            return Ok(());
+        };
+        let address = Pubkey::try_from(pubkey).map_err(|_| GeyserPluginError::AccountsUpdateError {
+            msg: "account pubkey is not 32 bytes".to_string(),
+        })?;
+        let value = serde_json::to_value(&decoded).map_err(custom)?;
+        self.publish(
+            format!("{}.accounts.{}", running.prefix, decoded.name()),
+            json!({
+                "slot": slot,
+                "address": address.to_string(),
+                "write_version": write_version,
+                "signature": signature.map(|s| s.to_string()),
+                "data": value["data"],
+            }),
+        );
+        Ok(())
+    }
+
+    fn notify_transaction(&self, transaction: ReplicaTransactionInfoVersions, slot: Slot) -> Result<()> {
+        let Some(running) = &self.running else {
+            return Ok(());
+        };
+        let (signature, is_vote, meta) = match transaction {
+            ReplicaTransactionInfoVersions::V0_0_1(t) => (t.signature, t.is_vote, t.transaction_status_meta),
+            ReplicaTransactionInfoVersions::V0_0_2(t) => (t.signature, t.is_vote, t.transaction_status_meta),
+        };
+        if is_vote || meta.status.is_err() {
+            return Ok(());
+        }
+        let Some(logs) = &meta.log_messages else {
+            return Ok(());
+        };
+        for (index, event) in program_data(logs).iter().filter_map(|data| decode_any_event(data)).enumerate() {
+            let value = serde_json::to_value(&event).map_err(custom)?;
+            self.publish(
+                format!("{}.events.{}", running.prefix, event.name()),
+                json!({
+                    "slot": slot,
+                    "signature": signature.to_string(),
+                    "index": index,
+                    "data": value["data"],
+                }),
+            );
+        }
+        Ok(())
+    }
+
+    fn account_data_notifications_enabled(&self) -> bool {
+        true
+    }
+
+    fn transaction_notifications_enabled(&self) -> bool {
+        true
+    }
+}
+
+/// Entry point the validator looks up when loading the plugin
+///
+/// # Safety
+///
+/// The returned pointer must be freed by the validator's plugin manager.
+#[no_mangle]
+#[allow(improper_ctypes_definitions)]
+pub unsafe extern "C" fn _create_plugin() -> *mut dyn GeyserPlugin {
+    Box::into_raw(Box::new(OracleGeyser::default()))
+}
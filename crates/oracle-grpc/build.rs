@@ -0,0 +1,11 @@
+//! Compiles `proto/` with protox, so building needs no `protoc` install.
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let files = protox::compile(["oracle/v1/oracle.proto"], ["proto"])?;
+    tonic_build::configure()
+        .build_client(true)
+        .build_server(true)
+        .compile_fds(files)?;
+    println!("cargo:rerun-if-changed=proto");
+    Ok(())
+}
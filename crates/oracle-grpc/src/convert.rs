@@ -0,0 +1,80 @@
+//! Conversions from client types to their protobuf messages.
+
+use oracle_client::stream::{SignalEvent, StreamItem};
+use oracle_client::types::{self, SignalDirection, SignalStatus};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::v1;
+
+pub fn status(status: &SignalStatus) -> v1::SignalStatus {
+    match status {
+        SignalStatus::Open => v1::SignalStatus::Open,
+        SignalStatus::Win => v1::SignalStatus::Win,
+        SignalStatus::Loss => v1::SignalStatus::Loss,
+        SignalStatus::Closed => v1::SignalStatus::Closed,
+        SignalStatus::BreakEven => v1::SignalStatus::BreakEven,
+    }
+}
+
+pub fn direction(direction: &SignalDirection) -> v1::SignalDirection {
+    match direction {
+        SignalDirection::Long => v1::SignalDirection::Long,
+        SignalDirection::Short => v1::SignalDirection::Short,
+    }
+}
+
+pub fn event(item: &StreamItem) -> v1::SignalEvent {
+    let event = match &item.event {
+        SignalEvent::Published(e) => v1::signal_event::Event::Published(v1::SignalPublished {
+            id: e.id,
+            token: e.token.to_string(),
+            score: e.score.into(),
+            timestamp: e.timestamp,
+            publisher_domain: e.publisher_domain.clone(),
+            reasoning_hash: Vec::new(),
+        }),
+        SignalEvent::PublishedWithProof(e) => v1::signal_event::Event::Published(v1::SignalPublished {
+            id: e.id,
+            token: e.token.to_string(),
+            score: e.score.into(),
+            timestamp: e.timestamp,
+            publisher_domain: e.publisher_domain.clone(),
+            reasoning_hash: e.reasoning_hash.to_vec(),
+        }),
+        SignalEvent::Closed(e) => v1::signal_event::Event::Closed(v1::SignalClosed {
+            id: e.id,
+            status: status(&e.status).into(),
+            roi_bps: e.roi_bps,
+        }),
+    };
+    v1::SignalEvent {
+        signature: item.signature.clone(),
+        slot: item.slot,
+        event: Some(event),
+    }
+}
+
+pub fn signal(address: &Pubkey, signal: &types::Signal) -> v1::Signal {
+    v1::Signal {
+        id: signal.id,
+        address: address.to_string(),
+        token: signal.token.to_string(),
+        symbol: signal.symbol.clone(),
+        score: signal.score.into(),
+        risk_level: signal.risk_level.into(),
+        sources_bitmap: signal.sources_bitmap.into(),
+        mcap_at_signal: signal.mcap_at_signal,
+        entry_price: signal.entry_price,
+        direction: direction(&signal.direction).into(),
+        ath_price: signal.ath_price,
+        exit_price: signal.exit_price,
+        roi_bps: signal.roi_bps,
+        timestamp: signal.timestamp,
+        status: status(&signal.status).into(),
+        reasoning_hash: signal.reasoning_hash.to_vec(),
+        reasoning_revealed: signal.reasoning_revealed,
+        price_pool: signal.price_pool.to_string(),
+        entry_verified: signal.entry_verified,
+        exit_verified: signal.exit_verified,
+    }
+}
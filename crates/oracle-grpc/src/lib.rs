@@ -0,0 +1,11 @@
+//! Generated protobuf types and gRPC client/server for `oracle.v1`.
+//!
+//! The service definition lives in `proto/oracle/v1/oracle.proto`; this
+//! crate is also usable as a typed Rust client.
+
+pub mod convert;
+
+#[allow(clippy::all)]
+pub mod v1 {
+    tonic::include_proto!("oracle.v1");
+}
@@ -0,0 +1,135 @@
+//! `oracle-grpc`: gRPC signal stream and stats for ORACLE Alpha.
+//!
+//! Serves the `oracle.v1.Oracle` service from `proto/oracle/v1/oracle.proto`.
+//! One `SignalStream` feeds a broadcast channel shared by every
+//! `StreamSignals` call; unary queries read the chain.
+
+// tonic's handlers return `Result<_, Status>`, and Status is large
+#![allow(clippy::result_large_err)]
+
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use anyhow::Result;
+use clap::Parser;
+use oracle_client::math;
+use oracle_client::stream::{SignalEvent, StreamItem};
+use oracle_client::{fetch, pda, ClientError};
+use oracle_grpc::convert;
+use oracle_grpc::v1::oracle_server::{Oracle, OracleServer};
+use oracle_grpc::v1::{self, GetSignalRequest, GetStatsRequest, Stats, StreamSignalsRequest};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::{Stream, StreamExt};
+use tonic::{Request, Response, Status};
+
+#[derive(Parser)]
+#[command(name = "oracle-grpc", version, about = "Serve ORACLE Alpha signals over gRPC")]
+struct Cli {
+    /// RPC endpoint
+    #[arg(long, short = 'u', env = "ORACLE_RPC_URL", default_value = "https://api.mainnet-beta.solana.com")]
+    url: String,
+
+    /// RPC websocket endpoint to subscribe to
+    #[arg(long, env = "ORACLE_WS_URL", default_value = "wss://api.mainnet-beta.solana.com")]
+    ws_url: String,
+
+    /// Address to listen on
+    #[arg(long, env = "ORACLE_GRPC_LISTEN", default_value = "0.0.0.0:50051")]
+    listen: SocketAddr,
+
+    /// Events buffered per stream before the client is dropped as lagged
+    #[arg(long, default_value_t = 1024)]
+    buffer: usize,
+}
+
+struct Service {
+    rpc: Arc<RpcClient>,
+    events: broadcast::Sender<StreamItem>,
+}
+
+type EventStream = Pin<Box<dyn Stream<Item = Result<v1::SignalEvent, Status>> + Send>>;
+
+#[tonic::async_trait]
+impl Oracle for Service {
+    type StreamSignalsStream = EventStream;
+
+    async fn stream_signals(&self, request: Request<StreamSignalsRequest>) -> Result<Response<EventStream>, Status> {
+        let min_score = request.into_inner().min_score;
+        let stream = BroadcastStream::new(self.events.subscribe())
+            .filter(move |item| match item {
+                Ok(item) => passes(item, min_score),
+                Err(_) => true,
+            })
+            // tonic ends the call at the first error, so a lagged client is
+            // disconnected rather than resumed with a gap
+            .map(|item| match item {
+                Ok(item) => Ok(convert::event(&item)),
+                Err(BroadcastStreamRecvError::Lagged(missed)) => {
+                    Err(Status::data_loss(format!("stream fell {missed} event(s) behind")))
+                }
+            });
+        Ok(Response::new(Box::pin(stream)))
+    }
+
+    async fn get_stats(&self, _: Request<GetStatsRequest>) -> Result<Response<Stats>, Status> {
+        let state = fetch::fetch_oracle_state(&self.rpc).await.map_err(status)?;
+        Ok(Response::new(Stats {
+            total_signals: state.total_signals,
+            total_wins: state.total_wins,
+            total_losses: state.total_losses,
+            win_rate_bps: math::win_rate_bps(state.total_wins, state.total_losses),
+        }))
+    }
+
+    async fn get_signal(&self, request: Request<GetSignalRequest>) -> Result<Response<v1::Signal>, Status> {
+        let id = request.into_inner().id;
+        let signal = fetch::fetch_signal(&self.rpc, id).await.map_err(status)?;
+        Ok(Response::new(convert::signal(&pda::signal(id), &signal)))
+    }
+}
+
+fn passes(item: &StreamItem, min_score: u32) -> bool {
+    match &item.event {
+        SignalEvent::Published(e) => u32::from(e.score) >= min_score,
+        SignalEvent::PublishedWithProof(e) => u32::from(e.score) >= min_score,
+        SignalEvent::Closed(_) => true,
+    }
+}
+
+fn status(err: ClientError) -> Status {
+    match err {
+        ClientError::AccountNotFound(address) => Status::not_found(format!("account {address} not found")),
+        err => {
+            eprintln!("rpc query failed: {err}");
+            Status::unavailable("upstream RPC query failed")
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let rpc = Arc::new(RpcClient::new_with_commitment(cli.url, CommitmentConfig::confirmed()));
+
+    let (events, _) = broadcast::channel(cli.buffer.max(1));
+    let mut stream = oracle_client::stream::SignalStream::new(cli.ws_url).spawn(cli.buffer.max(1));
+    let publisher = events.clone();
+    tokio::spawn(async move {
+        while let Some(item) = stream.recv().await {
+            // No open streams is not an error
+            let _ = publisher.send(item);
+        }
+    });
+
+    eprintln!("oracle-grpc listening on {}", cli.listen);
+    tonic::transport::Server::builder()
+        .add_service(OracleServer::new(Service { rpc, events }))
+        .serve(cli.listen)
+        .await?;
+    Ok(())
+}
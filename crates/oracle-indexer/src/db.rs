@@ -0,0 +1,205 @@
+//! Postgres writes.
+
+use anyhow::{Context, Result};
+use oracle_types::{OracleAccount, OracleEvent, OracleState, Signal, SignalClosed, Subscription};
+use solana_sdk::pubkey::Pubkey;
+use tokio_postgres::{Client, GenericClient, NoTls};
+
+const SCHEMA: &str = include_str!("schema.sql");
+
+pub struct Db {
+    client: Client,
+}
+
+impl Db {
+    /// Connect and apply the schema
+    pub async fn connect(url: &str) -> Result<Self> {
+        let (client, connection) = tokio_postgres::connect(url, NoTls)
+            .await
+            .context("connecting to Postgres")?;
+        tokio::spawn(async move {
+            if let Err(err) = connection.await {
+                eprintln!("postgres connection closed: {err}");
+            }
+        });
+        client.batch_execute(SCHEMA).await.context("applying schema")?;
+        Ok(Db { client })
+    }
+
+    /// Newest transaction signature already indexed
+    pub async fn cursor(&self) -> Result<Option<String>> {
+        let row = self.client.query_opt("SELECT signature FROM cursor", &[]).await?;
+        Ok(row.map(|row| row.get(0)))
+    }
+
+    /// Store a transaction's events and advance the cursor past it, atomically
+    pub async fn record_transaction(
+        &mut self,
+        signature: &str,
+        slot: u64,
+        block_time: Option<i64>,
+        events: &[OracleEvent],
+    ) -> Result<()> {
+        let tx = self.client.transaction().await?;
+        for (idx, event) in events.iter().enumerate() {
+            tx.execute(
+                "INSERT INTO events (signature, idx, kind, data, slot, block_time)
+                 VALUES ($1, $2, $3, $4, $5, $6)
+                 ON CONFLICT DO NOTHING",
+                &[
+                    &signature,
+                    &(idx as i32),
+                    &event.name(),
+                    &serde_json::to_value(event)?["data"],
+                    &(slot as i64),
+                    &block_time,
+                ],
+            )
+            .await?;
+            if let OracleEvent::SignalClosed(closed) = event {
+                insert_settlement(&tx, signature, slot, block_time, closed).await?;
+            }
+        }
+        tx.execute(
+            "INSERT INTO cursor (id, signature, slot) VALUES (TRUE, $1, $2)
+             ON CONFLICT (id) DO UPDATE SET signature = $1, slot = $2",
+            &[&signature, &(slot as i64)],
+        )
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Upsert an account into its table
+    pub async fn upsert_account(&self, address: &Pubkey, account: &OracleAccount, slot: u64) -> Result<()> {
+        let address = address.to_string();
+        match account {
+            OracleAccount::Signal(signal) => upsert_signal(&self.client, &address, signal, slot).await,
+            OracleAccount::OracleState(state) => upsert_stats(&self.client, &address, state, slot).await,
+            OracleAccount::Subscription(subscription) => {
+                upsert_subscription(&self.client, &address, subscription, slot).await
+            }
+            other => {
+                self.client
+                    .execute(
+                        "INSERT INTO accounts (address, kind, data, slot) VALUES ($1, $2, $3, $4)
+                         ON CONFLICT (address) DO UPDATE SET kind = $2, data = $3, slot = $4",
+                        &[&address, &other.name(), &serde_json::to_value(other)?["data"], &(slot as i64)],
+                    )
+                    .await?;
+                Ok(())
+            }
+        }
+    }
+}
+
+async fn insert_settlement(
+    client: &impl GenericClient,
+    signature: &str,
+    slot: u64,
+    block_time: Option<i64>,
+    closed: &SignalClosed,
+) -> Result<()> {
+    client
+        .execute(
+            "INSERT INTO settlements (signature, signal_id, status, roi_bps, slot, block_time)
+             VALUES ($1, $2, $3, $4, $5, $6)
+             ON CONFLICT DO NOTHING",
+            &[
+                &signature,
+                &(closed.id as i64),
+                &format!("{:?}", closed.status),
+                &closed.roi_bps,
+                &(slot as i64),
+                &block_time,
+            ],
+        )
+        .await?;
+    Ok(())
+}
+
+async fn upsert_signal(client: &impl GenericClient, address: &str, signal: &Signal, slot: u64) -> Result<()> {
+    client
+        .execute(
+            "INSERT INTO signals (address, id, token, symbol, score, risk_level, sources_bitmap,
+                 mcap_at_signal, entry_price, direction, ath_price, exit_price, roi_bps, published_at,
+                 status, reasoning_hash, reasoning_revealed, price_pool, entry_verified, exit_verified, slot)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21)
+             ON CONFLICT (address) DO UPDATE SET
+                 ath_price = $11, exit_price = $12, roi_bps = $13, status = $15,
+                 reasoning_revealed = $17, price_pool = $18, entry_verified = $19, exit_verified = $20, slot = $21
+             WHERE signals.slot <= $21",
+            &[
+                &address,
+                &(signal.id as i64),
+                &signal.token.to_string(),
+                &signal.symbol,
+                &i16::from(signal.score),
+                &i16::from(signal.risk_level),
+                &i16::from(signal.sources_bitmap),
+                &(signal.mcap_at_signal as i64),
+                &(signal.entry_price as i64),
+                &format!("{:?}", signal.direction),
+                &(signal.ath_price as i64),
+                &(signal.exit_price as i64),
+                &signal.roi_bps,
+                &signal.timestamp,
+                &format!("{:?}", signal.status),
+                &&signal.reasoning_hash[..],
+                &signal.reasoning_revealed,
+                &signal.price_pool.to_string(),
+                &signal.entry_verified,
+                &signal.exit_verified,
+                &(slot as i64),
+            ],
+        )
+        .await?;
+    Ok(())
+}
+
+async fn upsert_stats(client: &impl GenericClient, address: &str, state: &OracleState, slot: u64) -> Result<()> {
+    client
+        .execute(
+            "INSERT INTO stats (address, authority, total_signals, total_wins, total_losses, compressed_signals, slot)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)
+             ON CONFLICT (address) DO UPDATE SET
+                 authority = $2, total_signals = $3, total_wins = $4, total_losses = $5,
+                 compressed_signals = $6, slot = $7
+             WHERE stats.slot <= $7",
+            &[
+                &address,
+                &state.authority.to_string(),
+                &(state.total_signals as i64),
+                &(state.total_wins as i64),
+                &(state.total_losses as i64),
+                &(state.compressed_signals as i64),
+                &(slot as i64),
+            ],
+        )
+        .await?;
+    Ok(())
+}
+
+async fn upsert_subscription(
+    client: &impl GenericClient,
+    address: &str,
+    subscription: &Subscription,
+    slot: u64,
+) -> Result<()> {
+    client
+        .execute(
+            "INSERT INTO subscriptions (address, subscriber, tier, expires_at, slot)
+             VALUES ($1, $2, $3, $4, $5)
+             ON CONFLICT (address) DO UPDATE SET tier = $3, expires_at = $4, slot = $5
+             WHERE subscriptions.slot <= $5",
+            &[
+                &address,
+                &subscription.subscriber.to_string(),
+                &i16::from(subscription.tier),
+                &subscription.expires_at,
+                &(slot as i64),
+            ],
+        )
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,107 @@
+//! Following the chain over RPC.
+//!
+//! Transactions are paged with `getSignaturesForAddress` back to the stored
+//! cursor and replayed oldest-first, so a restart resumes exactly where the
+//! last run stopped. Accounts are re-snapshotted with `getProgramAccounts`
+//! each round, which picks up state no event describes.
+
+use anyhow::{Context, Result};
+use oracle_client::stream::program_data;
+use oracle_client::PROGRAM_ID;
+use oracle_types::{decode_any_account, decode_any_event, OracleEvent};
+use solana_account_decoder_client_types::UiAccountEncoding;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_rpc_client_api::config::{RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcTransactionConfig};
+use solana_rpc_client_api::response::RpcConfirmedTransactionStatusWithSignature;
+use solana_sdk::signature::Signature;
+use solana_transaction_status_client_types::UiTransactionEncoding;
+
+use crate::db::Db;
+
+/// Largest page `getSignaturesForAddress` returns
+const SIGNATURE_PAGE: usize = 1_000;
+
+/// Index every program transaction newer than the cursor; returns how many
+pub async fn sync_transactions(rpc: &RpcClient, db: &mut Db) -> Result<usize> {
+    let until = db
+        .cursor()
+        .await?
+        .map(|signature| signature.parse::<Signature>())
+        .transpose()
+        .context("stored cursor is not a signature")?;
+    let pending = new_signatures(rpc, until).await?;
+
+    for status in &pending {
+        let events = if status.err.is_some() {
+            Vec::new()
+        } else {
+            transaction_events(rpc, &status.signature.parse()?).await?
+        };
+        db.record_transaction(&status.signature, status.slot, status.block_time, &events)
+            .await?;
+    }
+    Ok(pending.len())
+}
+
+/// Upsert every program account; returns how many were decoded
+pub async fn sync_accounts(rpc: &RpcClient, db: &Db) -> Result<usize> {
+    // Lower bound on the snapshot's slot, so newer rows are never overwritten
+    let slot = rpc.get_slot().await?;
+    let config = RpcProgramAccountsConfig {
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            commitment: Some(rpc.commitment()),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+    let mut decoded = 0;
+    for (address, account) in rpc.get_program_accounts_with_config(&PROGRAM_ID, config).await? {
+        if let Some(account) = decode_any_account(&account.data) {
+            db.upsert_account(&address, &account, slot).await?;
+            decoded += 1;
+        }
+    }
+    Ok(decoded)
+}
+
+/// Signatures newer than `until`, oldest first
+async fn new_signatures(
+    rpc: &RpcClient,
+    until: Option<Signature>,
+) -> Result<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+    let mut out = Vec::new();
+    let mut before = None;
+    loop {
+        let config = GetConfirmedSignaturesForAddress2Config {
+            before,
+            until,
+            limit: Some(SIGNATURE_PAGE),
+            commitment: Some(rpc.commitment()),
+        };
+        let page = rpc.get_signatures_for_address_with_config(&PROGRAM_ID, config).await?;
+        let last_page = page.len() < SIGNATURE_PAGE;
+        before = page.last().map(|status| status.signature.parse()).transpose()?;
+        out.extend(page);
+        if last_page {
+            break;
+        }
+    }
+    out.reverse();
+    Ok(out)
+}
+
+async fn transaction_events(rpc: &RpcClient, signature: &Signature) -> Result<Vec<OracleEvent>> {
+    let config = RpcTransactionConfig {
+        encoding: Some(UiTransactionEncoding::Json),
+        commitment: Some(rpc.commitment()),
+        max_supported_transaction_version: Some(0),
+    };
+    let tx = rpc.get_transaction_with_config(signature, config).await?;
+    let logs: Option<Vec<String>> = tx.transaction.meta.and_then(|meta| meta.log_messages.into());
+    Ok(program_data(&logs.unwrap_or_default())
+        .iter()
+        .filter_map(|data| decode_any_event(data))
+        .collect())
+}
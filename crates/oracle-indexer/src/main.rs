@@ -0,0 +1,51 @@
+//! `oracle-indexer`: mirror ORACLE Alpha accounts and events into Postgres.
+//!
+//! Signals, settlements, the oracle's stats and subscriptions get their own
+//! tables; every other account and every event is kept as JSON. See
+//! `schema.sql` for the layout.
+
+mod db;
+mod follow;
+
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Parser;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+
+#[derive(Parser)]
+#[command(name = "oracle-indexer", version, about = "Index ORACLE Alpha into Postgres")]
+struct Cli {
+    /// RPC endpoint
+    #[arg(long, short = 'u', env = "ORACLE_RPC_URL", default_value = "https://api.mainnet-beta.solana.com")]
+    url: String,
+
+    /// Postgres connection string
+    #[arg(long, env = "DATABASE_URL")]
+    database_url: String,
+
+    /// Seconds between sync rounds
+    #[arg(long, default_value_t = 10)]
+    interval: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let rpc = RpcClient::new_with_commitment(cli.url.clone(), CommitmentConfig::confirmed());
+    let mut db = db::Db::connect(&cli.database_url).await?;
+
+    let mut ticker = tokio::time::interval(Duration::from_secs(cli.interval.max(1)));
+    loop {
+        ticker.tick().await;
+        match follow::sync_transactions(&rpc, &mut db).await {
+            Ok(0) => {}
+            Ok(count) => eprintln!("indexed {count} transaction(s)"),
+            Err(err) => eprintln!("transaction sync failed: {err:#}"),
+        }
+        if let Err(err) = follow::sync_accounts(&rpc, &db).await {
+            eprintln!("account sync failed: {err:#}");
+        }
+    }
+}
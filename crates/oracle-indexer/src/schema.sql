@@ -0,0 +1,99 @@
+-- ORACLE Alpha indexer schema. Idempotent: applied on every start.
+--
+-- u64 amounts are stored as BIGINT; pubkeys as base58 TEXT.
+
+CREATE TABLE IF NOT EXISTS signals (
+    address            TEXT PRIMARY KEY,
+    id                 BIGINT NOT NULL UNIQUE,
+    token              TEXT NOT NULL,
+    symbol             TEXT NOT NULL,
+    score              SMALLINT NOT NULL,
+    risk_level         SMALLINT NOT NULL,
+    sources_bitmap     SMALLINT NOT NULL,
+    mcap_at_signal     BIGINT NOT NULL,
+    entry_price        BIGINT NOT NULL,
+    direction          TEXT NOT NULL,
+    ath_price          BIGINT NOT NULL,
+    exit_price         BIGINT NOT NULL,
+    roi_bps            BIGINT NOT NULL,
+    published_at       BIGINT NOT NULL,
+    status             TEXT NOT NULL,
+    reasoning_hash     BYTEA NOT NULL,
+    reasoning_revealed BOOLEAN NOT NULL,
+    price_pool         TEXT NOT NULL,
+    entry_verified     BOOLEAN NOT NULL,
+    exit_verified      BOOLEAN NOT NULL,
+    slot               BIGINT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS signals_token_idx ON signals (token);
+CREATE INDEX IF NOT EXISTS signals_status_idx ON signals (status);
+
+CREATE TABLE IF NOT EXISTS settlements (
+    signature  TEXT NOT NULL,
+    signal_id  BIGINT NOT NULL,
+    status     TEXT NOT NULL,
+    roi_bps    BIGINT NOT NULL,
+    slot       BIGINT NOT NULL,
+    block_time BIGINT,
+    PRIMARY KEY (signature, signal_id)
+);
+CREATE INDEX IF NOT EXISTS settlements_signal_idx ON settlements (signal_id);
+
+-- One row: the latest OracleState snapshot
+CREATE TABLE IF NOT EXISTS stats (
+    address            TEXT PRIMARY KEY,
+    authority          TEXT NOT NULL,
+    total_signals      BIGINT NOT NULL,
+    total_wins         BIGINT NOT NULL,
+    total_losses       BIGINT NOT NULL,
+    compressed_signals BIGINT NOT NULL,
+    slot               BIGINT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS subscriptions (
+    address    TEXT PRIMARY KEY,
+    subscriber TEXT NOT NULL UNIQUE,
+    tier       SMALLINT NOT NULL,
+    expires_at BIGINT NOT NULL,
+    slot       BIGINT NOT NULL
+);
+
+-- Every other program account, decoded to JSON
+CREATE TABLE IF NOT EXISTS accounts (
+    address TEXT PRIMARY KEY,
+    kind    TEXT NOT NULL,
+    data    JSONB NOT NULL,
+    slot    BIGINT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS accounts_kind_idx ON accounts (kind);
+
+-- Every event; `seq` follows chain order, since transactions are replayed
+-- oldest-first
+CREATE TABLE IF NOT EXISTS events (
+    seq        BIGSERIAL UNIQUE,
+    signature  TEXT NOT NULL,
+    idx        INTEGER NOT NULL,
+    kind       TEXT NOT NULL,
+    data       JSONB NOT NULL,
+    slot       BIGINT NOT NULL,
+    block_time BIGINT,
+    PRIMARY KEY (signature, idx)
+);
+CREATE INDEX IF NOT EXISTS events_kind_idx ON events (kind, slot);
+
+-- Off-chain price observations (PRICE_DECIMALS), written by the price
+-- poller and replayed by the backtester
+CREATE TABLE IF NOT EXISTS price_checkpoints (
+    token  TEXT NOT NULL,
+    ts     BIGINT NOT NULL,
+    price  BIGINT NOT NULL,
+    source TEXT NOT NULL,
+    PRIMARY KEY (token, ts, source)
+);
+
+-- Newest transaction signature fully indexed
+CREATE TABLE IF NOT EXISTS cursor (
+    id        BOOLEAN PRIMARY KEY DEFAULT TRUE CHECK (id),
+    signature TEXT NOT NULL,
+    slot      BIGINT NOT NULL
+);
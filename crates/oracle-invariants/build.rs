@@ -0,0 +1,23 @@
+//! Enables the LiteSVM properties when the program binary exists, and
+//! fails without it under `ORACLE_REQUIRE_PROGRAM`; see oracle-test-utils'
+//! build script.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn main() {
+    println!("cargo::rustc-check-cfg=cfg(oracle_program)");
+    println!("cargo::rerun-if-env-changed=ORACLE_PROGRAM_SO");
+    println!("cargo::rerun-if-env-changed=ORACLE_REQUIRE_PROGRAM");
+
+    let deploy = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap()).join("../../target/deploy");
+    let _ = fs::create_dir_all(&deploy);
+    println!("cargo::rerun-if-changed={}", deploy.display());
+    let path = env::var_os("ORACLE_PROGRAM_SO").map_or_else(|| deploy.join("oracle.so"), PathBuf::from);
+    if path.exists() {
+        println!("cargo::rustc-cfg=oracle_program");
+    } else if env::var_os("ORACLE_REQUIRE_PROGRAM").is_some() {
+        panic!("{} is missing and ORACLE_REQUIRE_PROGRAM is set", path.display());
+    }
+}
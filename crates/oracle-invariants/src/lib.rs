@@ -0,0 +1,145 @@
+//! Property-based invariant checks for the ORACLE Alpha program.
+//!
+//! [`op`] generates random publish, ATH, close and clock-warp steps;
+//! `tests/invariants.rs` runs sequences of them against the compiled
+//! program in LiteSVM and, after every step, checks the program's state
+//! with [`check`]. The program has no expiry instruction, so "expiring" a
+//! signal is modelled as time passing with the signal left open.
+
+use std::collections::BTreeMap;
+
+use oracle_client::types::{OracleConfig, OracleState, Signal, SignalDirection, SignalStatus};
+use oracle_math::{classify, roi_bps, Direction, Outcome, WIN_THRESHOLD_BPS};
+use proptest::prelude::*;
+use proptest::sample::Index;
+
+/// One step of a generated run
+#[derive(Debug, Clone)]
+pub enum Op {
+    Publish { entry_price: u64, short: bool },
+    /// New price for one of the published signals
+    Ath { signal: Index, price: u64 },
+    Close { signal: Index, exit_price: u64 },
+    /// Let time pass without touching any signal
+    Warp { seconds: i64 },
+}
+
+/// Prices weighted towards the edges the ROI math has to survive
+pub fn price() -> impl Strategy<Value = u64> {
+    prop_oneof![
+        4 => 1u64..=1_000_000_000_000,
+        1 => 1u64..=10,
+        1 => Just(0u64),
+        1 => (u64::MAX - 10)..=u64::MAX,
+    ]
+}
+
+pub fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        3 => (1u64..=1_000_000_000_000, any::<bool>())
+            .prop_map(|(entry_price, short)| Op::Publish { entry_price, short }),
+        3 => (any::<Index>(), price()).prop_map(|(signal, price)| Op::Ath { signal, price }),
+        3 => (any::<Index>(), price()).prop_map(|(signal, exit_price)| Op::Close { signal, exit_price }),
+        1 => (1i64..=30 * 86_400).prop_map(|seconds| Op::Warp { seconds }),
+    ]
+}
+
+/// Program state after a step
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    pub state: OracleState,
+    /// Config signals are settled under
+    pub config: OracleConfig,
+    /// Every signal, by id
+    pub signals: Vec<Signal>,
+}
+
+/// Check `now` against the invariants, and against `prev` (the snapshot
+/// before the step) for the ones about how state may change. `exits` holds
+/// the exit price of every close that succeeded.
+pub fn check(prev: Option<&Snapshot>, now: &Snapshot, exits: &BTreeMap<u64, u64>) -> Result<(), String> {
+    let state = &now.state;
+    let count = |status: SignalStatus| now.signals.iter().filter(|signal| signal.status == status).count() as u64;
+    let settled = now.signals.len() as u64 - count(SignalStatus::Open);
+
+    ensure(state.total_signals == now.signals.len() as u64, || {
+        format!("total_signals {} but {} signals exist", state.total_signals, now.signals.len())
+    })?;
+    ensure(state.total_wins + state.total_losses <= settled, || {
+        format!("{} wins + {} losses > {settled} settled", state.total_wins, state.total_losses)
+    })?;
+    ensure(state.total_wins == count(SignalStatus::Win), || {
+        format!("total_wins {} but {} signals won", state.total_wins, count(SignalStatus::Win))
+    })?;
+    ensure(state.total_losses == count(SignalStatus::Loss), || {
+        format!("total_losses {} but {} signals lost", state.total_losses, count(SignalStatus::Loss))
+    })?;
+
+    for signal in &now.signals {
+        let direction = match signal.direction {
+            SignalDirection::Long => Direction::Long,
+            SignalDirection::Short => Direction::Short,
+        };
+        if signal.status == SignalStatus::Open {
+            ensure(signal.exit_price == 0 && signal.roi_bps == 0, || {
+                format!("open signal #{} has an exit", signal.id)
+            })?;
+            ensure(!exits.contains_key(&signal.id), || format!("signal #{} closed but still open", signal.id))?;
+            // The best price only ever moves in the signal's favour
+            let favourable = match direction {
+                Direction::Long => signal.ath_price >= signal.entry_price,
+                Direction::Short => signal.ath_price <= signal.entry_price,
+            };
+            ensure(favourable, || {
+                format!("signal #{} best price {} is worse than entry", signal.id, signal.ath_price)
+            })?;
+            continue;
+        }
+
+        let exit = exits.get(&signal.id).copied();
+        ensure(exit == Some(signal.exit_price), || {
+            format!("signal #{} settled at {} but closed at {exit:?}", signal.id, signal.exit_price)
+        })?;
+        if let Some(expected) = roi_bps(signal.entry_price, signal.exit_price, direction) {
+            ensure(signal.roi_bps == expected, || {
+                format!("signal #{} roi {} but the math gives {expected}", signal.id, signal.roi_bps)
+            })?;
+        }
+        let win_threshold_bps = now
+            .config
+            .win_threshold_bps
+            .get(signal.risk_level as usize)
+            .copied()
+            .unwrap_or(WIN_THRESHOLD_BPS);
+        let expected = match classify(signal.roi_bps, win_threshold_bps, now.config.break_even_bps) {
+            Outcome::Win => SignalStatus::Win,
+            Outcome::Loss => SignalStatus::Loss,
+            Outcome::Closed => SignalStatus::Closed,
+            Outcome::BreakEven => SignalStatus::BreakEven,
+        };
+        ensure(signal.status == expected, || {
+            format!("signal #{} is {:?} at roi {}", signal.id, signal.status, signal.roi_bps)
+        })?;
+    }
+
+    let Some(prev) = prev else { return Ok(()) };
+    ensure(state.total_signals >= prev.state.total_signals, || "total_signals decreased".into())?;
+    ensure(state.total_wins >= prev.state.total_wins, || "total_wins decreased".into())?;
+    ensure(state.total_losses >= prev.state.total_losses, || "total_losses decreased".into())?;
+    for (before, after) in prev.signals.iter().zip(&now.signals) {
+        if before.status != SignalStatus::Open {
+            let unchanged =
+                (before.status, before.roi_bps, before.exit_price) == (after.status, after.roi_bps, after.exit_price);
+            ensure(unchanged, || format!("settled signal #{} changed", before.id))?;
+        }
+    }
+    Ok(())
+}
+
+fn ensure(holds: bool, message: impl FnOnce() -> String) -> Result<(), String> {
+    if holds {
+        Ok(())
+    } else {
+        Err(message())
+    }
+}
@@ -0,0 +1,86 @@
+//! Random operation sequences against the compiled program.
+//!
+//! They need `target/deploy/oracle.so` (or `ORACLE_PROGRAM_SO`), which
+//! oracle-test-utils' build script produces when the Solana toolchain is
+//! installed, and are ignored without it.
+//! `PROPTEST_CASES` raises the number of runs.
+
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+
+use oracle::{OracleError, SignalDirection};
+use oracle_invariants::{check, op, Op, Snapshot};
+use oracle_test_utils::{is_oracle_error, signal_params, OracleFixture};
+use proptest::collection::vec;
+use proptest::prelude::*;
+use solana_sdk::pubkey::Pubkey;
+
+fn snapshot(fx: &OracleFixture) -> Snapshot {
+    let state = fx.oracle_state();
+    let signals = (0..state.total_signals)
+        .map(|id| fx.signal(id).expect("published signal exists"))
+        .collect();
+    Snapshot {
+        state,
+        config: fx.config(),
+        signals,
+    }
+}
+
+fn run(ops: Vec<Op>) -> Result<(), TestCaseError> {
+    let mut fx = OracleFixture::new();
+    let token = Pubkey::new_unique();
+    let mut exits = BTreeMap::new();
+    let mut prev = snapshot(&fx);
+
+    for (step, op) in ops.iter().enumerate() {
+        let published = prev.state.total_signals as usize;
+        match op {
+            Op::Publish { entry_price, short } => {
+                let mut params = signal_params(token, *entry_price);
+                if *short {
+                    params.direction = SignalDirection::Short;
+                }
+                prop_assert!(fx.publish(params).is_ok(), "step {step}: publish failed");
+            }
+            Op::Ath { signal, price } if published > 0 => {
+                let id = signal.index(published) as u64;
+                prop_assert!(fx.update_ath(id, *price).is_ok(), "step {step}: update_ath failed");
+            }
+            Op::Close { signal, exit_price } if published > 0 => {
+                let id = signal.index(published) as u64;
+                let result = fx.close(id, *exit_price);
+                match exits.entry(id) {
+                    Entry::Occupied(_) => prop_assert!(
+                        is_oracle_error(&result, OracleError::SignalAlreadyClosed),
+                        "step {step}: second close of #{id} did not fail as already closed"
+                    ),
+                    Entry::Vacant(entry) => {
+                        prop_assert!(result.is_ok(), "step {step}: close of #{id} failed");
+                        entry.insert(*exit_price);
+                    }
+                }
+            }
+            Op::Warp { seconds } => fx.warp(*seconds),
+            // Nothing published yet to update or close
+            Op::Ath { .. } | Op::Close { .. } => {}
+        }
+
+        let now = snapshot(&fx);
+        if let Err(violation) = check(Some(&prev), &now, &exits) {
+            return Err(TestCaseError::fail(format!("step {step} ({op:?}): {violation}")));
+        }
+        prev = now;
+    }
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    #[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+    fn stats_and_settlements_stay_consistent(ops in vec(op(), 1..60)) {
+        run(ops)?;
+    }
+}
@@ -0,0 +1,310 @@
+//! `oracle-keeper`: reference keeper for the ORACLE Alpha program.
+//!
+//! Each tick it prices every open signal that has a verified pool, closes
+//! those past their invalidation price with `invalidate_signal`, records
+//! new best prices with `update_ath` and settles the take-profit levels the
+//! price has reached with `settle_take_profit`. It then burns the subscriber
+//! passes of subscriptions that have lapsed with `burn_expired_pass`, moves
+//! the model portfolio along with `portfolio_enter` and `portfolio_exit`,
+//! and logs the DCA tranches that have come due for the vault executor.
+//! Instructions are batched into transactions; a failed batch is retried
+//! with a fresh fee estimate at the next urgency level. Once those land,
+//! the signals still open are marked to market with `mark_signals`, a few
+//! signals per transaction.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use clap::{Parser, ValueEnum};
+use oracle_client::fees::{self, FeeConfig, Urgency};
+use oracle_client::math::{self, Direction};
+use oracle_client::types::{Signal, SignalDirection, SignalStatus, TakeProfitLadder};
+use oracle_client::{fetch, instructions, prices, query, ClientError};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signature};
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::Transaction;
+
+/// Signals marked per `mark_signals` instruction
+const MARK_BATCH: usize = 5;
+
+#[derive(Parser)]
+#[command(name = "oracle-keeper", version, about = "Keep ORACLE Alpha signals and passes up to date")]
+struct Cli {
+    /// RPC endpoint
+    #[arg(long, short = 'u', env = "ORACLE_RPC_URL", default_value = "https://api.mainnet-beta.solana.com")]
+    url: String,
+
+    /// Oracle authority keypair file
+    #[arg(long, short = 'k', env = "ORACLE_KEYPAIR", default_value = "~/.config/solana/id.json")]
+    keypair: String,
+
+    /// Seconds between ticks
+    #[arg(long, default_value_t = 30)]
+    interval: u64,
+
+    /// Starting priority-fee urgency; each retry escalates one level
+    #[arg(long, value_enum, default_value_t = FeeUrgency::Normal)]
+    urgency: FeeUrgency,
+
+    /// Attempts per transaction before giving up until the next tick
+    #[arg(long, default_value_t = 3)]
+    retries: u32,
+
+    /// Instructions per transaction
+    #[arg(long, default_value_t = 4)]
+    batch: usize,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum FeeUrgency {
+    Low,
+    Normal,
+    High,
+    Urgent,
+}
+
+impl From<FeeUrgency> for Urgency {
+    fn from(urgency: FeeUrgency) -> Self {
+        match urgency {
+            FeeUrgency::Low => Urgency::Low,
+            FeeUrgency::Normal => Urgency::Normal,
+            FeeUrgency::High => Urgency::High,
+            FeeUrgency::Urgent => Urgency::Urgent,
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let rpc = RpcClient::new_with_commitment(cli.url.clone(), CommitmentConfig::confirmed());
+    let path = expand_home(&cli.keypair);
+    let keypair = read_keypair_file(&path).map_err(|err| anyhow!("reading keypair {path}: {err}"))?;
+    let fee_config = FeeConfig {
+        urgency: cli.urgency.into(),
+        ..FeeConfig::default()
+    };
+
+    eprintln!("oracle-keeper running as {} against {}", keypair.pubkey(), cli.url);
+    let mut ticker = tokio::time::interval(Duration::from_secs(cli.interval.max(1)));
+    loop {
+        ticker.tick().await;
+        if let Err(err) = tick(&rpc, &keypair, &fee_config, &cli).await {
+            eprintln!("tick failed: {err:#}");
+        }
+    }
+}
+
+async fn tick(rpc: &RpcClient, keypair: &Keypair, fee_config: &FeeConfig, cli: &Cli) -> Result<()> {
+    let mut pending = pool_updates(rpc, &keypair.pubkey()).await?;
+    pending.extend(expired_passes(rpc).await?);
+    pending.extend(portfolio_updates(rpc).await?);
+    if let Err(err) = report_due_tranches(rpc).await {
+        eprintln!("checking DCA plans failed: {err:#}");
+    }
+
+    for batch in pending.chunks(cli.batch.max(1)) {
+        match send_with_retry(rpc, keypair, fee_config, batch, cli.retries).await {
+            Ok(signature) => eprintln!("sent {} instruction(s): {signature}", batch.len()),
+            Err(err) => eprintln!("batch dropped until next tick: {err:#}"),
+        }
+    }
+
+    // Each mark carries six accounts per signal, so they go one per transaction
+    for mark in marks(rpc).await? {
+        match send_with_retry(rpc, keypair, fee_config, std::slice::from_ref(&mark), cli.retries).await {
+            Ok(signature) => eprintln!("marked {} signal(s): {signature}", (mark.accounts.len() - 1) / 6),
+            Err(err) => eprintln!("mark dropped until next tick: {err:#}"),
+        }
+    }
+    Ok(())
+}
+
+/// `mark_signals` for every open signal with a verified pool, `MARK_BATCH`
+/// signals per instruction
+async fn marks(rpc: &RpcClient) -> Result<Vec<Instruction>> {
+    let mut marks = Vec::new();
+    for (address, signal) in query::fetch_open_signals(rpc).await? {
+        if signal.price_pool == Pubkey::default() {
+            continue;
+        }
+        match prices::fetch_pool_accounts(rpc, &signal.price_pool).await {
+            Ok(pool) => marks.push((address, pool)),
+            Err(err) => eprintln!("signal #{}: reading pool failed: {err}", signal.id),
+        }
+    }
+    Ok(marks.chunks(MARK_BATCH).map(instructions::mark_signals).collect())
+}
+
+/// `invalidate_signal` for every open signal whose pool price crossed its
+/// invalidation price; for the rest, `update_ath` when the pool price beats
+/// the recorded best and `settle_take_profit` for every unsettled level it
+/// has reached
+async fn pool_updates(rpc: &RpcClient, authority: &Pubkey) -> Result<Vec<Instruction>> {
+    let invalidations: HashMap<Pubkey, u64> = query::fetch_invalidations(rpc)
+        .await?
+        .into_iter()
+        .map(|(_, invalidation)| (invalidation.signal, invalidation.price))
+        .collect();
+    let ladders: HashMap<Pubkey, TakeProfitLadder> = query::fetch_take_profit_ladders(rpc)
+        .await?
+        .into_iter()
+        .map(|(_, ladder)| (ladder.signal, ladder))
+        .collect();
+    let mut out = Vec::new();
+    for (address, signal) in query::fetch_open_signals(rpc).await? {
+        if signal.price_pool == Pubkey::default() {
+            continue;
+        }
+        let price = match prices::fetch_pool_price(rpc, &signal.price_pool, &signal.token).await {
+            Ok(price) => price,
+            Err(err) => {
+                eprintln!("signal #{}: pricing failed: {err}", signal.id);
+                continue;
+            }
+        };
+        if let Some(invalidation_price) = invalidations.get(&address) {
+            if math::reaches(*invalidation_price, price.price, direction(&signal)) {
+                let pool = prices::fetch_pool_accounts(rpc, &signal.price_pool).await?;
+                out.push(instructions::invalidate_signal(authority, &address, &pool, &signal.publisher, &signal.token));
+                continue;
+            }
+        }
+        if math::is_new_best(signal.ath_price, price.price, direction(&signal)) {
+            out.push(instructions::update_ath(authority, &address, price.price, &price.quote_mint));
+        }
+
+        let reached: Vec<u8> = ladders.get(&address).map_or(Vec::new(), |ladder| {
+            (0..ladder.levels.len() as u8)
+                .filter(|level| {
+                    let take_profit = &ladder.levels[*level as usize];
+                    take_profit.settled_at == 0 && math::reaches(price.price, take_profit.price, direction(&signal))
+                })
+                .collect()
+        });
+        if !reached.is_empty() {
+            let pool = prices::fetch_pool_accounts(rpc, &signal.price_pool).await?;
+            out.extend(reached.into_iter().map(|level| {
+                instructions::settle_take_profit(authority, &address, &pool, level, &signal.publisher, &signal.token)
+            }));
+        }
+    }
+    Ok(out)
+}
+
+/// `portfolio_exit` for every model portfolio position whose signal has
+/// settled, then `portfolio_enter` for each signal it has yet to consider
+async fn portfolio_updates(rpc: &RpcClient) -> Result<Vec<Instruction>> {
+    let portfolio = match fetch::fetch_model_portfolio(rpc).await {
+        Ok(portfolio) => portfolio,
+        Err(ClientError::AccountNotFound(_)) => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+    let mut out = Vec::new();
+    for position in &portfolio.positions {
+        let signal: Signal = fetch::fetch(rpc, &position.signal).await?;
+        if signal.status != SignalStatus::Open {
+            out.push(instructions::portfolio_exit(&position.signal));
+        }
+    }
+    let total_signals = fetch::fetch_oracle_state(rpc).await?.total_signals;
+    out.extend((portfolio.next_id..total_signals).map(instructions::portfolio_enter));
+    Ok(out)
+}
+
+/// `burn_expired_pass` for every lapsed subscription whose pass still holds a balance
+async fn expired_passes(rpc: &RpcClient) -> Result<Vec<Instruction>> {
+    let now = rpc.get_block_time(rpc.get_slot().await?).await?;
+    let expired: Vec<Pubkey> = query::fetch_subscriptions(rpc)
+        .await?
+        .into_iter()
+        .filter(|(_, subscription)| subscription.expires_at <= now)
+        .map(|(_, subscription)| subscription.subscriber)
+        .collect();
+
+    let balances = fetch::fetch_pass_balances(rpc, &expired).await?;
+    Ok(expired
+        .iter()
+        .zip(balances)
+        .filter(|(_, balance)| *balance > 0)
+        .map(|(subscriber, _)| instructions::burn_expired_pass(subscriber))
+        .collect())
+}
+
+/// Log the next due DCA tranche of every open signal with a plan
+async fn report_due_tranches(rpc: &RpcClient) -> Result<()> {
+    let now = rpc.get_block_time(rpc.get_slot().await?).await?;
+    let open: HashMap<Pubkey, u64> = query::fetch_open_signals(rpc)
+        .await?
+        .into_iter()
+        .map(|(address, signal)| (address, signal.id))
+        .collect();
+    for (_, plan) in query::fetch_dca_plans(rpc).await? {
+        let Some(id) = open.get(&plan.signal) else { continue };
+        let next = math::next_dca_tranche(plan.start, plan.interval_secs, plan.tranches, plan.executed, now);
+        if let Some(tranche) = next {
+            eprintln!("signal #{id}: DCA tranche {} of {} due ({} bps)", tranche + 1, plan.tranches, plan.tranche_bps);
+        }
+    }
+    Ok(())
+}
+
+/// Send `batch`, re-pricing and escalating urgency after each failed attempt
+async fn send_with_retry(
+    rpc: &RpcClient,
+    keypair: &Keypair,
+    fee_config: &FeeConfig,
+    batch: &[Instruction],
+    retries: u32,
+) -> Result<Signature> {
+    let mut config = *fee_config;
+    let mut backoff = Duration::from_millis(500);
+    let mut attempt = 1;
+    loop {
+        match send(rpc, keypair, &config, batch.to_vec()).await {
+            Ok(signature) => return Ok(signature),
+            Err(err) if attempt < retries => {
+                eprintln!("attempt {attempt} failed ({err:#}); retrying");
+                config.urgency = escalate(config.urgency);
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+async fn send(rpc: &RpcClient, keypair: &Keypair, fee_config: &FeeConfig, instructions: Vec<Instruction>) -> Result<Signature> {
+    let instructions = fees::with_priority_fee(rpc, fee_config, instructions).await?;
+    let blockhash = rpc.get_latest_blockhash().await?;
+    let tx = Transaction::new_signed_with_payer(&instructions, Some(&keypair.pubkey()), &[keypair], blockhash);
+    rpc.send_and_confirm_transaction(&tx).await.context("transaction failed")
+}
+
+fn escalate(urgency: Urgency) -> Urgency {
+    match urgency {
+        Urgency::Low => Urgency::Normal,
+        Urgency::Normal => Urgency::High,
+        Urgency::High | Urgency::Urgent => Urgency::Urgent,
+    }
+}
+
+fn direction(signal: &Signal) -> Direction {
+    match signal.direction {
+        SignalDirection::Long => Direction::Long,
+        SignalDirection::Short => Direction::Short,
+    }
+}
+
+fn expand_home(path: &str) -> String {
+    match (path.strip_prefix("~/"), std::env::var("HOME")) {
+        (Some(rest), Ok(home)) => format!("{home}/{rest}"),
+        _ => path.to_string(),
+    }
+}
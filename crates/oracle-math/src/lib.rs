@@ -0,0 +1,401 @@
+//! ROI and outcome math for ORACLE signals.
+//!
+//! The on-chain program, the client SDK and the backtester all classify
+//! signals through these functions, so they cannot disagree on what counts
+//! as a win. `no_std` and allocation-free so it builds for SBF.
+
+#![no_std]
+
+/// Basis points in 100%
+pub const BPS: i64 = 10_000;
+/// Default ROI at or above which a closed signal counts as a win (50%)
+pub const WIN_THRESHOLD_BPS: u32 = 5_000;
+/// Seconds in a (365-day) year, the period ROI is annualized over
+pub const SECS_PER_YEAR: i64 = 365 * 24 * 60 * 60;
+/// Holds shorter than this are annualized as if they lasted this long, so a
+/// signal closed seconds after publish doesn't scale its ROI by millions
+pub const MIN_ANNUALIZED_HOLD_SECS: i64 = 60 * 60;
+
+/// Side a signal is called on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Long,
+    Short,
+}
+
+/// How a closed signal is scored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Win,
+    Loss,
+    Closed, // Closed flat or with a gain below the win threshold
+    BreakEven, // Closed within the break-even band, neither win nor loss
+}
+
+/// ROI from `entry` to `exit` in basis points; shorts profit from a falling
+/// price. `None` when there is no entry price to measure against.
+pub fn roi_bps(entry: u64, exit: u64, direction: Direction) -> Option<i64> {
+    if entry == 0 {
+        return None;
+    }
+    let change = match direction {
+        Direction::Long => exit as i128 - entry as i128,
+        Direction::Short => entry as i128 - exit as i128,
+    };
+    let roi = change * BPS as i128 / entry as i128;
+    Some(roi.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+}
+
+/// Costs a follower pays on top of the quoted prices, in basis points of
+/// the amount traded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Friction {
+    pub entry_slippage_bps: u16,
+    pub exit_slippage_bps: u16,
+    /// Charged on both the entry and the exit swap
+    pub swap_fee_bps: u16,
+}
+
+/// ROI from `entry` to `exit` net of `friction`: both fills land worse than
+/// quoted by their slippage plus the swap fee. `None` without an entry.
+pub fn net_roi_bps(entry: u64, exit: u64, direction: Direction, friction: Friction) -> Option<i64> {
+    let entry_cost = friction.entry_slippage_bps as u128 + friction.swap_fee_bps as u128;
+    let exit_cost = friction.exit_slippage_bps as u128 + friction.swap_fee_bps as u128;
+    let (entry, exit) = match direction {
+        Direction::Long => (slip(entry, entry_cost, true), slip(exit, exit_cost, false)),
+        Direction::Short => (slip(entry, entry_cost, false), slip(exit, exit_cost, true)),
+    };
+    roi_bps(entry, exit, direction)
+}
+
+/// `price` moved `cost_bps` up or down
+fn slip(price: u64, cost_bps: u128, up: bool) -> u64 {
+    let delta = price as u128 * cost_bps / BPS as u128;
+    let price = if up { price as u128 + delta } else { (price as u128).saturating_sub(delta) };
+    price.min(u64::MAX as u128) as u64
+}
+
+/// ROI of a basket from its members' `(weight_bps, roi_bps)`, weights
+/// summing to `BPS`
+pub fn weighted_roi_bps(members: impl IntoIterator<Item = (u16, i64)>) -> i64 {
+    let total: i128 = members
+        .into_iter()
+        .map(|(weight_bps, roi_bps)| weight_bps as i128 * roi_bps as i128)
+        .sum();
+    (total / BPS as i128).clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+/// `roi_bps` earned over `held_secs`, scaled linearly to a year
+pub fn annualized_roi_bps(roi_bps: i64, held_secs: i64) -> i64 {
+    let held = held_secs.max(MIN_ANNUALIZED_HOLD_SECS) as i128;
+    (roi_bps as i128 * SECS_PER_YEAR as i128 / held).clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+/// ROI of a pair trade on equal notional: the long leg's return minus the
+/// short leg's, each leg given as `(entry, exit)`
+pub fn spread_roi_bps(long: (u64, u64), short: (u64, u64)) -> i64 {
+    let long = roi_bps(long.0, long.1, Direction::Long).unwrap_or(0);
+    let short = roi_bps(short.0, short.1, Direction::Short).unwrap_or(0);
+    long.saturating_add(short)
+}
+
+/// Price averaged over `(weight_bps, price)` entries by weight; 0 when
+/// the entries weigh nothing
+pub fn average_price(entries: impl IntoIterator<Item = (u16, u64)>) -> u64 {
+    let (weighted, weight) = entries
+        .into_iter()
+        .fold((0u128, 0u128), |(weighted, weight), (weight_bps, price)| {
+            (weighted + weight_bps as u128 * price as u128, weight + weight_bps as u128)
+        });
+    if weight == 0 {
+        return 0;
+    }
+    (weighted / weight).min(u64::MAX as u128) as u64
+}
+
+/// ROI of `first` followed by `second`, compounded; a leg losing more than
+/// everything (a short past 2x) counts as a total loss
+pub fn compound_roi_bps(first: i64, second: i64) -> i64 {
+    let growth = |roi_bps: i64| (BPS as i128 + roi_bps as i128).max(0);
+    let combined = growth(first) * growth(second) / BPS as i128 - BPS as i128;
+    combined.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+/// Excess return of a signal's `roi_bps` over holding the benchmark long
+/// from `benchmark_entry` to `benchmark_exit`. `None` until both benchmark
+/// prices are known.
+pub fn alpha_bps(roi_bps: i64, benchmark_entry: u64, benchmark_exit: u64) -> Option<i64> {
+    if benchmark_exit == 0 {
+        return None;
+    }
+    let benchmark_roi = self::roi_bps(benchmark_entry, benchmark_exit, Direction::Long)?;
+    Some(roi_bps.saturating_sub(benchmark_roi))
+}
+
+/// Price at which a position entered at `entry` with `leverage_bps`
+/// (10_000 = 1x) loses all its margin, ignoring maintenance margin and
+/// fees. A 1x long never liquidates and gets 0.
+pub fn liquidation_price(entry: u64, leverage_bps: u32, direction: Direction) -> u64 {
+    if leverage_bps == 0 {
+        return 0;
+    }
+    let buffer = entry as u128 * BPS as u128 / leverage_bps as u128;
+    let price = match direction {
+        Direction::Long => (entry as u128).saturating_sub(buffer),
+        Direction::Short => entry as u128 + buffer,
+    };
+    price.min(u64::MAX as u128) as u64
+}
+
+/// Outcome of a signal closed at `roi_bps`, winning at `win_threshold_bps`
+/// or better. Closes within `break_even_bps` of zero either way are
+/// break-even; 0 disables the band.
+pub fn classify(roi_bps: i64, win_threshold_bps: u32, break_even_bps: u16) -> Outcome {
+    if roi_bps >= win_threshold_bps as i64 {
+        Outcome::Win
+    } else if break_even_bps > 0 && roi_bps.unsigned_abs() <= break_even_bps as u64 {
+        Outcome::BreakEven
+    } else if roi_bps < 0 {
+        Outcome::Loss
+    } else {
+        Outcome::Closed
+    }
+}
+
+/// Earliest tranche of a DCA plan that is due at `now` and not marked in
+/// `executed` (bit `i` set once tranche `i` ran). Tranche `i` comes due
+/// `i * interval_secs` after `start`.
+pub fn next_dca_tranche(start: i64, interval_secs: i64, tranches: u8, executed: u16, now: i64) -> Option<u8> {
+    if now < start || interval_secs <= 0 {
+        return None;
+    }
+    let due = ((now - start) / interval_secs).saturating_add(1).min(tranches.min(16) as i64) as u8;
+    (0..due).find(|tranche| executed & (1 << tranche) == 0)
+}
+
+/// Whether `price` beats `best` as the most favourable price seen
+/// (highest for longs, lowest for shorts)
+pub fn is_new_best(best: u64, price: u64, direction: Direction) -> bool {
+    match direction {
+        Direction::Long => price > best,
+        Direction::Short => price < best,
+    }
+}
+
+/// Whether `price` is at or past `target` in `direction`'s favour (at or
+/// above it for longs, at or below it for shorts)
+pub fn reaches(price: u64, target: u64, direction: Direction) -> bool {
+    price == target || is_new_best(target, price, direction)
+}
+
+/// Adverse move from the best price seen to `price`, in basis points of `best`
+pub fn drawdown_bps(best: u64, price: u64, direction: Direction) -> u64 {
+    if best == 0 {
+        return 0;
+    }
+    let adverse = match direction {
+        Direction::Long => best.saturating_sub(price),
+        Direction::Short => price.saturating_sub(best),
+    };
+    (adverse as u128 * BPS as u128 / best as u128).min(u64::MAX as u128) as u64
+}
+
+/// Wins as a share of decided (win or loss) signals, in basis points
+pub fn win_rate_bps(wins: u64, losses: u64) -> u64 {
+    let decided = wins as u128 + losses as u128;
+    if decided == 0 {
+        return 0;
+    }
+    (wins as u128 * BPS as u128 / decided) as u64
+}
+
+/// Current and longest runs of consecutive wins and losses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Streaks {
+    pub win: u32,
+    pub loss: u32,
+    pub max_win: u32,
+    pub max_loss: u32,
+}
+
+impl Streaks {
+    /// Streaks after one more settlement; a flat close breaks both runs
+    pub fn after(self, outcome: Outcome) -> Streaks {
+        let (win, loss) = match outcome {
+            Outcome::Win => (self.win.saturating_add(1), 0),
+            Outcome::Loss => (0, self.loss.saturating_add(1)),
+            Outcome::Closed | Outcome::BreakEven => (0, 0),
+        };
+        Streaks {
+            win,
+            loss,
+            max_win: self.max_win.max(win),
+            max_loss: self.max_loss.max(loss),
+        }
+    }
+}
+
+/// Value the performance index starts at
+pub const INDEX_BASE: u64 = 10_000;
+
+/// Equity curve of a follower putting everything into each settled signal
+/// in turn: starts at `INDEX_BASE` and is multiplied by every return
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PerformanceIndex(pub u64);
+
+impl Default for PerformanceIndex {
+    fn default() -> Self {
+        PerformanceIndex(INDEX_BASE)
+    }
+}
+
+impl PerformanceIndex {
+    /// Index after one more settlement at `roi_bps`; losing everything (a
+    /// short past 2x) takes it to 0 for good
+    pub fn after(self, roi_bps: i64) -> PerformanceIndex {
+        let growth = (BPS as i128 + roi_bps as i128).max(0) as u128;
+        PerformanceIndex((self.0 as u128 * growth / BPS as u128).min(u64::MAX as u128) as u64)
+    }
+}
+
+/// Fixed-point scale of `RoiMoments::mean`
+pub const MOMENT_SCALE: i64 = 10_000;
+/// ROI samples are clamped to this magnitude (a 1000x) before they enter
+/// the running moments, so one absurd price can't overflow them
+pub const MAX_MOMENT_ROI_BPS: i64 = 1_000 * BPS;
+
+/// Running mean and variance of settled ROI (Welford's algorithm)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RoiMoments {
+    pub count: u64,
+    /// Mean ROI in basis points, scaled by `MOMENT_SCALE`
+    pub mean: i64,
+    /// Sum of squared deviations from the mean, in bps²
+    pub m2: u128,
+}
+
+impl RoiMoments {
+    /// Moments after one more settlement at `roi_bps`
+    pub fn after(self, roi_bps: i64) -> RoiMoments {
+        let count = self.count.saturating_add(1);
+        let x = roi_bps.clamp(-MAX_MOMENT_ROI_BPS, MAX_MOMENT_ROI_BPS) as i128 * MOMENT_SCALE as i128;
+        let delta = x - self.mean as i128;
+        let mean = self.mean as i128 + delta / count as i128;
+        let scale = MOMENT_SCALE as i128 * MOMENT_SCALE as i128;
+        let m2 = self.m2.saturating_add((delta * (x - mean)).max(0) as u128 / scale as u128);
+        RoiMoments {
+            count,
+            mean: mean as i64,
+            m2,
+        }
+    }
+
+    /// Mean ROI in basis points
+    pub fn mean_bps(&self) -> i64 {
+        self.mean / MOMENT_SCALE
+    }
+
+    /// Sample standard deviation of ROI in basis points; `None` below two
+    /// samples
+    pub fn stddev_bps(&self) -> Option<u64> {
+        if self.count < 2 {
+            return None;
+        }
+        Some(isqrt(self.m2 / (self.count - 1) as u128) as u64)
+    }
+
+    /// Mean over standard deviation (a Sharpe ratio with no risk-free
+    /// rate), in basis points; `None` until the spread is measurable
+    pub fn sharpe_bps(&self) -> Option<i64> {
+        let stddev = self.stddev_bps().filter(|stddev| *stddev > 0)?;
+        Some((self.mean as i128 / stddev as i128) as i64)
+    }
+}
+
+/// Integer square root, rounded down
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = x / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+/// Number of buckets in the on-chain ROI histogram
+pub const ROI_BUCKET_COUNT: usize = 16;
+/// Lowest ROI counted in each histogram bucket; each bucket runs up to the
+/// next floor, the first takes everything below -75% and the last
+/// everything from +1000% up
+pub const ROI_BUCKET_FLOORS_BPS: [i64; ROI_BUCKET_COUNT] = [
+    -BPS, -7_500, -5_000, -2_500, 0, 1_000, 2_500, 5_000, 7_500, 10_000, 15_000, 20_000, 30_000, 50_000, 75_000,
+    100_000,
+];
+
+/// Histogram bucket `roi_bps` falls in
+pub fn roi_bucket(roi_bps: i64) -> usize {
+    ROI_BUCKET_FLOORS_BPS[1..].iter().take_while(|floor| roi_bps >= **floor).count()
+}
+
+/// Bucket holding the `percentile_bps` percentile (5_000 = median) of a
+/// histogram; `None` when it is empty
+pub fn percentile_bucket(counts: &[u32; ROI_BUCKET_COUNT], percentile_bps: u64) -> Option<usize> {
+    let total: u64 = counts.iter().map(|count| *count as u64).sum();
+    if total == 0 {
+        return None;
+    }
+    // 1-based rank of the sample at the percentile
+    let rank = (total * percentile_bps.min(BPS as u64)).div_ceil(BPS as u64).max(1);
+    let mut seen = 0;
+    counts.iter().position(|count| {
+        seen += *count as u64;
+        seen >= rank
+    })
+}
+
+/// `score` decayed by `age_secs` against `half_life_secs`, rounded to the
+/// nearest point. A non-positive half-life disables decay.
+///
+/// Whole half-lives halve the score exactly; the remainder uses a
+/// quadratic fit of 2^-x on [0, 1] (within 1% of the true curve), which
+/// keeps it integer-only for SBF.
+pub fn effective_score(score: u8, age_secs: i64, half_life_secs: i64) -> u8 {
+    if half_life_secs <= 0 || age_secs <= 0 {
+        return score;
+    }
+    let halvings = age_secs / half_life_secs;
+    if halvings >= 8 {
+        return 0;
+    }
+    // Fraction of the current half-life elapsed, in bps
+    let x = ((age_secs % half_life_secs) as i128 * BPS as i128 / half_life_secs as i128) as i64;
+    let factor_bps = BPS - 6_534 * x / BPS + 1_534 * x * x / (BPS * BPS);
+    let decayed = (score as i64 * factor_bps) >> halvings;
+    ((decayed + BPS / 2) / BPS) as u8
+}
+
+/// Largest suggested position, as a fraction of capital in bps, per risk
+/// level (safest first)
+pub const POSITION_SIZE_CAP_BPS: [u64; 4] = [2_500, 1_500, 1_000, 500];
+/// Pseudo-observations the score counts as when estimating the win
+/// probability, so a short track record doesn't dominate
+pub const SCORE_PRIOR_WEIGHT: u64 = 10;
+
+/// Suggested position size as a fraction of capital, in bps: half Kelly
+/// at even odds, `2p - 1` halved, capped by the risk level. The win
+/// probability `p` blends the oracle's decided record with the score as
+/// a prior worth `SCORE_PRIOR_WEIGHT` signals.
+pub fn position_size_bps(wins: u64, losses: u64, score: u8, risk_level: u8) -> u64 {
+    let Some(cap) = POSITION_SIZE_CAP_BPS.get(risk_level as usize) else {
+        return 0;
+    };
+    let decided = wins as u128 + losses as u128 + SCORE_PRIOR_WEIGHT as u128;
+    let observed = wins as u128 * BPS as u128 + score.min(100) as u128 * 100 * SCORE_PRIOR_WEIGHT as u128;
+    let p_bps = (observed / decided) as u64;
+    let kelly_bps = (2 * p_bps).saturating_sub(BPS as u64);
+    (kelly_bps / 2).min(*cap)
+}
@@ -0,0 +1,122 @@
+//! Table tests for the shared ROI and outcome math.
+
+use oracle_math::{
+    classify, effective_score, percentile_bucket, position_size_bps, roi_bps, Direction, Outcome, RoiMoments,
+    MAX_MOMENT_ROI_BPS, ROI_BUCKET_COUNT,
+};
+
+#[test]
+fn roi_is_measured_from_entry_in_the_signal_direction() {
+    let cases = [
+        (1_000, 2_000, Direction::Long, Some(10_000)),
+        (1_000, 500, Direction::Long, Some(-5_000)),
+        (1_000, 500, Direction::Short, Some(5_000)),
+        (1_000, 2_000, Direction::Short, Some(-10_000)),
+        // A short can lose more than everything
+        (1_000, 3_000, Direction::Short, Some(-20_000)),
+        (1_000, 1_000, Direction::Short, Some(0)),
+        (0, 1_000, Direction::Long, None),
+        (1, u64::MAX, Direction::Long, Some(i64::MAX)),
+    ];
+    for (entry, exit, direction, expected) in cases {
+        assert_eq!(roi_bps(entry, exit, direction), expected, "{entry} -> {exit} {direction:?}");
+    }
+}
+
+#[test]
+fn classify_orders_win_break_even_loss_and_closed() {
+    let cases = [
+        (5_000, 0, Outcome::Win),
+        (4_999, 0, Outcome::Closed),
+        (0, 0, Outcome::Closed),
+        (-1, 0, Outcome::Loss),
+        (50, 100, Outcome::BreakEven),
+        (-100, 100, Outcome::BreakEven),
+        (-101, 100, Outcome::Loss),
+        (101, 100, Outcome::Closed),
+        // The win threshold takes precedence over a wide band
+        (5_000, 10_000, Outcome::Win),
+    ];
+    for (roi, break_even_bps, expected) in cases {
+        assert_eq!(classify(roi, 5_000, break_even_bps), expected, "{roi} bps, band {break_even_bps}");
+    }
+}
+
+#[test]
+fn effective_score_halves_each_half_life() {
+    let cases = [
+        (80, 0, 100, 80),
+        (80, 100, 0, 80),
+        (80, -5, 100, 80),
+        (80, 100, 100, 40),
+        (80, 200, 100, 20),
+        (80, 800, 100, 0),
+        // Mid half-life lands within a point of 80 / sqrt(2) and 100 / 2^1.5
+        (80, 50, 100, 57),
+        (100, 150, 100, 36),
+    ];
+    for (score, age, half_life, expected) in cases {
+        assert_eq!(effective_score(score, age, half_life), expected, "{score} after {age}s of {half_life}s");
+    }
+}
+
+#[test]
+fn percentile_bucket_finds_the_ranked_sample() {
+    let mut counts = [0; ROI_BUCKET_COUNT];
+    assert_eq!(percentile_bucket(&counts, 5_000), None);
+
+    counts[2] = 3;
+    counts[5] = 1;
+    let cases = [
+        (0, Some(2)),
+        (5_000, Some(2)),
+        (7_500, Some(2)),
+        (7_501, Some(5)),
+        (10_000, Some(5)),
+        (20_000, Some(5)),
+    ];
+    for (percentile, expected) in cases {
+        assert_eq!(percentile_bucket(&counts, percentile), expected, "p{percentile}");
+    }
+}
+
+#[test]
+fn roi_moments_track_mean_and_spread() {
+    let one = RoiMoments::default().after(100);
+    assert_eq!((one.count, one.mean_bps(), one.m2), (1, 100, 0));
+    assert_eq!(one.stddev_bps(), None);
+    assert_eq!(one.sharpe_bps(), None);
+
+    // Sample standard deviation of {100, 300} is 141.4
+    let two = one.after(300);
+    assert_eq!((two.count, two.mean_bps(), two.m2), (2, 200, 20_000));
+    assert_eq!(two.stddev_bps(), Some(141));
+    assert_eq!(two.sharpe_bps(), Some(14_184));
+
+    // Identical samples have no spread to divide by
+    assert_eq!(one.after(100).sharpe_bps(), None);
+
+    let clamped = RoiMoments::default().after(i64::MAX);
+    assert_eq!(clamped.mean_bps(), MAX_MOMENT_ROI_BPS);
+}
+
+#[test]
+fn position_size_is_half_kelly_capped_by_risk_level() {
+    let cases = [
+        // (wins, losses, score, risk level, size)
+        (0, 0, 50, 0, 0),
+        (0, 0, 100, 0, 2_500),
+        (0, 0, 100, 3, 500),
+        (10, 0, 80, 1, 1_500),
+        (5, 5, 60, 2, 500),
+        (0, 20, 90, 0, 0),
+        (10, 0, 80, 4, 0),
+    ];
+    for (wins, losses, score, risk_level, expected) in cases {
+        assert_eq!(
+            position_size_bps(wins, losses, score, risk_level),
+            expected,
+            "{wins}W {losses}L score {score} risk {risk_level}"
+        );
+    }
+}
@@ -0,0 +1,137 @@
+//! `oracle-migrate`: bring program accounts up to the current layout.
+//!
+//! Finds every account still in a legacy layout (today: signals created
+//! before `direction` and the price-check fields existed) and sends the
+//! matching `migrate_*` instructions in rate-limited batches, reporting
+//! progress as it goes. The oracle authority signs and pays the extra
+//! rent.
+//!
+//! Runs are resumable: migrated accounts drop out of the enumeration, so
+//! re-running picks up wherever an interrupted run stopped. When a batch
+//! fails, its accounts are retried one by one, and accounts an overlapping
+//! run already migrated are counted as done.
+
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use clap::Parser;
+use oracle::OracleError;
+use oracle_client::submit::{SubmitConfig, Submitter};
+use oracle_client::{instructions, query, signer};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+
+#[derive(Parser)]
+#[command(name = "oracle-migrate", version, about = "Migrate ORACLE Alpha accounts to the current layout")]
+struct Cli {
+    /// RPC endpoint
+    #[arg(long, short = 'u', env = "ORACLE_RPC_URL", default_value = "https://api.mainnet-beta.solana.com")]
+    url: String,
+
+    /// Oracle authority: keypair file, `usb://ledger` or remote signer URL
+    #[arg(long, short = 'k', env = "ORACLE_KEYPAIR", default_value = "~/.config/solana/id.json")]
+    keypair: String,
+
+    /// Derivation path for Ledger keys (e.g. `0/0`)
+    #[arg(long)]
+    derivation_path: Option<String>,
+
+    /// Accounts migrated per transaction
+    #[arg(long, default_value_t = 8)]
+    batch: usize,
+
+    /// Milliseconds between transactions
+    #[arg(long, default_value_t = 500)]
+    interval_ms: u64,
+
+    /// Stop after this many accounts
+    #[arg(long)]
+    limit: Option<usize>,
+
+    /// List the accounts that need migrating without sending anything
+    #[arg(long)]
+    dry_run: bool,
+}
+
+#[derive(Default)]
+struct Progress {
+    migrated: usize,
+    skipped: usize,
+    failed: Vec<u64>,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let rpc = RpcClient::new_with_commitment(cli.url.clone(), CommitmentConfig::confirmed());
+
+    let mut pending = query::fetch_legacy_signals(&rpc).await?;
+    eprintln!("{} signal(s) in the legacy layout", pending.len());
+    if let Some(limit) = cli.limit {
+        pending.truncate(limit);
+    }
+    if cli.dry_run {
+        for (id, address) in &pending {
+            println!("signal #{id} {address}");
+        }
+        return Ok(());
+    }
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    let signer = signer::load_signer(&cli.keypair, cli.derivation_path.as_deref())?;
+    let authority = signer.pubkey();
+    let submitter = Submitter::new(&rpc, SubmitConfig::default());
+    let send = |key: String, signals: &[(u64, Pubkey)]| {
+        let instructions: Vec<_> = signals
+            .iter()
+            .map(|(_, address)| instructions::migrate_signal(&authority, address))
+            .collect();
+        let submitter = &submitter;
+        let signer = signer.as_ref();
+        async move { submitter.submit(&key, &authority, &[signer], &instructions).await }
+    };
+
+    let mut progress = Progress::default();
+    let mut ticker = tokio::time::interval(Duration::from_millis(cli.interval_ms.max(1)));
+    for batch in pending.chunks(cli.batch.max(1)) {
+        ticker.tick().await;
+        let (first, last) = (batch[0].0, batch[batch.len() - 1].0);
+        match send(format!("migrate-signal:{first}-{last}"), batch).await {
+            Ok(submitted) => {
+                progress.migrated += batch.len();
+                eprintln!("signals #{first}..=#{last}: {}", submitted.signature);
+            }
+            Err(err) => {
+                eprintln!("batch #{first}..=#{last} failed ({err}); retrying one by one");
+                for signal in batch {
+                    ticker.tick().await;
+                    match send(format!("migrate-signal:{}", signal.0), std::slice::from_ref(signal)).await {
+                        Ok(_) => progress.migrated += 1,
+                        Err(err) if err.is_oracle_error(OracleError::AlreadyMigrated) => progress.skipped += 1,
+                        Err(err) => {
+                            eprintln!("signal #{}: {err}", signal.0);
+                            progress.failed.push(signal.0);
+                        }
+                    }
+                }
+            }
+        }
+        let done = progress.migrated + progress.skipped + progress.failed.len();
+        eprintln!(
+            "{done}/{} done: {} migrated, {} already migrated, {} failed",
+            pending.len(),
+            progress.migrated,
+            progress.skipped,
+            progress.failed.len()
+        );
+    }
+
+    if !progress.failed.is_empty() {
+        bail!("{} signal(s) failed to migrate: {:?}", progress.failed.len(), progress.failed);
+    }
+    Ok(())
+}
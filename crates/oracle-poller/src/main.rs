@@ -0,0 +1,230 @@
+//! `oracle-poller`: price poller feeding ATH updates.
+//!
+//! Each tick it loads the open signals, prices each distinct token once
+//! (from the signal's verified pool, else from the aggregator API), and
+//! submits `update_ath` for every signal the price beats, batched into
+//! transactions. Updates already sent are remembered so a price that the
+//! RPC node hasn't caught up with yet isn't submitted twice. With
+//! `--database-url` every observed price is also written to the indexer's
+//! `price_checkpoints` table for the backtester.
+
+mod sources;
+
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Context, Result};
+use clap::Parser;
+use oracle_client::fees::{self, FeeConfig};
+use oracle_client::math::{self, Direction};
+use oracle_client::types::{Signal, SignalDirection};
+use oracle_client::prices::USDC_MINT;
+use oracle_client::{instructions, query};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signature};
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::Transaction;
+use tokio_postgres::NoTls;
+
+use sources::PriceKey;
+
+#[derive(Parser)]
+#[command(name = "oracle-poller", version, about = "Poll prices and record ATHs for open ORACLE Alpha signals")]
+struct Cli {
+    /// RPC endpoint
+    #[arg(long, short = 'u', env = "ORACLE_RPC_URL", default_value = "https://api.mainnet-beta.solana.com")]
+    url: String,
+
+    /// Oracle authority keypair file
+    #[arg(long, short = 'k', env = "ORACLE_KEYPAIR", default_value = "~/.config/solana/id.json")]
+    keypair: String,
+
+    /// Seconds between ticks
+    #[arg(long, default_value_t = 15)]
+    interval: u64,
+
+    /// Price API for signals without a verified pool
+    #[arg(long, env = "ORACLE_PRICE_API", default_value = "https://lite-api.jup.ag/price/v3")]
+    price_api: String,
+
+    /// Price only from verified pools
+    #[arg(long)]
+    no_aggregator: bool,
+
+    /// `update_ath` instructions per transaction
+    #[arg(long, default_value_t = 6)]
+    batch: usize,
+
+    /// Record observed prices in this database's `price_checkpoints`
+    #[arg(long, env = "DATABASE_URL")]
+    database_url: Option<String>,
+}
+
+struct Poller {
+    rpc: RpcClient,
+    http: reqwest::Client,
+    keypair: Keypair,
+    db: Option<tokio_postgres::Client>,
+    /// Last ATH submitted per signal, so stale reads don't resend it
+    sent: HashMap<Pubkey, u64>,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let path = expand_home(&cli.keypair);
+    let keypair = read_keypair_file(&path).map_err(|err| anyhow!("reading keypair {path}: {err}"))?;
+    let db = match &cli.database_url {
+        Some(url) => Some(connect(url).await?),
+        None => None,
+    };
+    let mut poller = Poller {
+        rpc: RpcClient::new_with_commitment(cli.url.clone(), CommitmentConfig::confirmed()),
+        http: reqwest::Client::builder().timeout(Duration::from_secs(10)).build()?,
+        keypair,
+        db,
+        sent: HashMap::new(),
+    };
+
+    eprintln!("oracle-poller running as {} against {}", poller.keypair.pubkey(), cli.url);
+    let mut ticker = tokio::time::interval(Duration::from_secs(cli.interval.max(1)));
+    loop {
+        ticker.tick().await;
+        if let Err(err) = poller.tick(&cli).await {
+            eprintln!("tick failed: {err:#}");
+        }
+    }
+}
+
+impl Poller {
+    async fn tick(&mut self, cli: &Cli) -> Result<()> {
+        let signals = query::fetch_open_signals(&self.rpc).await?;
+        let open: HashSet<Pubkey> = signals.iter().map(|(address, _)| *address).collect();
+        self.sent.retain(|address, _| open.contains(address));
+
+        let aggregator = (!cli.no_aggregator).then_some(cli.price_api.as_str());
+        let mut keys: Vec<PriceKey> = signals
+            .iter()
+            .filter_map(|(_, signal)| price_key(signal, aggregator.is_some()))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        keys.sort_by_key(|key| *key.token());
+        let prices = sources::fetch_prices(&self.rpc, &self.http, aggregator, &keys).await;
+
+        if let Some(db) = &self.db {
+            if let Err(err) = record_checkpoints(db, &prices).await {
+                eprintln!("recording checkpoints failed: {err:#}");
+            }
+        }
+
+        let mut updates = Vec::new();
+        for (address, signal) in &signals {
+            let Some(key) = price_key(signal, aggregator.is_some()) else {
+                continue;
+            };
+            let (Some(price), Some(quote_mint)) = (prices.get(&key), quote_mint(signal, &key)) else {
+                continue;
+            };
+            let best = self.sent.get(address).map_or(signal.ath_price, |sent| {
+                if math::is_new_best(signal.ath_price, *sent, direction(signal)) {
+                    *sent
+                } else {
+                    signal.ath_price
+                }
+            });
+            if math::is_new_best(best, *price, direction(signal)) {
+                updates.push((*address, *price, quote_mint));
+            }
+        }
+
+        for batch in updates.chunks(cli.batch.max(1)) {
+            let ixs = batch
+                .iter()
+                .map(|(address, price, quote_mint)| {
+                    instructions::update_ath(&self.keypair.pubkey(), address, *price, quote_mint)
+                })
+                .collect();
+            match self.send(ixs).await {
+                Ok(signature) => {
+                    eprintln!("updated {} ATH(s): {signature}", batch.len());
+                    self.sent.extend(batch.iter().map(|(address, price, _)| (*address, *price)));
+                }
+                Err(err) => eprintln!("batch dropped until next tick: {err:#}"),
+            }
+        }
+        Ok(())
+    }
+
+    async fn send(&self, instructions: Vec<Instruction>) -> Result<Signature> {
+        let instructions = fees::with_priority_fee(&self.rpc, &FeeConfig::default(), instructions).await?;
+        let blockhash = self.rpc.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(&instructions, Some(&self.keypair.pubkey()), &[&self.keypair], blockhash);
+        self.rpc.send_and_confirm_transaction(&tx).await.context("transaction failed")
+    }
+}
+
+fn price_key(signal: &Signal, aggregator: bool) -> Option<PriceKey> {
+    if signal.price_pool != Pubkey::default() {
+        Some(PriceKey::Pool {
+            pool: signal.price_pool,
+            token: signal.token,
+        })
+    } else {
+        aggregator.then_some(PriceKey::Aggregator(signal.token))
+    }
+}
+
+/// Mint a `key` price is quoted in, if the signal accepts it: pool prices
+/// are in the pool's other mint, checked against the signal's quote when
+/// it was verified, and aggregator prices are in USD
+fn quote_mint(signal: &Signal, key: &PriceKey) -> Option<Pubkey> {
+    match key {
+        PriceKey::Pool { .. } => Some(signal.quote_mint),
+        PriceKey::Aggregator(_) => {
+            [USDC_MINT, Pubkey::default()].contains(&signal.quote_mint).then_some(USDC_MINT)
+        }
+    }
+}
+
+async fn record_checkpoints(db: &tokio_postgres::Client, prices: &HashMap<PriceKey, u64>) -> Result<()> {
+    let ts = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+    for (key, price) in prices {
+        db.execute(
+            "INSERT INTO price_checkpoints (token, ts, price, source) VALUES ($1, $2, $3, $4)
+             ON CONFLICT DO NOTHING",
+            &[&key.token().to_string(), &ts, &(*price as i64), &key.source()],
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+async fn connect(url: &str) -> Result<tokio_postgres::Client> {
+    let (client, connection) = tokio_postgres::connect(url, NoTls)
+        .await
+        .context("connecting to Postgres")?;
+    tokio::spawn(async move {
+        if let Err(err) = connection.await {
+            eprintln!("postgres connection closed: {err}");
+        }
+    });
+    Ok(client)
+}
+
+fn direction(signal: &Signal) -> Direction {
+    match signal.direction {
+        SignalDirection::Long => Direction::Long,
+        SignalDirection::Short => Direction::Short,
+    }
+}
+
+fn expand_home(path: &str) -> String {
+    match (path.strip_prefix("~/"), std::env::var("HOME")) {
+        (Some(rest), Ok(home)) => format!("{home}/{rest}"),
+        _ => path.to_string(),
+    }
+}
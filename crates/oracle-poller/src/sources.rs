@@ -0,0 +1,97 @@
+//! Where prices come from.
+//!
+//! Signals with a verified pool are priced from that pool, exactly as the
+//! program would price them. The rest fall back to an aggregator price
+//! API (Jupiter's, by default), requested in batches.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use oracle::PRICE_DECIMALS;
+use oracle_client::prices;
+use serde_json::Value;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+
+/// Mints per aggregator request
+const AGGREGATOR_BATCH: usize = 50;
+
+/// What a price is keyed on; signals sharing a key share one lookup
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PriceKey {
+    Pool { pool: Pubkey, token: Pubkey },
+    Aggregator(Pubkey),
+}
+
+impl PriceKey {
+    pub fn token(&self) -> &Pubkey {
+        match self {
+            PriceKey::Pool { token, .. } | PriceKey::Aggregator(token) => token,
+        }
+    }
+
+    /// Recorded in `price_checkpoints.source`
+    pub fn source(&self) -> &'static str {
+        match self {
+            PriceKey::Pool { .. } => "pool",
+            PriceKey::Aggregator(_) => "aggregator",
+        }
+    }
+}
+
+/// Price every key, scaled by 10^PRICE_DECIMALS. Keys that couldn't be
+/// priced are logged and left out.
+pub async fn fetch_prices(
+    rpc: &RpcClient,
+    http: &reqwest::Client,
+    aggregator_url: Option<&str>,
+    keys: &[PriceKey],
+) -> HashMap<PriceKey, u64> {
+    let mut out = HashMap::new();
+    let mut aggregated = Vec::new();
+    for key in keys {
+        match key {
+            PriceKey::Pool { pool, token } => match prices::fetch_pool_price(rpc, pool, token).await {
+                Ok(price) => {
+                    out.insert(*key, price.price);
+                }
+                Err(err) => eprintln!("pool {pool}: pricing {token} failed: {err}"),
+            },
+            PriceKey::Aggregator(token) => aggregated.push(*token),
+        }
+    }
+
+    let Some(url) = aggregator_url else {
+        return out;
+    };
+    for chunk in aggregated.chunks(AGGREGATOR_BATCH) {
+        match fetch_aggregator(http, url, chunk).await {
+            Ok(prices) => out.extend(prices.into_iter().map(|(token, price)| (PriceKey::Aggregator(token), price))),
+            Err(err) => eprintln!("aggregator request failed: {err:#}"),
+        }
+    }
+    out
+}
+
+/// `GET {url}?ids=a,b,...`, answered with `{"<mint>": {"usdPrice": 1.23}}`
+async fn fetch_aggregator(http: &reqwest::Client, url: &str, tokens: &[Pubkey]) -> Result<Vec<(Pubkey, u64)>> {
+    let ids = tokens.iter().map(Pubkey::to_string).collect::<Vec<_>>().join(",");
+    let body: Value = http
+        .get(url)
+        .query(&[("ids", ids)])
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await
+        .context("decoding aggregator response")?;
+
+    let scale = 10f64.powi(i32::from(PRICE_DECIMALS));
+    Ok(tokens
+        .iter()
+        .filter_map(|token| {
+            let price = body.get(token.to_string())?.get("usdPrice")?.as_f64()?;
+            (price.is_finite() && price > 0.0).then(|| (*token, (price * scale).round() as u64))
+        })
+        .collect())
+}
@@ -0,0 +1,67 @@
+//! JSON frames sent to clients.
+//!
+//! Every frame carries `"v"`, the schema version, and `"type"`. Fields are
+//! only ever added within a version; renaming or removing one bumps
+//! `SCHEMA_VERSION`.
+//!
+//! - `hello`: sent once on connect
+//! - `signal_published`: `signature`, `slot`, `signal { id, token, score,
+//!   timestamp, publisher_domain, reasoning_hash }` (`reasoning_hash` is hex,
+//!   or null when the signal was published without a proof)
+//! - `signal_closed`: `signature`, `slot`, `signal { id, status, roi_bps }`
+//! - `lagged`: `missed` frames were dropped because the client read too slowly
+
+use oracle_client::stream::{SignalEvent, StreamItem};
+use serde_json::{json, Value};
+
+pub const SCHEMA_VERSION: u32 = 1;
+
+pub fn hello() -> Value {
+    json!({ "v": SCHEMA_VERSION, "type": "hello" })
+}
+
+pub fn lagged(missed: u64) -> Value {
+    json!({ "v": SCHEMA_VERSION, "type": "lagged", "missed": missed })
+}
+
+pub fn event(item: &StreamItem) -> Value {
+    let (kind, signal) = match &item.event {
+        SignalEvent::Published(e) => (
+            "signal_published",
+            json!({
+                "id": e.id,
+                "token": e.token.to_string(),
+                "score": e.score,
+                "timestamp": e.timestamp,
+                "publisher_domain": e.publisher_domain,
+                "reasoning_hash": null,
+            }),
+        ),
+        SignalEvent::PublishedWithProof(e) => (
+            "signal_published",
+            json!({
+                "id": e.id,
+                "token": e.token.to_string(),
+                "score": e.score,
+                "timestamp": e.timestamp,
+                "publisher_domain": e.publisher_domain,
+                "reasoning_hash": hex::encode(e.reasoning_hash),
+            }),
+        ),
+        SignalEvent::Closed(e) => (
+            "signal_closed",
+            json!({
+                "id": e.id,
+                "status": format!("{:?}", e.status),
+                "roi_bps": e.roi_bps,
+            }),
+        ),
+    };
+    json!({
+        "v": SCHEMA_VERSION,
+        "type": kind,
+        "signature": item.signature,
+        "slot": item.slot,
+        "signal": signal,
+    })
+}
@@ -0,0 +1,88 @@
+//! `oracle-push`: broadcast signal events to WebSocket clients as they land.
+//!
+//! One `SignalStream` feeds a broadcast channel; each client connection on
+//! `/ws` receives every event from the moment it connects. See `frame` for
+//! the wire format.
+
+mod frame;
+
+use std::net::SocketAddr;
+
+use anyhow::Result;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::State;
+use axum::response::Response;
+use axum::routing::get;
+use axum::Router;
+use clap::Parser;
+use oracle_client::stream::SignalStream;
+use serde_json::Value;
+use tokio::sync::broadcast::{self, error::RecvError};
+
+#[derive(Parser)]
+#[command(name = "oracle-push", version, about = "Push ORACLE Alpha signal events over WebSocket")]
+struct Cli {
+    /// RPC websocket endpoint to subscribe to
+    #[arg(long, env = "ORACLE_WS_URL", default_value = "wss://api.mainnet-beta.solana.com")]
+    ws_url: String,
+
+    /// Address to listen on
+    #[arg(long, env = "ORACLE_PUSH_LISTEN", default_value = "0.0.0.0:8081")]
+    listen: SocketAddr,
+
+    /// Frames buffered per client before it is reported as lagged
+    #[arg(long, default_value_t = 1024)]
+    buffer: usize,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    let (frames, _) = broadcast::channel::<Value>(cli.buffer.max(1));
+    let mut events = SignalStream::new(cli.ws_url).spawn(cli.buffer.max(1));
+    let publisher = frames.clone();
+    tokio::spawn(async move {
+        while let Some(item) = events.recv().await {
+            // No connected clients is not an error
+            let _ = publisher.send(frame::event(&item));
+        }
+    });
+
+    let app = Router::new().route("/ws", get(upgrade)).with_state(frames);
+    let listener = tokio::net::TcpListener::bind(cli.listen).await?;
+    eprintln!("oracle-push listening on {}", cli.listen);
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+async fn upgrade(ws: WebSocketUpgrade, State(frames): State<broadcast::Sender<Value>>) -> Response {
+    ws.on_upgrade(move |socket| serve(socket, frames.subscribe()))
+}
+
+async fn serve(mut socket: WebSocket, mut frames: broadcast::Receiver<Value>) {
+    if send(&mut socket, &frame::hello()).await.is_err() {
+        return;
+    }
+    loop {
+        let frame = tokio::select! {
+            received = frames.recv() => match received {
+                Ok(frame) => frame,
+                Err(RecvError::Lagged(missed)) => frame::lagged(missed),
+                Err(RecvError::Closed) => return,
+            },
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return,
+                // The feed is one-way; anything else from the client is ignored
+                Some(Ok(_)) => continue,
+            },
+        };
+        if send(&mut socket, &frame).await.is_err() {
+            return;
+        }
+    }
+}
+
+async fn send(socket: &mut WebSocket, frame: &Value) -> Result<(), axum::Error> {
+    socket.send(Message::Text(frame.to_string().into())).await
+}
@@ -0,0 +1,97 @@
+//! Comparing replayed state with what is on-chain.
+
+use std::collections::{BTreeMap, HashSet};
+
+use oracle_types::{OracleState, Signal, SignalStatus};
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::replay::{Replay, Tally};
+
+/// One value that differs between the replay and the chain
+#[derive(Debug, Serialize)]
+pub struct Mismatch {
+    pub scope: String,
+    pub field: &'static str,
+    pub replayed: String,
+    pub on_chain: String,
+}
+
+pub fn diff(replay: &Replay, state: &OracleState, accounts: &[(Pubkey, Signal)]) -> Vec<Mismatch> {
+    let mut out = Vec::new();
+    let mut check = |scope: &str, field: &'static str, replayed: String, on_chain: String| {
+        if replayed != on_chain {
+            out.push(Mismatch {
+                scope: scope.to_string(),
+                field,
+                replayed,
+                on_chain,
+            });
+        }
+    };
+
+    check("oracle", "total_signals", replay.total_signals.to_string(), state.total_signals.to_string());
+    check("oracle", "total_wins", replay.total_wins.to_string(), state.total_wins.to_string());
+    check("oracle", "total_losses", replay.total_losses.to_string(), state.total_losses.to_string());
+    check(
+        "oracle",
+        "compressed_signals",
+        replay.compressed_signals.to_string(),
+        state.compressed_signals.to_string(),
+    );
+    let streaks = &state.streaks;
+    let on_chain = (streaks.win, streaks.loss, streaks.max_win, streaks.max_loss);
+    let replayed = (replay.streaks.win, replay.streaks.loss, replay.streaks.max_win, replay.streaks.max_loss);
+    check("oracle", "streaks", format!("{replayed:?}"), format!("{on_chain:?}"));
+    let moments = &state.roi_moments;
+    let on_chain = (moments.count, moments.mean, moments.m2);
+    let replayed = (replay.roi_moments.count, replay.roi_moments.mean, replay.roi_moments.m2);
+    check("oracle", "roi_moments", format!("{replayed:?}"), format!("{on_chain:?}"));
+    check(
+        "oracle",
+        "roi_histogram",
+        format!("{:?}", replay.roi_histogram),
+        format!("{:?}", state.roi_histogram),
+    );
+    check(
+        "oracle",
+        "performance_index",
+        replay.performance_index.0.to_string(),
+        state.performance_index.to_string(),
+    );
+
+    let mut seen = HashSet::new();
+    let mut by_token = BTreeMap::<Pubkey, Tally>::new();
+    for (_, signal) in accounts {
+        seen.insert(signal.id);
+        by_token.entry(signal.token).or_default().add(signal.status);
+        let scope = format!("signal #{}", signal.id);
+        let Some(replayed) = replay.signals.get(&signal.id) else {
+            check(&scope, "publish event", "missing".into(), "account exists".into());
+            continue;
+        };
+        check(&scope, "token", replayed.token.to_string(), signal.token.to_string());
+        check(&scope, "status", format!("{:?}", replayed.status()), format!("{:?}", signal.status));
+        if let Some((_, roi_bps)) = replayed.settlement {
+            if signal.status != SignalStatus::Open {
+                check(&scope, "roi_bps", roi_bps.to_string(), signal.roi_bps.to_string());
+            }
+        }
+    }
+    for (id, replayed) in &replay.signals {
+        if !replayed.compressed && !seen.contains(id) {
+            check(&format!("signal #{id}"), "account", "published".into(), "missing".into());
+        }
+    }
+
+    let replayed_tokens = replay.by_token(true);
+    let tokens: HashSet<&Pubkey> = replayed_tokens.keys().chain(by_token.keys()).collect();
+    let mut tokens: Vec<_> = tokens.into_iter().collect();
+    tokens.sort();
+    for token in tokens {
+        let replayed = replayed_tokens.get(token).copied().unwrap_or_default();
+        let on_chain = by_token.get(token).copied().unwrap_or_default();
+        check(&format!("token {token}"), "tally", format!("{replayed:?}"), format!("{on_chain:?}"));
+    }
+    out
+}
@@ -0,0 +1,121 @@
+//! `oracle-replay`: rebuild ORACLE Alpha state from its event history and
+//! diff it against the chain.
+//!
+//! Every `SignalPublished*`, `CompressedSignalWritten` and `SignalClosed`
+//! event is replayed in chain order to compute the expected `OracleState`
+//! counters, each signal's status and ROI, and per-token and per-publisher
+//! outcome tallies. The counters, signal accounts and per-token tallies are
+//! then compared with the current accounts; any difference means missed or
+//! double-counted settlements or corrupted state. Per-publisher tallies
+//! have no on-chain counterpart and are only reported.
+//!
+//! Events come from RPC transaction history, or from an `oracle-indexer`
+//! database with `--database-url` (much faster on a long history). Only
+//! events up to the slot the accounts were read at are replayed; a signal
+//! touched while the tool runs can still show up as a difference, so
+//! re-run to confirm. Exits with status 1 when anything differs.
+
+mod diff;
+mod replay;
+mod source;
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use oracle_client::{fetch, query};
+use serde_json::json;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use tokio_postgres::NoTls;
+
+use crate::replay::Replay;
+
+#[derive(Parser)]
+#[command(name = "oracle-replay", version, about = "Replay ORACLE Alpha events and check on-chain state")]
+struct Cli {
+    /// RPC endpoint
+    #[arg(long, short = 'u', env = "ORACLE_RPC_URL", default_value = "https://api.mainnet-beta.solana.com")]
+    url: String,
+
+    /// Read events from this indexer database instead of RPC history
+    #[arg(long, env = "DATABASE_URL")]
+    database_url: Option<String>,
+
+    /// Print the full report as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let rpc = RpcClient::new_with_commitment(cli.url.clone(), CommitmentConfig::confirmed());
+
+    let state = fetch::fetch_oracle_state(&rpc).await?;
+    let accounts = query::fetch_signals(&rpc, Vec::new()).await?;
+    let slot = rpc.get_slot().await?;
+
+    let logged = match &cli.database_url {
+        Some(url) => {
+            let (client, connection) = tokio_postgres::connect(url, NoTls)
+                .await
+                .context("connecting to Postgres")?;
+            tokio::spawn(async move {
+                if let Err(err) = connection.await {
+                    eprintln!("postgres connection closed: {err}");
+                }
+            });
+            source::from_db(&client, slot).await?
+        }
+        None => source::from_rpc(&rpc, slot).await?,
+    };
+    let mut replay = Replay::default();
+    for event in &logged {
+        replay.apply(event);
+    }
+    let mismatches = diff::diff(&replay, &state, &accounts);
+    let publishers = replay.by_publisher();
+
+    if cli.json {
+        let report = json!({
+            "slot": slot,
+            "events": replay.events,
+            "signals": replay.signals.len(),
+            "anomalies": replay.anomalies,
+            "mismatches": mismatches,
+            "tokens": replay
+                .by_token(false)
+                .into_iter()
+                .map(|(token, tally)| (token.to_string(), json!(tally)))
+                .collect::<serde_json::Map<_, _>>(),
+            "publishers": publishers,
+        });
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        println!("replayed {} event(s), {} signal(s), up to slot {slot}", replay.events, replay.signals.len());
+        println!("publishers:");
+        for (domain, tally) in &publishers {
+            let name = if domain.is_empty() { "(none)" } else { domain.as_str() };
+            println!(
+                "  {name}: {} signals, {} open, {} wins, {} losses, {} closed, {} break-even",
+                tally.signals, tally.open, tally.wins, tally.losses, tally.closed, tally.break_even
+            );
+        }
+        for anomaly in &replay.anomalies {
+            println!("anomaly: {anomaly}");
+        }
+        for mismatch in &mismatches {
+            println!(
+                "{} {}: replayed {}, on-chain {}",
+                mismatch.scope, mismatch.field, mismatch.replayed, mismatch.on_chain
+            );
+        }
+        if mismatches.is_empty() && replay.anomalies.is_empty() {
+            println!("on-chain state matches the event history");
+        }
+    }
+
+    if !mismatches.is_empty() || !replay.anomalies.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
@@ -0,0 +1,158 @@
+//! Rebuilding program state from events alone.
+
+use std::collections::BTreeMap;
+
+use oracle_client::math::{roi_bucket, Outcome, PerformanceIndex, RoiMoments, Streaks, ROI_BUCKET_COUNT};
+use oracle_types::{OracleEvent, SignalStatus};
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::source::Logged;
+
+/// A signal as its events describe it
+#[derive(Debug, Clone)]
+pub struct Replayed {
+    pub token: Pubkey,
+    /// Verified domain of the publisher, empty if none was linked
+    pub publisher: String,
+    /// Stored as a leaf in the signal tree rather than as an account
+    pub compressed: bool,
+    pub settlement: Option<(SignalStatus, i64)>,
+}
+
+impl Replayed {
+    pub fn status(&self) -> SignalStatus {
+        self.settlement.map_or(SignalStatus::Open, |(status, _)| status)
+    }
+}
+
+/// Signal counts by outcome
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Tally {
+    pub signals: u64,
+    pub open: u64,
+    pub wins: u64,
+    pub losses: u64,
+    pub closed: u64,
+    pub break_even: u64,
+}
+
+impl Tally {
+    pub fn add(&mut self, status: SignalStatus) {
+        self.signals += 1;
+        match status {
+            SignalStatus::Open => self.open += 1,
+            SignalStatus::Win => self.wins += 1,
+            SignalStatus::Loss => self.losses += 1,
+            SignalStatus::Closed => self.closed += 1,
+            SignalStatus::BreakEven => self.break_even += 1,
+        }
+    }
+}
+
+/// Expected state after applying every event in order
+#[derive(Debug, Default)]
+pub struct Replay {
+    pub events: u64,
+    pub total_signals: u64,
+    pub total_wins: u64,
+    pub total_losses: u64,
+    pub compressed_signals: u64,
+    pub streaks: Streaks,
+    pub roi_moments: RoiMoments,
+    pub roi_histogram: [u32; ROI_BUCKET_COUNT],
+    pub performance_index: PerformanceIndex,
+    pub signals: BTreeMap<u64, Replayed>,
+    /// Event sequences the program should never have produced
+    pub anomalies: Vec<String>,
+}
+
+impl Replay {
+    pub fn apply(&mut self, logged: &Logged) {
+        self.events += 1;
+        match &logged.event {
+            OracleEvent::SignalPublished(event) => {
+                self.publish(logged, event.id, event.token, &event.publisher_domain)
+            }
+            OracleEvent::SignalPublishedWithProof(event) => {
+                self.publish(logged, event.id, event.token, &event.publisher_domain)
+            }
+            OracleEvent::CompressedSignalWritten(event) => {
+                self.compressed_signals += 1;
+                match self.signals.get_mut(&event.id) {
+                    Some(signal) => signal.compressed = true,
+                    None => self.anomaly(logged, format!("leaf written for unknown signal #{}", event.id)),
+                }
+            }
+            OracleEvent::SignalClosed(event) => {
+                let Some(signal) = self.signals.get_mut(&event.id) else {
+                    return self.anomaly(logged, format!("unknown signal #{} closed", event.id));
+                };
+                if signal.settlement.is_some() {
+                    return self.anomaly(logged, format!("signal #{} closed twice", event.id));
+                }
+                signal.settlement = Some((event.status, event.roi_bps));
+                let outcome = match event.status {
+                    SignalStatus::Win => Outcome::Win,
+                    SignalStatus::Loss => Outcome::Loss,
+                    SignalStatus::Closed => Outcome::Closed,
+                    SignalStatus::BreakEven => Outcome::BreakEven,
+                    SignalStatus::Open => return self.anomaly(logged, format!("signal #{} closed as open", event.id)),
+                };
+                match outcome {
+                    Outcome::Win => self.total_wins += 1,
+                    Outcome::Loss => self.total_losses += 1,
+                    Outcome::Closed | Outcome::BreakEven => {}
+                }
+                self.streaks = self.streaks.after(outcome);
+                self.roi_moments = self.roi_moments.after(event.roi_bps);
+                self.roi_histogram[roi_bucket(event.roi_bps)] += 1;
+                self.performance_index = self.performance_index.after(event.roi_bps);
+            }
+            _ => {}
+        }
+    }
+
+    fn publish(&mut self, logged: &Logged, id: u64, token: Pubkey, publisher: &str) {
+        // Ids are handed out from total_signals, so they arrive in sequence
+        if id != self.total_signals {
+            self.anomaly(logged, format!("signal #{id} published, expected #{}", self.total_signals));
+        }
+        if self.signals.contains_key(&id) {
+            return self.anomaly(logged, format!("signal #{id} published twice"));
+        }
+        self.total_signals += 1;
+        self.signals.insert(
+            id,
+            Replayed {
+                token,
+                publisher: publisher.to_string(),
+                compressed: false,
+                settlement: None,
+            },
+        );
+    }
+
+    fn anomaly(&mut self, logged: &Logged, message: String) {
+        self.anomalies.push(format!("{message} (slot {}, {})", logged.slot, logged.signature));
+    }
+
+    /// Outcomes per token; `accounts_only` leaves out compressed signals,
+    /// which have no account to compare against
+    pub fn by_token(&self, accounts_only: bool) -> BTreeMap<Pubkey, Tally> {
+        let mut tallies = BTreeMap::<Pubkey, Tally>::new();
+        for signal in self.signals.values().filter(|signal| !(accounts_only && signal.compressed)) {
+            tallies.entry(signal.token).or_default().add(signal.status());
+        }
+        tallies
+    }
+
+    /// Outcomes per publisher domain (`""` for signals without one)
+    pub fn by_publisher(&self) -> BTreeMap<String, Tally> {
+        let mut tallies = BTreeMap::<String, Tally>::new();
+        for signal in self.signals.values() {
+            tallies.entry(signal.publisher.clone()).or_default().add(signal.status());
+        }
+        tallies
+    }
+}
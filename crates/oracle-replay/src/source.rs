@@ -0,0 +1,99 @@
+//! Where the event history comes from: the chain itself, or the events
+//! table of an `oracle-indexer` database.
+
+use anyhow::{Context, Result};
+use oracle_client::stream::program_data;
+use oracle_client::PROGRAM_ID;
+use oracle_types::{decode_any_event, OracleEvent};
+use serde_json::json;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_rpc_client_api::config::RpcTransactionConfig;
+use solana_sdk::signature::Signature;
+use solana_transaction_status_client_types::UiTransactionEncoding;
+
+/// Largest page `getSignaturesForAddress` returns
+const SIGNATURE_PAGE: usize = 1_000;
+
+/// An event and the transaction that emitted it
+pub struct Logged {
+    pub signature: String,
+    pub slot: u64,
+    pub event: OracleEvent,
+}
+
+/// Every event of every successful program transaction up to `max_slot`,
+/// oldest first
+pub async fn from_rpc(rpc: &RpcClient, max_slot: u64) -> Result<Vec<Logged>> {
+    let mut statuses = Vec::new();
+    let mut before = None;
+    loop {
+        let config = GetConfirmedSignaturesForAddress2Config {
+            before,
+            until: None,
+            limit: Some(SIGNATURE_PAGE),
+            commitment: Some(rpc.commitment()),
+        };
+        let page = rpc.get_signatures_for_address_with_config(&PROGRAM_ID, config).await?;
+        let last_page = page.len() < SIGNATURE_PAGE;
+        before = page.last().map(|status| status.signature.parse()).transpose()?;
+        statuses.extend(page);
+        if last_page {
+            break;
+        }
+    }
+    statuses.retain(|status| status.err.is_none() && status.slot <= max_slot);
+    statuses.reverse();
+    eprintln!("replaying {} transaction(s)", statuses.len());
+
+    let config = RpcTransactionConfig {
+        encoding: Some(UiTransactionEncoding::Json),
+        commitment: Some(rpc.commitment()),
+        max_supported_transaction_version: Some(0),
+    };
+    let mut logged = Vec::new();
+    for (done, status) in statuses.iter().enumerate() {
+        let signature: Signature = status.signature.parse()?;
+        let tx = rpc
+            .get_transaction_with_config(&signature, config)
+            .await
+            .with_context(|| format!("fetching {signature}"))?;
+        let logs: Option<Vec<String>> = tx.transaction.meta.and_then(|meta| meta.log_messages.into());
+        for data in program_data(&logs.unwrap_or_default()) {
+            if let Some(event) = decode_any_event(&data) {
+                logged.push(Logged {
+                    signature: status.signature.clone(),
+                    slot: status.slot,
+                    event,
+                });
+            }
+        }
+        if (done + 1) % 500 == 0 {
+            eprintln!("fetched {} / {}", done + 1, statuses.len());
+        }
+    }
+    Ok(logged)
+}
+
+/// Every event the indexer stored up to `max_slot`, in chain order
+pub async fn from_db(db: &tokio_postgres::Client, max_slot: u64) -> Result<Vec<Logged>> {
+    let rows = db
+        .query(
+            "SELECT signature, slot, kind, data FROM events WHERE slot <= $1 ORDER BY seq",
+            &[&(max_slot as i64)],
+        )
+        .await?;
+    rows.iter()
+        .map(|row| {
+            let signature: String = row.get(0);
+            let tagged = json!({ "event": row.get::<_, String>(2), "data": row.get::<_, serde_json::Value>(3) });
+            let event =
+                serde_json::from_value(tagged).with_context(|| format!("decoding stored event of {signature}"))?;
+            Ok(Logged {
+                signature,
+                slot: row.get::<_, i64>(1) as u64,
+                event,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,25 @@
+//! Multi-source signal scoring.
+//!
+//! Source adapters each rate a candidate token 0-100 (or abstain). The
+//! ratings are combined into the signal's on-chain `score`, `risk_level`
+//! and `sources_bitmap`:
+//!
+//! ```text
+//! base  = Σ(score × weight) / Σ(weight)
+//! +15   two or more sources agree (confluence)
+//! +10   a smart-wallet source fired
+//! +5    a narrative source rated ≥ 80
+//! -10   only one source fired
+//! -5    no market data
+//! ```
+//!
+//! clamped to 0-100. Risk is EXTREME for a single source or a score below
+//! 40, otherwise LOW from 80, MEDIUM from 60 and HIGH below.
+
+mod score;
+mod scorer;
+mod source;
+
+pub use score::{combine, RiskLevel, Score, Weights};
+pub use scorer::{ScoreReport, Scorer};
+pub use source::{Candidate, FnSource, MarketData, Reading, SourceAdapter, SourceError, SourceKind};
@@ -0,0 +1,112 @@
+//! Combining source readings into a score.
+
+use crate::source::{Reading, SourceKind};
+
+const CONFLUENCE_BONUS: i64 = 15;
+const SMART_WALLET_BONUS: i64 = 10;
+const NARRATIVE_BONUS: i64 = 5;
+const SINGLE_SOURCE_PENALTY: i64 = 10;
+const NO_MARKET_DATA_PENALTY: i64 = 5;
+/// Narrative rating that counts as a strong match
+const STRONG_NARRATIVE: u8 = 80;
+
+/// Risk levels, in on-chain `risk_level` order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RiskLevel {
+    Low,
+    Medium,
+    High,
+    Extreme,
+}
+
+impl RiskLevel {
+    pub fn as_u8(self) -> u8 {
+        self as u8
+    }
+}
+
+/// Per-source weights in percent (150 = 1.5x)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Weights([u32; 8]);
+
+impl Default for Weights {
+    fn default() -> Self {
+        // smart-wallet-elite, smart-wallet-sniper, volume-spike, kol-tracker,
+        // kol-social, narrative-new, narrative-momentum, whale-tracker
+        Weights([150, 120, 100, 110, 90, 100, 120, 100])
+    }
+}
+
+impl Weights {
+    pub fn get(&self, kind: SourceKind) -> u32 {
+        self.0[kind as usize]
+    }
+
+    pub fn set(&mut self, kind: SourceKind, percent: u32) -> &mut Self {
+        self.0[kind as usize] = percent;
+        self
+    }
+
+    /// Reweight a source by its track record: 50% at a 0% win rate up to
+    /// 150% at a 100% win rate, scaled onto its base weight
+    pub fn with_win_rate(&mut self, kind: SourceKind, win_rate_bps: u64) -> &mut Self {
+        let factor = 50 + win_rate_bps.min(10_000) / 100;
+        let weight = u64::from(self.get(kind)) * factor / 100;
+        self.set(kind, weight as u32)
+    }
+}
+
+/// What goes on-chain for a candidate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Score {
+    pub score: u8,
+    pub risk_level: RiskLevel,
+    pub sources_bitmap: u8,
+}
+
+/// Combine readings (at most one per source) into a score
+pub fn combine(readings: &[Reading], weights: &Weights, has_market_data: bool) -> Score {
+    let mut sources_bitmap = 0u8;
+    let mut weighted = 0u64;
+    let mut total_weight = 0u64;
+    for reading in readings {
+        if sources_bitmap & reading.kind.bit() != 0 {
+            continue;
+        }
+        sources_bitmap |= reading.kind.bit();
+        let weight = u64::from(weights.get(reading.kind));
+        weighted += u64::from(reading.score.min(100)) * weight;
+        total_weight += weight;
+    }
+    let sources = sources_bitmap.count_ones();
+
+    let mut score = weighted.checked_div(total_weight).unwrap_or(0) as i64;
+    if sources >= 2 {
+        score += CONFLUENCE_BONUS;
+    }
+    if sources == 1 {
+        score -= SINGLE_SOURCE_PENALTY;
+    }
+    if readings.iter().any(|r| r.kind.is_smart_wallet()) {
+        score += SMART_WALLET_BONUS;
+    }
+    if readings.iter().any(|r| r.kind.is_narrative() && r.score >= STRONG_NARRATIVE) {
+        score += NARRATIVE_BONUS;
+    }
+    if !has_market_data {
+        score -= NO_MARKET_DATA_PENALTY;
+    }
+    let score = if sources == 0 { 0 } else { score.clamp(0, 100) as u8 };
+
+    let risk_level = match score {
+        _ if sources <= 1 || score < 40 => RiskLevel::Extreme,
+        80.. => RiskLevel::Low,
+        60.. => RiskLevel::Medium,
+        _ => RiskLevel::High,
+    };
+    Score {
+        score,
+        risk_level,
+        sources_bitmap,
+    }
+}
@@ -0,0 +1,58 @@
+//! Running every adapter against a candidate.
+
+use futures_util::future::join_all;
+
+use crate::score::{combine, Score, Weights};
+use crate::source::{Candidate, Reading, SourceAdapter, SourceKind};
+
+/// Readings behind a score, and the sources that failed
+#[derive(Debug)]
+pub struct ScoreReport {
+    pub score: Score,
+    pub readings: Vec<Reading>,
+    pub failed: Vec<(SourceKind, String)>,
+}
+
+#[derive(Default)]
+pub struct Scorer {
+    adapters: Vec<Box<dyn SourceAdapter>>,
+    weights: Weights,
+}
+
+impl Scorer {
+    pub fn new(weights: Weights) -> Self {
+        Scorer {
+            adapters: Vec::new(),
+            weights,
+        }
+    }
+
+    pub fn with_source(mut self, adapter: impl SourceAdapter + 'static) -> Self {
+        self.adapters.push(Box::new(adapter));
+        self
+    }
+
+    pub fn weights(&self) -> &Weights {
+        &self.weights
+    }
+
+    /// Query every source concurrently and combine what they report
+    pub async fn score(&self, candidate: &Candidate) -> ScoreReport {
+        let results = join_all(self.adapters.iter().map(|adapter| adapter.evaluate(candidate))).await;
+
+        let mut readings = Vec::new();
+        let mut failed = Vec::new();
+        for (adapter, result) in self.adapters.iter().zip(results) {
+            match result {
+                Ok(Some(reading)) => readings.push(reading),
+                Ok(None) => {}
+                Err(err) => failed.push((adapter.kind(), err.to_string())),
+            }
+        }
+        ScoreReport {
+            score: combine(&readings, &self.weights, candidate.market.is_some()),
+            readings,
+            failed,
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Source adapters and what they rate.
+
+use futures_util::future::BoxFuture;
+use solana_pubkey::Pubkey;
+
+pub type SourceError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Signal sources, in `sources_bitmap` bit order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SourceKind {
+    SmartWalletElite,
+    SmartWalletSniper,
+    VolumeSpike,
+    KolTracker,
+    KolSocial,
+    NarrativeNew,
+    NarrativeMomentum,
+    WhaleTracker,
+}
+
+impl SourceKind {
+    pub const ALL: [SourceKind; 8] = [
+        SourceKind::SmartWalletElite,
+        SourceKind::SmartWalletSniper,
+        SourceKind::VolumeSpike,
+        SourceKind::KolTracker,
+        SourceKind::KolSocial,
+        SourceKind::NarrativeNew,
+        SourceKind::NarrativeMomentum,
+        SourceKind::WhaleTracker,
+    ];
+
+    /// This source's bit in `sources_bitmap`
+    pub fn bit(self) -> u8 {
+        1 << self as u8
+    }
+
+    /// Identifier used by the off-chain pipeline and API
+    pub fn name(self) -> &'static str {
+        match self {
+            SourceKind::SmartWalletElite => "smart-wallet-elite",
+            SourceKind::SmartWalletSniper => "smart-wallet-sniper",
+            SourceKind::VolumeSpike => "volume-spike",
+            SourceKind::KolTracker => "kol-tracker",
+            SourceKind::KolSocial => "kol-social",
+            SourceKind::NarrativeNew => "narrative-new",
+            SourceKind::NarrativeMomentum => "narrative-momentum",
+            SourceKind::WhaleTracker => "whale-tracker",
+        }
+    }
+
+    /// Sources in a bitmap
+    pub fn from_bitmap(bitmap: u8) -> impl Iterator<Item = SourceKind> {
+        SourceKind::ALL.into_iter().filter(move |kind| bitmap & kind.bit() != 0)
+    }
+
+    pub fn is_smart_wallet(self) -> bool {
+        matches!(self, SourceKind::SmartWalletElite | SourceKind::SmartWalletSniper)
+    }
+
+    pub fn is_narrative(self) -> bool {
+        matches!(self, SourceKind::NarrativeNew | SourceKind::NarrativeMomentum)
+    }
+}
+
+/// Market snapshot of a candidate, when one could be fetched
+#[derive(Debug, Clone, Default)]
+pub struct MarketData {
+    pub price: u64, // PRICE_DECIMALS
+    pub mcap: u64,
+    pub volume_1h: u64,
+    pub volume_24h: u64,
+    pub holders: u64,
+}
+
+/// Token being considered for a signal
+#[derive(Debug, Clone)]
+pub struct Candidate {
+    pub token: Pubkey,
+    pub symbol: String,
+    pub market: Option<MarketData>,
+}
+
+/// One source's rating of a candidate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reading {
+    pub kind: SourceKind,
+    pub score: u8, // 0-100
+    pub detail: String,
+}
+
+/// A pluggable signal source
+///
+/// `evaluate` returns `Ok(None)` when the source has nothing to say about
+/// the candidate; errors are reported but do not stop the other sources.
+pub trait SourceAdapter: Send + Sync {
+    fn kind(&self) -> SourceKind;
+
+    fn evaluate<'a>(&'a self, candidate: &'a Candidate) -> BoxFuture<'a, Result<Option<Reading>, SourceError>>;
+}
+
+/// Adapter from a synchronous function of the candidate
+pub struct FnSource<F> {
+    kind: SourceKind,
+    rate: F,
+}
+
+impl<F> FnSource<F>
+where
+    F: Fn(&Candidate) -> Option<(u8, String)> + Send + Sync,
+{
+    pub fn new(kind: SourceKind, rate: F) -> Self {
+        FnSource { kind, rate }
+    }
+}
+
+impl<F> SourceAdapter for FnSource<F>
+where
+    F: Fn(&Candidate) -> Option<(u8, String)> + Send + Sync,
+{
+    fn kind(&self) -> SourceKind {
+        self.kind
+    }
+
+    fn evaluate<'a>(&'a self, candidate: &'a Candidate) -> BoxFuture<'a, Result<Option<Reading>, SourceError>> {
+        let reading = (self.rate)(candidate).map(|(score, detail)| Reading {
+            kind: self.kind,
+            score: score.min(100),
+            detail,
+        });
+        Box::pin(async move { Ok(reading) })
+    }
+}
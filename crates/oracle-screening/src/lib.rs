@@ -0,0 +1,280 @@
+//! Pre-publish safety screening.
+//!
+//! Before a signal goes on-chain its token is checked for the usual rug
+//! vectors: a live mint authority (supply can be inflated), a live freeze
+//! authority (holders can be frozen, i.e. a honeypot), a handful of wallets
+//! holding most of the supply, and pool liquidity that isn't burned or
+//! locked. The resulting [`Report`] hashes to 32 bytes suitable for the
+//! signal's `reasoning_hash`, so the screening that justified a publish is
+//! committed alongside it. A [`HolderSnapshot`] likewise hashes to the
+//! signal's holder-distribution commitment.
+
+pub mod mint;
+
+use std::collections::HashSet;
+use std::fmt;
+
+use oracle_client::{ClientError, Result};
+use sha2::{Digest, Sha256};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+
+use mint::MintInfo;
+
+/// Offset of `owner` in an SPL Token / Token-2022 token account
+const TOKEN_ACCOUNT_OWNER_OFFSET: usize = 32;
+
+/// Owner of the incinerator, where burned LP tokens are sent
+pub const INCINERATOR: Pubkey = solana_sdk::pubkey!("1nc1nerator11111111111111111111111111111111");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Status {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Status::Pass => "pass",
+            Status::Warn => "warn",
+            Status::Fail => "fail",
+        })
+    }
+}
+
+/// Outcome of one screening check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+    pub detail: String,
+}
+
+/// Thresholds a candidate is screened against
+#[derive(Debug, Clone)]
+pub struct Policy {
+    /// Share of supply the top holders may hold before failing
+    pub max_top_holders_bps: u16,
+    /// Share of supply the top holders may hold before warning
+    pub warn_top_holders_bps: u16,
+    /// How many of the largest holders count toward concentration
+    pub top_holders: usize,
+    /// Share of LP supply that must be burned or locked
+    pub min_lp_locked_bps: u16,
+    /// Token account owners excluded from concentration (pool vaults,
+    /// bonding curves)
+    pub ignored_holders: HashSet<Pubkey>,
+    /// Token account owners whose LP counts as locked (the incinerator,
+    /// locker program vaults)
+    pub lockers: HashSet<Pubkey>,
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Policy {
+            max_top_holders_bps: 5_000,
+            warn_top_holders_bps: 3_000,
+            top_holders: 10,
+            min_lp_locked_bps: 9_000,
+            ignored_holders: HashSet::new(),
+            lockers: HashSet::from([INCINERATOR]),
+        }
+    }
+}
+
+/// Screening result for one token
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub token: Pubkey,
+    pub checks: Vec<Check>,
+}
+
+impl Report {
+    /// Worst status across all checks
+    pub fn status(&self) -> Status {
+        self.checks.iter().map(|check| check.status).max().unwrap_or(Status::Pass)
+    }
+
+    /// Whether nothing failed (warnings are left to the publisher)
+    pub fn passed(&self) -> bool {
+        self.status() != Status::Fail
+    }
+
+    /// SHA256 of the canonical report text, for the signal's `reasoning_hash`
+    pub fn hash(&self) -> [u8; 32] {
+        Sha256::digest(self.to_string().as_bytes()).into()
+    }
+}
+
+/// Canonical text form: the token, then one `name status detail` line per
+/// check in the order they ran. This is what [`Report::hash`] commits to.
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "screening {}", self.token)?;
+        for check in &self.checks {
+            writeln!(f, "{} {} {}", check.name, check.status, check.detail)?;
+        }
+        Ok(())
+    }
+}
+
+/// Largest holders of a token at the time it was taken, net of
+/// [`Policy::ignored_holders`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HolderSnapshot {
+    pub token: Pubkey,
+    pub supply: u64,
+    /// (owner, amount), largest first
+    pub holders: Vec<(Pubkey, u64)>,
+}
+
+impl HolderSnapshot {
+    /// Share of supply held by the snapshotted holders
+    pub fn top_holders_bps(&self) -> u16 {
+        let held = self.holders.iter().map(|(_, amount)| amount).sum();
+        share_bps(held, self.supply).min(10_000) as u16
+    }
+
+    /// SHA256 of the canonical snapshot text, for the on-chain commitment
+    pub fn hash(&self) -> [u8; 32] {
+        Sha256::digest(self.to_string().as_bytes()).into()
+    }
+}
+
+/// Canonical text form: the token and supply, then one `owner amount` line
+/// per holder, largest first. This is what [`HolderSnapshot::hash`] commits to.
+impl fmt::Display for HolderSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "holders {} supply {}", self.token, self.supply)?;
+        for (owner, amount) in &self.holders {
+            writeln!(f, "{owner} {amount}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Snapshot the [`Policy::top_holders`] largest holders of `token`
+pub async fn holder_snapshot(rpc: &RpcClient, policy: &Policy, token: &Pubkey) -> Result<HolderSnapshot> {
+    let mint = fetch_mint(rpc, token).await?;
+    Ok(HolderSnapshot {
+        token: *token,
+        supply: mint.supply,
+        holders: top_holders(rpc, policy, token).await?,
+    })
+}
+
+/// Screen `token`, optionally checking the lock status of its pool's LP
+/// mint (concentrated-liquidity pools have no LP mint to check)
+pub async fn screen(rpc: &RpcClient, policy: &Policy, token: &Pubkey, lp_mint: Option<&Pubkey>) -> Result<Report> {
+    let mint = fetch_mint(rpc, token).await?;
+    let mut checks = vec![
+        authority_check("mint_authority", mint.mint_authority, Status::Fail),
+        authority_check("freeze_authority", mint.freeze_authority, Status::Fail),
+        concentration_check(rpc, policy, token, mint.supply).await?,
+    ];
+    if let Some(lp_mint) = lp_mint {
+        checks.push(lp_check(rpc, policy, lp_mint).await?);
+    }
+    Ok(Report { token: *token, checks })
+}
+
+async fn fetch_mint(rpc: &RpcClient, mint: &Pubkey) -> Result<MintInfo> {
+    let account = rpc.get_account(mint).await?;
+    MintInfo::decode(&account.data).ok_or(ClientError::InvalidAccountData(*mint))
+}
+
+fn authority_check(name: &'static str, authority: Option<Pubkey>, live: Status) -> Check {
+    match authority {
+        Some(authority) => Check {
+            name,
+            status: live,
+            detail: format!("live ({authority})"),
+        },
+        None => Check {
+            name,
+            status: Status::Pass,
+            detail: "revoked".into(),
+        },
+    }
+}
+
+/// Largest token accounts of `mint` as (owner, amount)
+async fn largest_holders(rpc: &RpcClient, mint: &Pubkey) -> Result<Vec<(Pubkey, u64)>> {
+    let largest = rpc.get_token_largest_accounts(mint).await?;
+    let addresses = largest
+        .iter()
+        .map(|balance| balance.address.parse().map_err(|_| ClientError::InvalidAccountData(*mint)))
+        .collect::<Result<Vec<Pubkey>>>()?;
+    let accounts = rpc.get_multiple_accounts(&addresses).await?;
+
+    let mut holders = Vec::with_capacity(largest.len());
+    for ((address, balance), account) in addresses.iter().zip(&largest).zip(accounts) {
+        let owner = account
+            .and_then(|account| {
+                let bytes = account.data.get(TOKEN_ACCOUNT_OWNER_OFFSET..TOKEN_ACCOUNT_OWNER_OFFSET + 32)?;
+                Some(Pubkey::new_from_array(bytes.try_into().ok()?))
+            })
+            .ok_or(ClientError::InvalidAccountData(*address))?;
+        let amount = balance.amount.amount.parse().map_err(|_| ClientError::InvalidAccountData(*address))?;
+        holders.push((owner, amount));
+    }
+    Ok(holders)
+}
+
+/// Largest holders of `mint` that count toward concentration
+async fn top_holders(rpc: &RpcClient, policy: &Policy, mint: &Pubkey) -> Result<Vec<(Pubkey, u64)>> {
+    Ok(largest_holders(rpc, mint)
+        .await?
+        .into_iter()
+        .filter(|(owner, _)| !policy.ignored_holders.contains(owner))
+        .take(policy.top_holders)
+        .collect())
+}
+
+fn share_bps(amount: u64, supply: u64) -> u64 {
+    if supply == 0 {
+        return 0;
+    }
+    (u128::from(amount) * 10_000 / u128::from(supply)) as u64
+}
+
+async fn concentration_check(rpc: &RpcClient, policy: &Policy, token: &Pubkey, supply: u64) -> Result<Check> {
+    let held: u64 = top_holders(rpc, policy, token).await?.iter().map(|(_, amount)| amount).sum();
+    let bps = share_bps(held, supply);
+    let status = if bps > u64::from(policy.max_top_holders_bps) {
+        Status::Fail
+    } else if bps > u64::from(policy.warn_top_holders_bps) {
+        Status::Warn
+    } else {
+        Status::Pass
+    };
+    Ok(Check {
+        name: "top_holders",
+        status,
+        detail: format!("top {} hold {bps} bps", policy.top_holders),
+    })
+}
+
+async fn lp_check(rpc: &RpcClient, policy: &Policy, lp_mint: &Pubkey) -> Result<Check> {
+    let mint = fetch_mint(rpc, lp_mint).await?;
+    let locked: u64 = largest_holders(rpc, lp_mint)
+        .await?
+        .into_iter()
+        .filter(|(owner, _)| policy.lockers.contains(owner))
+        .map(|(_, amount)| amount)
+        .sum();
+    // A fully burned LP supply leaves nothing to pull
+    let bps = if mint.supply == 0 { 10_000 } else { share_bps(locked, mint.supply) };
+    let status = if bps >= u64::from(policy.min_lp_locked_bps) {
+        Status::Pass
+    } else {
+        Status::Fail
+    };
+    Ok(Check {
+        name: "lp_locked",
+        status,
+        detail: format!("{bps} bps of {lp_mint} locked"),
+    })
+}
@@ -0,0 +1,42 @@
+//! Minimal SPL Token / Token-2022 mint decoding.
+//!
+//! Both programs share the 82-byte base mint layout, so the extensions
+//! Token-2022 appends after it don't need to be understood here.
+
+use solana_sdk::pubkey::Pubkey;
+
+const MINT_LEN: usize = 82;
+
+/// The parts of a mint screening cares about
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MintInfo {
+    pub mint_authority: Option<Pubkey>,
+    pub supply: u64,
+    pub decimals: u8,
+    pub freeze_authority: Option<Pubkey>,
+}
+
+impl MintInfo {
+    pub fn decode(data: &[u8]) -> Option<MintInfo> {
+        let data = data.get(..MINT_LEN)?;
+        // is_initialized
+        if data[45] != 1 {
+            return None;
+        }
+        Some(MintInfo {
+            mint_authority: coption_pubkey(&data[0..36])?,
+            supply: u64::from_le_bytes(data[36..44].try_into().ok()?),
+            decimals: data[44],
+            freeze_authority: coption_pubkey(&data[46..82])?,
+        })
+    }
+}
+
+/// A `COption<Pubkey>`: a u32 tag followed by the key
+fn coption_pubkey(bytes: &[u8]) -> Option<Option<Pubkey>> {
+    match u32::from_le_bytes(bytes[..4].try_into().ok()?) {
+        0 => Some(None),
+        1 => Some(Some(Pubkey::new_from_array(bytes[4..36].try_into().ok()?))),
+        _ => None,
+    }
+}
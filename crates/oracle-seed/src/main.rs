@@ -0,0 +1,214 @@
+//! `oracle-seed`: fill a devnet or localnet oracle with sample data.
+//!
+//! Initializes the oracle and its config if they don't exist yet (the
+//! signer becomes the authority), then publishes a stream of plausible
+//! signals on well-known tokens and plays each one out: some run up and
+//! close as wins, some close as losses, some close flat, and the rest are
+//! left open, as if still waiting to expire. Half carry a reasoning
+//! commitment. `--seed` makes a run reproducible.
+//!
+//! Refuses to run against mainnet.
+
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use clap::Parser;
+use oracle::{SignalDirection, PRICE_DECIMALS};
+use oracle_client::instructions::{self, SignalParams};
+use oracle_client::prices::USDC_MINT;
+use oracle_client::rationale::reasoning_hash;
+use oracle_client::submit::{SubmitConfig, Submitter};
+use oracle_client::{fetch, pda, signer, ClientError};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+
+#[derive(Parser)]
+#[command(name = "oracle-seed", version, about = "Seed an ORACLE Alpha instance with sample signals")]
+struct Cli {
+    /// RPC endpoint
+    #[arg(long, short = 'u', env = "ORACLE_RPC_URL", default_value = "http://127.0.0.1:8899")]
+    url: String,
+
+    /// Keypair file, `usb://ledger` or remote signer URL
+    #[arg(long, short = 'k', env = "ORACLE_KEYPAIR", default_value = "~/.config/solana/id.json")]
+    keypair: String,
+
+    /// Signals to publish
+    #[arg(long, default_value_t = 40)]
+    signals: usize,
+
+    /// Random seed (random if unset)
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Milliseconds to wait between signals, to spread their timestamps
+    #[arg(long, default_value_t = 0)]
+    delay_ms: u64,
+}
+
+/// Sample tokens with a rough USD price
+const TOKENS: &[(&str, &str, f64)] = &[
+    ("BONK", "DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263", 0.000_022),
+    ("WIF", "EKpQGSJtjMFqKZ9KQanSqYXRcF8fBopzLHYxdM65zcjm", 1.85),
+    ("JUP", "JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN", 0.92),
+    ("POPCAT", "7GCihgDB8fe6KNjn2MYtkzZcRjQy3t9GHdC8uHYmW2hr", 0.61),
+    ("MEW", "MEW1gQWJ3nEXg2qgERiKu7FAFj79PHvQVREQUzScPP5", 0.0041),
+    ("PYTH", "HZ1JovNiVvGrGNiiYvEozEVgZ58xaU3RKwX8eACQBCt3", 0.31),
+    ("JTO", "jtojtomepa8beP8AuQc6eXt5FriJwfFMwQx2v2f9mCL", 2.4),
+    ("RAY", "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R", 3.1),
+    ("ORCA", "orcaEKTdK7LKz57vaAYr9QeNsVEPfiu6QeMU1kektZE", 2.2),
+    ("SAMO", "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU", 0.0095),
+];
+
+/// How a sample signal plays out
+#[derive(Debug, Clone, Copy)]
+enum Plan {
+    Win,
+    Loss,
+    Flat,
+    Open,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    if cli.url.contains("mainnet") {
+        bail!("refusing to seed sample data on mainnet ({})", cli.url);
+    }
+    let rpc = RpcClient::new_with_commitment(cli.url.clone(), CommitmentConfig::confirmed());
+    let signer = signer::load_signer(&cli.keypair, None)?;
+    let authority = signer.pubkey();
+    let submitter = Submitter::new(&rpc, SubmitConfig::default());
+    let send = |key: String, instructions: Vec<Instruction>| {
+        let (submitter, signer) = (&submitter, signer.as_ref());
+        async move {
+            submitter
+                .submit(&key, &authority, &[signer], &instructions)
+                .await
+                .map(|submitted| submitted.signature)
+        }
+    };
+
+    match fetch::fetch_oracle_state(&rpc).await {
+        Ok(state) if state.authority != authority => {
+            bail!("oracle authority is {}, not {authority}", state.authority)
+        }
+        Ok(_) => {}
+        Err(ClientError::AccountNotFound(_)) => {
+            send("initialize".into(), vec![instructions::initialize(&authority)]).await?;
+            eprintln!("initialized the oracle with authority {authority}");
+        }
+        Err(err) => return Err(err.into()),
+    }
+    match fetch::fetch_config(&rpc).await {
+        Ok(_) => {}
+        Err(ClientError::AccountNotFound(_)) => {
+            send("initialize-config".into(), vec![instructions::initialize_config(&authority)]).await?;
+            eprintln!("initialized the oracle config");
+        }
+        Err(err) => return Err(err.into()),
+    }
+
+    let seed = cli.seed.unwrap_or_else(rand::random);
+    eprintln!("seeding {} signal(s) with seed {seed}", cli.signals);
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut tally = [0usize; 4];
+
+    for _ in 0..cli.signals {
+        let id = fetch::next_signal_id(&rpc).await?;
+        let (symbol, mint, usd) = TOKENS[rng.gen_range(0..TOKENS.len())];
+        let entry_price = to_price(usd * rng.gen_range(0.8..1.2));
+        let score: u8 = rng.gen_range(45..=98);
+        let params = SignalParams {
+            token: mint.parse::<Pubkey>()?,
+            symbol: symbol.to_string(),
+            score,
+            risk_level: match score {
+                85.. => 0,
+                70..=84 => 1,
+                55..=69 => 2,
+                _ => 3,
+            },
+            sources_bitmap: rng.gen_range(1..=u8::MAX),
+            mcap: rng.gen_range(5_000_000..2_000_000_000),
+            entry_price,
+            direction: if rng.gen_bool(0.2) { SignalDirection::Short } else { SignalDirection::Long },
+            quote_mint: USDC_MINT,
+            parent_signal: None,
+        };
+        let short = params.direction == SignalDirection::Short;
+        let token = params.token;
+        let publish = if rng.gen_bool(0.5) {
+            let reasoning = format!("{symbol}: score {score}, sample reasoning #{id} (seed {seed})");
+            instructions::publish_signal_with_proof(&authority, id, false, params, reasoning_hash(reasoning.as_bytes()))
+        } else {
+            instructions::publish_signal(&authority, id, false, params)
+        };
+        send(format!("seed-publish-{id}"), vec![publish]).await?;
+
+        // Favourable move (as a fraction) the signal reaches, and where it exits
+        let plan = match rng.gen_range(0..100) {
+            0..35 => Plan::Win,
+            35..65 => Plan::Loss,
+            65..80 => Plan::Flat,
+            _ => Plan::Open,
+        };
+        let (peak, exit) = match plan {
+            Plan::Win => {
+                let exit = rng.gen_range(0.5..3.0);
+                (exit + rng.gen_range(0.0..0.5), Some(exit))
+            }
+            Plan::Loss => (rng.gen_range(0.0..0.3), Some(-rng.gen_range(0.1..0.8))),
+            Plan::Flat => {
+                let exit = rng.gen_range(0.0..0.45);
+                (exit + rng.gen_range(0.0..0.2), Some(exit))
+            }
+            Plan::Open => (rng.gen_range(0.0..0.6), None),
+        };
+        let signal = pda::signal(id);
+        let peak_price = moved(entry_price, peak, short);
+        let mut steps = vec![instructions::update_ath(&authority, &signal, peak_price, &USDC_MINT)];
+        if let Some(exit) = exit {
+            let exit_price = moved(entry_price, exit, short);
+            steps.push(instructions::close_signal(
+                &authority,
+                &signal,
+                exit_price,
+                &USDC_MINT,
+                &authority,
+                &token,
+                false,
+            ));
+        }
+        send(format!("seed-settle-{id}"), steps).await?;
+
+        tally[plan as usize] += 1;
+        eprintln!("#{id} {symbol} score {score} {}: {plan:?}", if short { "short" } else { "long" });
+        if cli.delay_ms > 0 {
+            tokio::time::sleep(Duration::from_millis(cli.delay_ms)).await;
+        }
+    }
+
+    println!(
+        "seeded {} signal(s): {} wins, {} losses, {} flat, {} open",
+        cli.signals, tally[0], tally[1], tally[2], tally[3]
+    );
+    Ok(())
+}
+
+/// USD price scaled to PRICE_DECIMALS
+fn to_price(usd: f64) -> u64 {
+    ((usd * 10f64.powi(PRICE_DECIMALS as i32)).round() as u64).max(1)
+}
+
+/// `entry` after a move of `change` in the signal's favour (negative:
+/// against it); shorts profit from the price falling
+fn moved(entry: u64, change: f64, short: bool) -> u64 {
+    let factor = if short { 1.0 - change } else { 1.0 + change };
+    ((entry as f64 * factor.max(0.01)).round() as u64).max(1)
+}
@@ -0,0 +1,199 @@
+//! Portable snapshots of ORACLE Alpha program state.
+//!
+//! A [`Snapshot`] is every account the program owns, read in one
+//! `getProgramAccounts` call so they all come from the same slot, plus
+//! (optionally) the program and its program-data account. It is plain
+//! JSON, so it can be attached to an incident, diffed, or checked in as a
+//! fixture.
+//!
+//! [`Snapshot::write_account_dir`] writes it out in the `solana account
+//! --output json` format that `solana-test-validator --account-dir`
+//! loads, which is how `oracle-snapshot restore` brings a cluster's state
+//! up locally.
+
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use oracle_client::PROGRAM_ID;
+use oracle_types::{decode_any_account, Discriminator, OracleState};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use solana_account_decoder_client_types::UiAccountEncoding;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_rpc_client_api::request::RpcRequest;
+use solana_rpc_client_api::response::{OptionalContext, RpcKeyedAccount};
+use solana_sdk::pubkey::Pubkey;
+
+/// Version of the snapshot layout written by this crate
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Owner of upgradeable programs and their program-data accounts
+const BPF_LOADER_UPGRADEABLE: Pubkey = solana_sdk::pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+
+/// Offset of `authority` in the `OracleState` account
+const ORACLE_STATE_AUTHORITY_OFFSET: usize = 8;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub version: u32,
+    /// Slot the program accounts were read at
+    pub slot: u64,
+    pub program_id: String,
+    /// Unix time the snapshot was taken
+    pub taken_at: i64,
+    pub accounts: Vec<SnapshotAccount>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotAccount {
+    pub pubkey: String,
+    /// Decoded account type, for readers of the file; not used on restore
+    pub kind: Option<String>,
+    pub lamports: u64,
+    pub owner: String,
+    pub executable: bool,
+    pub rent_epoch: u64,
+    /// Account data, base64
+    pub data: String,
+}
+
+impl SnapshotAccount {
+    fn new(pubkey: &Pubkey, lamports: u64, owner: &Pubkey, executable: bool, rent_epoch: u64, data: &[u8]) -> Self {
+        SnapshotAccount {
+            pubkey: pubkey.to_string(),
+            kind: decode_any_account(data).map(|account| account.name().to_string()),
+            lamports,
+            owner: owner.to_string(),
+            executable,
+            rent_epoch,
+            data: BASE64.encode(data),
+        }
+    }
+}
+
+impl Snapshot {
+    /// Read every program account; with `include_program`, also the
+    /// program and program-data accounts so the restored validator runs the
+    /// same binary
+    pub async fn take(rpc: &RpcClient, include_program: bool) -> Result<Self> {
+        let config = RpcProgramAccountsConfig {
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                commitment: Some(rpc.commitment()),
+                ..RpcAccountInfoConfig::default()
+            },
+            with_context: Some(true),
+            ..RpcProgramAccountsConfig::default()
+        };
+        let response: OptionalContext<Vec<RpcKeyedAccount>> = rpc
+            .send(RpcRequest::GetProgramAccounts, json!([PROGRAM_ID.to_string(), config]))
+            .await?;
+        let (slot, keyed) = match response {
+            OptionalContext::Context(response) => (response.context.slot, response.value),
+            OptionalContext::NoContext(value) => (rpc.get_slot().await?, value),
+        };
+
+        let mut accounts = Vec::with_capacity(keyed.len() + 2);
+        for RpcKeyedAccount { pubkey, account } in keyed {
+            let Some(data) = account.data.decode() else {
+                bail!("account {pubkey} came back in an unexpected encoding");
+            };
+            let owner: Pubkey = account.owner.parse()?;
+            accounts.push(SnapshotAccount::new(
+                &pubkey.parse()?,
+                account.lamports,
+                &owner,
+                account.executable,
+                account.rent_epoch,
+                &data,
+            ));
+        }
+        if include_program {
+            let (program_data, _) = Pubkey::find_program_address(&[PROGRAM_ID.as_ref()], &BPF_LOADER_UPGRADEABLE);
+            for address in [PROGRAM_ID, program_data] {
+                let account = rpc
+                    .get_account(&address)
+                    .await
+                    .with_context(|| format!("fetching program account {address}"))?;
+                accounts.push(SnapshotAccount::new(
+                    &address,
+                    account.lamports,
+                    &account.owner,
+                    account.executable,
+                    account.rent_epoch,
+                    &account.data,
+                ));
+            }
+        }
+        accounts.sort_by(|a, b| a.pubkey.cmp(&b.pubkey));
+
+        Ok(Snapshot {
+            version: FORMAT_VERSION,
+            slot,
+            program_id: PROGRAM_ID.to_string(),
+            taken_at: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs() as i64),
+            accounts,
+        })
+    }
+
+    pub fn read(path: &Path) -> Result<Self> {
+        let file = std::fs::File::open(path).with_context(|| format!("opening {}", path.display()))?;
+        let snapshot: Snapshot = serde_json::from_reader(std::io::BufReader::new(file))
+            .with_context(|| format!("parsing {}", path.display()))?;
+        if snapshot.version != FORMAT_VERSION {
+            bail!("snapshot format {} is not supported (expected {FORMAT_VERSION})", snapshot.version);
+        }
+        Ok(snapshot)
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let file = std::fs::File::create(path).with_context(|| format!("creating {}", path.display()))?;
+        serde_json::to_writer(std::io::BufWriter::new(file), self)?;
+        Ok(())
+    }
+
+    /// Hand the oracle to `authority`, so a local keypair can drive the
+    /// restored state (close signals, run migrations)
+    pub fn set_authority(&mut self, authority: &Pubkey) -> Result<()> {
+        for account in &mut self.accounts {
+            let mut data = BASE64.decode(&account.data)?;
+            if data.get(..8) != Some(&OracleState::DISCRIMINATOR[..]) {
+                continue;
+            }
+            let end = ORACLE_STATE_AUTHORITY_OFFSET + 32;
+            data[ORACLE_STATE_AUTHORITY_OFFSET..end].copy_from_slice(authority.as_ref());
+            account.data = BASE64.encode(&data);
+            return Ok(());
+        }
+        bail!("snapshot has no OracleState account")
+    }
+
+    /// Write one `<pubkey>.json` per account in the format
+    /// `solana-test-validator --account-dir` loads
+    pub fn write_account_dir(&self, dir: &Path) -> Result<()> {
+        std::fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+        for account in &self.accounts {
+            let space = BASE64.decode(&account.data)?.len();
+            let file = json!({
+                "pubkey": account.pubkey,
+                "account": {
+                    "lamports": account.lamports,
+                    "data": [account.data, "base64"],
+                    "owner": account.owner,
+                    "executable": account.executable,
+                    "rentEpoch": account.rent_epoch,
+                    "space": space,
+                },
+            });
+            let path = dir.join(format!("{}.json", account.pubkey));
+            std::fs::write(&path, serde_json::to_vec_pretty(&file)?)
+                .with_context(|| format!("writing {}", path.display()))?;
+        }
+        Ok(())
+    }
+}
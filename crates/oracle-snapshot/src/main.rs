@@ -0,0 +1,120 @@
+//! `oracle-snapshot`: capture ORACLE Alpha program state and bring it up
+//! on a local validator.
+//!
+//! ```text
+//! oracle-snapshot create --out devnet.json -u https://api.devnet.solana.com
+//! oracle-snapshot restore devnet.json --authority <local key> --start
+//! ```
+//!
+//! `restore` writes the accounts where `solana-test-validator
+//! --account-dir` can load them and, with `--start`, launches the
+//! validator on a fresh ledger. The validator's clock starts at the
+//! current time, not the snapshot's.
+
+use std::path::PathBuf;
+use std::process::Command as Process;
+
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand};
+use oracle_snapshot::Snapshot;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Parser)]
+#[command(name = "oracle-snapshot", version, about = "Snapshot and restore ORACLE Alpha accounts")]
+struct Cli {
+    /// RPC endpoint
+    #[arg(long, short = 'u', env = "ORACLE_RPC_URL", default_value = "https://api.mainnet-beta.solana.com")]
+    url: String,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Write every program account (and the program) to a snapshot file
+    Create {
+        #[arg(long)]
+        out: PathBuf,
+        /// Leave out the program and program-data accounts
+        #[arg(long)]
+        skip_program: bool,
+    },
+    /// Prepare a snapshot for `solana-test-validator`
+    Restore {
+        snapshot: PathBuf,
+        /// Directory to write the account files to
+        #[arg(long, default_value = "snapshot-accounts")]
+        accounts_dir: PathBuf,
+        /// Make this key the oracle authority in the restored state
+        #[arg(long)]
+        authority: Option<Pubkey>,
+        /// Launch `solana-test-validator` with the restored accounts
+        #[arg(long)]
+        start: bool,
+        /// Ledger directory for the validator (reset on start)
+        #[arg(long, default_value = "test-ledger")]
+        ledger: PathBuf,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Create { out, skip_program } => {
+            let rpc = RpcClient::new_with_commitment(cli.url, CommitmentConfig::confirmed());
+            let snapshot = Snapshot::take(&rpc, !skip_program).await?;
+            snapshot.write(&out)?;
+            eprintln!(
+                "wrote {} account(s) at slot {} to {}",
+                snapshot.accounts.len(),
+                snapshot.slot,
+                out.display()
+            );
+        }
+        Command::Restore {
+            snapshot,
+            accounts_dir,
+            authority,
+            start,
+            ledger,
+        } => {
+            let mut snapshot = Snapshot::read(&snapshot)?;
+            if let Some(authority) = authority {
+                snapshot.set_authority(&authority)?;
+                eprintln!("oracle authority set to {authority}");
+            }
+            snapshot.write_account_dir(&accounts_dir)?;
+            eprintln!(
+                "wrote {} account(s) from slot {} to {}",
+                snapshot.accounts.len(),
+                snapshot.slot,
+                accounts_dir.display()
+            );
+
+            let args = [
+                "--reset".as_ref(),
+                "--ledger".as_ref(),
+                ledger.as_os_str(),
+                "--account-dir".as_ref(),
+                accounts_dir.as_os_str(),
+            ];
+            if !start {
+                let args: Vec<_> = args.iter().map(|arg| arg.to_string_lossy()).collect();
+                println!("solana-test-validator {}", args.join(" "));
+                return Ok(());
+            }
+            let status = Process::new("solana-test-validator")
+                .args(args)
+                .status()
+                .context("starting solana-test-validator")?;
+            if !status.success() {
+                bail!("solana-test-validator exited with {status}");
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,76 @@
+//! Finds, or builds, the program binary the LiteSVM tests load.
+//!
+//! `ORACLE_PROGRAM_SO` wins when set. Otherwise the program is built into
+//! `target/deploy` with `cargo build-sbf` when the Solana toolchain is
+//! installed, and an existing `target/deploy/oracle.so` is used when it is
+//! not. Tests that need the binary are compiled with `cfg(oracle_program)`
+//! only when one was found, so without it they report as ignored rather
+//! than failing. With `ORACLE_REQUIRE_PROGRAM` set, as CI does, a missing
+//! binary or a failed `cargo build-sbf` fails the build instead.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+fn main() {
+    println!("cargo::rustc-check-cfg=cfg(oracle_program)");
+    println!("cargo::rerun-if-env-changed=ORACLE_PROGRAM_SO");
+    println!("cargo::rerun-if-env-changed=ORACLE_REQUIRE_PROGRAM");
+    let required = env::var_os("ORACLE_REQUIRE_PROGRAM").is_some();
+
+    let root = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap()).join("../..");
+    let program = root.join("programs/oracle");
+    let deploy = root.join("target/deploy");
+    println!("cargo::rerun-if-changed={}", program.join("src").display());
+    println!("cargo::rerun-if-changed={}", program.join("Cargo.toml").display());
+    // Watched as a directory so an `anchor build` in between is picked up
+    let _ = fs::create_dir_all(&deploy);
+    println!("cargo::rerun-if-changed={}", deploy.display());
+
+    let path = match env::var_os("ORACLE_PROGRAM_SO") {
+        Some(path) => PathBuf::from(path),
+        None => {
+            build_sbf(&root, &program, &deploy, required);
+            deploy.join("oracle.so")
+        }
+    };
+    if path.exists() {
+        println!("cargo::rustc-cfg=oracle_program");
+    } else if required {
+        panic!("{} is missing and ORACLE_REQUIRE_PROGRAM is set", path.display());
+    } else {
+        println!(
+            "cargo::warning={} is missing and `cargo build-sbf` is unavailable; LiteSVM tests are ignored",
+            path.display()
+        );
+    }
+}
+
+/// Build the program with `cargo build-sbf`, if it is installed; when the
+/// binary is `required`, a failed build is fatal
+fn build_sbf(root: &Path, program: &Path, deploy: &Path, required: bool) {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let installed = Command::new(&cargo)
+        .args(["build-sbf", "--version"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success());
+    if !installed {
+        return;
+    }
+    // A target directory of its own, so the outer build's lock is never contended
+    let status = Command::new(&cargo)
+        .arg("build-sbf")
+        .arg("--manifest-path")
+        .arg(program.join("Cargo.toml"))
+        .arg("--sbf-out-dir")
+        .arg(deploy)
+        .env("CARGO_TARGET_DIR", root.join("target/sbf"))
+        .status();
+    if !status.is_ok_and(|status| status.success()) {
+        assert!(!required, "`cargo build-sbf` failed and ORACLE_REQUIRE_PROGRAM is set");
+        println!("cargo::warning=`cargo build-sbf` failed; using the existing binary, if any");
+    }
+}
@@ -0,0 +1,325 @@
+//! LiteSVM fixtures for the ORACLE Alpha program.
+//!
+//! `OracleFixture` loads the compiled program into an in-process LiteSVM,
+//! initializes the oracle and its config, and wraps the common flows
+//! (publish, close, ATH updates, clock warps) so integrators can test
+//! against the real program without a validator.
+//!
+//! The program binary is read from `ORACLE_PROGRAM_SO` if set, otherwise
+//! from `target/deploy/oracle.so` at the workspace root. The build script
+//! produces it with `cargo build-sbf` when the Solana toolchain is
+//! installed; without one, build it with `anchor build`.
+
+// LiteSVM's own result type; boxing it would only get in callers' way
+#![allow(clippy::result_large_err)]
+
+use std::path::PathBuf;
+
+use borsh::BorshDeserialize;
+use litesvm::types::{FailedTransactionMetadata, TransactionResult};
+use litesvm::LiteSVM;
+use oracle::pools::{PoolAdapter, Whirlpool};
+use oracle::{OracleError, SignalDirection};
+use oracle_client::instructions::{self, PoolAccounts, SignalParams};
+use oracle_client::types::{decode_account, Discriminator, OracleConfig, OracleState, Signal};
+use oracle_client::{pda, PROGRAM_ID};
+use solana_sdk::account::Account;
+use solana_sdk::clock::Clock;
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+pub use litesvm;
+
+/// Slots per second of wall-clock time, for clock warps
+const SLOTS_PER_SECOND: i64 = 2;
+
+/// SPL Token program, owner of fixture mints
+pub const TOKEN_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+/// Size of an SPL Token mint and the offset of its `supply`
+const MINT_SIZE: usize = 82;
+const MINT_SUPPLY_OFFSET: usize = 36;
+/// Size of an SPL Token account and the offsets of its fields
+const TOKEN_ACCOUNT_SIZE: usize = 165;
+const TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = 64;
+const TOKEN_ACCOUNT_STATE_OFFSET: usize = 108;
+/// Size of a Whirlpool and the offsets of the fields the program reads
+const WHIRLPOOL_SIZE: usize = 653;
+const WHIRLPOOL_DISCRIMINATOR: [u8; 8] = [63, 149, 209, 12, 225, 128, 99, 9];
+const WHIRLPOOL_SQRT_PRICE_OFFSET: usize = 65;
+const WHIRLPOOL_MINT_A_OFFSET: usize = 101;
+const WHIRLPOOL_VAULT_A_OFFSET: usize = 133;
+const WHIRLPOOL_MINT_B_OFFSET: usize = 181;
+const WHIRLPOOL_VAULT_B_OFFSET: usize = 213;
+/// Decimals of `QUOTE_MINT` as `create_pool` creates it
+pub const QUOTE_DECIMALS: u8 = 6;
+
+/// Quote mint of `signal_params` signals
+pub const QUOTE_MINT: Pubkey = oracle_client::prices::USDC_MINT;
+
+/// Where the compiled program is loaded from
+pub fn program_path() -> PathBuf {
+    match std::env::var_os("ORACLE_PROGRAM_SO") {
+        Some(path) => PathBuf::from(path),
+        None => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../target/deploy/oracle.so"),
+    }
+}
+
+/// Signal parameters with sensible defaults for tests
+pub fn signal_params(token: Pubkey, entry_price: u64) -> SignalParams {
+    SignalParams {
+        token,
+        symbol: "TEST".to_string(),
+        score: 80,
+        risk_level: 1,
+        sources_bitmap: 0b1,
+        mcap: 1_000_000,
+        entry_price,
+        direction: SignalDirection::Long,
+        quote_mint: QUOTE_MINT,
+        parent_signal: None,
+    }
+}
+
+/// Whether `result` failed with `error` from the oracle program
+pub fn is_oracle_error(result: &TransactionResult, error: OracleError) -> bool {
+    matches!(
+        result,
+        Err(FailedTransactionMetadata {
+            err: TransactionError::InstructionError(_, InstructionError::Custom(code)),
+            ..
+        }) if *code == u32::from(error)
+    )
+}
+
+/// The program running in LiteSVM, initialized with `authority`
+pub struct OracleFixture {
+    pub svm: LiteSVM,
+    pub authority: Keypair,
+}
+
+impl OracleFixture {
+    /// Load the program from `program_path()` and initialize the oracle
+    /// and its config
+    pub fn new() -> Self {
+        Self::with_program(&program_path())
+    }
+
+    pub fn with_program(path: &std::path::Path) -> Self {
+        let mut svm = LiteSVM::new();
+        svm.add_program_from_file(PROGRAM_ID, path).unwrap_or_else(|err| {
+            panic!("loading {} failed ({err}); build the program with `anchor build`", path.display())
+        });
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 100 * LAMPORTS_PER_SOL).expect("airdrop");
+
+        let mut fixture = OracleFixture { svm, authority };
+        let authority = fixture.authority.pubkey();
+        fixture
+            .send(&[instructions::initialize(&authority), instructions::initialize_config(&authority)], &[])
+            .expect("initialize oracle");
+        fixture
+    }
+
+    /// A new keypair holding `sol` SOL
+    pub fn funded_keypair(&mut self, sol: u64) -> Keypair {
+        let keypair = Keypair::new();
+        self.svm.airdrop(&keypair.pubkey(), sol * LAMPORTS_PER_SOL).expect("airdrop");
+        keypair
+    }
+
+    /// An initialized SPL Token mint with `supply` and no authorities
+    pub fn create_mint(&mut self, supply: u64, decimals: u8) -> Pubkey {
+        let mint = Pubkey::new_unique();
+        self.create_mint_at(mint, supply, decimals);
+        mint
+    }
+
+    /// `create_mint` at a fixed address, e.g. a well-known quote mint
+    pub fn create_mint_at(&mut self, mint: Pubkey, supply: u64, decimals: u8) {
+        let mut data = vec![0; MINT_SIZE];
+        data[MINT_SUPPLY_OFFSET..MINT_SUPPLY_OFFSET + 8].copy_from_slice(&supply.to_le_bytes());
+        data[MINT_SUPPLY_OFFSET + 8] = decimals;
+        data[MINT_SUPPLY_OFFSET + 9] = 1; // is_initialized
+        let account = Account {
+            lamports: self.svm.minimum_balance_for_rent_exemption(MINT_SIZE),
+            data,
+            owner: TOKEN_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+        self.svm.set_account(mint, account).expect("create mint");
+    }
+
+    /// An initialized SPL Token account for `mint` owned by `owner`, holding `amount`
+    pub fn create_token_account(&mut self, mint: &Pubkey, owner: &Pubkey, amount: u64) -> Pubkey {
+        let mut data = vec![0; TOKEN_ACCOUNT_SIZE];
+        data[..32].copy_from_slice(mint.as_ref());
+        data[32..64].copy_from_slice(owner.as_ref());
+        data[TOKEN_ACCOUNT_AMOUNT_OFFSET..TOKEN_ACCOUNT_AMOUNT_OFFSET + 8].copy_from_slice(&amount.to_le_bytes());
+        data[TOKEN_ACCOUNT_STATE_OFFSET] = 1; // initialized
+        let address = Pubkey::new_unique();
+        let account = Account {
+            lamports: self.svm.minimum_balance_for_rent_exemption(TOKEN_ACCOUNT_SIZE),
+            data,
+            owner: TOKEN_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+        self.svm.set_account(address, account).expect("create token account");
+        address
+    }
+
+    /// A Whirlpool pricing `token` (mint A, created with `QUOTE_DECIMALS`)
+    /// at `price` (PRICE_DECIMALS) in `QUOTE_MINT`, holding `quote_reserve`
+    /// base units of the quote mint. Creates `QUOTE_MINT` if needed.
+    pub fn create_pool(&mut self, token: &Pubkey, price: u64, quote_reserve: u64) -> PoolAccounts {
+        if self.svm.get_account(&QUOTE_MINT).is_none() {
+            self.create_mint_at(QUOTE_MINT, u64::MAX, QUOTE_DECIMALS);
+        }
+        let address = Pubkey::new_unique();
+        let pool = PoolAccounts {
+            pool: address,
+            mint_a: *token,
+            mint_b: QUOTE_MINT,
+            vault_a: self.create_token_account(token, &address, 0),
+            vault_b: self.create_token_account(&QUOTE_MINT, &address, quote_reserve),
+        };
+
+        let mut data = vec![0; WHIRLPOOL_SIZE];
+        data[..8].copy_from_slice(&WHIRLPOOL_DISCRIMINATOR);
+        data[WHIRLPOOL_MINT_A_OFFSET..WHIRLPOOL_MINT_A_OFFSET + 32].copy_from_slice(token.as_ref());
+        data[WHIRLPOOL_VAULT_A_OFFSET..WHIRLPOOL_VAULT_A_OFFSET + 32].copy_from_slice(pool.vault_a.as_ref());
+        data[WHIRLPOOL_MINT_B_OFFSET..WHIRLPOOL_MINT_B_OFFSET + 32].copy_from_slice(QUOTE_MINT.as_ref());
+        data[WHIRLPOOL_VAULT_B_OFFSET..WHIRLPOOL_VAULT_B_OFFSET + 32].copy_from_slice(pool.vault_b.as_ref());
+        let account = Account {
+            lamports: self.svm.minimum_balance_for_rent_exemption(WHIRLPOOL_SIZE),
+            data,
+            owner: Whirlpool::PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+        self.svm.set_account(address, account).expect("create pool");
+        self.set_pool_price(&pool, price);
+        pool
+    }
+
+    /// Move a `create_pool` pool to `price` (PRICE_DECIMALS)
+    pub fn set_pool_price(&mut self, pool: &PoolAccounts, price: u64) {
+        // Both mints share decimals, so the raw price is the human price;
+        // f64 is well inside the program's price deviation tolerance
+        let human = price as f64 / 10f64.powi(oracle::PRICE_DECIMALS as i32);
+        let sqrt_price = (human.sqrt() * 2f64.powi(64)) as u128;
+        let mut account = self.svm.get_account(&pool.pool).expect("pool exists");
+        account.data[WHIRLPOOL_SQRT_PRICE_OFFSET..WHIRLPOOL_SQRT_PRICE_OFFSET + 16]
+            .copy_from_slice(&sqrt_price.to_le_bytes());
+        self.svm.set_account(pool.pool, account).expect("update pool");
+    }
+
+    /// Balance of SPL Token account `address`
+    pub fn token_balance(&self, address: &Pubkey) -> u64 {
+        let account = self.svm.get_account(address).expect("token account exists");
+        let amount = &account.data[TOKEN_ACCOUNT_AMOUNT_OFFSET..TOKEN_ACCOUNT_AMOUNT_OFFSET + 8];
+        u64::from_le_bytes(amount.try_into().expect("8 bytes"))
+    }
+
+    /// Overwrite a fixture token account's balance, e.g. to simulate swap proceeds
+    pub fn set_token_balance(&mut self, address: &Pubkey, amount: u64) {
+        let mut account = self.svm.get_account(address).expect("token account exists");
+        account.data[TOKEN_ACCOUNT_AMOUNT_OFFSET..TOKEN_ACCOUNT_AMOUNT_OFFSET + 8]
+            .copy_from_slice(&amount.to_le_bytes());
+        self.svm.set_account(*address, account).expect("update token account");
+    }
+
+    /// Overwrite a fixture mint's supply, e.g. to simulate dilution
+    pub fn set_mint_supply(&mut self, mint: &Pubkey, supply: u64) {
+        let mut account = self.svm.get_account(mint).expect("mint exists");
+        account.data[MINT_SUPPLY_OFFSET..MINT_SUPPLY_OFFSET + 8].copy_from_slice(&supply.to_le_bytes());
+        self.svm.set_account(*mint, account).expect("update mint");
+    }
+
+    /// Send `instructions` paid for by the authority, also signed by `signers`
+    pub fn send(&mut self, instructions: &[Instruction], signers: &[&Keypair]) -> TransactionResult {
+        let mut all: Vec<&Keypair> = vec![&self.authority];
+        all.extend_from_slice(signers);
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.authority.pubkey()),
+            &all,
+            self.svm.latest_blockhash(),
+        );
+        let result = self.svm.send_transaction(tx);
+        // Identical follow-up transactions must not collide on signature
+        self.svm.expire_blockhash();
+        result
+    }
+
+    /// Publish a signal; returns its id
+    pub fn publish(&mut self, params: SignalParams) -> Result<u64, FailedTransactionMetadata> {
+        let id = self.oracle_state().total_signals;
+        let ix = instructions::publish_signal(&self.authority.pubkey(), id, false, params);
+        self.send(&[ix], &[])?;
+        Ok(id)
+    }
+
+    /// Close signal `id`, with the exit quoted in `QUOTE_MINT`
+    pub fn close(&mut self, id: u64, exit_price: u64) -> TransactionResult {
+        let authority = self.authority.pubkey();
+        let token = self.signal(id).map_or_else(Pubkey::default, |signal| signal.token);
+        let signal = pda::signal(id);
+        let snapshot = self.svm.get_account(&pda::supply_snapshot(&signal)).is_some();
+        let ix = instructions::close_signal(&authority, &signal, exit_price, &QUOTE_MINT, &authority, &token, snapshot);
+        self.send(&[ix], &[])
+    }
+
+    /// Raise signal `id`'s ATH, quoted in `QUOTE_MINT`
+    pub fn update_ath(&mut self, id: u64, price: u64) -> TransactionResult {
+        let ix = instructions::update_ath(&self.authority.pubkey(), &pda::signal(id), price, &QUOTE_MINT);
+        self.send(&[ix], &[])
+    }
+
+    /// Decode the program account at `address`, if it exists
+    pub fn account<T: Discriminator + BorshDeserialize>(&self, address: &Pubkey) -> Option<T> {
+        decode_account(&self.svm.get_account(address)?.data)
+    }
+
+    pub fn oracle_state(&self) -> OracleState {
+        self.account(&pda::oracle_state()).expect("oracle state")
+    }
+
+    pub fn config(&self) -> OracleConfig {
+        self.account(&pda::config()).expect("oracle config")
+    }
+
+    pub fn signal(&self, id: u64) -> Option<Signal> {
+        self.account(&pda::signal(id))
+    }
+
+    pub fn clock(&self) -> Clock {
+        self.svm.get_sysvar()
+    }
+
+    /// Move the clock forward `seconds`, advancing the slot to match
+    pub fn warp(&mut self, seconds: i64) {
+        let mut clock = self.clock();
+        clock.unix_timestamp += seconds;
+        clock.slot += (seconds * SLOTS_PER_SECOND).max(0) as u64;
+        self.svm.set_sysvar(&clock);
+    }
+
+    /// Set the clock to `unix_timestamp`
+    pub fn set_time(&mut self, unix_timestamp: i64) {
+        let mut clock = self.clock();
+        clock.unix_timestamp = unix_timestamp;
+        self.svm.set_sysvar(&clock);
+    }
+}
+
+impl Default for OracleFixture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,1881 @@
+//! Signal lifecycle against the compiled program.
+//!
+//! They need `target/deploy/oracle.so`, which the build script produces
+//! with `cargo build-sbf` when the Solana toolchain is installed; without
+//! the toolchain or an existing binary they are ignored, except under
+//! `ORACLE_REQUIRE_PROGRAM` (set in CI), where the build fails instead.
+
+use anchor_lang::{AccountDeserialize, AccountSerialize, AnchorSerialize};
+use oracle::{BasketMemberParams, ExecutionSide, OracleError, SignalDirection, TakeProfitParams};
+use oracle_client::types::{
+    AccessClaim, Basket, DcaPlan, Discovery, Discriminator, HolderCommitment, Invalidation, ModelPortfolio, PairSignal,
+    PerpParams, PublisherStats, Signal, SignalBoost, SignalEntries, SignalLineage, SignalMarketCap, SignalNotes,
+    SignalRating, SignalSizing, SignalStatus, Subscription, SupplySnapshot, TakeProfitLadder, TierAllowlist,
+    TokenHistory, Vault, VaultDepositor,
+};
+use oracle_client::{instructions, pda, PROGRAM_ID};
+use oracle_test_utils::{is_oracle_error, signal_params, OracleFixture, QUOTE_DECIMALS, QUOTE_MINT, TOKEN_PROGRAM_ID};
+use solana_sdk::account::Account;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn publish_assigns_sequential_ids() {
+    let mut fx = OracleFixture::new();
+    let token = Pubkey::new_unique();
+
+    assert_eq!(fx.publish(signal_params(token, 1_000)).unwrap(), 0);
+    assert_eq!(fx.publish(signal_params(token, 2_000)).unwrap(), 1);
+
+    let signal = fx.signal(1).unwrap();
+    assert_eq!(signal.entry_price, 2_000);
+    assert_eq!(signal.status, SignalStatus::Open);
+    assert_eq!(fx.oracle_state().total_signals, 2);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn close_at_double_is_a_win() {
+    let mut fx = OracleFixture::new();
+    let id = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+
+    fx.warp(3_600);
+    fx.close(id, 2_000).unwrap();
+
+    let signal = fx.signal(id).unwrap();
+    assert_eq!(signal.roi_bps, 10_000);
+    assert_eq!(fx.oracle_state().total_wins, 1);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn short_ath_tracks_the_low() {
+    let mut fx = OracleFixture::new();
+    let mut params = signal_params(Pubkey::new_unique(), 1_000);
+    params.direction = SignalDirection::Short;
+    let id = fx.publish(params).unwrap();
+
+    fx.update_ath(id, 800).unwrap();
+    fx.update_ath(id, 900).unwrap();
+
+    assert_eq!(fx.signal(id).unwrap().ath_price, 800);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn closing_twice_fails() {
+    let mut fx = OracleFixture::new();
+    let id = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+    fx.close(id, 500).unwrap();
+
+    let result = fx.close(id, 600);
+    assert!(is_oracle_error(&result, OracleError::SignalAlreadyClosed));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn publisher_stats_count_settlements() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    fx.send(
+        &[instructions::register_publisher(&authority), instructions::init_publisher_stats(&authority)],
+        &[],
+    )
+    .unwrap();
+
+    let token = Pubkey::new_unique();
+    let win = fx.publish(signal_params(token, 1_000)).unwrap();
+    let loss = fx.publish(signal_params(token, 1_000)).unwrap();
+    let close = |id, exit_price| {
+        instructions::close_signal(&authority, &pda::signal(id), exit_price, &QUOTE_MINT, &authority, &token, false)
+    };
+    fx.send(&[close(win, 2_000)], &[]).unwrap();
+    fx.send(&[close(loss, 500)], &[]).unwrap();
+
+    let stats: PublisherStats = fx.account(&pda::publisher_stats(&authority)).unwrap();
+    assert_eq!((stats.signals, stats.wins, stats.losses), (2, 1, 1));
+    assert_eq!(stats.cumulative_roi_bps, 10_000 - 5_000);
+    assert_eq!(stats.streaks, fx.oracle_state().streaks);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn first_settlement_creates_the_publishers_stats() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let id = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+    assert_eq!(fx.signal(id).unwrap().publisher, authority);
+    assert!(fx.account::<PublisherStats>(&pda::publisher_stats(&authority)).is_none());
+
+    fx.close(id, 2_000).unwrap();
+
+    let stats: PublisherStats = fx.account(&pda::publisher_stats(&authority)).unwrap();
+    assert_eq!(stats.wallet, authority);
+    assert_eq!((stats.signals, stats.wins, stats.losses), (1, 1, 0));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn settling_into_another_wallets_stats_fails() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let token = Pubkey::new_unique();
+    let id = fx.publish(signal_params(token, 1_000)).unwrap();
+
+    let other = Pubkey::new_unique();
+    let ix = instructions::close_signal(&authority, &pda::signal(id), 2_000, &QUOTE_MINT, &other, &token, false);
+    assert!(fx.send(&[ix], &[]).is_err());
+    assert_eq!(fx.signal(id).unwrap().status, SignalStatus::Open);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn flat_close_breaks_streaks() {
+    let mut fx = OracleFixture::new();
+    for exit_price in [2_000, 2_000, 1_000, 500] {
+        let id = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+        fx.close(id, exit_price).unwrap();
+    }
+
+    let streaks = fx.oracle_state().streaks;
+    assert_eq!((streaks.win, streaks.loss), (0, 1));
+    assert_eq!((streaks.max_win, streaks.max_loss), (2, 1));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn roi_moments_and_histogram_follow_settlements() {
+    let mut fx = OracleFixture::new();
+    for exit_price in [2_000, 500] {
+        let id = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+        fx.close(id, exit_price).unwrap();
+    }
+
+    let moments = fx.oracle_state().roi_moments;
+    let moments = oracle_client::math::RoiMoments {
+        count: moments.count,
+        mean: moments.mean,
+        m2: moments.m2,
+    };
+    assert_eq!(moments.mean_bps(), 2_500);
+    // Samples of +10_000 and -5_000 bps: stddev 7_500 * sqrt(2)
+    assert_eq!(moments.stddev_bps(), Some(10_606));
+
+    let histogram = fx.oracle_state().roi_histogram;
+    assert_eq!(histogram.iter().sum::<u32>(), 2);
+    assert_eq!(histogram[oracle_client::math::roi_bucket(10_000)], 1);
+    assert_eq!(histogram[oracle_client::math::roi_bucket(-5_000)], 1);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn effective_score_halves_each_half_life() {
+    let mut fx = OracleFixture::new();
+    let mut params = signal_params(Pubkey::new_unique(), 1_000);
+    params.score = 80;
+    let id = fx.publish(params).unwrap();
+
+    fx.warp(fx.config().score_half_life_secs);
+    let meta = fx.send(&[instructions::effective_score(&pda::signal(id))], &[]).unwrap();
+    assert_eq!(meta.return_data.data, vec![40]);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn longer_symbols_need_a_raised_limit() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let mut params = signal_params(Pubkey::new_unique(), 1_000);
+    params.symbol = "FARTCOINSOL".into();
+    let ix = instructions::publish_signal(&authority, 0, false, params.clone());
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::SymbolTooLong));
+
+    let update = oracle::ConfigUpdate {
+        max_symbol_len: Some(16),
+        ..Default::default()
+    };
+    fx.send(&[instructions::update_config(&authority, update)], &[]).unwrap();
+    let short = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+    let long = fx.publish(params).unwrap();
+    assert_eq!(fx.signal(long).unwrap().symbol, "FARTCOINSOL");
+
+    let address = pda::signal(short);
+    let before = fx.svm.get_account(&address).unwrap().data.len();
+    fx.send(&[instructions::realloc_signal(&authority, &address, "FARTCOINSOL".into())], &[]).unwrap();
+    assert_eq!(fx.svm.get_account(&address).unwrap().data.len(), before + 1);
+    assert_eq!(fx.signal(short).unwrap().symbol, "FARTCOINSOL");
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn signal_accounts_keep_a_reserved_tail() {
+    let mut fx = OracleFixture::new();
+    let id = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+    let signal = fx.signal(id).unwrap();
+    let data = fx.svm.get_account(&pda::signal(id)).unwrap().data;
+
+    assert_eq!(data.len(), oracle::signal_space(signal.symbol.len()));
+    let tail = &data[data.len() - oracle::SIGNAL_RESERVED_BYTES..];
+    assert!(tail.iter().all(|byte| *byte == 0));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn notes_append_in_order() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let signal = pda::signal(fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap());
+
+    fx.send(
+        &[
+            instructions::init_signal_notes(&authority, &signal),
+            instructions::append_signal_note(&authority, &signal, "taking partial profit".into()),
+        ],
+        &[],
+    )
+    .unwrap();
+    fx.send(&[instructions::append_signal_note(&authority, &signal, "thesis invalidated".into())], &[]).unwrap();
+
+    let notes: SignalNotes = fx.account(&pda::signal_notes(&signal)).unwrap();
+    let texts: Vec<_> = notes.notes.iter().map(|note| note.text.as_str()).collect();
+    assert_eq!(texts, ["taking partial profit", "thesis invalidated"]);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn subscribers_rate_closed_signals_once() {
+    let mut fx = OracleFixture::new();
+    let subscriber = fx.funded_keypair(10);
+    let id = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+    let signal = pda::signal(id);
+    fx.send(&[instructions::subscribe(&subscriber.pubkey(), 0, 1, false, false)], &[&subscriber]).unwrap();
+
+    let rate = instructions::rate_signal(&subscriber.pubkey(), &signal, 4);
+    assert!(is_oracle_error(&fx.send(std::slice::from_ref(&rate), &[&subscriber]), OracleError::SignalStillOpen));
+
+    fx.close(id, 2_000).unwrap();
+    fx.send(std::slice::from_ref(&rate), &[&subscriber]).unwrap();
+    assert!(fx.send(&[rate], &[&subscriber]).is_err());
+
+    let ratings: SignalRating = fx.account(&pda::signal_rating(&signal)).unwrap();
+    assert_eq!((ratings.count, ratings.sum, ratings.counts[3]), (1, 4, 1));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn boosts_fill_free_featured_slots() {
+    let mut fx = OracleFixture::new();
+    let booster = fx.funded_keypair(100);
+    let mut ids = Vec::new();
+    for price in 1..=oracle::FEATURED_SLOTS as u64 + 1 {
+        let id = fx.publish(signal_params(Pubkey::new_unique(), price * 1_000)).unwrap();
+        let ix = instructions::boost_signal(&booster.pubkey(), &pda::signal(id), price * oracle::MIN_BOOST_LAMPORTS);
+        fx.send(&[ix], &[&booster]).unwrap();
+        ids.push(id);
+    }
+
+    // Boosts only take free slots, however hot
+    let discovery: Discovery = fx.account(&pda::discovery()).unwrap();
+    let featured: Vec<Pubkey> = discovery.featured.iter().map(|slot| slot.signal).collect();
+    assert!(featured.contains(&pda::signal(ids[0])));
+    assert!(!featured.contains(&pda::signal(ids[oracle::FEATURED_SLOTS])));
+
+    let boost: SignalBoost = fx.account(&pda::signal_boost(&pda::signal(ids[1]))).unwrap();
+    assert_eq!(boost.heat, 2 * oracle::MIN_BOOST_LAMPORTS);
+
+    let dust = instructions::boost_signal(&booster.pubkey(), &pda::signal(ids[1]), 1);
+    assert!(is_oracle_error(&fx.send(&[dust], &[&booster]), OracleError::InvalidAmount));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn featured_auction_refunds_outbid_bidders() {
+    let mut fx = OracleFixture::new();
+    let alice = fx.funded_keypair(100);
+    let bob = fx.funded_keypair(100);
+    let id = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+    let signal = pda::signal(id);
+    let epoch = (fx.clock().unix_timestamp / oracle::AUCTION_EPOCH_SECS + 1) as u64;
+    let bid = oracle::MIN_BOOST_LAMPORTS * 10;
+
+    fx.send(&[instructions::bid_featured(&alice.pubkey(), &signal, epoch, bid, None)], &[&alice]).unwrap();
+    // Raising by less than the increment, or without refunding alice, fails
+    let low = instructions::bid_featured(&bob.pubkey(), &signal, epoch, bid + 1, Some(alice.pubkey()));
+    assert!(is_oracle_error(&fx.send(&[low], &[&bob]), OracleError::BidTooLow));
+    let unrefunded = instructions::bid_featured(&bob.pubkey(), &signal, epoch, bid * 2, None);
+    assert!(is_oracle_error(&fx.send(&[unrefunded], &[&bob]), OracleError::InvalidRefundAccount));
+
+    let alice_before = fx.svm.get_balance(&alice.pubkey()).unwrap();
+    let raise = instructions::bid_featured(&bob.pubkey(), &signal, epoch, bid * 2, Some(alice.pubkey()));
+    fx.send(&[raise], &[&bob]).unwrap();
+    assert_eq!(fx.svm.get_balance(&alice.pubkey()).unwrap(), alice_before + bid);
+
+    let settle = instructions::settle_auction(&bob.pubkey(), epoch);
+    assert!(is_oracle_error(&fx.send(std::slice::from_ref(&settle), &[&bob]), OracleError::AuctionStillOpen));
+    fx.warp(oracle::AUCTION_EPOCH_SECS);
+    let treasury_before = fx.svm.get_balance(&pda::treasury()).unwrap_or(0);
+    fx.send(&[settle], &[&bob]).unwrap();
+    assert_eq!(fx.svm.get_balance(&pda::treasury()).unwrap(), treasury_before + bid * 2);
+
+    let discovery: Discovery = fx.account(&pda::discovery()).unwrap();
+    assert_eq!(discovery.featured[0].signal, signal);
+    assert_eq!(discovery.featured[0].expires_at, (epoch as i64 + 1) * oracle::AUCTION_EPOCH_SECS);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn basket_settles_on_weighted_roi() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let member = |weight_bps, entry_price| BasketMemberParams {
+        token: Pubkey::new_unique(),
+        weight_bps,
+        entry_price,
+    };
+
+    let unbalanced = vec![member(5_000, 1_000), member(4_000, 1_000)];
+    let ix = instructions::publish_basket(&authority, 0, "AI-MEMES".into(), unbalanced);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::InvalidBasket));
+
+    let members = vec![member(7_500, 1_000), member(2_500, 2_000)];
+    let ix = instructions::publish_basket(&authority, 0, "AI-MEMES".into(), members);
+    fx.send(&[ix], &[]).unwrap();
+    assert_eq!(fx.oracle_state().total_baskets, 1);
+
+    // +100% on three quarters, -50% on the rest
+    fx.send(&[instructions::close_basket(&authority, 0, vec![2_000, 1_000])], &[]).unwrap();
+    let basket: Basket = fx.account(&pda::basket(0)).unwrap();
+    assert_eq!(basket.roi_bps, 6_250);
+    assert_eq!(basket.status, SignalStatus::Win);
+    assert_eq!(fx.oracle_state().total_wins, 0);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn pair_settles_on_spread() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let token = Pubkey::new_unique();
+
+    let same = instructions::publish_pair(&authority, 0, (token, 1_000), (token, 1_000));
+    assert!(is_oracle_error(&fx.send(&[same], &[]), OracleError::InvalidPair));
+
+    let ix = instructions::publish_pair(&authority, 0, (token, 1_000), (Pubkey::new_unique(), 500));
+    fx.send(&[ix], &[]).unwrap();
+
+    // Long +30%, short leg fell 25%: spread +55%
+    fx.send(&[instructions::close_pair(&authority, 0, 1_300, 375)], &[]).unwrap();
+    let pair: PairSignal = fx.account(&pda::pair_signal(0)).unwrap();
+    assert_eq!(pair.roi_bps, 5_500);
+    assert_eq!(pair.status, SignalStatus::Win);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn perp_leverage_implies_liquidation_price() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let signal = pda::signal(fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap());
+
+    // Risk level 1 allows up to 2x
+    let excessive = instructions::set_perp_params(&authority, &signal, 30_000, 0);
+    assert!(is_oracle_error(&fx.send(&[excessive], &[]), OracleError::LeverageExceeded));
+
+    fx.send(&[instructions::set_perp_params(&authority, &signal, 20_000, 7)], &[]).unwrap();
+    let params: PerpParams = fx.account(&pda::perp_params(&signal)).unwrap();
+    assert_eq!((params.leverage_bps, params.liquidation_price, params.market_index), (20_000, 500, 7));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn losses_shrink_suggested_size() {
+    let mut fx = OracleFixture::new();
+    let sizing = |fx: &OracleFixture, id| fx.account::<SignalSizing>(&pda::signal_sizing(&pda::signal(id))).unwrap();
+
+    // Score 80 alone suggests half of a 60% Kelly, capped at 15% for risk level 1
+    let first = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+    assert_eq!(sizing(&fx, first).size_bps, 1_500);
+    fx.close(first, 500).unwrap();
+    for _ in 0..3 {
+        let id = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+        fx.close(id, 500).unwrap();
+    }
+
+    let id = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+    assert_eq!(sizing(&fx, id).size_bps, 714);
+    assert_eq!(sizing(&fx, id).win_rate_bps, 0);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn supply_dilution_is_flagged() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let mint = fx.create_mint(1_000_000, 6);
+    let id = fx.publish(signal_params(mint, 1_000)).unwrap();
+    let signal = pda::signal(id);
+    fx.send(&[instructions::snapshot_entry_supply(&authority, &signal, &mint)], &[]).unwrap();
+
+    // The entry snapshot makes the mint part of the close
+    fx.set_mint_supply(&mint, 1_200_000);
+    let ix = instructions::close_signal(&authority, &signal, 1_500, &QUOTE_MINT, &authority, &mint, false);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::SupplyMintMissing));
+    fx.close(id, 1_500).unwrap();
+
+    let snapshot: SupplySnapshot = fx.account(&pda::supply_snapshot(&signal)).unwrap();
+    assert_eq!((snapshot.entry_supply, snapshot.exit_supply), (1_000_000, 1_200_000));
+    assert_eq!(snapshot.supply_change_bps, 2_000);
+    assert!(snapshot.flagged);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn holder_distribution_commits_once() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let signal = pda::signal(fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap());
+
+    let empty = instructions::commit_holder_distribution(&authority, &signal, [0; 32], 4_000);
+    assert!(is_oracle_error(&fx.send(&[empty], &[]), OracleError::InvalidHolderCommitment));
+
+    fx.send(&[instructions::commit_holder_distribution(&authority, &signal, [7; 32], 4_000)], &[]).unwrap();
+    let commitment: HolderCommitment = fx.account(&pda::holder_commitment(&signal)).unwrap();
+    assert_eq!((commitment.snapshot_hash, commitment.top_holders_bps), ([7; 32], 4_000));
+
+    // The snapshot can't be swapped for a friendlier one later
+    fx.warp(1);
+    let again = instructions::commit_holder_distribution(&authority, &signal, [8; 32], 1_000);
+    assert!(fx.send(&[again], &[]).is_err());
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn republish_cooldown_needs_parent() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let update = oracle::ConfigUpdate {
+        republish_cooldown_secs: Some(6 * 60 * 60),
+        ..Default::default()
+    };
+    fx.send(&[instructions::update_config(&authority, update)], &[]).unwrap();
+
+    let token = Pubkey::new_unique();
+    let publish = |fx: &OracleFixture, params| {
+        instructions::publish_signal(&authority, fx.oracle_state().total_signals, false, params)
+    };
+    let first = fx.publish(signal_params(token, 1_000)).unwrap();
+    fx.close(first, 500).unwrap();
+    let unlinked = publish(&fx, signal_params(token, 600));
+    assert!(is_oracle_error(&fx.send(&[unlinked], &[]), OracleError::RepublishCooldown));
+
+    let mut linked = signal_params(token, 600);
+    linked.parent_signal = Some(pda::signal(first));
+    let second = fx.publish(linked).unwrap();
+    fx.close(second, 400).unwrap();
+
+    // Only the token's last closed signal can be linked
+    let mut stale = signal_params(token, 400);
+    stale.parent_signal = Some(pda::signal(first));
+    let stale = publish(&fx, stale);
+    assert!(is_oracle_error(&fx.send(&[stale], &[]), OracleError::RepublishCooldown));
+
+    fx.warp(6 * 60 * 60);
+    fx.publish(signal_params(token, 400)).unwrap();
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn closing_recreates_a_missing_token_history() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let update = oracle::ConfigUpdate {
+        republish_cooldown_secs: Some(6 * 60 * 60),
+        ..Default::default()
+    };
+    fx.send(&[instructions::update_config(&authority, update)], &[]).unwrap();
+
+    // A signal published before its token had a history still starts the cooldown
+    let token = Pubkey::new_unique();
+    let id = fx.publish(signal_params(token, 1_000)).unwrap();
+    fx.svm.set_account(pda::token_history(&token), Account::default()).unwrap();
+    fx.close(id, 500).unwrap();
+
+    let history: TokenHistory = fx.account(&pda::token_history(&token)).unwrap();
+    assert_eq!((history.token, history.last_signal), (token, pda::signal(id)));
+    let unlinked = instructions::publish_signal(&authority, id + 1, false, signal_params(token, 600));
+    assert!(is_oracle_error(&fx.send(&[unlinked], &[]), OracleError::RepublishCooldown));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn reopened_signals_chain_attempts() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let token = Pubkey::new_unique();
+    let reopen = |fx: &OracleFixture, parent, entry_price| {
+        let id = fx.oracle_state().total_signals;
+        instructions::reopen_signal(&authority, id, false, parent, signal_params(token, entry_price), [0; 32])
+    };
+
+    let first = fx.publish(signal_params(token, 1_000)).unwrap();
+    let open_parent = reopen(&fx, first, 1_000);
+    assert!(fx.send(&[open_parent], &[]).is_err(), "parent must be closed");
+    fx.close(first, 800).unwrap();
+    let second = fx.oracle_state().total_signals;
+    fx.send(&[reopen(&fx, first, 800)], &[]).unwrap();
+    fx.close(second, 1_200).unwrap();
+
+    // Only the token's last closed signal can be re-entered
+    let stale = reopen(&fx, first, 1_200);
+    assert!(is_oracle_error(&fx.send(&[stale], &[]), OracleError::InvalidParentSignal));
+
+    let third = fx.oracle_state().total_signals;
+    fx.send(&[reopen(&fx, second, 1_200)], &[]).unwrap();
+    let lineage: SignalLineage = fx.account(&pda::signal_lineage(&pda::signal(third))).unwrap();
+    assert_eq!(lineage.root, pda::signal(first));
+    assert_eq!(lineage.attempt, 3);
+    // -20% then +50%
+    assert_eq!(lineage.prior_roi_bps, 2_000);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn break_even_band_counts_as_neither() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let update = oracle::ConfigUpdate {
+        break_even_bps: Some(200),
+        ..Default::default()
+    };
+    fx.send(&[instructions::update_config(&authority, update)], &[]).unwrap();
+
+    let flat = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+    fx.close(flat, 990).unwrap();
+    assert_eq!(fx.signal(flat).unwrap().status, SignalStatus::BreakEven);
+    assert_eq!((fx.oracle_state().total_wins, fx.oracle_state().total_losses), (0, 0));
+
+    let lost = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+    fx.close(lost, 970).unwrap();
+    assert_eq!(fx.signal(lost).unwrap().status, SignalStatus::Loss);
+    assert_eq!(fx.oracle_state().total_losses, 1);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn prices_must_match_the_signal_quote() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let token = Pubkey::new_unique();
+
+    let mut params = signal_params(token, 1_000);
+    params.quote_mint = token;
+    let ix = instructions::publish_signal(&authority, 0, false, params);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::InvalidQuoteMint));
+
+    let id = fx.publish(signal_params(token, 1_000)).unwrap();
+    assert_eq!(fx.signal(id).unwrap().quote_mint, QUOTE_MINT);
+
+    let other = Pubkey::new_unique();
+    let signal = pda::signal(id);
+    let ix = instructions::update_ath(&authority, &signal, 1_800, &other);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::QuoteMismatch));
+    let ix = instructions::close_signal(&authority, &signal, 1_600, &other, &authority, &token, false);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::QuoteMismatch));
+
+    fx.update_ath(id, 1_800).unwrap();
+    fx.close(id, 1_600).unwrap();
+    assert_eq!(fx.signal(id).unwrap().status, SignalStatus::Win);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn win_threshold_follows_risk_level() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let update = oracle::ConfigUpdate {
+        win_threshold_bps: Some([0, 2_000, 5_000, 5_000]),
+        ..Default::default()
+    };
+    assert!(is_oracle_error(
+        &fx.send(&[instructions::update_config(&authority, update)], &[]),
+        OracleError::InvalidWinThreshold
+    ));
+    let update = oracle::ConfigUpdate {
+        win_threshold_bps: Some([1_000, 2_000, 5_000, 5_000]),
+        ..Default::default()
+    };
+    fx.send(&[instructions::update_config(&authority, update)], &[]).unwrap();
+
+    let mut params = signal_params(Pubkey::new_unique(), 1_000);
+    params.risk_level = 0;
+    let large_cap = fx.publish(params).unwrap();
+    let mut params = signal_params(Pubkey::new_unique(), 1_000);
+    params.risk_level = 3;
+    let degen = fx.publish(params).unwrap();
+
+    fx.close(large_cap, 1_200).unwrap();
+    fx.close(degen, 1_200).unwrap();
+    assert_eq!(fx.signal(large_cap).unwrap().status, SignalStatus::Win);
+    assert_eq!(fx.signal(degen).unwrap().status, SignalStatus::Closed);
+    assert_eq!(fx.oracle_state().total_wins, 1);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn added_entries_average_the_entry_price() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let id = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+    let signal = pda::signal(id);
+
+    fx.send(&[instructions::add_entry(&authority, &signal, 700, 5_000)], &[]).unwrap();
+    assert_eq!(fx.signal(id).unwrap().entry_price, 850);
+    fx.send(&[instructions::add_entry(&authority, &signal, 1_400, 2_500)], &[]).unwrap();
+    let averaged = fx.signal(id).unwrap();
+    assert_eq!(averaged.entry_price, (1_000 * 2_500 + 700 * 5_000 + 1_400 * 2_500) / 10_000);
+    assert_eq!(averaged.ath_price, 1_400);
+
+    let entries: SignalEntries = fx.account(&pda::signal_entries(&signal)).unwrap();
+    assert_eq!((entries.initial_price, entries.entries.len()), (1_000, 2));
+    let ix = instructions::add_entry(&authority, &signal, 900, 5_000);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::InvalidEntry));
+
+    fx.close(id, 1_520).unwrap();
+    assert_eq!(fx.signal(id).unwrap().roi_bps, (1_520 - 950) * 10_000 / 950);
+    let ix = instructions::add_entry(&authority, &signal, 900, 1_000);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::SignalAlreadyClosed));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn dca_plan_is_declared_once() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let id = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+    let signal = pda::signal(id);
+
+    let ix = instructions::set_dca_plan(&authority, &signal, 3, 3_600, 4_000);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::InvalidDcaPlan));
+    let ix = instructions::set_dca_plan(&authority, &signal, 3, 3_600, 3_000);
+    fx.send(std::slice::from_ref(&ix), &[]).unwrap();
+
+    let plan: DcaPlan = fx.account(&pda::dca_plan(&signal)).unwrap();
+    assert_eq!((plan.tranches, plan.tranche_bps, plan.executed), (3, 3_000, 0));
+    assert_eq!(plan.start, fx.clock().unix_timestamp);
+    assert!(fx.send(&[ix], &[]).is_err());
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn take_profit_ladder_validates_levels() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let id = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+    let signal = pda::signal(id);
+    let level = |price, exit_bps| TakeProfitParams { price, exit_bps };
+
+    let below_entry = vec![level(900, 5_000)];
+    let ix = instructions::set_take_profits(&authority, &signal, below_entry);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::InvalidTakeProfits));
+    let over_full = vec![level(1_500, 6_000), level(2_000, 5_000)];
+    let ix = instructions::set_take_profits(&authority, &signal, over_full);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::InvalidTakeProfits));
+
+    let ix = instructions::set_take_profits(&authority, &signal, vec![level(1_500, 5_000), level(2_000, 5_000)]);
+    fx.send(&[ix], &[]).unwrap();
+    let ladder: TakeProfitLadder = fx.account(&pda::take_profit(&signal)).unwrap();
+    let levels: Vec<_> = ladder.levels.iter().map(|l| (l.price, l.exit_bps, l.settled_at)).collect();
+    assert_eq!(levels, [(1_500, 5_000, 0), (2_000, 5_000, 0)]);
+    assert_eq!((ladder.realized_roi_bps, ladder.exited_bps), (0, 0));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn settled_take_profits_count_toward_the_close() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let liquidity = oracle::MIN_POOL_LIQUIDITY * 10u64.pow(QUOTE_DECIMALS as u32);
+    let level = |price, exit_bps| TakeProfitParams { price, exit_bps };
+    let verified_signal = |fx: &mut OracleFixture| {
+        let token = fx.create_mint(1_000_000_000, QUOTE_DECIMALS);
+        let id = fx.publish(signal_params(token, 1_000_000_000)).unwrap();
+        let pool = fx.create_pool(&token, 1_000_000_000, liquidity);
+        fx.send(&[instructions::verify_entry_price(&authority, &pda::signal(id), &pool)], &[]).unwrap();
+        (id, pda::signal(id), pool, token)
+    };
+
+    // Half the position exits at +50%, the rest at the +20% close
+    let (id, signal, pool, token) = verified_signal(&mut fx);
+    let ladder = vec![level(1_500_000_000, 5_000), level(2_000_000_000, 2_500)];
+    fx.send(&[instructions::set_take_profits(&authority, &signal, ladder)], &[]).unwrap();
+    fx.set_pool_price(&pool, 1_500_000_000);
+    fx.send(&[instructions::settle_take_profit(&authority, &signal, &pool, 0, &authority, &token)], &[]).unwrap();
+    let ix = instructions::settle_take_profit(&authority, &signal, &pool, 1, &authority, &token);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::TakeProfitNotReached));
+    fx.close(id, 1_200_000_000).unwrap();
+    let closed = fx.signal(id).unwrap();
+    assert_eq!((closed.exit_price, closed.roi_bps), (1_200_000_000, 5_000 * 5_000 / 10_000 + 5_000 * 2_000 / 10_000));
+
+    // A ladder that exits the whole position closes the signal at its last level
+    let (id, signal, pool, token) = verified_signal(&mut fx);
+    let ladder = vec![level(1_500_000_000, 5_000), level(2_000_000_000, 5_000)];
+    fx.send(&[instructions::set_take_profits(&authority, &signal, ladder)], &[]).unwrap();
+    fx.set_pool_price(&pool, 2_000_000_000);
+    for level in 0..2 {
+        let ix = instructions::settle_take_profit(&authority, &signal, &pool, level, &authority, &token);
+        fx.send(&[ix], &[]).unwrap();
+    }
+    let closed = fx.signal(id).unwrap();
+    assert_eq!(closed.status, SignalStatus::Win);
+    assert!(closed.exit_verified);
+    assert_eq!((closed.exit_price, closed.roi_bps), (2_000_000_000, 5_000 * 5_000 / 10_000 + 5_000 * 10_000 / 10_000));
+    assert_eq!(fx.oracle_state().total_wins, 1);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn invalidation_price_must_close_as_loss() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let id = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+    let signal = pda::signal(id);
+
+    for price in [0, 1_200] {
+        let ix = instructions::set_invalidation_price(&authority, &signal, price);
+        assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::InvalidInvalidationPrice));
+    }
+    let ix = instructions::set_invalidation_price(&authority, &signal, 800);
+    fx.send(std::slice::from_ref(&ix), &[]).unwrap();
+
+    let invalidation: Invalidation = fx.account(&pda::invalidation(&signal)).unwrap();
+    assert_eq!((invalidation.signal, invalidation.price), (signal, 800));
+    assert!(fx.send(&[ix], &[]).is_err());
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn invalidation_settles_like_a_close() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let liquidity = oracle::MIN_POOL_LIQUIDITY * 10u64.pow(QUOTE_DECIMALS as u32);
+    let token = fx.create_mint(1_000_000_000, QUOTE_DECIMALS);
+    let id = fx.publish(signal_params(token, 1_000_000_000)).unwrap();
+    let signal = pda::signal(id);
+    let pool = fx.create_pool(&token, 1_000_000_000, liquidity);
+    fx.send(
+        &[
+            instructions::verify_entry_price(&authority, &signal, &pool),
+            instructions::snapshot_entry_supply(&authority, &signal, &token),
+            instructions::set_invalidation_price(&authority, &signal, 800_000_000),
+        ],
+        &[],
+    )
+    .unwrap();
+
+    // Anyone can invalidate; the loss still lands in the publisher's stats
+    fx.set_mint_supply(&token, 1_100_000_000);
+    fx.set_pool_price(&pool, 700_000_000);
+    let keeper = fx.funded_keypair(1);
+    let ix = instructions::invalidate_signal(&keeper.pubkey(), &signal, &pool, &authority, &token);
+    fx.send(&[ix], &[&keeper]).unwrap();
+    assert_eq!(fx.signal(id).unwrap().status, SignalStatus::Loss);
+
+    let stats: PublisherStats = fx.account(&pda::publisher_stats(&authority)).unwrap();
+    assert_eq!((stats.wallet, stats.losses), (authority, 1));
+    let history: TokenHistory = fx.account(&pda::token_history(&token)).unwrap();
+    assert_eq!(history.last_signal, signal);
+    let snapshot: SupplySnapshot = fx.account(&pda::supply_snapshot(&signal)).unwrap();
+    assert_eq!((snapshot.exit_supply, snapshot.supply_change_bps), (1_100_000_000, 1_000));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn roi_is_annualized_over_the_holding_time() {
+    let mut fx = OracleFixture::new();
+    let quick = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+    let slow = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+
+    fx.warp(2 * 60 * 60);
+    fx.close(quick, 1_100).unwrap();
+    fx.warp(60 * 24 * 60 * 60 - 2 * 60 * 60);
+    fx.close(slow, 1_100).unwrap();
+
+    let quick = fx.signal(quick).unwrap();
+    let slow = fx.signal(slow).unwrap();
+    assert_eq!((quick.roi_bps, slow.roi_bps), (1_000, 1_000));
+    assert_eq!(quick.roi_annualized_bps, 1_000 * 365 * 12);
+    assert_eq!(slow.roi_annualized_bps, 1_000 * 365 / 60);
+    let moments = fx.oracle_state().roi_annualized_moments;
+    assert_eq!(moments.count, 2);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn friction_is_deducted_from_net_roi() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let friction = oracle::Friction {
+        entry_slippage_bps: 100,
+        exit_slippage_bps: 100,
+        swap_fee_bps: 30,
+    };
+    let invalid = oracle::ConfigUpdate {
+        friction: Some(oracle::Friction { swap_fee_bps: 9_900, ..friction }),
+        ..Default::default()
+    };
+    let ix = instructions::update_config(&authority, invalid);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::InvalidBps));
+    let update = oracle::ConfigUpdate {
+        friction: Some(friction),
+        ..Default::default()
+    };
+    fx.send(&[instructions::update_config(&authority, update)], &[]).unwrap();
+
+    let id = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+    fx.close(id, 2_000).unwrap();
+    let signal = fx.signal(id).unwrap();
+    // Filled at 1013 and 1974 rather than 1000 and 2000
+    assert_eq!((signal.roi_bps, signal.net_roi_bps), (10_000, 9_486));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn marks_need_verified_pools() {
+    let mut fx = OracleFixture::new();
+    let id = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+
+    let ix = instructions::mark_signals(&[]);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::InvalidMarkAccounts));
+    let pool = instructions::PoolAccounts {
+        pool: Pubkey::new_unique(),
+        mint_a: Pubkey::new_unique(),
+        mint_b: QUOTE_MINT,
+        vault_a: Pubkey::new_unique(),
+        vault_b: Pubkey::new_unique(),
+    };
+    let ix = instructions::mark_signals(&[(pda::signal(id), pool)]);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::PoolNotVerified));
+
+    let state = fx.oracle_state();
+    assert_eq!((state.unrealized_roi_bps, state.marked_at), (0, 0));
+    assert_eq!(fx.signal(id).unwrap().unrealized_roi_bps, 0);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn performance_index_compounds_settlements() {
+    let mut fx = OracleFixture::new();
+    assert_eq!(fx.oracle_state().performance_index, 10_000);
+    let win = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+    let loss = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+
+    fx.close(win, 2_000).unwrap();
+    assert_eq!(fx.oracle_state().performance_index, 20_000);
+    fx.close(loss, 750).unwrap();
+    assert_eq!(fx.oracle_state().performance_index, 15_000);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn model_portfolio_sizes_and_caps_positions() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let ix = instructions::init_model_portfolio(&authority, 10_000, 0);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::InvalidPortfolio));
+    fx.send(&[instructions::init_model_portfolio(&authority, 10_000, 2)], &[]).unwrap();
+
+    let ids: Vec<u64> = (0..3).map(|_| fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap()).collect();
+    let enters: Vec<_> = ids.iter().map(|id| instructions::portfolio_enter(*id)).collect();
+    fx.send(&enters, &[]).unwrap();
+    let ix = instructions::portfolio_enter(ids[2] + 1);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::PortfolioUpToDate));
+
+    let portfolio: ModelPortfolio = fx.account(&pda::model_portfolio()).unwrap();
+    assert_eq!((portfolio.positions.len(), portfolio.skipped, portfolio.cash), (2, 1, 0));
+
+    // Halving one position takes NAV to 7_500, a 25% drawdown
+    let ix = instructions::portfolio_exit(&pda::signal(ids[0]));
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::SignalStillOpen));
+    fx.close(ids[0], 500).unwrap();
+    fx.send(&[instructions::portfolio_exit(&pda::signal(ids[0]))], &[]).unwrap();
+    let portfolio: ModelPortfolio = fx.account(&pda::model_portfolio()).unwrap();
+    assert_eq!((portfolio.nav, portfolio.cash, portfolio.max_drawdown_bps), (7_500, 2_500, 2_500));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn benchmarks_need_a_configured_pool() {
+    let mut fx = OracleFixture::new();
+    let payer = fx.authority.pubkey();
+    let id = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+    let signal = pda::signal(id);
+    let pool = instructions::PoolAccounts {
+        pool: Pubkey::new_unique(),
+        mint_a: fx.create_mint(1_000_000, 9),
+        mint_b: fx.create_mint(1_000_000, 6),
+        vault_a: Pubkey::new_unique(),
+        vault_b: Pubkey::new_unique(),
+    };
+
+    for benchmark in [0, oracle::BENCHMARK_COUNT as u8] {
+        let ix = instructions::record_benchmark(&payer, &signal, benchmark, &pool);
+        assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::InvalidBenchmark));
+    }
+    assert_eq!(fx.signal(id).unwrap().closed_at, 0);
+    fx.close(id, 1_100).unwrap();
+    assert_eq!(fx.signal(id).unwrap().closed_at, fx.clock().unix_timestamp);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn vault_derisks_past_its_drawdown_threshold() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let executor = fx.funded_keypair(10);
+    let base_mint = fx.create_mint(1_000_000, 6);
+    fx.send(&[instructions::initialize_vault(&authority, &base_mint, executor.pubkey())], &[]).unwrap();
+    let update = oracle::ConfigUpdate {
+        vault_max_drawdown_bps: Some(2_000),
+        ..Default::default()
+    };
+    fx.send(&[instructions::update_config(&authority, update)], &[]).unwrap();
+    let vault_base_account = fx.create_token_account(&base_mint, &pda::vault(), 10_000);
+    let report = |fx: &mut OracleFixture, nav| {
+        fx.set_token_balance(&vault_base_account, nav);
+        let ix = instructions::report_vault_nav(&executor.pubkey(), &vault_base_account, None, &[], nav);
+        fx.send(&[ix], &[&executor]).unwrap();
+    };
+
+    let ix = instructions::report_vault_nav(&authority, &vault_base_account, None, &[], 10_000);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::Unauthorized));
+    for nav in [10_000, 8_500] {
+        report(&mut fx, nav);
+    }
+    let vault: Vault = fx.account(&pda::vault()).unwrap();
+    assert_eq!((vault.peak_nav, vault.derisked), (10_000, false));
+    report(&mut fx, 8_000);
+    assert!(fx.account::<Vault>(&pda::vault()).unwrap().derisked);
+
+    fx.send(&[instructions::reset_vault_derisk(&authority)], &[]).unwrap();
+    let vault: Vault = fx.account(&pda::vault()).unwrap();
+    assert_eq!((vault.peak_nav, vault.derisked), (8_000, false));
+    let ix = instructions::reset_vault_derisk(&authority);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::VaultNotDerisked));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn access_claims_precede_the_reveal() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let subscriber = fx.funded_keypair(10);
+    let id = fx.oracle_state().total_signals;
+    let params = signal_params(Pubkey::new_unique(), 1_000);
+    fx.send(&[instructions::publish_signal_with_proof(&authority, id, false, params, [9; 32])], &[]).unwrap();
+    let signal = pda::signal(id);
+
+    let claim = instructions::claim_access(&subscriber.pubkey(), &signal);
+    assert!(fx.send(std::slice::from_ref(&claim), &[&subscriber]).is_err());
+    fx.send(&[instructions::subscribe(&subscriber.pubkey(), 0, 1, false, false)], &[&subscriber]).unwrap();
+    fx.send(std::slice::from_ref(&claim), &[&subscriber]).unwrap();
+    let receipt: AccessClaim = fx.account(&pda::access_claim(&signal, &subscriber.pubkey())).unwrap();
+    assert_eq!((receipt.subscriber, receipt.slot), (subscriber.pubkey(), fx.clock().slot));
+
+    let late = fx.funded_keypair(10);
+    fx.send(&[instructions::subscribe(&late.pubkey(), 0, 1, false, false)], &[&late]).unwrap();
+    fx.send(&[instructions::reveal_reasoning(&authority, &signal)], &[]).unwrap();
+    let ix = instructions::claim_access(&late.pubkey(), &signal);
+    assert!(is_oracle_error(&fx.send(&[ix], &[&late]), OracleError::ReasoningAlreadyRevealed));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn access_logs_need_a_reasoning_commitment() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let id = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+    let signal = pda::signal(id);
+
+    let ix = instructions::log_access(&authority, &signal, [7; 32]);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::NoReasoningCommitment));
+    let outsider = fx.funded_keypair(1);
+    let ix = instructions::log_access(&outsider.pubkey(), &signal, [7; 32]);
+    assert!(is_oracle_error(&fx.send(&[ix], &[&outsider]), OracleError::Unauthorized));
+
+    let id = fx.oracle_state().total_signals;
+    let params = signal_params(Pubkey::new_unique(), 1_000);
+    fx.send(&[instructions::publish_signal_with_proof(&authority, id, false, params, [9; 32])], &[]).unwrap();
+    fx.send(&[instructions::log_access(&authority, &pda::signal(id), [7; 32])], &[]).unwrap();
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn allowlisted_tiers_admit_approved_wallets_up_to_capacity() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let (approved, other) = (fx.funded_keypair(10), fx.funded_keypair(10));
+    fx.send(&[instructions::set_tier_allowlist(&authority, 1, 1)], &[]).unwrap();
+    fx.send(&[instructions::allowlist_add(&authority, 1, approved.pubkey())], &[]).unwrap();
+    let ix = instructions::allowlist_add(&authority, 1, other.pubkey());
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::AllowlistFull));
+
+    let ix = instructions::subscribe(&other.pubkey(), 1, 1, false, false);
+    assert!(is_oracle_error(&fx.send(&[ix], &[&other]), OracleError::NotAllowlisted));
+    fx.send(&[instructions::subscribe(&approved.pubkey(), 1, 1, true, false)], &[&approved]).unwrap();
+    fx.send(&[instructions::subscribe(&other.pubkey(), 0, 1, false, false)], &[&other]).unwrap();
+
+    fx.send(&[instructions::allowlist_remove(&authority, 1, approved.pubkey())], &[]).unwrap();
+    let allowlist: TierAllowlist = fx.account(&pda::tier_allowlist(1)).unwrap();
+    assert_eq!((allowlist.capacity, allowlist.count), (1, 0));
+    assert!(fx.send(&[instructions::subscribe(&approved.pubkey(), 1, 1, true, false)], &[&approved]).is_err());
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn subscriptions_transfer_after_a_cooldown() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let (alice, bob, carol) = (fx.funded_keypair(10), fx.funded_keypair(10), fx.funded_keypair(10));
+    fx.send(&[instructions::subscribe(&alice.pubkey(), 0, 1, false, false)], &[&alice]).unwrap();
+    let expires_at = fx.account::<Subscription>(&pda::subscription(&alice.pubkey())).unwrap().expires_at;
+
+    let ix = instructions::transfer_subscription(&alice.pubkey(), &bob.pubkey(), 0, false, false);
+    assert!(is_oracle_error(&fx.send(&[ix], &[&alice]), OracleError::TransfersDisabled));
+    let update = oracle::ConfigUpdate {
+        subscription_transfer_cooldown_secs: Some(24 * 60 * 60),
+        ..Default::default()
+    };
+    fx.send(&[instructions::update_config(&authority, update)], &[]).unwrap();
+    let ix = instructions::transfer_subscription(&alice.pubkey(), &bob.pubkey(), 0, false, false);
+    fx.send(&[ix], &[&alice]).unwrap();
+
+    let moved: Subscription = fx.account(&pda::subscription(&bob.pubkey())).unwrap();
+    assert_eq!((moved.subscriber, moved.expires_at), (bob.pubkey(), expires_at));
+    let alice_sub: Subscription = fx.account(&pda::subscription(&alice.pubkey())).unwrap();
+    assert!(alice_sub.expires_at <= fx.clock().unix_timestamp);
+
+    let ix = instructions::transfer_subscription(&bob.pubkey(), &carol.pubkey(), 0, false, false);
+    assert!(is_oracle_error(&fx.send(std::slice::from_ref(&ix), &[&bob]), OracleError::TransferCooldown));
+    fx.warp(24 * 60 * 60);
+    fx.send(&[ix], &[&bob]).unwrap();
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn paused_subscriptions_keep_their_remaining_time() {
+    let mut fx = OracleFixture::new();
+    let subscriber = fx.funded_keypair(10);
+    let address = pda::subscription(&subscriber.pubkey());
+    fx.send(&[instructions::subscribe(&subscriber.pubkey(), 0, 1, false, false)], &[&subscriber]).unwrap();
+    let expires_at = fx.account::<Subscription>(&address).unwrap().expires_at;
+    let pause = instructions::pause_subscription(&subscriber.pubkey(), false);
+    let resume = instructions::resume_subscription(&subscriber.pubkey(), false);
+
+    fx.send(std::slice::from_ref(&pause), &[&subscriber]).unwrap();
+    assert_eq!(fx.account::<Subscription>(&address).unwrap().expires_at, fx.clock().unix_timestamp);
+    fx.warp(10 * 24 * 60 * 60);
+    fx.send(std::slice::from_ref(&resume), &[&subscriber]).unwrap();
+    assert_eq!(fx.account::<Subscription>(&address).unwrap().expires_at, expires_at + 10 * 24 * 60 * 60);
+
+    // Only MAX_PAUSE_SECS of a pause is credited back
+    fx.send(std::slice::from_ref(&pause), &[&subscriber]).unwrap();
+    fx.warp(oracle::MAX_PAUSE_SECS + 24 * 60 * 60);
+    fx.send(std::slice::from_ref(&resume), &[&subscriber]).unwrap();
+    let subscription: Subscription = fx.account(&address).unwrap();
+    assert_eq!(subscription.expires_at, expires_at + 10 * 24 * 60 * 60 + oracle::MAX_PAUSE_SECS);
+    assert!(is_oracle_error(&fx.send(&[pause], &[&subscriber]), OracleError::PauseLimitReached));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn vault_flows_are_priced_at_epoch_end() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let executor = fx.funded_keypair(10);
+    let base_mint = fx.create_mint(10_000_000, 6);
+    fx.send(&[instructions::initialize_vault(&authority, &base_mint, executor.pubkey())], &[]).unwrap();
+    let vault_base_account = fx.create_token_account(&base_mint, &pda::vault(), 0);
+    let (alice, bob) = (fx.funded_keypair(10), fx.funded_keypair(10));
+    let base = |fx: &mut OracleFixture, owner: &Pubkey| instructions::DepositAccounts {
+        base_mint,
+        owner_base_account: fx.create_token_account(&base_mint, owner, 5_000),
+        vault_base_account,
+        token_program: TOKEN_PROGRAM_ID,
+    };
+    let (alice_base, bob_base) = (base(&mut fx, &alice.pubkey()), base(&mut fx, &bob.pubkey()));
+    let report = |nav| instructions::report_vault_nav(&executor.pubkey(), &vault_base_account, None, &[], nav);
+
+    fx.send(&[instructions::request_vault_deposit(&alice.pubkey(), &alice_base, 1_000)], &[&alice]).unwrap();
+    let ix = instructions::process_vault_epoch(&authority, 0);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::EpochNotOver));
+    fx.warp(oracle::VAULT_EPOCH_SECS);
+    let ix = instructions::process_vault_epoch(&authority, 0);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::StaleVaultNav));
+    fx.send(&[report(1_000)], &[&executor]).unwrap();
+    fx.send(&[instructions::process_vault_epoch(&authority, 0)], &[]).unwrap();
+    fx.send(&[instructions::settle_vault_depositor(&alice_base, &alice.pubkey(), 0)], &[]).unwrap();
+    let depositor: VaultDepositor = fx.account(&pda::vault_depositor(&alice.pubkey())).unwrap();
+    assert_eq!(depositor.shares, 1_000);
+
+    // The vault doubles; Bob's deposit buys at the new price and Alice's half redeems at it
+    fx.send(&[instructions::request_vault_deposit(&bob.pubkey(), &bob_base, 1_000)], &[&bob]).unwrap();
+    fx.send(&[instructions::request_vault_withdrawal(&alice.pubkey(), 500)], &[&alice]).unwrap();
+    fx.set_token_balance(&vault_base_account, 3_000);
+    fx.warp(oracle::VAULT_EPOCH_SECS);
+    fx.send(&[report(3_000)], &[&executor]).unwrap();
+    fx.send(&[instructions::process_vault_epoch(&authority, 1)], &[]).unwrap();
+    let settle = [
+        instructions::settle_vault_depositor(&alice_base, &alice.pubkey(), 1),
+        instructions::settle_vault_depositor(&bob_base, &bob.pubkey(), 1),
+    ];
+    fx.send(&settle, &[]).unwrap();
+
+    let bob_shares = fx.account::<VaultDepositor>(&pda::vault_depositor(&bob.pubkey())).unwrap().shares;
+    assert_eq!(bob_shares, 500);
+    assert_eq!(fx.token_balance(&alice_base.owner_base_account), 5_000);
+    let vault: Vault = fx.account(&pda::vault()).unwrap();
+    assert_eq!((vault.total_shares, vault.owed_withdrawals), (1_000, 0));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn vault_flows_stop_at_epoch_end() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let executor = fx.funded_keypair(10);
+    let base_mint = fx.create_mint(10_000_000, 6);
+    fx.send(&[instructions::initialize_vault(&authority, &base_mint, executor.pubkey())], &[]).unwrap();
+    let vault_base_account = fx.create_token_account(&base_mint, &pda::vault(), 0);
+    let (alice, bob) = (fx.funded_keypair(10), fx.funded_keypair(10));
+    let base = |fx: &mut OracleFixture, owner: &Pubkey| instructions::DepositAccounts {
+        base_mint,
+        owner_base_account: fx.create_token_account(&base_mint, owner, 5_000),
+        vault_base_account,
+        token_program: TOKEN_PROGRAM_ID,
+    };
+    let (alice_base, bob_base) = (base(&mut fx, &alice.pubkey()), base(&mut fx, &bob.pubkey()));
+    let report = |nav| instructions::report_vault_nav(&executor.pubkey(), &vault_base_account, None, &[], nav);
+
+    fx.send(&[instructions::request_vault_deposit(&alice.pubkey(), &alice_base, 1_000)], &[&alice]).unwrap();
+    fx.warp(oracle::VAULT_EPOCH_SECS);
+    fx.send(&[report(1_000)], &[&executor]).unwrap();
+    fx.send(&[instructions::process_vault_epoch(&authority, 0)], &[]).unwrap();
+    fx.send(&[instructions::settle_vault_depositor(&alice_base, &alice.pubkey(), 0)], &[]).unwrap();
+
+    // The vault loses everything; once the NAV the epoch prices at is known, nothing more can queue into it
+    fx.set_token_balance(&vault_base_account, 0);
+    fx.warp(oracle::VAULT_EPOCH_SECS);
+    fx.send(&[report(0)], &[&executor]).unwrap();
+    let ix = instructions::request_vault_deposit(&bob.pubkey(), &bob_base, 1_000);
+    assert!(is_oracle_error(&fx.send(&[ix], &[&bob]), OracleError::VaultEpochClosing));
+    let ix = instructions::request_vault_withdrawal(&alice.pubkey(), 500);
+    assert!(is_oracle_error(&fx.send(&[ix], &[&alice]), OracleError::VaultEpochClosing));
+    fx.send(&[instructions::process_vault_epoch(&authority, 1)], &[]).unwrap();
+
+    // Alice's shares are now worth nothing, so Bob's deposit is handed back rather than buying them
+    fx.send(&[instructions::request_vault_deposit(&bob.pubkey(), &bob_base, 1_000)], &[&bob]).unwrap();
+    fx.warp(oracle::VAULT_EPOCH_SECS);
+    fx.send(&[report(1_000)], &[&executor]).unwrap();
+    fx.send(&[instructions::process_vault_epoch(&authority, 2)], &[]).unwrap();
+    fx.send(&[instructions::settle_vault_depositor(&bob_base, &bob.pubkey(), 2)], &[]).unwrap();
+    assert_eq!(fx.token_balance(&bob_base.owner_base_account), 5_000);
+    let bob: VaultDepositor = fx.account(&pda::vault_depositor(&bob.pubkey())).unwrap();
+    assert_eq!(bob.shares, 0);
+    let vault: Vault = fx.account(&pda::vault()).unwrap();
+    assert_eq!((vault.total_shares, vault.owed_withdrawals), (1_000, 0));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn followers_are_attributed_executions_by_shares_held() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let executor = fx.funded_keypair(10);
+    let base_mint = fx.create_mint(10_000_000, 6);
+    fx.send(&[instructions::initialize_vault(&authority, &base_mint, executor.pubkey())], &[]).unwrap();
+    let vault_base_account = fx.create_token_account(&base_mint, &pda::vault(), 0);
+    let (alice, bob) = (fx.funded_keypair(10), fx.funded_keypair(10));
+    let base = |fx: &mut OracleFixture, owner: &Pubkey| instructions::DepositAccounts {
+        base_mint,
+        owner_base_account: fx.create_token_account(&base_mint, owner, 5_000),
+        vault_base_account,
+        token_program: TOKEN_PROGRAM_ID,
+    };
+    let (alice_base, bob_base) = (base(&mut fx, &alice.pubkey()), base(&mut fx, &bob.pubkey()));
+    let report = |nav| instructions::report_vault_nav(&executor.pubkey(), &vault_base_account, None, &[], nav);
+    // Executions need a Jupiter route, so grow the vault's accumulators directly
+    let execute = |fx: &mut OracleFixture, entered: i128, realized_pnl: i128| {
+        let mut account = fx.svm.get_account(&pda::vault()).unwrap();
+        let mut vault = oracle::Vault::try_deserialize(&mut account.data.as_slice()).unwrap();
+        vault.entered_per_share += oracle::vault::per_share(entered, vault.total_shares);
+        vault.realized_pnl_per_share += oracle::vault::per_share(realized_pnl, vault.total_shares);
+        let mut data = Vec::new();
+        vault.try_serialize(&mut data).unwrap();
+        account.data[..data.len()].copy_from_slice(&data);
+        fx.svm.set_account(pda::vault(), account).unwrap();
+    };
+
+    fx.send(&[instructions::request_vault_deposit(&alice.pubkey(), &alice_base, 1_000)], &[&alice]).unwrap();
+    fx.warp(oracle::VAULT_EPOCH_SECS);
+    fx.send(&[report(1_000)], &[&executor]).unwrap();
+    fx.send(&[instructions::process_vault_epoch(&authority, 0)], &[]).unwrap();
+    fx.send(&[instructions::settle_vault_depositor(&alice_base, &alice.pubkey(), 0)], &[]).unwrap();
+
+    // Bob's queued deposit holds no shares yet, so this epoch's trades are all Alice's
+    fx.send(&[instructions::request_vault_deposit(&bob.pubkey(), &bob_base, 1_000)], &[&bob]).unwrap();
+    execute(&mut fx, 400, 100);
+    fx.warp(oracle::VAULT_EPOCH_SECS);
+    fx.send(&[report(2_000)], &[&executor]).unwrap();
+    fx.send(&[instructions::process_vault_epoch(&authority, 1)], &[]).unwrap();
+    let ix = instructions::accrue_vault_depositor(&bob.pubkey());
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::UnsettledVaultFlows));
+    fx.send(&[instructions::settle_vault_depositor(&bob_base, &bob.pubkey(), 1)], &[]).unwrap();
+
+    // Both now hold 1,000 of 2,000 shares
+    execute(&mut fx, 600, -200);
+    let accrue = [
+        instructions::accrue_vault_depositor(&alice.pubkey()),
+        instructions::accrue_vault_depositor(&bob.pubkey()),
+    ];
+    fx.send(&accrue, &[]).unwrap();
+    let alice: VaultDepositor = fx.account(&pda::vault_depositor(&alice.pubkey())).unwrap();
+    let bob: VaultDepositor = fx.account(&pda::vault_depositor(&bob.pubkey())).unwrap();
+    assert_eq!((alice.entered, alice.realized_pnl), (700, 0));
+    assert_eq!((bob.entered, bob.realized_pnl), (300, -100));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn vault_lst_is_valued_at_the_pool_exchange_rate() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let executor = fx.funded_keypair(10);
+    // SOL at 2.0 in the vault's base; the pool's mints share decimals, so a lamport is worth 2 base units
+    let sol_mint = solana_sdk::pubkey!("So11111111111111111111111111111111111111112");
+    fx.create_mint_at(sol_mint, u64::MAX, QUOTE_DECIMALS);
+    let sol_pool = fx.create_pool(&sol_mint, 2_000_000_000, 1_000_000);
+    let base_mint = QUOTE_MINT;
+    fx.send(&[instructions::initialize_vault(&authority, &base_mint, executor.pubkey())], &[]).unwrap();
+    let lst_mint = fx.create_mint(1_000_000, 9);
+    let vault_lst_account = fx.create_token_account(&lst_mint, &pda::vault(), 2_000);
+
+    // A stake pool whose LST trades at 1.1 SOL
+    let stake_pool = Pubkey::new_unique();
+    let mut data = vec![0; 300];
+    data[0] = 1;
+    data[162..194].copy_from_slice(lst_mint.as_ref());
+    data[258..266].copy_from_slice(&1_100u64.to_le_bytes());
+    data[266..274].copy_from_slice(&1_000u64.to_le_bytes());
+    data[274..282].copy_from_slice(&fx.clock().epoch.to_le_bytes());
+    let lamports = fx.svm.minimum_balance_for_rent_exemption(data.len());
+    let pool_account = Account {
+        lamports,
+        data,
+        owner: oracle::vault::STAKE_POOL_PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    fx.svm.set_account(stake_pool, pool_account).unwrap();
+
+    let ix = instructions::stake_idle_sol(&executor.pubkey(), &vault_lst_account, &stake_pool, 1_000, Vec::new());
+    assert!(is_oracle_error(&fx.send(&[ix], &[&executor]), OracleError::StakingDisabled));
+    let update = oracle::ConfigUpdate {
+        vault_stake_pool: Some(stake_pool),
+        benchmark_pools: Some([sol_pool.pool, Pubkey::default()]),
+        ..Default::default()
+    };
+    fx.send(&[instructions::update_config(&authority, update)], &[]).unwrap();
+
+    let other_lst_account = fx.create_token_account(&base_mint, &pda::vault(), 2_000);
+    let ix = instructions::sync_vault_lst(&other_lst_account, &stake_pool);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::InvalidStakePool));
+    fx.send(&[instructions::sync_vault_lst(&vault_lst_account, &stake_pool)], &[]).unwrap();
+    let vault: Vault = fx.account(&pda::vault()).unwrap();
+    assert_eq!((vault.lst_amount, vault.lst_lamports), (2_000, 2_200));
+
+    // NAV counts the staked SOL at the SOL benchmark pool, and only at a recent exchange rate
+    let vault_base_account = fx.create_token_account(&base_mint, &pda::vault(), 600);
+    let report =
+        |sol_pool| instructions::report_vault_nav(&executor.pubkey(), &vault_base_account, sol_pool, &[], 5_000);
+    assert!(is_oracle_error(&fx.send(&[report(None)], &[&executor]), OracleError::SolPoolRequired));
+    fx.send(&[report(Some(&sol_pool))], &[&executor]).unwrap();
+    fx.warp(oracle::MAX_LST_VALUE_AGE_SECS + 1);
+    assert!(is_oracle_error(&fx.send(&[report(Some(&sol_pool))], &[&executor]), OracleError::StaleLstValue));
+    fx.send(&[instructions::sync_vault_lst(&vault_lst_account, &stake_pool)], &[]).unwrap();
+    fx.send(&[report(Some(&sol_pool))], &[&executor]).unwrap();
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn vault_emergency_opens_immediate_withdrawals() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let executor = fx.funded_keypair(10);
+    let base_mint = fx.create_mint(10_000_000, 6);
+    fx.send(&[instructions::initialize_vault(&authority, &base_mint, executor.pubkey())], &[]).unwrap();
+    let vault_base_account = fx.create_token_account(&base_mint, &pda::vault(), 0);
+    let (alice, bob) = (fx.funded_keypair(10), fx.funded_keypair(10));
+    let base = |fx: &mut OracleFixture, owner: &Pubkey| instructions::DepositAccounts {
+        base_mint,
+        owner_base_account: fx.create_token_account(&base_mint, owner, 5_000),
+        vault_base_account,
+        token_program: TOKEN_PROGRAM_ID,
+    };
+    let (alice_base, bob_base) = (base(&mut fx, &alice.pubkey()), base(&mut fx, &bob.pubkey()));
+    let report = |nav| instructions::report_vault_nav(&executor.pubkey(), &vault_base_account, None, &[], nav);
+
+    fx.send(&[instructions::request_vault_deposit(&alice.pubkey(), &alice_base, 1_000)], &[&alice]).unwrap();
+    fx.warp(oracle::VAULT_EPOCH_SECS);
+    fx.send(&[report(1_000)], &[&executor]).unwrap();
+    fx.send(&[instructions::process_vault_epoch(&authority, 0)], &[]).unwrap();
+    fx.send(&[instructions::settle_vault_depositor(&alice_base, &alice.pubkey(), 0)], &[]).unwrap();
+    fx.send(&[instructions::request_vault_deposit(&bob.pubkey(), &bob_base, 500)], &[&bob]).unwrap();
+    fx.send(&[instructions::request_vault_withdrawal(&alice.pubkey(), 200)], &[&alice]).unwrap();
+
+    let ix = instructions::emergency_withdraw(&alice.pubkey(), &alice_base, 1_000);
+    assert!(is_oracle_error(&fx.send(&[ix], &[&alice]), OracleError::VaultNotInEmergency));
+    let ix = instructions::declare_vault_emergency(&executor.pubkey());
+    assert!(is_oracle_error(&fx.send(&[ix], &[&executor]), OracleError::Unauthorized));
+    fx.send(&[instructions::declare_vault_emergency(&authority)], &[]).unwrap();
+    let ix = instructions::request_vault_deposit(&bob.pubkey(), &bob_base, 500);
+    assert!(is_oracle_error(&fx.send(&[ix], &[&bob]), OracleError::VaultInEmergency));
+
+    // Bob's queued deposit comes straight back; Alice's queued withdrawal rejoins her shares
+    fx.send(&[instructions::emergency_withdraw(&bob.pubkey(), &bob_base, 0)], &[&bob]).unwrap();
+    assert_eq!(fx.token_balance(&bob_base.owner_base_account), 5_000);
+    let ix = instructions::emergency_withdraw(&alice.pubkey(), &alice_base, 500);
+    assert!(is_oracle_error(&fx.send(&[ix], &[&alice]), OracleError::StaleEmergencyNav));
+
+    // 400 of the vault's 1,000 is still lent out, so 700 shares are owed 100 past what is liquid
+    fx.set_token_balance(&vault_base_account, 600);
+    let mut account = fx.svm.get_account(&pda::vault()).unwrap();
+    let mut vault = oracle::Vault::try_deserialize(&mut account.data.as_slice()).unwrap();
+    vault.lent_amount = 400;
+    let mut data = Vec::new();
+    vault.try_serialize(&mut data).unwrap();
+    account.data[..data.len()].copy_from_slice(&data);
+    fx.svm.set_account(pda::vault(), account).unwrap();
+    fx.send(&[report(1_000)], &[&executor]).unwrap();
+    fx.send(&[instructions::emergency_withdraw(&alice.pubkey(), &alice_base, 700)], &[&alice]).unwrap();
+    assert_eq!(fx.token_balance(&alice_base.owner_base_account), 4_600);
+    let depositor: VaultDepositor = fx.account(&pda::vault_depositor(&alice.pubkey())).unwrap();
+    assert_eq!((depositor.shares, depositor.emergency_owed), (300, 100));
+
+    // Recalling the loan brings the rest in, and the owed part is paid with it
+    fx.set_token_balance(&vault_base_account, 400);
+    fx.send(&[instructions::emergency_withdraw(&alice.pubkey(), &alice_base, 300)], &[&alice]).unwrap();
+    assert_eq!(fx.token_balance(&alice_base.owner_base_account), 5_000);
+    let vault: Vault = fx.account(&pda::vault()).unwrap();
+    assert_eq!((vault.total_shares, vault.queued_deposits, vault.queued_withdrawal_shares), (0, 0, 0));
+    assert_eq!(vault.owed_withdrawals, 0);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn allocation_caps_are_set_per_risk_level() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    assert_eq!(fx.config().max_allocation_bps, oracle::DEFAULT_MAX_ALLOCATION_BPS);
+
+    let update = oracle::ConfigUpdate {
+        max_allocation_bps: Some([1_000, 500, 300, 10_001]),
+        ..Default::default()
+    };
+    assert!(is_oracle_error(
+        &fx.send(&[instructions::update_config(&authority, update)], &[]),
+        OracleError::InvalidBps
+    ));
+    let update = oracle::ConfigUpdate {
+        max_allocation_bps: Some([2_000, 1_000, 500, 0]),
+        ..Default::default()
+    };
+    fx.send(&[instructions::update_config(&authority, update)], &[]).unwrap();
+    assert_eq!(fx.config().max_allocation_bps, [2_000, 1_000, 500, 0]);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn rebalance_limits_are_configurable() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let config = fx.config();
+    assert_eq!(config.rebalance_band_bps, oracle::DEFAULT_REBALANCE_BAND_BPS);
+    assert_eq!(config.max_rebalance_bps, oracle::DEFAULT_MAX_REBALANCE_BPS);
+
+    let update = oracle::ConfigUpdate {
+        max_rebalance_bps: Some(10_001),
+        ..Default::default()
+    };
+    assert!(is_oracle_error(
+        &fx.send(&[instructions::update_config(&authority, update)], &[]),
+        OracleError::InvalidBps
+    ));
+    let update = oracle::ConfigUpdate {
+        rebalance_band_bps: Some(250),
+        max_rebalance_bps: Some(500),
+        ..Default::default()
+    };
+    fx.send(&[instructions::update_config(&authority, update)], &[]).unwrap();
+    let config = fx.config();
+    assert_eq!((config.rebalance_band_bps, config.max_rebalance_bps), (250, 500));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn performance_fees_crystallize_above_the_high_water_mark() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let executor = fx.funded_keypair(10);
+    let base_mint = fx.create_mint(10_000_000, 6);
+    fx.send(&[instructions::initialize_vault(&authority, &base_mint, executor.pubkey())], &[]).unwrap();
+    let vault_base_account = fx.create_token_account(&base_mint, &pda::vault(), 0);
+    let update = oracle::ConfigUpdate {
+        vault_performance_fee_bps: Some(2_000),
+        ..Default::default()
+    };
+    fx.send(&[instructions::update_config(&authority, update)], &[]).unwrap();
+    let alice = fx.funded_keypair(10);
+    let alice_base = instructions::DepositAccounts {
+        base_mint,
+        owner_base_account: fx.create_token_account(&base_mint, &alice.pubkey(), 5_000),
+        vault_base_account,
+        token_program: TOKEN_PROGRAM_ID,
+    };
+    let treasury_base = instructions::DepositAccounts {
+        owner_base_account: fx.create_token_account(&base_mint, &pda::treasury(), 0),
+        ..alice_base
+    };
+    let report = |nav| instructions::report_vault_nav(&executor.pubkey(), &vault_base_account, None, &[], nav);
+    let close_epoch = |fx: &mut OracleFixture, epoch: u64, nav: u64| {
+        fx.warp(oracle::VAULT_EPOCH_SECS);
+        fx.set_token_balance(&vault_base_account, nav);
+        fx.send(&[report(nav)], &[&executor]).unwrap();
+        fx.send(&[instructions::crystallize_vault_fees(&treasury_base)], &[]).unwrap();
+        fx.send(&[instructions::process_vault_epoch(&authority, epoch)], &[]).unwrap();
+    };
+
+    fx.send(&[instructions::request_vault_deposit(&alice.pubkey(), &alice_base, 1_000)], &[&alice]).unwrap();
+    fx.warp(oracle::VAULT_EPOCH_SECS);
+    fx.send(&[report(1_000)], &[&executor]).unwrap();
+    let ix = instructions::process_vault_epoch(&authority, 0);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::FeesNotCrystallized));
+    fx.send(&[instructions::crystallize_vault_fees(&treasury_base)], &[]).unwrap();
+    fx.send(&[instructions::process_vault_epoch(&authority, 0)], &[]).unwrap();
+    fx.send(&[instructions::settle_vault_depositor(&alice_base, &alice.pubkey(), 0)], &[]).unwrap();
+
+    // 500 gained above the 1.0 mark pays 20% and moves the mark to 1.4; a deposit
+    // queued after the NAV report can't shrink the equity it is charged on
+    fx.set_token_balance(&vault_base_account, 1_500);
+    fx.warp(oracle::VAULT_EPOCH_SECS);
+    fx.send(&[report(1_500)], &[&executor]).unwrap();
+    let ix = instructions::request_vault_deposit(&alice.pubkey(), &alice_base, 1_000);
+    assert!(is_oracle_error(&fx.send(&[ix], &[&alice]), OracleError::VaultEpochClosing));
+    fx.send(&[instructions::crystallize_vault_fees(&treasury_base)], &[]).unwrap();
+    fx.send(&[instructions::process_vault_epoch(&authority, 1)], &[]).unwrap();
+    assert_eq!(fx.token_balance(&treasury_base.owner_base_account), 100);
+    let vault: Vault = fx.account(&pda::vault()).unwrap();
+    assert_eq!((vault.nav, vault.fees_paid), (1_400, 100));
+    assert_eq!(vault.high_water_mark, 14 * oracle_client::types::PER_SHARE_SCALE as u128 / 10);
+
+    // A recovery that stays below the mark pays nothing
+    close_epoch(&mut fx, 2, 1_300);
+    assert_eq!(fx.token_balance(&treasury_base.owner_base_account), 100);
+    let ix = instructions::crystallize_vault_fees(&treasury_base);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::EpochNotOver));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn vault_nav_reports_are_held_to_measured_balances() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let executor = fx.funded_keypair(10);
+    let base_mint = fx.create_mint(10_000_000, 6);
+    fx.send(&[instructions::initialize_vault(&authority, &base_mint, executor.pubkey())], &[]).unwrap();
+    let vault_base_account = fx.create_token_account(&base_mint, &pda::vault(), 0);
+    let alice = fx.funded_keypair(10);
+    let alice_base = instructions::DepositAccounts {
+        base_mint,
+        owner_base_account: fx.create_token_account(&base_mint, &alice.pubkey(), 5_000),
+        vault_base_account,
+        token_program: TOKEN_PROGRAM_ID,
+    };
+    let report = |nav| instructions::report_vault_nav(&executor.pubkey(), &vault_base_account, None, &[], nav);
+
+    // The vault holds Alice's 1,000, and a report may only stray from that by the 1% tolerance
+    fx.send(&[instructions::request_vault_deposit(&alice.pubkey(), &alice_base, 1_000)], &[&alice]).unwrap();
+    fx.warp(oracle::VAULT_EPOCH_SECS);
+    assert!(is_oracle_error(&fx.send(&[report(1_020)], &[&executor]), OracleError::NavOutOfRange));
+    fx.send(&[report(1_000)], &[&executor]).unwrap();
+    fx.send(&[instructions::process_vault_epoch(&authority, 0)], &[]).unwrap();
+    fx.send(&[instructions::settle_vault_depositor(&alice_base, &alice.pubkey(), 0)], &[]).unwrap();
+    fx.send(&[report(1_000)], &[&executor]).unwrap();
+
+    // Doubling the share price in one report is past the default 20% step, even when measured
+    fx.set_token_balance(&vault_base_account, 2_000);
+    assert!(is_oracle_error(&fx.send(&[report(2_000)], &[&executor]), OracleError::NavChangeTooLarge));
+    let update = oracle::ConfigUpdate {
+        vault_max_nav_change_bps: Some(10_000),
+        ..Default::default()
+    };
+    fx.send(&[instructions::update_config(&authority, update)], &[]).unwrap();
+    fx.send(&[report(2_000)], &[&executor]).unwrap();
+    let vault: Vault = fx.account(&pda::vault()).unwrap();
+    assert_eq!(vault.nav_share_price, 2 * oracle_client::types::PER_SHARE_SCALE as u128);
+
+    // An open position the report leaves out can't be valued
+    let mut account = fx.svm.get_account(&pda::vault()).unwrap();
+    let mut vault = oracle::Vault::try_deserialize(&mut account.data.as_slice()).unwrap();
+    vault.open_positions = 1;
+    let mut data = Vec::new();
+    vault.try_serialize(&mut data).unwrap();
+    account.data[..data.len()].copy_from_slice(&data);
+    fx.svm.set_account(pda::vault(), account).unwrap();
+    assert!(is_oracle_error(&fx.send(&[report(2_000)], &[&executor]), OracleError::InvalidNavAccounts));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn verified_exits_use_the_entry_pool() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let token = fx.create_mint(1_000_000_000, QUOTE_DECIMALS);
+    let id = fx.publish(signal_params(token, 1_000_000_000)).unwrap();
+    let signal = pda::signal(id);
+    let liquidity = oracle::MIN_POOL_LIQUIDITY * 10u64.pow(QUOTE_DECIMALS as u32);
+
+    // A pool too thin to price against can't verify the entry
+    let thin = fx.create_pool(&token, 1_000_000_000, liquidity - 1);
+    let ix = instructions::verify_entry_price(&authority, &signal, &thin);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::PoolLiquidityTooLow));
+
+    let pool = fx.create_pool(&token, 1_000_000_000, liquidity);
+    fx.send(&[instructions::verify_entry_price(&authority, &signal, &pool)], &[]).unwrap();
+    assert_eq!(fx.signal(id).unwrap().price_pool, pool.pool);
+
+    // Another pool quoting a made-up exit can't settle it
+    let other = fx.create_pool(&token, 2_000_000_000, liquidity);
+    let ix = instructions::close_signal_verified(&authority, &signal, &other, 2_000_000_000, &authority, &token);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::ExitPoolMismatch));
+
+    fx.set_pool_price(&pool, 1_500_000_000);
+    let ix = instructions::close_signal_verified(&authority, &signal, &pool, 1_500_000_000, &authority, &token);
+    fx.send(&[ix], &[]).unwrap();
+    assert!(fx.signal(id).unwrap().exit_verified);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn added_entries_unset_entry_verification() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let token = fx.create_mint(1_000_000_000, QUOTE_DECIMALS);
+    let id = fx.publish(signal_params(token, 1_000_000_000)).unwrap();
+    let signal = pda::signal(id);
+    let liquidity = oracle::MIN_POOL_LIQUIDITY * 10u64.pow(QUOTE_DECIMALS as u32);
+    let pool = fx.create_pool(&token, 1_000_000_000, liquidity);
+    fx.send(&[instructions::verify_entry_price(&authority, &signal, &pool)], &[]).unwrap();
+    assert!(fx.signal(id).unwrap().entry_verified);
+
+    // The averaged entry was never checked against the pool
+    fx.send(&[instructions::add_entry(&authority, &signal, 100_000_000, 5_000)], &[]).unwrap();
+    let scaled = fx.signal(id).unwrap();
+    assert_eq!(scaled.entry_price, 550_000_000);
+    assert!(!scaled.entry_verified);
+    assert_eq!(scaled.price_pool, pool.pool);
+
+    // The exit stays pinned to the pool the publish entry was checked against
+    let other = fx.create_pool(&token, 1_500_000_000, liquidity);
+    let ix = instructions::close_signal_verified(&authority, &signal, &other, 1_500_000_000, &authority, &token);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::ExitPoolMismatch));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn market_caps_are_verified_once_at_the_verified_pool() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let token = fx.create_mint(1_000_000_000, QUOTE_DECIMALS);
+    let id = fx.publish(signal_params(token, 1_000_000_000)).unwrap();
+    let signal = pda::signal(id);
+    let liquidity = oracle::MIN_POOL_LIQUIDITY * 10u64.pow(QUOTE_DECIMALS as u32);
+    let pool = fx.create_pool(&token, 1_000_000_000, liquidity);
+
+    // Nothing to price against until the entry is verified
+    let ix = instructions::verify_market_cap(&authority, &signal, &pool);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::PoolNotVerified));
+    fx.send(&[instructions::verify_entry_price(&authority, &signal, &pool)], &[]).unwrap();
+
+    // A pool quoting a friendlier price can't stand in for the verified one
+    let other = fx.create_pool(&token, 1_100_000_000, liquidity);
+    let ix = instructions::verify_market_cap(&authority, &signal, &other);
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::PoolNotVerified));
+
+    fx.send(&[instructions::verify_market_cap(&authority, &signal, &pool)], &[]).unwrap();
+    let market_cap: SignalMarketCap = fx.account(&pda::signal_market_cap(&signal)).unwrap();
+    assert_eq!((market_cap.pool, market_cap.price), (pool.pool, 1_000_000_000));
+
+    // The verdict can't be rewritten once the price moves
+    fx.set_pool_price(&pool, 2_000_000_000);
+    fx.warp(1);
+    assert!(fx.send(&[instructions::verify_market_cap(&authority, &signal, &pool)], &[]).is_err());
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn executions_are_floored_at_the_verified_pool() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let executor = fx.funded_keypair(10);
+    let token = fx.create_mint(1_000_000_000, QUOTE_DECIMALS);
+    let id = fx.publish(signal_params(token, 2_000_000_000)).unwrap();
+    let signal = pda::signal(id);
+    let liquidity = oracle::MIN_POOL_LIQUIDITY * 10u64.pow(QUOTE_DECIMALS as u32);
+    let pool = fx.create_pool(&token, 2_000_000_000, liquidity);
+    fx.send(&[instructions::verify_entry_price(&authority, &signal, &pool)], &[]).unwrap();
+
+    fx.send(&[instructions::initialize_vault(&authority, &QUOTE_MINT, executor.pubkey())], &[]).unwrap();
+    let swap = instructions::SwapAccounts {
+        vault_base_account: fx.create_token_account(&QUOTE_MINT, &pda::vault(), 10_000),
+        vault_token_account: fx.create_token_account(&token, &pda::vault(), 0),
+    };
+    let salt = [7; 32];
+    let commit_hash = oracle::vault::execution_commitment(&signal, ExecutionSide::Entry, 1_000, &salt);
+    let slot = fx.clock().slot;
+    fx.send(&[instructions::schedule_execution(&authority, &signal, commit_hash, slot, slot + 100)], &[]).unwrap();
+
+    // Executions price against the signal's verified pool, not one the executor picks
+    let other = fx.create_pool(&token, 1_000_000_000, liquidity);
+    let ix = instructions::execute_signal(
+        &executor.pubkey(),
+        &signal,
+        &swap,
+        &other,
+        ExecutionSide::Entry,
+        1_000,
+        0,
+        salt,
+        Vec::new(),
+        Vec::new(),
+        false,
+    );
+    assert!(is_oracle_error(&fx.send(&[ix], &[&executor]), OracleError::PoolNotVerified));
+
+    // With a zero quote the floor is still the pool's: 1_000 base buys 500 tokens at 2.0
+    let spot = oracle::vault::spot_out(ExecutionSide::Entry, 1_000, 2_000_000_000, QUOTE_DECIMALS, QUOTE_DECIMALS);
+    assert_eq!(spot, Some(500));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn short_signals_only_execute_on_perps_at_the_verified_pool() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let executor = fx.funded_keypair(10);
+    let token = fx.create_mint(1_000_000_000, QUOTE_DECIMALS);
+    let mut params = signal_params(token, 2_000_000_000);
+    params.direction = SignalDirection::Short;
+    let signal = pda::signal(fx.publish(params).unwrap());
+    let liquidity = oracle::MIN_POOL_LIQUIDITY * 10u64.pow(QUOTE_DECIMALS as u32);
+    let pool = fx.create_pool(&token, 2_000_000_000, liquidity);
+    fx.send(&[instructions::verify_entry_price(&authority, &signal, &pool)], &[]).unwrap();
+    fx.send(&[instructions::initialize_vault(&authority, &QUOTE_MINT, executor.pubkey())], &[]).unwrap();
+    fx.send(&[instructions::set_perp_params(&authority, &signal, 0, 3)], &[]).unwrap();
+    let swap = instructions::SwapAccounts {
+        vault_base_account: fx.create_token_account(&QUOTE_MINT, &pda::vault(), 10_000),
+        vault_token_account: fx.create_token_account(&token, &pda::vault(), 0),
+    };
+
+    // The short is priced off the verified pool, not one the executor picks
+    let other = fx.create_pool(&token, 1_000, liquidity);
+    let ix = instructions::execute_short(
+        &executor.pubkey(),
+        &signal,
+        &QUOTE_MINT,
+        &swap.vault_base_account,
+        &other,
+        ExecutionSide::Entry,
+        1_000,
+        Vec::new(),
+    );
+    assert!(is_oracle_error(&fx.send(&[ix], &[&executor]), OracleError::PoolNotVerified));
+
+    // And the vault never buys it spot
+    let salt = [7; 32];
+    let commit_hash = oracle::vault::execution_commitment(&signal, ExecutionSide::Entry, 1_000, &salt);
+    let slot = fx.clock().slot;
+    fx.send(&[instructions::schedule_execution(&authority, &signal, commit_hash, slot, slot + 100)], &[]).unwrap();
+    let ix = instructions::execute_signal(
+        &executor.pubkey(),
+        &signal,
+        &swap,
+        &pool,
+        ExecutionSide::Entry,
+        1_000,
+        0,
+        salt,
+        Vec::new(),
+        Vec::new(),
+        false,
+    );
+    assert!(is_oracle_error(&fx.send(&[ix], &[&executor]), OracleError::NotALongSignal));
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn max_leverage_is_bounded() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+
+    for bad in [9_999, oracle::MAX_LEVERAGE_BPS + 1] {
+        let update = oracle::ConfigUpdate {
+            max_leverage_bps: Some([30_000, 20_000, 10_000, bad]),
+            ..Default::default()
+        };
+        assert!(is_oracle_error(
+            &fx.send(&[instructions::update_config(&authority, update)], &[]),
+            OracleError::InvalidBps
+        ));
+    }
+    let update = oracle::ConfigUpdate {
+        max_leverage_bps: Some([oracle::MAX_LEVERAGE_BPS, 50_000, 20_000, 10_000]),
+        ..Default::default()
+    };
+    fx.send(&[instructions::update_config(&authority, update)], &[]).unwrap();
+    assert_eq!(fx.config().max_leverage_bps, [oracle::MAX_LEVERAGE_BPS, 50_000, 20_000, 10_000]);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn subscribing_mints_the_pass() {
+    let mut fx = OracleFixture::new();
+    let subscriber = fx.funded_keypair(10);
+    let owner = subscriber.pubkey();
+    fx.send(&[instructions::initialize_pass_mint(&fx.authority.pubkey())], &[]).unwrap();
+
+    // Once the pass mint exists, subscribing without a pass account fails
+    let ix = instructions::subscribe(&owner, 0, 1, false, false);
+    assert!(is_oracle_error(&fx.send(&[ix], &[&subscriber]), OracleError::PassAccountRequired));
+
+    let ixs = [
+        instructions::create_subscriber_pass_account(&owner, &owner),
+        instructions::subscribe(&owner, 0, 1, false, true),
+    ];
+    fx.send(&ixs, &[&subscriber]).unwrap();
+    assert_eq!(fx.token_balance(&pda::subscriber_pass(&owner)), 1);
+
+    // Extending keeps the one pass
+    fx.send(&[instructions::subscribe(&owner, 0, 1, false, true)], &[&subscriber]).unwrap();
+    assert_eq!(fx.token_balance(&pda::subscriber_pass(&owner)), 1);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn transfers_move_the_pass() {
+    let mut fx = OracleFixture::new();
+    let authority = fx.authority.pubkey();
+    let (alice, bob) = (fx.funded_keypair(10), fx.funded_keypair(10));
+    let update = oracle::ConfigUpdate {
+        subscription_transfer_cooldown_secs: Some(24 * 60 * 60),
+        ..Default::default()
+    };
+    fx.send(&[instructions::update_config(&authority, update), instructions::initialize_pass_mint(&authority)], &[])
+        .unwrap();
+    let ixs = [
+        instructions::create_subscriber_pass_account(&alice.pubkey(), &alice.pubkey()),
+        instructions::subscribe(&alice.pubkey(), 0, 1, false, true),
+    ];
+    fx.send(&ixs, &[&alice]).unwrap();
+
+    // Keeping the pass while giving the time away isn't possible
+    let ix = instructions::transfer_subscription(&alice.pubkey(), &bob.pubkey(), 0, false, false);
+    assert!(is_oracle_error(&fx.send(&[ix], &[&alice]), OracleError::PassAccountRequired));
+
+    let ixs = [
+        instructions::create_subscriber_pass_account(&alice.pubkey(), &bob.pubkey()),
+        instructions::transfer_subscription(&alice.pubkey(), &bob.pubkey(), 0, false, true),
+    ];
+    fx.send(&ixs, &[&alice]).unwrap();
+    assert_eq!(fx.token_balance(&pda::subscriber_pass(&alice.pubkey())), 0);
+    assert_eq!(fx.token_balance(&pda::subscriber_pass(&bob.pubkey())), 1);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn paused_subscriptions_give_up_the_pass() {
+    let mut fx = OracleFixture::new();
+    let subscriber = fx.funded_keypair(10);
+    let owner = subscriber.pubkey();
+    fx.send(&[instructions::initialize_pass_mint(&fx.authority.pubkey())], &[]).unwrap();
+    let ixs = [
+        instructions::create_subscriber_pass_account(&owner, &owner),
+        instructions::subscribe(&owner, 0, 1, false, true),
+    ];
+    fx.send(&ixs, &[&subscriber]).unwrap();
+
+    let ix = instructions::pause_subscription(&owner, false);
+    assert!(is_oracle_error(&fx.send(&[ix], &[&subscriber]), OracleError::PassAccountRequired));
+    fx.send(&[instructions::pause_subscription(&owner, true)], &[&subscriber]).unwrap();
+    assert_eq!(fx.token_balance(&pda::subscriber_pass(&owner)), 0);
+
+    fx.warp(24 * 60 * 60);
+    fx.send(&[instructions::resume_subscription(&owner, true)], &[&subscriber]).unwrap();
+    assert_eq!(fx.token_balance(&pda::subscriber_pass(&owner)), 1);
+}
+
+#[test]
+#[cfg_attr(not(oracle_program), ignore = "needs target/deploy/oracle.so")]
+fn legacy_signal_migrates() {
+    let mut fx = OracleFixture::new();
+    let id = fx.publish(signal_params(Pubkey::new_unique(), 1_000)).unwrap();
+    let current = fx.signal(id).unwrap();
+
+    // Rewrite the account in the pre-`direction` layout
+    let legacy = oracle::LegacySignal {
+        id,
+        token: current.token,
+        symbol: current.symbol.clone(),
+        score: current.score,
+        risk_level: current.risk_level,
+        sources_bitmap: current.sources_bitmap,
+        mcap_at_signal: current.mcap_at_signal,
+        entry_price: current.entry_price,
+        ath_price: current.ath_price,
+        exit_price: 0,
+        roi_bps: 0,
+        timestamp: current.timestamp,
+        status: oracle::SignalStatus::Open,
+        reasoning_hash: [7; 32],
+        reasoning_revealed: false,
+        bump: current.bump,
+    };
+    let mut data = Signal::DISCRIMINATOR.to_vec();
+    legacy.serialize(&mut data).unwrap();
+    data.resize(oracle::LegacySignal::SPACE, 0);
+    let address = pda::signal(id);
+    let lamports = fx.svm.minimum_balance_for_rent_exemption(data.len());
+    fx.svm
+        .set_account(
+            address,
+            Account {
+                lamports,
+                data,
+                owner: PROGRAM_ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+    assert!(fx.signal(id).is_none());
+
+    let ix = instructions::migrate_signal(&fx.authority.pubkey(), &address);
+    fx.send(std::slice::from_ref(&ix), &[]).unwrap();
+
+    let migrated = fx.signal(id).unwrap();
+    assert_eq!(migrated.reasoning_hash, [7; 32]);
+    assert_eq!(migrated.direction, oracle_client::types::SignalDirection::Long);
+    assert_eq!(migrated.bump, current.bump);
+    assert_eq!(migrated.quote_mint, Pubkey::default());
+    assert!(is_oracle_error(&fx.send(&[ix], &[]), OracleError::AlreadyMigrated));
+}
@@ -0,0 +1,1001 @@
+//! Account layouts and the enums they use.
+
+#[cfg(feature = "borsh")]
+use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use solana_pubkey::Pubkey;
+
+use crate::{Discriminator, BENCHMARK_COUNT, FEATURED_SLOTS, MAX_RATING, RISK_LEVEL_COUNT, ROI_BUCKET_COUNT, TIER_COUNT};
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OracleState {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub authority: Pubkey,
+    pub total_signals: u64,
+    pub total_wins: u64,
+    pub total_losses: u64,
+    pub compressed_signals: u64, // Leaves appended to the signal tree
+    pub streaks: Streaks,
+    pub roi_moments: RoiMoments, // Running mean/variance of settled ROI
+    pub roi_histogram: [u32; ROI_BUCKET_COUNT], // Settlements per oracle_math::ROI_BUCKET_FLOORS_BPS bucket
+    pub total_baskets: u64,
+    pub total_pairs: u64,
+    pub roi_annualized_moments: RoiMoments, // Running mean/variance of settled annualized ROI
+    pub unrealized_roi_bps: i64, // Sum of open signals' marked ROI, one unit position each
+    pub marked_at: i64,          // Last mark_signals run
+    pub performance_index: u64,  // Equity curve over settled ROI, see oracle_math::PerformanceIndex
+    pub bump: u8,
+}
+
+impl Discriminator for OracleState {
+    const DISCRIMINATOR: [u8; 8] = [97, 156, 157, 189, 194, 73, 8, 15];
+}
+
+/// Current and longest runs of consecutive wins and losses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Streaks {
+    pub win: u32,
+    pub loss: u32,
+    pub max_win: u32,
+    pub max_loss: u32,
+}
+
+/// Slippage and fees a follower pays, see `oracle_math::Friction`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Friction {
+    pub entry_slippage_bps: u16,
+    pub exit_slippage_bps: u16,
+    pub swap_fee_bps: u16,       // Charged on both the entry and the exit swap
+}
+
+/// Welford accumulators for settled ROI, see `oracle_math::RoiMoments`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RoiMoments {
+    pub count: u64,
+    pub mean: i64,               // Mean ROI in bps, scaled by MOMENT_SCALE
+    pub m2: u128,                // Sum of squared deviations, in bps²
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Signal {
+    pub id: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub token: Pubkey,
+    pub symbol: String,          // Up to config.max_symbol_len, see signal_space
+    pub score: u8,
+    pub risk_level: u8,
+    pub sources_bitmap: u8,      // Bitmap of signal sources
+    pub mcap_at_signal: u64,
+    pub entry_price: u64,
+    pub direction: SignalDirection,
+    pub ath_price: u64,          // Best price seen (lowest for shorts)
+    pub exit_price: u64,
+    pub roi_bps: i64,            // ROI in basis points (can be negative)
+    pub timestamp: i64,
+    pub status: SignalStatus,
+    pub reasoning_hash: [u8; 32], // SHA256 of reasoning committed BEFORE outcome
+    pub reasoning_revealed: bool, // Whether reasoning has been revealed publicly
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub price_pool: Pubkey,       // DEX pool the entry/exit prices were checked against
+    pub entry_verified: bool,
+    pub exit_verified: bool,
+    pub bump: u8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub quote_mint: Pubkey,       // Mint every price is quoted in; default on older signals
+    pub roi_annualized_bps: i64,  // roi_bps scaled to a year of holding, set at settlement
+    pub net_roi_bps: i64,         // roi_bps after config.friction, set at settlement
+    pub unrealized_roi_bps: i64,  // ROI at the last mark while open, 0 once settled
+    pub closed_at: i64,           // Settlement time, 0 while open
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub publisher: Pubkey,        // Wallet whose stats the signal settles into
+}
+
+impl Discriminator for Signal {
+    const DISCRIMINATOR: [u8; 8] = [20, 6, 227, 69, 183, 62, 78, 246];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SignalStatus {
+    Open,
+    Win,
+    Loss,
+    Closed,
+    BreakEven,   // Within config.break_even_bps of zero, neither win nor loss
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OracleConfig {
+    pub max_slippage_bps: [u16; RISK_LEVEL_COUNT], // Per risk level, for vault swaps
+    pub max_leverage_bps: [u32; RISK_LEVEL_COUNT], // Per risk level, for perp shorts (10_000 = 1x)
+    pub vault_reserve_bps: u16,  // Share of vault base kept liquid when lending
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub access_pass_tree: Pubkey, // Bubblegum tree holding cNFT access passes
+    pub tier_price_lamports: [u64; TIER_COUNT], // Price of one subscription period
+    pub storage_mode: StorageMode,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal_tree: Pubkey,     // Merkle tree holding compressed-mode signals
+    pub elite_score: u8,         // Min score to mint a signal asset
+    pub asset_royalty_bps: u16,  // Resale royalty paid to the publisher
+    pub score_half_life_secs: i64, // Effective-score decay; 0 disables it
+    pub max_symbol_len: u8,      // Longest symbol a signal can be published with
+    pub max_mcap_discrepancy_bps: u16, // Claimed/verified mcap gap that gets flagged
+    pub max_supply_change_bps: u16, // Mint supply change over a signal's life that gets flagged
+    pub republish_cooldown_secs: i64, // Wait after a token's signal closes before another; 0 disables it
+    pub break_even_bps: u16,     // ROI band either side of zero that settles as break-even
+    pub win_threshold_bps: [u32; RISK_LEVEL_COUNT], // Per risk level, ROI a close needs to count as a win
+    pub friction: Friction,      // Follower costs net_roi_bps deducts from the gross ROI
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey::array"))]
+    pub benchmark_pools: [Pubkey; BENCHMARK_COUNT], // Pools benchmark prices are read from, default if unset
+    pub vault_max_drawdown_bps: u16, // Vault NAV drawdown that de-risks it; 0 disables it
+    pub vault_derisk_bps: u16,   // Entry size cap while de-risked, as a share of liquid base; 0 halts entries
+    pub subscription_transfer_cooldown_secs: i64, // Wait before a subscription moves again; 0 disables transfers
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub vault_stake_pool: Pubkey, // SPL stake pool idle vault SOL is staked into, default if unset
+    pub max_allocation_bps: [u16; RISK_LEVEL_COUNT], // Per risk level, most of the vault one signal can hold
+    pub rebalance_band_bps: u16, // Weight drift from target, in bps of the vault, that allows a rebalance
+    pub max_rebalance_bps: u16,  // Largest single rebalance trade, in bps of the vault
+    pub vault_performance_fee_bps: u16, // Share of vault gains above the high-water mark paid to the treasury
+    pub vault_nav_tolerance_bps: u16, // Widest gap between a reported NAV and the vault's measured value
+    pub vault_max_nav_change_bps: u16, // Largest share price move between NAV reports; 0 disables it
+    pub bump: u8,
+}
+
+impl Discriminator for OracleConfig {
+    const DISCRIMINATOR: [u8; 8] = [133, 196, 152, 50, 27, 21, 145, 254];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Vault {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub authority: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub executor: Pubkey,        // Keeper allowed to route executions
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub base_mint: Pubkey,       // Quote asset held between signals
+    pub total_executions: u64,
+    pub lent_amount: u64,        // Base principal currently parked in lending
+    pub nav: u64,                // Last NAV the executor reported, in base mint units, within the measured range
+    pub peak_nav: u64,           // Highest reported NAV since the last de-risk reset
+    pub derisked: bool,          // Set once drawdown crosses the configured threshold
+    pub nav_at: i64,             // When nav was last reported
+    pub total_shares: u64,       // Settled follower shares
+    pub epoch: u64,              // Epoch currently queueing flows
+    pub epoch_started_at: i64,
+    pub queued_deposits: u64,    // Base held for deposits queued this epoch
+    pub queued_withdrawal_shares: u64,
+    pub owed_withdrawals: u64,   // Base owed to processed withdrawals not yet settled
+    pub entered_per_share: i128, // Base spent on entries per share, scaled by PER_SHARE_SCALE
+    pub realized_pnl_per_share: i128, // Realized spot PnL per share, scaled by PER_SHARE_SCALE
+    pub lst_amount: u64,         // LST held for idle SOL, as of the last sync
+    pub lst_lamports: u64,       // Its SOL value at the stake pool's exchange rate
+    pub lst_synced_at: i64,
+    pub emergency: bool,         // Emergency exit declared; entries stop and withdrawals are immediate
+    pub emergency_at: i64,
+    pub high_water_mark: u128,   // Highest post-fee equity per share, scaled by PER_SHARE_SCALE; 0 until first set
+    pub next_fee_epoch: u64,     // First epoch whose fees haven't been crystallized
+    pub fees_paid: u64,          // Performance fees paid to the treasury, in base
+    pub last_flow_at: i64,       // When a deposit or withdrawal was last queued
+    pub open_positions: u32,     // Positions holding tokens or a short; NAV reports value each one
+    pub nav_share_price: u128,   // Equity per share at the last NAV report, scaled by PER_SHARE_SCALE
+    pub bump: u8,
+}
+
+impl Discriminator for Vault {
+    const DISCRIMINATOR: [u8; 8] = [211, 8, 232, 43, 2, 152, 117, 119];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VaultPosition {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    pub token_amount: u64,       // Signal token currently held
+    pub cost_basis: u64,         // Base spent on entries
+    pub proceeds: u64,           // Base received from exits
+    pub market_index: u16,       // Drift perp market for shorts
+    pub short_base_amount: u64,  // Open short size (Drift base precision)
+    pub short_notional: u64,     // Open short notional in base mint units
+    pub exited_cost: u64,        // Share of cost_basis already sold
+    pub entered_per_share: i128, // This position's part of the vault's accumulators
+    pub realized_pnl_per_share: i128,
+    pub bump: u8,
+}
+
+impl Discriminator for VaultPosition {
+    const DISCRIMINATOR: [u8; 8] = [122, 109, 193, 63, 69, 148, 90, 237];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SignalDirection {
+    Long,
+    Short,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ExecutionSide {
+    Entry,
+    Exit,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Subscription {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub subscriber: Pubkey,
+    pub tier: u8,
+    pub expires_at: i64,
+    pub transferable_at: i64,    // Earliest time the subscription can be transferred on
+    pub paused_at: i64,          // 0 unless paused
+    pub paused_remaining_secs: i64, // Time left when paused, restored on resume
+    pub pauses: u8,              // Pauses used by the current subscription
+    pub bump: u8,
+}
+
+impl Discriminator for Subscription {
+    const DISCRIMINATOR: [u8; 8] = [64, 7, 26, 135, 102, 132, 98, 33];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Publisher {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub wallet: Pubkey,
+    pub domain: String,          // Verified `.sol` name, without the suffix
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub domain_account: Pubkey,  // SNS name account the domain was verified against
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Discriminator for Publisher {
+    const DISCRIMINATOR: [u8; 8] = [86, 152, 93, 215, 234, 89, 232, 104];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PublisherStats {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub wallet: Pubkey,
+    pub signals: u64,            // Signals settled
+    pub wins: u64,
+    pub losses: u64,
+    pub cumulative_roi_bps: i64, // Sum of settled ROI, saturating
+    pub last_active: i64,        // Last settlement (or creation)
+    pub streaks: Streaks,
+    pub cumulative_roi_annualized_bps: i64, // Sum of settled annualized ROI, saturating
+    pub bump: u8,
+}
+
+impl Discriminator for PublisherStats {
+    const DISCRIMINATOR: [u8; 8] = [134, 104, 140, 170, 51, 129, 47, 105];
+}
+
+/// Where an oracle keeps its signals
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum StorageMode {
+    Accounts,    // One PDA per signal
+    Compressed,  // Leaf hashes in a concurrent Merkle tree
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ThreadAction {
+    Created,
+    Funded,
+    Closed,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExecutionTicket {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    pub commit_hash: [u8; 32],   // sha256(signal, side, amount_in, salt)
+    pub valid_from_slot: u64,
+    pub valid_until_slot: u64,
+    pub executed: bool,
+    pub bump: u8,
+}
+
+impl Discriminator for ExecutionTicket {
+    const DISCRIMINATOR: [u8; 8] = [85, 115, 140, 204, 139, 96, 80, 109];
+}
+
+/// Publisher commentary appended to a signal after publish
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalNotes {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    pub notes: Vec<SignalNote>,  // Oldest first; the account grows with each
+    pub bump: u8,
+}
+
+impl Discriminator for SignalNotes {
+    const DISCRIMINATOR: [u8; 8] = [120, 183, 247, 190, 34, 42, 238, 118];
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalNote {
+    pub timestamp: i64,
+    pub text: String,
+}
+
+/// Subscriber ratings of one signal
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalRating {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    pub count: u32,
+    pub sum: u64,                // Mean rating = sum / count
+    pub counts: [u32; MAX_RATING as usize], // Ratings per star, 1 first
+    pub bump: u8,
+}
+
+impl Discriminator for SignalRating {
+    const DISCRIMINATOR: [u8; 8] = [34, 4, 155, 181, 206, 2, 101, 254];
+}
+
+/// Proof that `subscriber` has rated `signal`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RatingReceipt {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub subscriber: Pubkey,
+    pub rating: u8,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+impl Discriminator for RatingReceipt {
+    const DISCRIMINATOR: [u8; 8] = [217, 182, 161, 46, 5, 210, 16, 197];
+}
+
+/// Paid boosts of one signal
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalBoost {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    pub heat: u64,               // Lamports paid in boosts, ever
+    pub expires_at: i64,         // Featured eligibility ends here
+    pub bump: u8,
+}
+
+impl Discriminator for SignalBoost {
+    const DISCRIMINATOR: [u8; 8] = [147, 100, 70, 102, 157, 215, 26, 196];
+}
+
+/// One featured slot; `signal` is the default key when empty
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FeaturedSlot {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    pub heat: u64,
+    pub expires_at: i64,
+}
+
+/// Featured slots of the signal feed, held by the hottest active boosts
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Discovery {
+    pub featured: [FeaturedSlot; FEATURED_SLOTS],
+    pub bump: u8,
+}
+
+impl Discriminator for Discovery {
+    const DISCRIMINATOR: [u8; 8] = [117, 225, 254, 55, 131, 129, 48, 15];
+}
+
+/// One escrowed featured-slot bid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AuctionBid {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub bidder: Pubkey,
+    pub lamports: u64,
+}
+
+/// Escrowed bids for the featured slots of one epoch
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FeaturedAuction {
+    pub epoch: u64,
+    pub bids: [AuctionBid; FEATURED_SLOTS], // Unordered; zero lamports when empty
+    pub settled: bool,
+    pub bump: u8,
+}
+
+impl Discriminator for FeaturedAuction {
+    const DISCRIMINATOR: [u8; 8] = [104, 154, 197, 25, 214, 204, 29, 36];
+}
+
+/// One token of a basket
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BasketMember {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub token: Pubkey,
+    pub weight_bps: u16,
+    pub entry_price: u64,
+    pub exit_price: u64,
+    pub roi_bps: i64,
+}
+
+/// A signal on a weighted set of tokens
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Basket {
+    pub id: u64,
+    pub name: String,            // Thesis, e.g. "AI-MEMES"
+    pub members: Vec<BasketMember>,
+    pub timestamp: i64,
+    pub status: SignalStatus,
+    pub roi_bps: i64,            // Weighted ROI of the members once closed
+    pub bump: u8,
+}
+
+impl Discriminator for Basket {
+    const DISCRIMINATOR: [u8; 8] = [219, 79, 107, 135, 231, 243, 218, 248];
+}
+
+/// One leg of a pair signal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PairLeg {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub token: Pubkey,
+    pub entry_price: u64,
+    pub exit_price: u64,         // 0 while open
+}
+
+/// A call that one token will outperform another
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PairSignal {
+    pub id: u64,
+    pub long: PairLeg,
+    pub short: PairLeg,
+    pub timestamp: i64,
+    pub status: SignalStatus,
+    pub roi_bps: i64,            // Spread ROI once closed
+    pub bump: u8,
+}
+
+impl Discriminator for PairSignal {
+    const DISCRIMINATOR: [u8; 8] = [11, 13, 89, 119, 196, 227, 163, 114];
+}
+
+/// Perps execution guidance for a signal
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PerpParams {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    pub leverage_bps: u32,       // Recommended leverage (10_000 = 1x); 0 when unset
+    pub liquidation_price: u64,  // Implied by leverage_bps and the entry price
+    pub market_index: u16,       // Drift perp market that tracks the signal's token
+    pub bump: u8,
+}
+
+impl Discriminator for PerpParams {
+    const DISCRIMINATOR: [u8; 8] = [73, 20, 136, 137, 15, 212, 79, 126];
+}
+
+/// Position sizing suggested when a signal was published
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalSizing {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    pub size_bps: u16,           // Fraction of capital to commit
+    pub win_rate_bps: u16,       // Oracle win rate it was sized on
+    pub bump: u8,
+}
+
+impl Discriminator for SignalSizing {
+    const DISCRIMINATOR: [u8; 8] = [150, 49, 212, 27, 31, 223, 208, 215];
+}
+
+/// A signal's claimed market cap next to one computed on-chain
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalMarketCap {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    pub claimed_mcap: u64,       // Signal::mcap_at_signal
+    pub verified_mcap: u64,      // supply * price, in whole quote units
+    pub supply: u64,             // Mint supply (base units) it was computed from
+    pub price: u64,              // Pool spot price, PRICE_DECIMALS
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub quote_mint: Pubkey,      // What verified_mcap is denominated in
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub pool: Pubkey,
+    pub discrepancy_bps: u64,    // Gap of the claim from the verified value
+    pub flagged: bool,           // Gap above config.max_mcap_discrepancy_bps
+    pub bump: u8,
+}
+
+impl Discriminator for SignalMarketCap {
+    const DISCRIMINATOR: [u8; 8] = [152, 131, 170, 27, 66, 14, 128, 88];
+}
+
+/// Mint supply of a signal's token at publish and at close
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SupplySnapshot {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    pub entry_supply: u64,
+    pub entry_timestamp: i64,
+    pub exit_supply: u64,
+    pub exit_timestamp: i64,     // 0 until the exit snapshot
+    pub supply_change_bps: i64,  // Exit vs entry supply, positive when diluted
+    pub flagged: bool,           // Change above config.max_supply_change_bps
+    pub bump: u8,
+}
+
+impl Discriminator for SupplySnapshot {
+    const DISCRIMINATOR: [u8; 8] = [230, 52, 2, 192, 100, 187, 34, 51];
+}
+
+/// Hash of a signal token's top-holder snapshot, taken at publish
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HolderCommitment {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    pub snapshot_hash: [u8; 32], // SHA256 of the canonical snapshot text
+    pub top_holders_bps: u16,    // Share of supply the snapshotted holders held
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+impl Discriminator for HolderCommitment {
+    const DISCRIMINATOR: [u8; 8] = [178, 87, 243, 99, 132, 93, 41, 176];
+}
+
+/// Last close of any signal on a token, for the republish cooldown
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TokenHistory {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub token: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub last_signal: Pubkey,   // Most recently closed signal on the token
+    pub last_closed_at: i64,   // 0 until a signal on the token closes
+    pub bump: u8,
+}
+
+impl Discriminator for TokenHistory {
+    const DISCRIMINATOR: [u8; 8] = [42, 110, 164, 12, 200, 64, 231, 51];
+}
+
+/// Place of a reopened signal in its token's chain of attempts
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalLineage {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub parent: Pubkey,          // Attempt this one re-enters
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub root: Pubkey,            // First attempt of the chain
+    pub attempt: u16,            // 1-based; reopened signals start at 2
+    pub prior_roi_bps: i64,      // Compounded ROI of every earlier attempt
+    pub bump: u8,
+}
+
+impl Discriminator for SignalLineage {
+    const DISCRIMINATOR: [u8; 8] = [161, 222, 111, 33, 149, 184, 227, 228];
+}
+
+/// Entries a signal scaled into after publish
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalEntries {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    pub initial_price: u64,      // Entry price the signal was published with
+    pub entries: Vec<SignalEntry>, // Oldest first
+    pub bump: u8,
+}
+
+impl Discriminator for SignalEntries {
+    const DISCRIMINATOR: [u8; 8] = [160, 128, 190, 179, 250, 229, 110, 88];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalEntry {
+    pub price: u64,
+    pub weight_bps: u16,         // Share of the final position bought at `price`
+    pub timestamp: i64,
+}
+
+/// Tranches a signal's entry is bought in, and which of them have run
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DcaPlan {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    pub tranches: u8,
+    pub tranche_bps: u16,        // Share of the position each tranche buys
+    pub interval_secs: i64,      // Between consecutive tranches
+    pub start: i64,              // When tranche 0 comes due
+    pub executed: u16,           // Bit i set once tranche i ran
+    pub bump: u8,
+}
+
+impl Discriminator for DcaPlan {
+    const DISCRIMINATOR: [u8; 8] = [231, 97, 112, 227, 171, 241, 52, 84];
+}
+
+/// Exit levels a signal takes profit at, settled one by one
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TakeProfitLadder {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    pub levels: Vec<TakeProfitLevel>, // Nearest the entry first
+    pub realized_roi_bps: i64,   // Position ROI locked in by settled levels
+    pub exited_bps: u16,         // Share of the position settled levels exited
+    pub bump: u8,
+}
+
+impl Discriminator for TakeProfitLadder {
+    const DISCRIMINATOR: [u8; 8] = [0, 205, 111, 235, 178, 230, 113, 228];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TakeProfitLevel {
+    pub price: u64,
+    pub exit_bps: u16,           // Share of the position exited at `price`
+    pub settled_at: i64,         // 0 until the level is hit
+}
+
+/// Price past which anyone may close a signal as failed
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Invalidation {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    pub price: u64,
+    pub bump: u8,
+}
+
+impl Discriminator for Invalidation {
+    const DISCRIMINATOR: [u8; 8] = [245, 75, 180, 110, 142, 4, 107, 106];
+}
+
+/// Simulated follower with a fixed bankroll and a cap on concurrent positions
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ModelPortfolio {
+    pub max_positions: u8,
+    pub next_id: u64,            // Next signal to consider entering
+    pub cash: u64,
+    pub nav: u64,                // Cash plus open positions at cost
+    pub peak_nav: u64,
+    pub max_drawdown_bps: u16,   // Deepest fall of NAV from its peak
+    pub positions: Vec<PortfolioPosition>,
+    pub skipped: u64,            // Signals passed over: full, already settled or compressed
+    pub bump: u8,
+}
+
+impl Discriminator for ModelPortfolio {
+    const DISCRIMINATOR: [u8; 8] = [38, 177, 48, 9, 134, 163, 212, 161];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PortfolioPosition {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    pub size: u64,               // Cash committed at entry
+}
+
+/// Benchmark prices at a signal's entry and exit, by benchmark index, so its alpha can be recomputed from on-chain data
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalBenchmark {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    pub entry_prices: [u64; BENCHMARK_COUNT], // 0 until recorded
+    pub exit_prices: [u64; BENCHMARK_COUNT],  // 0 until recorded
+    pub bump: u8,
+}
+
+impl Discriminator for SignalBenchmark {
+    const DISCRIMINATOR: [u8; 8] = [124, 32, 115, 5, 173, 17, 198, 222];
+}
+
+/// Proof that `subscriber` held `signal` at `slot`, before its reveal
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AccessClaim {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub subscriber: Pubkey,
+    pub slot: u64,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+impl Discriminator for AccessClaim {
+    const DISCRIMINATOR: [u8; 8] = [62, 189, 225, 97, 65, 44, 29, 201];
+}
+
+/// Subscription tier restricted to approved wallets; its existence puts
+/// the tier in allowlist mode
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TierAllowlist {
+    pub tier: u8,
+    pub capacity: u32,           // Most wallets that can be allowlisted at once
+    pub count: u32,              // Wallets currently allowlisted
+    pub bump: u8,
+}
+
+impl Discriminator for TierAllowlist {
+    const DISCRIMINATOR: [u8; 8] = [217, 128, 80, 80, 85, 50, 143, 206];
+}
+
+/// Approval for `wallet` to subscribe to allowlisted `tier`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AllowlistEntry {
+    pub tier: u8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub wallet: Pubkey,
+    pub bump: u8,
+}
+
+impl Discriminator for AllowlistEntry {
+    const DISCRIMINATOR: [u8; 8] = [42, 59, 88, 1, 124, 138, 92, 236];
+}
+
+/// A follower's vault shares and the flows they have queued for the
+/// current epoch
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VaultDepositor {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub owner: Pubkey,
+    pub shares: u64,             // Settled shares, excluding any queued for withdrawal
+    pub queued_deposit: u64,     // Base waiting to buy shares at queued_epoch's end
+    pub queued_withdrawal_shares: u64, // Shares waiting to be redeemed at queued_epoch's end
+    pub queued_epoch: u64,
+    pub entered: u64,            // This follower's part of the vault's entries, in base
+    pub realized_pnl: i64,       // This follower's part of the vault's realized spot PnL
+    pub entered_checkpoint: i128, // Vault accumulators as of the last accrual
+    pub realized_pnl_checkpoint: i128,
+    pub emergency_owed: u64,     // Redeemed in emergency but not yet paid out of liquid base
+    pub bump: u8,
+}
+
+impl Discriminator for VaultDepositor {
+    const DISCRIMINATOR: [u8; 8] = [87, 109, 182, 106, 87, 96, 63, 211];
+}
+
+/// How a processed vault epoch priced its queued flows
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VaultEpoch {
+    pub epoch: u64,
+    pub equity: u64,             // NAV net of queued deposits and unclaimed payouts
+    pub total_shares: u64,       // Shares outstanding before the epoch's flows
+    pub deposits: u64,
+    pub minted_shares: u64,
+    pub withdrawal_shares: u64,
+    pub payout: u64,             // Base owed to the epoch's withdrawals
+    pub entered_per_share: i128, // Vault accumulators when the epoch closed
+    pub realized_pnl_per_share: i128,
+    pub refunded_deposits: u64,  // Deposits handed back because shares had no value to buy at
+    pub bump: u8,
+}
+
+impl Discriminator for VaultEpoch {
+    const DISCRIMINATOR: [u8; 8] = [255, 29, 204, 186, 8, 164, 16, 137];
+}
+
+/// Any account the program owns
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "account", content = "data"))]
+pub enum OracleAccount {
+    OracleState(OracleState),
+    Signal(Signal),
+    OracleConfig(OracleConfig),
+    Vault(Vault),
+    VaultPosition(VaultPosition),
+    Subscription(Subscription),
+    Publisher(Publisher),
+    PublisherStats(PublisherStats),
+    ExecutionTicket(ExecutionTicket),
+    SignalNotes(SignalNotes),
+    SignalRating(SignalRating),
+    RatingReceipt(RatingReceipt),
+    SignalBoost(SignalBoost),
+    Discovery(Discovery),
+    FeaturedAuction(FeaturedAuction),
+    Basket(Basket),
+    PairSignal(PairSignal),
+    PerpParams(PerpParams),
+    SignalSizing(SignalSizing),
+    SignalMarketCap(SignalMarketCap),
+    SupplySnapshot(SupplySnapshot),
+    HolderCommitment(HolderCommitment),
+    TokenHistory(TokenHistory),
+    SignalLineage(SignalLineage),
+    SignalEntries(SignalEntries),
+    DcaPlan(DcaPlan),
+    TakeProfitLadder(TakeProfitLadder),
+    Invalidation(Invalidation),
+    ModelPortfolio(ModelPortfolio),
+    SignalBenchmark(SignalBenchmark),
+    AccessClaim(AccessClaim),
+    TierAllowlist(TierAllowlist),
+    AllowlistEntry(AllowlistEntry),
+    VaultDepositor(VaultDepositor),
+    VaultEpoch(VaultEpoch),
+}
+
+impl OracleAccount {
+    /// Account type name, as used in the IDL
+    pub fn name(&self) -> &'static str {
+        match self {
+            OracleAccount::OracleState(_) => "OracleState",
+            OracleAccount::Signal(_) => "Signal",
+            OracleAccount::OracleConfig(_) => "OracleConfig",
+            OracleAccount::Vault(_) => "Vault",
+            OracleAccount::VaultPosition(_) => "VaultPosition",
+            OracleAccount::Subscription(_) => "Subscription",
+            OracleAccount::Publisher(_) => "Publisher",
+            OracleAccount::PublisherStats(_) => "PublisherStats",
+            OracleAccount::ExecutionTicket(_) => "ExecutionTicket",
+            OracleAccount::SignalNotes(_) => "SignalNotes",
+            OracleAccount::SignalRating(_) => "SignalRating",
+            OracleAccount::RatingReceipt(_) => "RatingReceipt",
+            OracleAccount::SignalBoost(_) => "SignalBoost",
+            OracleAccount::Discovery(_) => "Discovery",
+            OracleAccount::FeaturedAuction(_) => "FeaturedAuction",
+            OracleAccount::Basket(_) => "Basket",
+            OracleAccount::PairSignal(_) => "PairSignal",
+            OracleAccount::PerpParams(_) => "PerpParams",
+            OracleAccount::SignalSizing(_) => "SignalSizing",
+            OracleAccount::SignalMarketCap(_) => "SignalMarketCap",
+            OracleAccount::SupplySnapshot(_) => "SupplySnapshot",
+            OracleAccount::HolderCommitment(_) => "HolderCommitment",
+            OracleAccount::TokenHistory(_) => "TokenHistory",
+            OracleAccount::SignalLineage(_) => "SignalLineage",
+            OracleAccount::SignalEntries(_) => "SignalEntries",
+            OracleAccount::DcaPlan(_) => "DcaPlan",
+            OracleAccount::TakeProfitLadder(_) => "TakeProfitLadder",
+            OracleAccount::Invalidation(_) => "Invalidation",
+            OracleAccount::ModelPortfolio(_) => "ModelPortfolio",
+            OracleAccount::SignalBenchmark(_) => "SignalBenchmark",
+            OracleAccount::AccessClaim(_) => "AccessClaim",
+            OracleAccount::TierAllowlist(_) => "TierAllowlist",
+            OracleAccount::AllowlistEntry(_) => "AllowlistEntry",
+            OracleAccount::VaultDepositor(_) => "VaultDepositor",
+            OracleAccount::VaultEpoch(_) => "VaultEpoch",
+        }
+    }
+}
+
+/// Decode account data of any type, dispatching on its discriminator
+#[cfg(feature = "borsh")]
+pub fn decode_any_account(data: &[u8]) -> Option<OracleAccount> {
+    let discriminator: [u8; 8] = data.get(..8)?.try_into().ok()?;
+    match discriminator {
+        OracleState::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::OracleState),
+        Signal::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::Signal),
+        OracleConfig::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::OracleConfig),
+        Vault::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::Vault),
+        VaultPosition::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::VaultPosition),
+        Subscription::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::Subscription),
+        Publisher::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::Publisher),
+        PublisherStats::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::PublisherStats),
+        ExecutionTicket::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::ExecutionTicket),
+        SignalNotes::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::SignalNotes),
+        SignalRating::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::SignalRating),
+        RatingReceipt::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::RatingReceipt),
+        SignalBoost::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::SignalBoost),
+        Discovery::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::Discovery),
+        FeaturedAuction::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::FeaturedAuction),
+        Basket::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::Basket),
+        PairSignal::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::PairSignal),
+        PerpParams::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::PerpParams),
+        SignalSizing::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::SignalSizing),
+        SignalMarketCap::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::SignalMarketCap),
+        SupplySnapshot::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::SupplySnapshot),
+        HolderCommitment::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::HolderCommitment),
+        TokenHistory::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::TokenHistory),
+        SignalLineage::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::SignalLineage),
+        SignalEntries::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::SignalEntries),
+        DcaPlan::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::DcaPlan),
+        TakeProfitLadder::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::TakeProfitLadder),
+        Invalidation::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::Invalidation),
+        ModelPortfolio::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::ModelPortfolio),
+        SignalBenchmark::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::SignalBenchmark),
+        AccessClaim::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::AccessClaim),
+        TierAllowlist::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::TierAllowlist),
+        AllowlistEntry::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::AllowlistEntry),
+        VaultDepositor::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::VaultDepositor),
+        VaultEpoch::DISCRIMINATOR => crate::decode_account(data).map(OracleAccount::VaultEpoch),
+        _ => None,
+    }
+}
@@ -0,0 +1,1145 @@
+//! Events emitted by the program.
+
+#[cfg(feature = "borsh")]
+use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use solana_pubkey::Pubkey;
+
+use crate::accounts::*;
+use crate::Discriminator;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalPublished {
+    pub id: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub token: Pubkey,
+    pub score: u8,
+    pub timestamp: i64,
+    pub publisher_domain: String, // Verified `.sol` name, empty if none linked
+}
+
+impl Discriminator for SignalPublished {
+    const DISCRIMINATOR: [u8; 8] = [27, 49, 10, 82, 168, 186, 203, 42];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalClosed {
+    pub id: u64,
+    pub status: SignalStatus,
+    pub roi_bps: i64,
+}
+
+impl Discriminator for SignalClosed {
+    const DISCRIMINATOR: [u8; 8] = [129, 164, 126, 13, 84, 53, 21, 41];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalPublishedWithProof {
+    pub id: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub token: Pubkey,
+    pub score: u8,
+    pub reasoning_hash: [u8; 32],
+    pub timestamp: i64,
+    pub publisher_domain: String,
+}
+
+impl Discriminator for SignalPublishedWithProof {
+    const DISCRIMINATOR: [u8; 8] = [72, 82, 184, 90, 190, 47, 227, 117];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ReasoningRevealed {
+    pub id: u64,
+    pub reasoning_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+impl Discriminator for ReasoningRevealed {
+    const DISCRIMINATOR: [u8; 8] = [136, 27, 89, 205, 193, 65, 142, 210];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PriceChecked {
+    pub id: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub pool: Pubkey,
+    pub claimed_price: u64,
+    pub pool_price: u64,
+    pub deviation_bps: u64,
+}
+
+impl Discriminator for PriceChecked {
+    const DISCRIMINATOR: [u8; 8] = [179, 205, 37, 132, 223, 39, 65, 248];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConfigUpdated {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+impl Discriminator for ConfigUpdated {
+    const DISCRIMINATOR: [u8; 8] = [40, 241, 230, 122, 11, 19, 198, 194];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalExecuted {
+    pub id: u64,
+    pub side: ExecutionSide,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub min_amount_out: u64,
+}
+
+impl Discriminator for SignalExecuted {
+    const DISCRIMINATOR: [u8; 8] = [80, 120, 207, 216, 16, 154, 75, 166];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ShortExecuted {
+    pub id: u64,
+    pub side: ExecutionSide,
+    pub market_index: u16,
+    pub base_asset_amount: u64,
+    pub limit_price: u64,
+    pub notional: u64,
+}
+
+impl Discriminator for ShortExecuted {
+    const DISCRIMINATOR: [u8; 8] = [140, 139, 156, 103, 185, 59, 71, 125];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VaultLendingChanged {
+    pub deposited: u64,
+    pub withdrawn: u64,
+    pub lent_amount: u64,
+    pub liquid_amount: u64,
+}
+
+impl Discriminator for VaultLendingChanged {
+    const DISCRIMINATOR: [u8; 8] = [112, 104, 145, 196, 178, 64, 101, 140];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AccessPassVerified {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub asset_id: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub tree: Pubkey,
+    pub timestamp: i64,
+}
+
+impl Discriminator for AccessPassVerified {
+    const DISCRIMINATOR: [u8; 8] = [162, 220, 201, 165, 154, 181, 155, 192];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Subscribed {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub subscriber: Pubkey,
+    pub tier: u8,
+    pub expires_at: i64,
+    pub paid_lamports: u64,
+}
+
+impl Discriminator for Subscribed {
+    const DISCRIMINATOR: [u8; 8] = [135, 59, 105, 76, 190, 236, 138, 228];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SubscriberPassChanged {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub subscriber: Pubkey,
+    pub minted: bool,
+    pub timestamp: i64,
+}
+
+impl Discriminator for SubscriberPassChanged {
+    const DISCRIMINATOR: [u8; 8] = [184, 163, 99, 131, 31, 14, 237, 189];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PublisherDomainLinked {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub publisher: Pubkey,
+    pub domain: String,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub domain_account: Pubkey,
+}
+
+impl Discriminator for PublisherDomainLinked {
+    const DISCRIMINATOR: [u8; 8] = [122, 184, 107, 189, 124, 211, 182, 216];
+}
+
+/// Lightweight alert payload for notification services (Dialect/Telegram bridges)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalAlert {
+    pub id: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub token: Pubkey,
+    pub symbol: String,
+    pub score: u8,
+    pub direction: SignalDirection,
+    pub tier: u8,               // Minimum subscription tier entitled to this alert
+}
+
+impl Discriminator for SignalAlert {
+    const DISCRIMINATOR: [u8; 8] = [11, 233, 115, 196, 20, 255, 87, 206];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ThreadManaged {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub thread: Pubkey,
+    pub action: ThreadAction,
+    pub lamports: u64,
+}
+
+impl Discriminator for ThreadManaged {
+    const DISCRIMINATOR: [u8; 8] = [228, 34, 59, 122, 58, 66, 95, 143];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExecutionScheduled {
+    pub id: u64,
+    pub commit_hash: [u8; 32],
+    pub valid_from_slot: u64,
+    pub valid_until_slot: u64,
+}
+
+impl Discriminator for ExecutionScheduled {
+    const DISCRIMINATOR: [u8; 8] = [120, 150, 240, 196, 138, 46, 85, 43];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CompressedSignalWritten {
+    pub id: u64,
+    pub leaf_index: u32,
+    pub leaf: [u8; 32],
+}
+
+impl Discriminator for CompressedSignalWritten {
+    const DISCRIMINATOR: [u8; 8] = [82, 215, 209, 107, 65, 23, 97, 49];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalAssetMinted {
+    pub id: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub asset: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub owner: Pubkey,
+    pub royalty_bps: u16,
+}
+
+impl Discriminator for SignalAssetMinted {
+    const DISCRIMINATOR: [u8; 8] = [148, 105, 69, 114, 58, 69, 128, 128];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalNoteAdded {
+    pub id: u64,
+    pub index: u32,
+    pub timestamp: i64,
+    pub text: String,
+}
+
+impl Discriminator for SignalNoteAdded {
+    const DISCRIMINATOR: [u8; 8] = [227, 234, 216, 231, 127, 199, 202, 166];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalRated {
+    pub id: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub subscriber: Pubkey,
+    pub rating: u8,
+}
+
+impl Discriminator for SignalRated {
+    const DISCRIMINATOR: [u8; 8] = [248, 105, 50, 130, 59, 142, 138, 169];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalBoosted {
+    pub id: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub booster: Pubkey,
+    pub lamports: u64,
+    pub heat: u64,
+    pub featured: bool,
+}
+
+impl Discriminator for SignalBoosted {
+    const DISCRIMINATOR: [u8; 8] = [106, 219, 5, 250, 119, 41, 44, 68];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FeaturedBidPlaced {
+    pub epoch: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub signal: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub bidder: Pubkey,
+    pub lamports: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey::option"))]
+    pub refunded: Option<Pubkey>, // Bidder whose escrow was returned
+}
+
+impl Discriminator for FeaturedBidPlaced {
+    const DISCRIMINATOR: [u8; 8] = [12, 175, 254, 122, 83, 36, 84, 18];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FeaturedAuctionSettled {
+    pub epoch: u64,
+    pub winners: u8,
+    pub proceeds: u64,
+}
+
+impl Discriminator for FeaturedAuctionSettled {
+    const DISCRIMINATOR: [u8; 8] = [246, 127, 41, 230, 26, 51, 53, 190];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BasketPublished {
+    pub id: u64,
+    pub name: String,
+    pub members: u8,
+    pub timestamp: i64,
+}
+
+impl Discriminator for BasketPublished {
+    const DISCRIMINATOR: [u8; 8] = [8, 32, 87, 163, 185, 43, 232, 160];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BasketClosed {
+    pub id: u64,
+    pub status: SignalStatus,
+    pub roi_bps: i64,
+}
+
+impl Discriminator for BasketClosed {
+    const DISCRIMINATOR: [u8; 8] = [183, 16, 175, 152, 33, 98, 67, 36];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PairPublished {
+    pub id: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub long_token: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub short_token: Pubkey,
+    pub timestamp: i64,
+}
+
+impl Discriminator for PairPublished {
+    const DISCRIMINATOR: [u8; 8] = [211, 118, 146, 140, 41, 138, 108, 158];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PairClosed {
+    pub id: u64,
+    pub status: SignalStatus,
+    pub roi_bps: i64,
+}
+
+impl Discriminator for PairClosed {
+    const DISCRIMINATOR: [u8; 8] = [44, 133, 115, 118, 95, 41, 87, 136];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PerpParamsSet {
+    pub id: u64,
+    pub leverage_bps: u32,
+    pub liquidation_price: u64,
+    pub market_index: u16,
+}
+
+impl Discriminator for PerpParamsSet {
+    const DISCRIMINATOR: [u8; 8] = [16, 195, 134, 11, 165, 243, 60, 50];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalSized {
+    pub id: u64,
+    pub size_bps: u16,
+}
+
+impl Discriminator for SignalSized {
+    const DISCRIMINATOR: [u8; 8] = [163, 89, 86, 18, 125, 148, 92, 187];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MarketCapVerified {
+    pub id: u64,
+    pub claimed_mcap: u64,
+    pub verified_mcap: u64,
+    pub discrepancy_bps: u64,
+    pub flagged: bool,
+}
+
+impl Discriminator for MarketCapVerified {
+    const DISCRIMINATOR: [u8; 8] = [115, 11, 64, 213, 66, 165, 154, 10];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SupplyChanged {
+    pub id: u64,
+    pub entry_supply: u64,
+    pub exit_supply: u64,
+    pub supply_change_bps: i64,
+    pub flagged: bool,
+}
+
+impl Discriminator for SupplyChanged {
+    const DISCRIMINATOR: [u8; 8] = [253, 199, 35, 232, 241, 126, 162, 16];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HolderDistributionCommitted {
+    pub id: u64,
+    pub snapshot_hash: [u8; 32],
+    pub top_holders_bps: u16,
+    pub timestamp: i64,
+}
+
+impl Discriminator for HolderDistributionCommitted {
+    const DISCRIMINATOR: [u8; 8] = [223, 110, 141, 226, 92, 76, 76, 43];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalRepublished {
+    pub id: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub token: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub parent: Pubkey,
+}
+
+impl Discriminator for SignalRepublished {
+    const DISCRIMINATOR: [u8; 8] = [20, 92, 118, 80, 53, 47, 208, 42];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalReopened {
+    pub id: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub parent: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub root: Pubkey,
+    pub attempt: u16,
+    pub prior_roi_bps: i64,
+}
+
+impl Discriminator for SignalReopened {
+    const DISCRIMINATOR: [u8; 8] = [10, 244, 232, 36, 48, 112, 71, 188];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EntryAdded {
+    pub id: u64,
+    pub price: u64,
+    pub weight_bps: u16,
+    pub entry_price: u64,        // Signal's averaged entry price after the add
+    pub timestamp: i64,
+}
+
+impl Discriminator for EntryAdded {
+    const DISCRIMINATOR: [u8; 8] = [134, 64, 234, 183, 65, 209, 57, 47];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DcaPlanSet {
+    pub id: u64,
+    pub tranches: u8,
+    pub tranche_bps: u16,
+    pub interval_secs: i64,
+    pub start: i64,
+}
+
+impl Discriminator for DcaPlanSet {
+    const DISCRIMINATOR: [u8; 8] = [200, 203, 191, 152, 165, 110, 34, 98];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DcaTrancheExecuted {
+    pub id: u64,
+    pub tranche: u8,
+    pub timestamp: i64,
+}
+
+impl Discriminator for DcaTrancheExecuted {
+    const DISCRIMINATOR: [u8; 8] = [220, 93, 200, 134, 202, 189, 202, 188];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TakeProfitsSet {
+    pub id: u64,
+    pub levels: Vec<TakeProfitLevel>,
+}
+
+impl Discriminator for TakeProfitsSet {
+    const DISCRIMINATOR: [u8; 8] = [192, 208, 102, 95, 202, 84, 43, 228];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TakeProfitHit {
+    pub id: u64,
+    pub level: u8,
+    pub price: u64,
+    pub pool_price: u64,
+    pub realized_roi_bps: i64,
+    pub exited_bps: u16,
+}
+
+impl Discriminator for TakeProfitHit {
+    const DISCRIMINATOR: [u8; 8] = [5, 69, 0, 76, 80, 151, 199, 173];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InvalidationPriceSet {
+    pub id: u64,
+    pub price: u64,
+}
+
+impl Discriminator for InvalidationPriceSet {
+    const DISCRIMINATOR: [u8; 8] = [28, 43, 20, 18, 189, 224, 48, 96];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalInvalidated {
+    pub id: u64,
+    pub invalidation_price: u64,
+    pub pool_price: u64,
+}
+
+impl Discriminator for SignalInvalidated {
+    const DISCRIMINATOR: [u8; 8] = [201, 62, 87, 29, 110, 157, 232, 84];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignalMarked {
+    pub id: u64,
+    pub price: u64,
+    pub unrealized_roi_bps: i64,
+}
+
+impl Discriminator for SignalMarked {
+    const DISCRIMINATOR: [u8; 8] = [209, 56, 227, 160, 170, 130, 221, 151];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PortfolioEntered {
+    pub id: u64,
+    pub size: u64,               // 0 when the signal was skipped
+    pub cash: u64,
+    pub exposure_bps: u16,
+}
+
+impl Discriminator for PortfolioEntered {
+    const DISCRIMINATOR: [u8; 8] = [193, 203, 8, 79, 221, 117, 247, 207];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PortfolioExited {
+    pub id: u64,
+    pub proceeds: u64,
+    pub nav: u64,
+    pub max_drawdown_bps: u16,
+    pub exposure_bps: u16,
+}
+
+impl Discriminator for PortfolioExited {
+    const DISCRIMINATOR: [u8; 8] = [42, 108, 50, 64, 85, 144, 240, 114];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BenchmarkRecorded {
+    pub id: u64,
+    pub benchmark: u8,
+    pub price: u64,
+    pub at_exit: bool,
+}
+
+impl Discriminator for BenchmarkRecorded {
+    const DISCRIMINATOR: [u8; 8] = [27, 226, 97, 146, 14, 78, 214, 165];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VaultDerisked {
+    pub nav: u64,
+    pub peak_nav: u64,
+    pub drawdown_bps: u16,
+}
+
+impl Discriminator for VaultDerisked {
+    const DISCRIMINATOR: [u8; 8] = [143, 42, 227, 115, 155, 217, 161, 177];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VaultDeriskReset {
+    pub nav: u64,
+}
+
+impl Discriminator for VaultDeriskReset {
+    const DISCRIMINATOR: [u8; 8] = [26, 154, 108, 153, 114, 89, 72, 28];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AccessClaimed {
+    pub id: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub subscriber: Pubkey,
+    pub slot: u64,
+}
+
+impl Discriminator for AccessClaimed {
+    const DISCRIMINATOR: [u8; 8] = [254, 35, 227, 95, 4, 79, 138, 39];
+}
+
+/// Audit record of a reasoning document released to a subscriber
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AccessLogged {
+    pub id: u64,
+    pub subscriber_hash: [u8; 32],
+    pub reasoning_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+impl Discriminator for AccessLogged {
+    const DISCRIMINATOR: [u8; 8] = [243, 53, 225, 71, 64, 120, 109, 25];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TierAllowlistSet {
+    pub tier: u8,
+    pub capacity: u32,
+    pub count: u32,
+}
+
+impl Discriminator for TierAllowlistSet {
+    const DISCRIMINATOR: [u8; 8] = [148, 141, 143, 24, 64, 168, 177, 184];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AllowlistChanged {
+    pub tier: u8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub wallet: Pubkey,
+    pub added: bool,
+    pub count: u32,
+}
+
+impl Discriminator for AllowlistChanged {
+    const DISCRIMINATOR: [u8; 8] = [188, 211, 104, 55, 41, 170, 83, 47];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SubscriptionTransferred {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub from: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub to: Pubkey,
+    pub tier: u8,
+    pub expires_at: i64,
+}
+
+impl Discriminator for SubscriptionTransferred {
+    const DISCRIMINATOR: [u8; 8] = [230, 234, 33, 158, 191, 7, 192, 43];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SubscriptionPauseChanged {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub subscriber: Pubkey,
+    pub paused: bool,
+    pub expires_at: i64,
+    pub remaining_secs: i64,
+}
+
+impl Discriminator for SubscriptionPauseChanged {
+    const DISCRIMINATOR: [u8; 8] = [188, 28, 137, 5, 154, 34, 218, 121];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VaultFlowQueued {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub owner: Pubkey,
+    pub epoch: u64,
+    pub deposit: u64,
+    pub withdrawal_shares: u64,
+}
+
+impl Discriminator for VaultFlowQueued {
+    const DISCRIMINATOR: [u8; 8] = [69, 247, 169, 209, 77, 195, 192, 72];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VaultEpochProcessed {
+    pub epoch: u64,
+    pub equity: u64,
+    pub deposits: u64,
+    pub minted_shares: u64,
+    pub withdrawal_shares: u64,
+    pub payout: u64,
+    pub total_shares: u64,
+    pub refunded_deposits: u64,
+}
+
+impl Discriminator for VaultEpochProcessed {
+    const DISCRIMINATOR: [u8; 8] = [177, 210, 73, 244, 226, 213, 198, 81];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VaultDepositorSettled {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub owner: Pubkey,
+    pub epoch: u64,
+    pub shares: u64,
+    pub payout: u64,
+    pub refund: u64, // Deposit handed back by an epoch that refunded its deposits
+}
+
+impl Discriminator for VaultDepositorSettled {
+    const DISCRIMINATOR: [u8; 8] = [83, 46, 220, 87, 190, 163, 172, 157];
+}
+
+/// Per-share attribution of one spot execution; a follower's part of it is
+/// their held shares times these
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExecutionAttributed {
+    pub id: u64,
+    pub side: ExecutionSide,
+    pub total_shares: u64,
+    pub entered_per_share: i128,
+    pub realized_pnl_per_share: i128,
+}
+
+impl Discriminator for ExecutionAttributed {
+    const DISCRIMINATOR: [u8; 8] = [215, 142, 55, 63, 180, 174, 3, 163];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VaultDepositorAccrued {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub owner: Pubkey,
+    pub shares: u64,
+    pub entered: u64,
+    pub realized_pnl: i64,
+}
+
+impl Discriminator for VaultDepositorAccrued {
+    const DISCRIMINATOR: [u8; 8] = [232, 175, 31, 238, 143, 79, 56, 35];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VaultStakingChanged {
+    pub staked_lamports: u64,
+    pub unstaked_pool_tokens: u64,
+    pub lst_amount: u64,
+    pub lst_lamports: u64,       // LST value at the pool's exchange rate
+}
+
+impl Discriminator for VaultStakingChanged {
+    const DISCRIMINATOR: [u8; 8] = [218, 228, 118, 35, 20, 93, 203, 133];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VaultEmergencyDeclared {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+impl Discriminator for VaultEmergencyDeclared {
+    const DISCRIMINATOR: [u8; 8] = [238, 165, 134, 214, 88, 38, 60, 70];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VaultEmergencyWithdrawal {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub owner: Pubkey,
+    pub shares: u64,
+    pub payout: u64,
+    pub refund: u64,             // Queued deposit handed back
+    pub owed: u64,               // Still owed once this payout is made
+}
+
+impl Discriminator for VaultEmergencyWithdrawal {
+    const DISCRIMINATOR: [u8; 8] = [42, 239, 131, 97, 208, 73, 2, 240];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VaultRebalanced {
+    pub id: u64,
+    pub side: ExecutionSide,
+    pub weight_bps: u64,         // Position's weight before the trade
+    pub target_bps: u16,
+    pub amount_in: u64,
+    pub amount_out: u64,
+}
+
+impl Discriminator for VaultRebalanced {
+    const DISCRIMINATOR: [u8; 8] = [117, 48, 126, 17, 29, 0, 200, 28];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "borsh", derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VaultFeesCrystallized {
+    pub epoch: u64,
+    pub equity: u64,             // Pre-fee equity the fee was charged on
+    pub fee: u64,
+    pub high_water_mark: u128,
+}
+
+impl Discriminator for VaultFeesCrystallized {
+    const DISCRIMINATOR: [u8; 8] = [23, 189, 236, 231, 149, 45, 243, 36];
+}
+
+/// Any event the program emits
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "event", content = "data"))]
+pub enum OracleEvent {
+    SignalPublished(SignalPublished),
+    SignalClosed(SignalClosed),
+    SignalPublishedWithProof(SignalPublishedWithProof),
+    ReasoningRevealed(ReasoningRevealed),
+    PriceChecked(PriceChecked),
+    ConfigUpdated(ConfigUpdated),
+    SignalExecuted(SignalExecuted),
+    ShortExecuted(ShortExecuted),
+    VaultLendingChanged(VaultLendingChanged),
+    AccessPassVerified(AccessPassVerified),
+    Subscribed(Subscribed),
+    SubscriberPassChanged(SubscriberPassChanged),
+    PublisherDomainLinked(PublisherDomainLinked),
+    SignalAlert(SignalAlert),
+    ThreadManaged(ThreadManaged),
+    ExecutionScheduled(ExecutionScheduled),
+    CompressedSignalWritten(CompressedSignalWritten),
+    SignalAssetMinted(SignalAssetMinted),
+    SignalNoteAdded(SignalNoteAdded),
+    SignalRated(SignalRated),
+    SignalBoosted(SignalBoosted),
+    FeaturedBidPlaced(FeaturedBidPlaced),
+    FeaturedAuctionSettled(FeaturedAuctionSettled),
+    BasketPublished(BasketPublished),
+    BasketClosed(BasketClosed),
+    PairPublished(PairPublished),
+    PairClosed(PairClosed),
+    PerpParamsSet(PerpParamsSet),
+    SignalSized(SignalSized),
+    MarketCapVerified(MarketCapVerified),
+    SupplyChanged(SupplyChanged),
+    HolderDistributionCommitted(HolderDistributionCommitted),
+    SignalRepublished(SignalRepublished),
+    SignalReopened(SignalReopened),
+    EntryAdded(EntryAdded),
+    DcaPlanSet(DcaPlanSet),
+    DcaTrancheExecuted(DcaTrancheExecuted),
+    TakeProfitsSet(TakeProfitsSet),
+    TakeProfitHit(TakeProfitHit),
+    InvalidationPriceSet(InvalidationPriceSet),
+    SignalInvalidated(SignalInvalidated),
+    SignalMarked(SignalMarked),
+    PortfolioEntered(PortfolioEntered),
+    PortfolioExited(PortfolioExited),
+    BenchmarkRecorded(BenchmarkRecorded),
+    VaultDerisked(VaultDerisked),
+    VaultDeriskReset(VaultDeriskReset),
+    AccessClaimed(AccessClaimed),
+    AccessLogged(AccessLogged),
+    TierAllowlistSet(TierAllowlistSet),
+    AllowlistChanged(AllowlistChanged),
+    SubscriptionTransferred(SubscriptionTransferred),
+    SubscriptionPauseChanged(SubscriptionPauseChanged),
+    VaultFlowQueued(VaultFlowQueued),
+    VaultEpochProcessed(VaultEpochProcessed),
+    VaultDepositorSettled(VaultDepositorSettled),
+    ExecutionAttributed(ExecutionAttributed),
+    VaultDepositorAccrued(VaultDepositorAccrued),
+    VaultStakingChanged(VaultStakingChanged),
+    VaultEmergencyDeclared(VaultEmergencyDeclared),
+    VaultEmergencyWithdrawal(VaultEmergencyWithdrawal),
+    VaultRebalanced(VaultRebalanced),
+    VaultFeesCrystallized(VaultFeesCrystallized),
+}
+
+impl OracleEvent {
+    /// Event name, as used in the IDL
+    pub fn name(&self) -> &'static str {
+        match self {
+            OracleEvent::SignalPublished(_) => "SignalPublished",
+            OracleEvent::SignalClosed(_) => "SignalClosed",
+            OracleEvent::SignalPublishedWithProof(_) => "SignalPublishedWithProof",
+            OracleEvent::ReasoningRevealed(_) => "ReasoningRevealed",
+            OracleEvent::PriceChecked(_) => "PriceChecked",
+            OracleEvent::ConfigUpdated(_) => "ConfigUpdated",
+            OracleEvent::SignalExecuted(_) => "SignalExecuted",
+            OracleEvent::ShortExecuted(_) => "ShortExecuted",
+            OracleEvent::VaultLendingChanged(_) => "VaultLendingChanged",
+            OracleEvent::AccessPassVerified(_) => "AccessPassVerified",
+            OracleEvent::Subscribed(_) => "Subscribed",
+            OracleEvent::SubscriberPassChanged(_) => "SubscriberPassChanged",
+            OracleEvent::PublisherDomainLinked(_) => "PublisherDomainLinked",
+            OracleEvent::SignalAlert(_) => "SignalAlert",
+            OracleEvent::ThreadManaged(_) => "ThreadManaged",
+            OracleEvent::ExecutionScheduled(_) => "ExecutionScheduled",
+            OracleEvent::CompressedSignalWritten(_) => "CompressedSignalWritten",
+            OracleEvent::SignalAssetMinted(_) => "SignalAssetMinted",
+            OracleEvent::SignalNoteAdded(_) => "SignalNoteAdded",
+            OracleEvent::SignalRated(_) => "SignalRated",
+            OracleEvent::SignalBoosted(_) => "SignalBoosted",
+            OracleEvent::FeaturedBidPlaced(_) => "FeaturedBidPlaced",
+            OracleEvent::FeaturedAuctionSettled(_) => "FeaturedAuctionSettled",
+            OracleEvent::BasketPublished(_) => "BasketPublished",
+            OracleEvent::BasketClosed(_) => "BasketClosed",
+            OracleEvent::PairPublished(_) => "PairPublished",
+            OracleEvent::PairClosed(_) => "PairClosed",
+            OracleEvent::PerpParamsSet(_) => "PerpParamsSet",
+            OracleEvent::SignalSized(_) => "SignalSized",
+            OracleEvent::MarketCapVerified(_) => "MarketCapVerified",
+            OracleEvent::SupplyChanged(_) => "SupplyChanged",
+            OracleEvent::HolderDistributionCommitted(_) => "HolderDistributionCommitted",
+            OracleEvent::SignalRepublished(_) => "SignalRepublished",
+            OracleEvent::SignalReopened(_) => "SignalReopened",
+            OracleEvent::EntryAdded(_) => "EntryAdded",
+            OracleEvent::DcaPlanSet(_) => "DcaPlanSet",
+            OracleEvent::DcaTrancheExecuted(_) => "DcaTrancheExecuted",
+            OracleEvent::TakeProfitsSet(_) => "TakeProfitsSet",
+            OracleEvent::TakeProfitHit(_) => "TakeProfitHit",
+            OracleEvent::InvalidationPriceSet(_) => "InvalidationPriceSet",
+            OracleEvent::SignalInvalidated(_) => "SignalInvalidated",
+            OracleEvent::SignalMarked(_) => "SignalMarked",
+            OracleEvent::PortfolioEntered(_) => "PortfolioEntered",
+            OracleEvent::PortfolioExited(_) => "PortfolioExited",
+            OracleEvent::BenchmarkRecorded(_) => "BenchmarkRecorded",
+            OracleEvent::VaultDerisked(_) => "VaultDerisked",
+            OracleEvent::VaultDeriskReset(_) => "VaultDeriskReset",
+            OracleEvent::AccessClaimed(_) => "AccessClaimed",
+            OracleEvent::AccessLogged(_) => "AccessLogged",
+            OracleEvent::TierAllowlistSet(_) => "TierAllowlistSet",
+            OracleEvent::AllowlistChanged(_) => "AllowlistChanged",
+            OracleEvent::SubscriptionTransferred(_) => "SubscriptionTransferred",
+            OracleEvent::SubscriptionPauseChanged(_) => "SubscriptionPauseChanged",
+            OracleEvent::VaultFlowQueued(_) => "VaultFlowQueued",
+            OracleEvent::VaultEpochProcessed(_) => "VaultEpochProcessed",
+            OracleEvent::VaultDepositorSettled(_) => "VaultDepositorSettled",
+            OracleEvent::ExecutionAttributed(_) => "ExecutionAttributed",
+            OracleEvent::VaultDepositorAccrued(_) => "VaultDepositorAccrued",
+            OracleEvent::VaultStakingChanged(_) => "VaultStakingChanged",
+            OracleEvent::VaultEmergencyDeclared(_) => "VaultEmergencyDeclared",
+            OracleEvent::VaultEmergencyWithdrawal(_) => "VaultEmergencyWithdrawal",
+            OracleEvent::VaultRebalanced(_) => "VaultRebalanced",
+            OracleEvent::VaultFeesCrystallized(_) => "VaultFeesCrystallized",
+        }
+    }
+}
+
+/// Decode event data of any type, dispatching on its discriminator
+#[cfg(feature = "borsh")]
+pub fn decode_any_event(data: &[u8]) -> Option<OracleEvent> {
+    let discriminator: [u8; 8] = data.get(..8)?.try_into().ok()?;
+    match discriminator {
+        SignalPublished::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::SignalPublished),
+        SignalClosed::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::SignalClosed),
+        SignalPublishedWithProof::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::SignalPublishedWithProof),
+        ReasoningRevealed::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::ReasoningRevealed),
+        PriceChecked::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::PriceChecked),
+        ConfigUpdated::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::ConfigUpdated),
+        SignalExecuted::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::SignalExecuted),
+        ShortExecuted::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::ShortExecuted),
+        VaultLendingChanged::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::VaultLendingChanged),
+        AccessPassVerified::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::AccessPassVerified),
+        Subscribed::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::Subscribed),
+        SubscriberPassChanged::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::SubscriberPassChanged),
+        PublisherDomainLinked::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::PublisherDomainLinked),
+        SignalAlert::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::SignalAlert),
+        ThreadManaged::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::ThreadManaged),
+        ExecutionScheduled::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::ExecutionScheduled),
+        CompressedSignalWritten::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::CompressedSignalWritten),
+        SignalAssetMinted::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::SignalAssetMinted),
+        SignalNoteAdded::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::SignalNoteAdded),
+        SignalRated::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::SignalRated),
+        SignalBoosted::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::SignalBoosted),
+        FeaturedBidPlaced::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::FeaturedBidPlaced),
+        FeaturedAuctionSettled::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::FeaturedAuctionSettled),
+        BasketPublished::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::BasketPublished),
+        BasketClosed::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::BasketClosed),
+        PairPublished::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::PairPublished),
+        PairClosed::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::PairClosed),
+        PerpParamsSet::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::PerpParamsSet),
+        SignalSized::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::SignalSized),
+        MarketCapVerified::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::MarketCapVerified),
+        SupplyChanged::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::SupplyChanged),
+        HolderDistributionCommitted::DISCRIMINATOR => {
+            crate::decode_event(data).map(OracleEvent::HolderDistributionCommitted)
+        }
+        SignalRepublished::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::SignalRepublished),
+        SignalReopened::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::SignalReopened),
+        EntryAdded::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::EntryAdded),
+        DcaPlanSet::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::DcaPlanSet),
+        DcaTrancheExecuted::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::DcaTrancheExecuted),
+        TakeProfitsSet::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::TakeProfitsSet),
+        TakeProfitHit::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::TakeProfitHit),
+        InvalidationPriceSet::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::InvalidationPriceSet),
+        SignalInvalidated::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::SignalInvalidated),
+        SignalMarked::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::SignalMarked),
+        PortfolioEntered::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::PortfolioEntered),
+        PortfolioExited::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::PortfolioExited),
+        BenchmarkRecorded::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::BenchmarkRecorded),
+        VaultDerisked::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::VaultDerisked),
+        VaultDeriskReset::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::VaultDeriskReset),
+        AccessClaimed::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::AccessClaimed),
+        AccessLogged::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::AccessLogged),
+        TierAllowlistSet::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::TierAllowlistSet),
+        AllowlistChanged::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::AllowlistChanged),
+        SubscriptionTransferred::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::SubscriptionTransferred),
+        SubscriptionPauseChanged::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::SubscriptionPauseChanged),
+        VaultFlowQueued::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::VaultFlowQueued),
+        VaultEpochProcessed::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::VaultEpochProcessed),
+        VaultDepositorSettled::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::VaultDepositorSettled),
+        ExecutionAttributed::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::ExecutionAttributed),
+        VaultDepositorAccrued::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::VaultDepositorAccrued),
+        VaultStakingChanged::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::VaultStakingChanged),
+        VaultEmergencyDeclared::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::VaultEmergencyDeclared),
+        VaultEmergencyWithdrawal::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::VaultEmergencyWithdrawal),
+        VaultRebalanced::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::VaultRebalanced),
+        VaultFeesCrystallized::DISCRIMINATOR => crate::decode_event(data).map(OracleEvent::VaultFeesCrystallized),
+        _ => None,
+    }
+}
@@ -0,0 +1,61 @@
+//! Account and event layouts written by the ORACLE Alpha program.
+//!
+//! Field order and types match the program exactly, so Borsh-decoding an
+//! account (after its 8-byte discriminator) or an event yields the same
+//! values the program wrote. Serde derives are behind the `serde` feature
+//! for APIs and indexers that re-encode them; pubkeys serialize as base58
+//! strings.
+//!
+//! With the default `pda` feature, [`pda`] derives every program address
+//! without an RPC connection or the program crate.
+
+pub mod accounts;
+pub mod events;
+#[cfg(feature = "pda")]
+pub mod pda;
+#[cfg(feature = "serde")]
+mod serde_pubkey;
+
+pub use accounts::*;
+pub use events::*;
+
+/// Address of the ORACLE Alpha program
+pub const PROGRAM_ID: solana_pubkey::Pubkey = solana_pubkey::pubkey!("AL9bxB2BUHnPptqzospgwyeet8RwBbd4NmYmxuiNNzXd");
+
+/// Number of risk levels a signal can carry
+pub const RISK_LEVEL_COUNT: usize = 4;
+/// Number of subscription tiers
+pub const TIER_COUNT: usize = 3;
+/// Number of buckets in the settled-ROI histogram
+pub const ROI_BUCKET_COUNT: usize = 16;
+/// Ratings run from 1 to this many stars
+pub const MAX_RATING: u8 = 5;
+/// Number of featured slots in the discovery account
+pub const FEATURED_SLOTS: usize = 8;
+/// Benchmarks signals can be measured against (0 = SOL, 1 = BTC)
+pub const BENCHMARK_COUNT: usize = 2;
+/// Fixed-point scale of the vault's per-share accumulators
+pub const PER_SHARE_SCALE: i128 = 1_000_000_000_000;
+
+/// 8-byte Anchor discriminator prefixing an account or event
+pub trait Discriminator {
+    const DISCRIMINATOR: [u8; 8];
+}
+
+/// Decode an account's data, checking its discriminator
+///
+/// Trailing bytes are ignored: accounts are sized for their longest
+/// strings, so most carry unused space after the encoded value.
+#[cfg(feature = "borsh")]
+pub fn decode_account<T: Discriminator + borsh::BorshDeserialize>(data: &[u8]) -> Option<T> {
+    let body = data.strip_prefix(&T::DISCRIMINATOR)?;
+    T::deserialize(&mut &body[..]).ok()
+}
+
+/// Decode event data (as found in `Program data:` logs or CPI event data),
+/// checking its discriminator
+#[cfg(feature = "borsh")]
+pub fn decode_event<T: Discriminator + borsh::BorshDeserialize>(data: &[u8]) -> Option<T> {
+    let body = data.strip_prefix(&T::DISCRIMINATOR)?;
+    T::try_from_slice(body).ok()
+}
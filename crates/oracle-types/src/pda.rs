@@ -0,0 +1,396 @@
+//! Offline PDA derivation.
+//!
+//! Seeds mirror the program's `*_SEED` constants. Each `find_*` function
+//! returns the address and its bump (what another program's CPI or an
+//! airgapped signer needs); the plain functions return just the address.
+
+use solana_pubkey::Pubkey;
+
+use crate::PROGRAM_ID;
+
+pub const ORACLE_STATE_SEED: &[u8] = b"oracle_state";
+pub const SIGNAL_SEED: &[u8] = b"signal";
+pub const ORACLE_CONFIG_SEED: &[u8] = b"oracle_config";
+pub const VAULT_SEED: &[u8] = b"vault";
+pub const POSITION_SEED: &[u8] = b"position";
+pub const TICKET_SEED: &[u8] = b"ticket";
+pub const SUBSCRIPTION_SEED: &[u8] = b"subscription";
+pub const TREASURY_SEED: &[u8] = b"treasury";
+pub const PASS_MINT_SEED: &[u8] = b"pass_mint";
+pub const PUBLISHER_SEED: &[u8] = b"publisher";
+pub const TREE_AUTHORITY_SEED: &[u8] = b"tree_authority";
+pub const SIGNAL_ASSET_SEED: &[u8] = b"signal_asset";
+pub const PUBLISHER_STATS_SEED: &[u8] = b"publisher_stats";
+pub const SIGNAL_NOTES_SEED: &[u8] = b"signal_notes";
+pub const SIGNAL_RATING_SEED: &[u8] = b"signal_rating";
+pub const RATING_RECEIPT_SEED: &[u8] = b"rating_receipt";
+pub const SIGNAL_BOOST_SEED: &[u8] = b"signal_boost";
+pub const DISCOVERY_SEED: &[u8] = b"discovery";
+pub const AUCTION_SEED: &[u8] = b"auction";
+pub const BASKET_SEED: &[u8] = b"basket";
+pub const PAIR_SIGNAL_SEED: &[u8] = b"pair_signal";
+pub const PERP_PARAMS_SEED: &[u8] = b"perp_params";
+pub const SIGNAL_SIZING_SEED: &[u8] = b"signal_sizing";
+pub const SIGNAL_MARKET_CAP_SEED: &[u8] = b"signal_market_cap";
+pub const SUPPLY_SNAPSHOT_SEED: &[u8] = b"supply_snapshot";
+pub const HOLDER_COMMITMENT_SEED: &[u8] = b"holder_commitment";
+pub const TOKEN_HISTORY_SEED: &[u8] = b"token_history";
+pub const SIGNAL_LINEAGE_SEED: &[u8] = b"signal_lineage";
+pub const SIGNAL_ENTRIES_SEED: &[u8] = b"signal_entries";
+pub const DCA_PLAN_SEED: &[u8] = b"dca_plan";
+pub const TAKE_PROFIT_SEED: &[u8] = b"take_profit";
+pub const INVALIDATION_SEED: &[u8] = b"invalidation";
+pub const MODEL_PORTFOLIO_SEED: &[u8] = b"model_portfolio";
+pub const SIGNAL_BENCHMARK_SEED: &[u8] = b"signal_benchmark";
+pub const ACCESS_CLAIM_SEED: &[u8] = b"access_claim";
+pub const TIER_ALLOWLIST_SEED: &[u8] = b"tier_allowlist";
+pub const ALLOWLIST_ENTRY_SEED: &[u8] = b"allowlist_entry";
+pub const VAULT_DEPOSITOR_SEED: &[u8] = b"vault_depositor";
+pub const VAULT_EPOCH_SEED: &[u8] = b"vault_epoch";
+pub const VAULT_SOL_SEED: &[u8] = b"vault_sol";
+
+fn find(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &PROGRAM_ID)
+}
+
+pub fn find_oracle_state() -> (Pubkey, u8) {
+    find(&[ORACLE_STATE_SEED])
+}
+
+pub fn oracle_state() -> Pubkey {
+    find_oracle_state().0
+}
+
+pub fn find_config() -> (Pubkey, u8) {
+    find(&[ORACLE_CONFIG_SEED])
+}
+
+pub fn config() -> Pubkey {
+    find_config().0
+}
+
+/// Signal account for signal `id` (its index in publish order)
+pub fn find_signal(id: u64) -> (Pubkey, u8) {
+    find(&[SIGNAL_SEED, &id.to_le_bytes()])
+}
+
+pub fn signal(id: u64) -> Pubkey {
+    find_signal(id).0
+}
+
+pub fn find_vault() -> (Pubkey, u8) {
+    find(&[VAULT_SEED])
+}
+
+pub fn vault() -> Pubkey {
+    find_vault().0
+}
+
+/// Vault position opened for `signal`
+pub fn find_position(signal: &Pubkey) -> (Pubkey, u8) {
+    find(&[POSITION_SEED, signal.as_ref()])
+}
+
+pub fn position(signal: &Pubkey) -> Pubkey {
+    find_position(signal).0
+}
+
+/// Execution ticket scheduled for `signal`
+pub fn find_ticket(signal: &Pubkey) -> (Pubkey, u8) {
+    find(&[TICKET_SEED, signal.as_ref()])
+}
+
+pub fn ticket(signal: &Pubkey) -> Pubkey {
+    find_ticket(signal).0
+}
+
+pub fn find_subscription(subscriber: &Pubkey) -> (Pubkey, u8) {
+    find(&[SUBSCRIPTION_SEED, subscriber.as_ref()])
+}
+
+pub fn subscription(subscriber: &Pubkey) -> Pubkey {
+    find_subscription(subscriber).0
+}
+
+pub fn find_treasury() -> (Pubkey, u8) {
+    find(&[TREASURY_SEED])
+}
+
+pub fn treasury() -> Pubkey {
+    find_treasury().0
+}
+
+/// Token-2022 mint of the soulbound subscriber pass
+pub fn find_pass_mint() -> (Pubkey, u8) {
+    find(&[PASS_MINT_SEED])
+}
+
+pub fn pass_mint() -> Pubkey {
+    find_pass_mint().0
+}
+
+pub fn find_publisher(wallet: &Pubkey) -> (Pubkey, u8) {
+    find(&[PUBLISHER_SEED, wallet.as_ref()])
+}
+
+pub fn publisher(wallet: &Pubkey) -> Pubkey {
+    find_publisher(wallet).0
+}
+
+/// Authority of the compressed-mode signal tree
+pub fn find_tree_authority() -> (Pubkey, u8) {
+    find(&[TREE_AUTHORITY_SEED])
+}
+
+pub fn tree_authority() -> Pubkey {
+    find_tree_authority().0
+}
+
+/// Metaplex Core asset minted for `signal`
+pub fn find_signal_asset(signal: &Pubkey) -> (Pubkey, u8) {
+    find(&[SIGNAL_ASSET_SEED, signal.as_ref()])
+}
+
+pub fn signal_asset(signal: &Pubkey) -> Pubkey {
+    find_signal_asset(signal).0
+}
+
+/// Settlement statistics of the publisher signing with `wallet`
+pub fn find_publisher_stats(wallet: &Pubkey) -> (Pubkey, u8) {
+    find(&[PUBLISHER_STATS_SEED, wallet.as_ref()])
+}
+
+pub fn publisher_stats(wallet: &Pubkey) -> Pubkey {
+    find_publisher_stats(wallet).0
+}
+
+/// Notes appended to `signal`
+pub fn find_signal_notes(signal: &Pubkey) -> (Pubkey, u8) {
+    find(&[SIGNAL_NOTES_SEED, signal.as_ref()])
+}
+
+pub fn signal_notes(signal: &Pubkey) -> Pubkey {
+    find_signal_notes(signal).0
+}
+
+/// Subscriber rating accumulator of `signal`
+pub fn find_signal_rating(signal: &Pubkey) -> (Pubkey, u8) {
+    find(&[SIGNAL_RATING_SEED, signal.as_ref()])
+}
+
+pub fn signal_rating(signal: &Pubkey) -> Pubkey {
+    find_signal_rating(signal).0
+}
+
+/// Receipt of `subscriber`'s rating of `signal`
+pub fn find_rating_receipt(signal: &Pubkey, subscriber: &Pubkey) -> (Pubkey, u8) {
+    find(&[RATING_RECEIPT_SEED, signal.as_ref(), subscriber.as_ref()])
+}
+
+pub fn rating_receipt(signal: &Pubkey, subscriber: &Pubkey) -> Pubkey {
+    find_rating_receipt(signal, subscriber).0
+}
+
+/// Paid boosts of `signal`
+pub fn find_signal_boost(signal: &Pubkey) -> (Pubkey, u8) {
+    find(&[SIGNAL_BOOST_SEED, signal.as_ref()])
+}
+
+pub fn signal_boost(signal: &Pubkey) -> Pubkey {
+    find_signal_boost(signal).0
+}
+
+/// Featured slots of the signal feed
+pub fn find_discovery() -> (Pubkey, u8) {
+    find(&[DISCOVERY_SEED])
+}
+
+pub fn discovery() -> Pubkey {
+    find_discovery().0
+}
+
+/// Featured-slot auction of `epoch`
+pub fn find_auction(epoch: u64) -> (Pubkey, u8) {
+    find(&[AUCTION_SEED, &epoch.to_le_bytes()])
+}
+
+pub fn auction(epoch: u64) -> Pubkey {
+    find_auction(epoch).0
+}
+
+/// Basket signal `id`
+pub fn find_basket(id: u64) -> (Pubkey, u8) {
+    find(&[BASKET_SEED, &id.to_le_bytes()])
+}
+
+pub fn basket(id: u64) -> Pubkey {
+    find_basket(id).0
+}
+
+/// Pair signal `id`
+pub fn find_pair_signal(id: u64) -> (Pubkey, u8) {
+    find(&[PAIR_SIGNAL_SEED, &id.to_le_bytes()])
+}
+
+pub fn pair_signal(id: u64) -> Pubkey {
+    find_pair_signal(id).0
+}
+
+/// Perps execution guidance of `signal`
+pub fn find_perp_params(signal: &Pubkey) -> (Pubkey, u8) {
+    find(&[PERP_PARAMS_SEED, signal.as_ref()])
+}
+
+pub fn perp_params(signal: &Pubkey) -> Pubkey {
+    find_perp_params(signal).0
+}
+
+/// Position sizing suggested for `signal` at publish
+pub fn find_signal_sizing(signal: &Pubkey) -> (Pubkey, u8) {
+    find(&[SIGNAL_SIZING_SEED, signal.as_ref()])
+}
+
+pub fn signal_sizing(signal: &Pubkey) -> Pubkey {
+    find_signal_sizing(signal).0
+}
+
+/// Verified market cap of `signal`
+pub fn find_signal_market_cap(signal: &Pubkey) -> (Pubkey, u8) {
+    find(&[SIGNAL_MARKET_CAP_SEED, signal.as_ref()])
+}
+
+pub fn signal_market_cap(signal: &Pubkey) -> Pubkey {
+    find_signal_market_cap(signal).0
+}
+
+/// Mint supply snapshots of `signal`
+pub fn find_supply_snapshot(signal: &Pubkey) -> (Pubkey, u8) {
+    find(&[SUPPLY_SNAPSHOT_SEED, signal.as_ref()])
+}
+
+pub fn supply_snapshot(signal: &Pubkey) -> Pubkey {
+    find_supply_snapshot(signal).0
+}
+
+/// Top-holder distribution commitment of `signal`
+pub fn find_holder_commitment(signal: &Pubkey) -> (Pubkey, u8) {
+    find(&[HOLDER_COMMITMENT_SEED, signal.as_ref()])
+}
+
+pub fn holder_commitment(signal: &Pubkey) -> Pubkey {
+    find_holder_commitment(signal).0
+}
+
+/// Last close of any signal on `token`
+pub fn find_token_history(token: &Pubkey) -> (Pubkey, u8) {
+    find(&[TOKEN_HISTORY_SEED, token.as_ref()])
+}
+
+pub fn token_history(token: &Pubkey) -> Pubkey {
+    find_token_history(token).0
+}
+
+/// Lineage of `signal` when it reopened an earlier attempt
+pub fn find_signal_lineage(signal: &Pubkey) -> (Pubkey, u8) {
+    find(&[SIGNAL_LINEAGE_SEED, signal.as_ref()])
+}
+
+pub fn signal_lineage(signal: &Pubkey) -> Pubkey {
+    find_signal_lineage(signal).0
+}
+
+pub fn find_signal_entries(signal: &Pubkey) -> (Pubkey, u8) {
+    find(&[SIGNAL_ENTRIES_SEED, signal.as_ref()])
+}
+
+pub fn signal_entries(signal: &Pubkey) -> Pubkey {
+    find_signal_entries(signal).0
+}
+
+pub fn find_dca_plan(signal: &Pubkey) -> (Pubkey, u8) {
+    find(&[DCA_PLAN_SEED, signal.as_ref()])
+}
+
+pub fn dca_plan(signal: &Pubkey) -> Pubkey {
+    find_dca_plan(signal).0
+}
+
+pub fn find_take_profit(signal: &Pubkey) -> (Pubkey, u8) {
+    find(&[TAKE_PROFIT_SEED, signal.as_ref()])
+}
+
+pub fn take_profit(signal: &Pubkey) -> Pubkey {
+    find_take_profit(signal).0
+}
+
+pub fn find_invalidation(signal: &Pubkey) -> (Pubkey, u8) {
+    find(&[INVALIDATION_SEED, signal.as_ref()])
+}
+
+pub fn invalidation(signal: &Pubkey) -> Pubkey {
+    find_invalidation(signal).0
+}
+
+pub fn find_model_portfolio() -> (Pubkey, u8) {
+    find(&[MODEL_PORTFOLIO_SEED])
+}
+
+pub fn model_portfolio() -> Pubkey {
+    find_model_portfolio().0
+}
+
+pub fn find_signal_benchmark(signal: &Pubkey) -> (Pubkey, u8) {
+    find(&[SIGNAL_BENCHMARK_SEED, signal.as_ref()])
+}
+
+pub fn signal_benchmark(signal: &Pubkey) -> Pubkey {
+    find_signal_benchmark(signal).0
+}
+
+pub fn find_access_claim(signal: &Pubkey, subscriber: &Pubkey) -> (Pubkey, u8) {
+    find(&[ACCESS_CLAIM_SEED, signal.as_ref(), subscriber.as_ref()])
+}
+
+pub fn access_claim(signal: &Pubkey, subscriber: &Pubkey) -> Pubkey {
+    find_access_claim(signal, subscriber).0
+}
+
+pub fn find_tier_allowlist(tier: u8) -> (Pubkey, u8) {
+    find(&[TIER_ALLOWLIST_SEED, &[tier]])
+}
+
+pub fn tier_allowlist(tier: u8) -> Pubkey {
+    find_tier_allowlist(tier).0
+}
+
+pub fn find_allowlist_entry(tier: u8, wallet: &Pubkey) -> (Pubkey, u8) {
+    find(&[ALLOWLIST_ENTRY_SEED, &[tier], wallet.as_ref()])
+}
+
+pub fn allowlist_entry(tier: u8, wallet: &Pubkey) -> Pubkey {
+    find_allowlist_entry(tier, wallet).0
+}
+
+pub fn find_vault_depositor(owner: &Pubkey) -> (Pubkey, u8) {
+    find(&[VAULT_DEPOSITOR_SEED, owner.as_ref()])
+}
+
+pub fn vault_depositor(owner: &Pubkey) -> Pubkey {
+    find_vault_depositor(owner).0
+}
+
+pub fn find_vault_epoch(epoch: u64) -> (Pubkey, u8) {
+    find(&[VAULT_EPOCH_SEED, &epoch.to_le_bytes()])
+}
+
+pub fn vault_epoch(epoch: u64) -> Pubkey {
+    find_vault_epoch(epoch).0
+}
+
+pub fn find_vault_sol() -> (Pubkey, u8) {
+    find(&[VAULT_SOL_SEED])
+}
+
+pub fn vault_sol() -> Pubkey {
+    find_vault_sol().0
+}
@@ -0,0 +1,50 @@
+//! Serde adapter encoding a `Pubkey` as its base58 string.
+
+use serde::{de, Deserialize, Deserializer, Serializer};
+use solana_pubkey::Pubkey;
+
+pub fn serialize<S: Serializer>(pubkey: &Pubkey, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(pubkey)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pubkey, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(de::Error::custom)
+}
+
+/// Same, for an optional `Pubkey`
+pub mod option {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(pubkey: &Option<Pubkey>, serializer: S) -> Result<S::Ok, S::Error> {
+        match pubkey {
+            Some(pubkey) => serializer.collect_str(pubkey),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Pubkey>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|s| s.parse().map_err(de::Error::custom))
+            .transpose()
+    }
+}
+
+/// Same, for a fixed-size array of `Pubkey`s
+pub mod array {
+    use super::*;
+
+    pub fn serialize<S: Serializer, const N: usize>(pubkeys: &[Pubkey; N], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(pubkeys.iter().map(|pubkey| pubkey.to_string()))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(deserializer: D) -> Result<[Pubkey; N], D::Error> {
+        let strings = Vec::<String>::deserialize(deserializer)?;
+        let len = strings.len();
+        let pubkeys = strings
+            .into_iter()
+            .map(|s| s.parse().map_err(de::Error::custom))
+            .collect::<Result<Vec<Pubkey>, _>>()?;
+        pubkeys.try_into().map_err(|_| de::Error::invalid_length(len, &"a fixed-size pubkey array"))
+    }
+}
@@ -0,0 +1,101 @@
+//! Layout parity between the program's types and their `oracle_types` mirrors.
+//!
+//! Every account and event is encoded by the program crate, decoded with
+//! `oracle_types` and re-encoded; the bytes must match. Decoding starts from
+//! an all-zero value and then sets each byte of its encoding in turn, so
+//! bools, enum tags, options and collections are walked one field at a time.
+
+use anchor_lang::{AccountSerialize, AnchorDeserialize, Event};
+use oracle_types::{decode_account, decode_event, Discriminator};
+
+/// Room for the largest value a single set byte can decode to
+const BUFFER_LEN: usize = 1 << 16;
+
+/// Program-side encodings of the zero value and of every value reachable by
+/// setting one byte of its encoding
+fn encodings<P: AnchorDeserialize>(encode: impl Fn(&P) -> Vec<u8>) -> Vec<Vec<u8>> {
+    let mut buffer = vec![0u8; BUFFER_LEN];
+    let zero = encode(&P::deserialize(&mut &buffer[..]).expect("all-zero bytes decode"));
+    let len = zero.len() - 8;
+    let mut out = vec![zero];
+    for i in 0..len {
+        buffer[i] = 1;
+        if let Ok(value) = P::deserialize(&mut &buffer[..]) {
+            out.push(encode(&value));
+        }
+        buffer[i] = 0;
+    }
+    out
+}
+
+fn check_reencodes<T>(name: &str, data: &[u8], decoded: Option<T>)
+where
+    T: Discriminator + borsh::BorshSerialize,
+{
+    let decoded = decoded.unwrap_or_else(|| panic!("{name} does not decode: {data:?}"));
+    let reencoded = [&T::DISCRIMINATOR[..], &borsh::to_vec(&decoded).unwrap()].concat();
+    assert_eq!(reencoded, data, "{name} re-encodes differently");
+}
+
+fn account_parity<P, T>(name: &str)
+where
+    P: AccountSerialize + AnchorDeserialize,
+    T: Discriminator + borsh::BorshDeserialize + borsh::BorshSerialize,
+{
+    let encode = |value: &P| {
+        let mut data = Vec::new();
+        value.try_serialize(&mut data).expect("account serializes");
+        data
+    };
+    for data in encodings(encode) {
+        check_reencodes(name, &data, decode_account::<T>(&data));
+    }
+}
+
+fn event_parity<P, T>(name: &str)
+where
+    P: Event + AnchorDeserialize,
+    T: Discriminator + borsh::BorshDeserialize + borsh::BorshSerialize,
+{
+    for data in encodings(|value: &P| value.data()) {
+        check_reencodes(name, &data, decode_event::<T>(&data));
+    }
+}
+
+macro_rules! parity {
+    ($check:ident: $($name:ident,)*) => {
+        $($check::<oracle::$name, oracle_types::$name>(stringify!($name));)*
+    };
+}
+
+#[test]
+fn accounts_match_the_program() {
+    parity!(account_parity:
+        AccessClaim, AllowlistEntry, Basket, DcaPlan, Discovery, ExecutionTicket, FeaturedAuction,
+        HolderCommitment, Invalidation, ModelPortfolio, OracleConfig, OracleState, PairSignal,
+        PerpParams, Publisher, PublisherStats, RatingReceipt, Signal, SignalBenchmark, SignalBoost,
+        SignalEntries, SignalLineage, SignalMarketCap, SignalNotes, SignalRating, SignalSizing,
+        Subscription, SupplySnapshot, TakeProfitLadder, TierAllowlist, TokenHistory, Vault,
+        VaultDepositor, VaultEpoch, VaultPosition,
+    );
+}
+
+#[test]
+fn events_match_the_program() {
+    parity!(event_parity:
+        AccessClaimed, AccessLogged, AccessPassVerified, AllowlistChanged, BasketClosed,
+        BasketPublished, BenchmarkRecorded, CompressedSignalWritten, ConfigUpdated, DcaPlanSet,
+        DcaTrancheExecuted, EntryAdded, ExecutionAttributed, ExecutionScheduled,
+        FeaturedAuctionSettled, FeaturedBidPlaced, HolderDistributionCommitted,
+        InvalidationPriceSet, MarketCapVerified, PairClosed, PairPublished, PerpParamsSet,
+        PortfolioEntered, PortfolioExited, PriceChecked, PublisherDomainLinked, ReasoningRevealed,
+        ShortExecuted, SignalAlert, SignalAssetMinted, SignalBoosted, SignalClosed, SignalExecuted,
+        SignalInvalidated, SignalMarked, SignalNoteAdded, SignalPublished, SignalPublishedWithProof,
+        SignalRated, SignalReopened, SignalRepublished, SignalSized, Subscribed,
+        SubscriberPassChanged, SubscriptionPauseChanged, SubscriptionTransferred, SupplyChanged,
+        TakeProfitHit, TakeProfitsSet, ThreadManaged, TierAllowlistSet, VaultDepositorAccrued,
+        VaultDepositorSettled, VaultDeriskReset, VaultDerisked, VaultEmergencyDeclared,
+        VaultEmergencyWithdrawal, VaultEpochProcessed, VaultFeesCrystallized, VaultFlowQueued,
+        VaultLendingChanged, VaultRebalanced, VaultStakingChanged,
+    );
+}
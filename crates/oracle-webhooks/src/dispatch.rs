@@ -0,0 +1,122 @@
+//! Delivery loop.
+//!
+//! Each webhook walks the indexer's `events` table in `seq` order from its
+//! own cursor. An event is retried with exponential backoff; once retries
+//! are exhausted it is written to `dead_letters` and the cursor moves on,
+//! so one bad event cannot stall a webhook forever.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Result};
+use serde_json::{json, Value};
+use tokio_postgres::Client;
+
+use crate::sign::{signature_header, SIGNATURE_HEADER};
+
+pub struct Webhook {
+    pub id: i64,
+    pub url: String,
+    pub secret: String,
+    pub kinds: Option<Vec<String>>,
+    pub cursor: i64,
+}
+
+#[derive(Clone, Copy)]
+pub struct Policy {
+    pub attempts: u32,
+    pub initial_backoff: Duration,
+    pub batch: i64,
+}
+
+pub async fn active_webhooks(db: &Client) -> Result<Vec<Webhook>> {
+    let rows = db
+        .query("SELECT id, url, secret, kinds, cursor FROM webhooks WHERE active ORDER BY id", &[])
+        .await?;
+    Ok(rows
+        .iter()
+        .map(|row| Webhook {
+            id: row.get("id"),
+            url: row.get("url"),
+            secret: row.get("secret"),
+            kinds: row.get("kinds"),
+            cursor: row.get("cursor"),
+        })
+        .collect())
+}
+
+/// Deliver one batch of pending events to `webhook`; returns how many were handled
+pub async fn run_webhook(db: &Client, http: &reqwest::Client, webhook: &Webhook, policy: Policy) -> Result<usize> {
+    let rows = db
+        .query(
+            "SELECT seq, kind, signature, slot, block_time, data FROM events
+             WHERE seq > $1 AND ($2::TEXT[] IS NULL OR kind = ANY($2))
+             ORDER BY seq LIMIT $3",
+            &[&webhook.cursor, &webhook.kinds, &policy.batch],
+        )
+        .await?;
+
+    for row in &rows {
+        let seq: i64 = row.get("seq");
+        let payload = json!({
+            "id": seq,
+            "kind": row.get::<_, String>("kind"),
+            "signature": row.get::<_, String>("signature"),
+            "slot": row.get::<_, i64>("slot"),
+            "block_time": row.get::<_, Option<i64>>("block_time"),
+            "data": row.get::<_, Value>("data"),
+        });
+
+        if let Err((err, attempts)) = deliver_with_retry(http, webhook, &payload, policy).await {
+            eprintln!("webhook {}: event {seq} dead-lettered after {attempts} attempt(s): {err:#}", webhook.id);
+            db.execute(
+                "INSERT INTO dead_letters (webhook_id, event_seq, payload, error, attempts)
+                 VALUES ($1, $2, $3, $4, $5)",
+                &[&webhook.id, &seq, &payload, &format!("{err:#}"), &(attempts as i32)],
+            )
+            .await?;
+        }
+        db.execute("UPDATE webhooks SET cursor = $2 WHERE id = $1", &[&webhook.id, &seq])
+            .await?;
+    }
+    Ok(rows.len())
+}
+
+async fn deliver_with_retry(
+    http: &reqwest::Client,
+    webhook: &Webhook,
+    payload: &Value,
+    policy: Policy,
+) -> Result<(), (anyhow::Error, u32)> {
+    let mut backoff = policy.initial_backoff;
+    let mut attempt = 1;
+    loop {
+        match deliver(http, &webhook.url, &webhook.secret, payload).await {
+            Ok(()) => return Ok(()),
+            Err(err) if attempt >= policy.attempts => return Err((err, attempt)),
+            Err(_) => {
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// POST `payload` once, signed; any non-2xx response is a failure
+pub async fn deliver(http: &reqwest::Client, url: &str, secret: &str, payload: &Value) -> Result<()> {
+    let body = payload.to_string().into_bytes();
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let response = http
+        .post(url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header(SIGNATURE_HEADER, signature_header(secret, timestamp, &body))
+        .header("X-Oracle-Event", payload["kind"].as_str().unwrap_or_default())
+        .header("X-Oracle-Delivery", payload["id"].to_string())
+        .body(body)
+        .send()
+        .await?;
+    if !response.status().is_success() {
+        bail!("{} from {url}", response.status());
+    }
+    Ok(())
+}
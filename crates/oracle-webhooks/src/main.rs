@@ -0,0 +1,185 @@
+//! `oracle-webhooks`: POST ORACLE Alpha events to registered webhooks.
+//!
+//! Reads events from the `oracle-indexer` database, so delivery survives
+//! restarts and needs no chain access. Webhooks are managed with the
+//! `add` / `remove` / `list` subcommands and delivered to by `serve`.
+//! See `sign` for how receivers verify payloads.
+
+mod dispatch;
+mod sign;
+
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand};
+use futures_util::future::join_all;
+use serde_json::Value;
+use tokio_postgres::{Client, NoTls};
+
+const SCHEMA: &str = include_str!("schema.sql");
+
+#[derive(Parser)]
+#[command(name = "oracle-webhooks", version, about = "Deliver ORACLE Alpha events to webhooks")]
+struct Cli {
+    /// Postgres connection string (the indexer's database)
+    #[arg(long, env = "DATABASE_URL")]
+    database_url: String,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the dispatcher
+    Serve {
+        /// Seconds between polls for new events
+        #[arg(long, default_value_t = 2)]
+        interval: u64,
+        /// Delivery attempts before an event is dead-lettered
+        #[arg(long, default_value_t = 5)]
+        attempts: u32,
+        /// Delay before the first retry, in milliseconds; doubles per retry
+        #[arg(long, default_value_t = 1_000)]
+        backoff_ms: u64,
+        /// Events per webhook per poll
+        #[arg(long, default_value_t = 100)]
+        batch: i64,
+    },
+    /// Register a webhook; prints its id and signing secret
+    Add {
+        #[arg(long)]
+        url: String,
+        /// Event kinds to deliver (e.g. SignalPublished); all when omitted
+        #[arg(long, value_delimiter = ',')]
+        kinds: Vec<String>,
+        /// Deliver past events too, instead of only new ones
+        #[arg(long)]
+        from_start: bool,
+    },
+    /// Deactivate a webhook
+    Remove {
+        #[arg(long)]
+        id: i64,
+    },
+    /// List webhooks and their dead-letter counts
+    List,
+    /// Retry a dead-lettered delivery once, deleting it on success
+    Redeliver {
+        #[arg(long)]
+        dead_letter: i64,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let (db, connection) = tokio_postgres::connect(&cli.database_url, NoTls)
+        .await
+        .context("connecting to Postgres")?;
+    tokio::spawn(async move {
+        if let Err(err) = connection.await {
+            eprintln!("postgres connection closed: {err}");
+        }
+    });
+    db.batch_execute(SCHEMA).await.context("applying schema")?;
+    let http = reqwest::Client::builder().timeout(Duration::from_secs(10)).build()?;
+
+    match cli.command {
+        Command::Serve {
+            interval,
+            attempts,
+            backoff_ms,
+            batch,
+        } => {
+            let policy = dispatch::Policy {
+                attempts: attempts.max(1),
+                initial_backoff: Duration::from_millis(backoff_ms),
+                batch: batch.max(1),
+            };
+            serve(&db, &http, Duration::from_secs(interval.max(1)), policy).await
+        }
+        Command::Add { url, kinds, from_start } => {
+            let secret = sign::generate_secret();
+            let kinds = (!kinds.is_empty()).then_some(kinds);
+            let row = db
+                .query_one(
+                    "INSERT INTO webhooks (url, secret, kinds, cursor)
+                     VALUES ($1, $2, $3, CASE WHEN $4 THEN 0 ELSE (SELECT COALESCE(MAX(seq), 0) FROM events) END)
+                     RETURNING id",
+                    &[&url, &secret, &kinds, &from_start],
+                )
+                .await?;
+            println!("id: {}", row.get::<_, i64>(0));
+            println!("secret: {secret}");
+            Ok(())
+        }
+        Command::Remove { id } => {
+            if db.execute("UPDATE webhooks SET active = FALSE WHERE id = $1", &[&id]).await? == 0 {
+                bail!("no webhook {id}");
+            }
+            Ok(())
+        }
+        Command::List => list(&db).await,
+        Command::Redeliver { dead_letter } => {
+            let row = db
+                .query_opt(
+                    "SELECT w.url, w.secret, d.payload FROM dead_letters d
+                     JOIN webhooks w ON w.id = d.webhook_id WHERE d.id = $1",
+                    &[&dead_letter],
+                )
+                .await?
+                .with_context(|| format!("no dead letter {dead_letter}"))?;
+            let payload: Value = row.get("payload");
+            dispatch::deliver(&http, row.get("url"), row.get("secret"), &payload).await?;
+            db.execute("DELETE FROM dead_letters WHERE id = $1", &[&dead_letter]).await?;
+            println!("delivered");
+            Ok(())
+        }
+    }
+}
+
+async fn serve(db: &Client, http: &reqwest::Client, interval: Duration, policy: dispatch::Policy) -> Result<()> {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        let webhooks = match dispatch::active_webhooks(db).await {
+            Ok(webhooks) => webhooks,
+            Err(err) => {
+                eprintln!("loading webhooks failed: {err:#}");
+                continue;
+            }
+        };
+        // Webhooks run concurrently; each one's events stay in order
+        let results = join_all(webhooks.iter().map(|webhook| dispatch::run_webhook(db, http, webhook, policy))).await;
+        for (webhook, result) in webhooks.iter().zip(results) {
+            if let Err(err) = result {
+                eprintln!("webhook {}: {err:#}", webhook.id);
+            }
+        }
+    }
+}
+
+async fn list(db: &Client) -> Result<()> {
+    let rows = db
+        .query(
+            "SELECT w.id, w.url, w.kinds, w.active, w.cursor,
+                    (SELECT COUNT(*) FROM dead_letters d WHERE d.webhook_id = w.id) AS dead
+             FROM webhooks w ORDER BY w.id",
+            &[],
+        )
+        .await?;
+    for row in rows {
+        let kinds: Option<Vec<String>> = row.get("kinds");
+        println!(
+            "{}\t{}\t{}\tactive={}\tcursor={}\tdead_letters={}",
+            row.get::<_, i64>("id"),
+            row.get::<_, String>("url"),
+            kinds.map_or_else(|| "*".to_string(), |kinds| kinds.join(",")),
+            row.get::<_, bool>("active"),
+            row.get::<_, i64>("cursor"),
+            row.get::<_, i64>("dead"),
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,23 @@
+-- oracle-webhooks tables, alongside the indexer's. Idempotent.
+
+CREATE TABLE IF NOT EXISTS webhooks (
+    id         BIGSERIAL PRIMARY KEY,
+    url        TEXT NOT NULL,
+    secret     TEXT NOT NULL,
+    kinds      TEXT[],                    -- Event kinds to deliver; NULL = all
+    active     BOOLEAN NOT NULL DEFAULT TRUE,
+    cursor     BIGINT NOT NULL DEFAULT 0, -- Last events.seq delivered or dead-lettered
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+-- Deliveries that exhausted their retries
+CREATE TABLE IF NOT EXISTS dead_letters (
+    id         BIGSERIAL PRIMARY KEY,
+    webhook_id BIGINT NOT NULL REFERENCES webhooks (id) ON DELETE CASCADE,
+    event_seq  BIGINT NOT NULL,
+    payload    JSONB NOT NULL,
+    error      TEXT NOT NULL,
+    attempts   INTEGER NOT NULL,
+    failed_at  TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+CREATE INDEX IF NOT EXISTS dead_letters_webhook_idx ON dead_letters (webhook_id);
@@ -0,0 +1,24 @@
+//! Payload signatures.
+//!
+//! Each delivery carries `X-Oracle-Signature: t=<unix seconds>,v1=<hex>`,
+//! where `v1` is HMAC-SHA256 over `"<t>.<body>"` keyed with the webhook's
+//! secret. Receivers recompute it and reject stale `t` values to stop
+//! replays.
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+pub const SIGNATURE_HEADER: &str = "X-Oracle-Signature";
+
+pub fn signature_header(secret: &str, timestamp: u64, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    format!("t={timestamp},v1={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// New random webhook secret
+pub fn generate_secret() -> String {
+    format!("whsec_{}", hex::encode(rand::random::<[u8; 32]>()))
+}
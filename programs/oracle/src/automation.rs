@@ -0,0 +1,114 @@
+//! Clockwork-compatible automation threads.
+//!
+//! Permissionless cranks (`mark_signals`, `settle_take_profit`,
+//! `invalidate_signal`, `burn_expired_pass`) run as threads owned by the
+//! treasury PDA, so they are created, funded and torn down on-chain instead
+//! of from operator scripts. A thread can't sign as the oracle authority,
+//! so authority-gated updates such as `update_ath` stay with the operator.
+//! Only the types needed for the CPI are mirrored here; they serialize
+//! identically to the thread program's own.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+
+/// Clockwork thread program (v2)
+pub const THREAD_PROGRAM_ID: Pubkey = pubkey!("CLoCKyJ6DXBJqqu2VWx9RLbgnwwR6BMHHuyasVmfMzBh");
+
+/// sha256("global:thread_create")[..8]
+const THREAD_CREATE_DISCRIMINATOR: [u8; 8] = [54, 1, 238, 224, 71, 244, 252, 173];
+/// sha256("global:thread_delete")[..8]
+const THREAD_DELETE_DISCRIMINATOR: [u8; 8] = [146, 6, 95, 17, 35, 98, 44, 140];
+
+/// Account reference inside a thread instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ThreadAccount {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// Instruction a thread executes on each trigger
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ThreadInstruction {
+    pub program_id: Pubkey,
+    pub accounts: Vec<ThreadAccount>,
+    pub data: Vec<u8>,
+}
+
+/// Subset of the thread program's `Trigger` enum (same variant order)
+#[derive(AnchorSerialize)]
+enum Trigger {
+    #[allow(dead_code)]
+    Account { address: Pubkey, offset: u64, size: u64 },
+    Cron { schedule: String, skippable: bool },
+}
+
+/// Thread PDA for `authority` and `id`
+pub fn thread_address(authority: &Pubkey, id: &[u8]) -> Pubkey {
+    Pubkey::find_program_address(&[b"thread", authority.as_ref(), id], &THREAD_PROGRAM_ID).0
+}
+
+/// Create a cron-triggered thread paid for and owned by `authority`
+pub fn thread_create<'info>(
+    thread_program: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    thread: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    id: Vec<u8>,
+    instructions: Vec<ThreadInstruction>,
+    schedule: String,
+    amount: u64,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let mut data = THREAD_CREATE_DISCRIMINATOR.to_vec();
+    amount.serialize(&mut data)?;
+    id.serialize(&mut data)?;
+    instructions.serialize(&mut data)?;
+    Trigger::Cron {
+        schedule,
+        skippable: true,
+    }
+    .serialize(&mut data)?;
+
+    let ix = Instruction {
+        program_id: THREAD_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(authority.key(), true),
+            AccountMeta::new(authority.key(), true), // payer
+            AccountMeta::new_readonly(system_program.key(), false),
+            AccountMeta::new(thread.key(), false),
+        ],
+        data,
+    };
+    invoke_signed(
+        &ix,
+        &[authority.clone(), system_program.clone(), thread.clone(), thread_program.clone()],
+        &[authority_seeds],
+    )?;
+    Ok(())
+}
+
+/// Delete a thread, returning its lamports to `authority`
+pub fn thread_delete<'info>(
+    thread_program: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    thread: &AccountInfo<'info>,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let ix = Instruction {
+        program_id: THREAD_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(authority.key(), true),
+            AccountMeta::new(authority.key(), false), // close_to
+            AccountMeta::new(thread.key(), false),
+        ],
+        data: THREAD_DELETE_DISCRIMINATOR.to_vec(),
+    };
+    invoke_signed(
+        &ix,
+        &[authority.clone(), thread.clone(), thread_program.clone()],
+        &[authority_seeds],
+    )?;
+    Ok(())
+}
@@ -0,0 +1,132 @@
+//! Metaplex Core assets minted for elite signals.
+//!
+//! Each qualifying signal can be minted once as a Core asset owned by the
+//! publisher, with a royalties plugin paying the publisher on resale. Only
+//! the types needed for `CreateV1` are mirrored here; they serialize
+//! identically to mpl-core's own.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+/// Metaplex Core program
+pub const CORE_PROGRAM_ID: Pubkey = pubkey!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
+
+/// `MplAssetInstruction::CreateV1`
+const CREATE_V1_DISCRIMINATOR: u8 = 0;
+
+#[derive(AnchorSerialize)]
+enum DataState {
+    AccountState,
+}
+
+#[derive(AnchorSerialize)]
+struct Creator {
+    address: Pubkey,
+    percentage: u8,
+}
+
+#[derive(AnchorSerialize)]
+enum RuleSet {
+    None,
+}
+
+/// Subset of mpl-core's `Plugin` enum (same variant order)
+#[derive(AnchorSerialize)]
+enum Plugin {
+    Royalties {
+        basis_points: u16,
+        creators: Vec<Creator>,
+        rule_set: RuleSet,
+    },
+}
+
+/// Subset of mpl-core's `PluginAuthority` enum (same variant order)
+#[derive(AnchorSerialize)]
+#[allow(dead_code)]
+enum PluginAuthority {
+    None,
+    Owner,
+    UpdateAuthority,
+}
+
+#[derive(AnchorSerialize)]
+struct PluginAuthorityPair {
+    plugin: Plugin,
+    authority: Option<PluginAuthority>,
+}
+
+#[derive(AnchorSerialize)]
+struct CreateV1Args {
+    data_state: DataState,
+    name: String,
+    uri: String,
+    plugins: Option<Vec<PluginAuthorityPair>>,
+}
+
+/// Accounts for creating a signal asset
+pub struct AssetAccounts<'a, 'info> {
+    pub core_program: &'a AccountInfo<'info>,
+    pub asset: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub owner: &'a AccountInfo<'info>,
+    pub update_authority: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+/// Create a Core asset at the PDA `asset`, paying `royalty_bps` to `owner`
+pub fn create_asset(
+    accounts: &AssetAccounts,
+    name: String,
+    uri: String,
+    royalty_bps: u16,
+    asset_seeds: &[&[u8]],
+) -> Result<()> {
+    let args = CreateV1Args {
+        data_state: DataState::AccountState,
+        name,
+        uri,
+        plugins: Some(vec![PluginAuthorityPair {
+            plugin: Plugin::Royalties {
+                basis_points: royalty_bps,
+                creators: vec![Creator {
+                    address: accounts.owner.key(),
+                    percentage: 100,
+                }],
+                rule_set: RuleSet::None,
+            },
+            authority: Some(PluginAuthority::UpdateAuthority),
+        }]),
+    };
+    let mut data = vec![CREATE_V1_DISCRIMINATOR];
+    args.serialize(&mut data)?;
+
+    // Omitted optional accounts are passed as the Core program id
+    let ix = Instruction {
+        program_id: CORE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.asset.key(), true),
+            AccountMeta::new_readonly(CORE_PROGRAM_ID, false), // collection
+            AccountMeta::new_readonly(CORE_PROGRAM_ID, false), // authority
+            AccountMeta::new(accounts.payer.key(), true),
+            AccountMeta::new_readonly(accounts.owner.key(), false),
+            AccountMeta::new_readonly(accounts.update_authority.key(), false),
+            AccountMeta::new_readonly(accounts.system_program.key(), false),
+            AccountMeta::new_readonly(CORE_PROGRAM_ID, false), // log_wrapper
+        ],
+        data,
+    };
+    invoke_signed(
+        &ix,
+        &[
+            accounts.asset.clone(),
+            accounts.payer.clone(),
+            accounts.owner.clone(),
+            accounts.update_authority.clone(),
+            accounts.system_program.clone(),
+            accounts.core_program.clone(),
+        ],
+        &[asset_seeds],
+    )?;
+    Ok(())
+}
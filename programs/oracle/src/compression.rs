@@ -0,0 +1,153 @@
+//! SPL account compression (concurrent Merkle trees).
+//!
+//! Used both to check membership proofs (cNFT access passes) and as the
+//! storage backend for oracles running in compressed mode, where a signal
+//! lives as a leaf hash in a tree owned by the program instead of a PDA.
+//! Full leaf data is logged through the noop program so indexers can
+//! rebuild it from transaction history.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+
+/// SPL account compression
+pub const COMPRESSION_PROGRAM_ID: Pubkey = pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+/// SPL noop (log wrapper)
+pub const NOOP_PROGRAM_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+/// sha256("global:verify_leaf")[..8]
+const VERIFY_LEAF_DISCRIMINATOR: [u8; 8] = [124, 220, 22, 223, 104, 10, 250, 224];
+/// sha256("global:init_empty_merkle_tree")[..8]
+const INIT_TREE_DISCRIMINATOR: [u8; 8] = [191, 11, 119, 7, 180, 107, 220, 110];
+/// sha256("global:append")[..8]
+const APPEND_DISCRIMINATOR: [u8; 8] = [149, 120, 18, 222, 236, 225, 88, 203];
+/// sha256("global:replace_leaf")[..8]
+const REPLACE_LEAF_DISCRIMINATOR: [u8; 8] = [204, 165, 76, 100, 73, 147, 0, 128];
+
+/// Have the compression program check `leaf` at `index` against `root`.
+///
+/// `proof` holds the sibling nodes (as accounts, per the compression
+/// program's convention), leaf to root; the call fails if they don't hash
+/// up to the tree's current root.
+pub fn verify_leaf<'info>(
+    compression_program: &AccountInfo<'info>,
+    merkle_tree: &AccountInfo<'info>,
+    proof: &[AccountInfo<'info>],
+    root: [u8; 32],
+    leaf: [u8; 32],
+    index: u32,
+) -> Result<()> {
+    let mut data = VERIFY_LEAF_DISCRIMINATOR.to_vec();
+    root.serialize(&mut data)?;
+    leaf.serialize(&mut data)?;
+    index.serialize(&mut data)?;
+
+    let mut accounts = vec![AccountMeta::new_readonly(merkle_tree.key(), false)];
+    accounts.extend(proof.iter().map(|node| AccountMeta::new_readonly(node.key(), false)));
+
+    let mut infos = vec![merkle_tree.clone()];
+    infos.extend_from_slice(proof);
+    infos.push(compression_program.clone());
+
+    invoke(
+        &Instruction {
+            program_id: COMPRESSION_PROGRAM_ID,
+            accounts,
+            data,
+        },
+        &infos,
+    )?;
+    Ok(())
+}
+
+/// Accounts shared by every tree-mutating CPI
+pub struct TreeAccounts<'a, 'info> {
+    pub compression_program: &'a AccountInfo<'info>,
+    pub merkle_tree: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub noop_program: &'a AccountInfo<'info>,
+}
+
+/// Initialize a pre-allocated tree with `authority` (a PDA) as its owner
+pub fn init_tree(
+    tree: &TreeAccounts,
+    max_depth: u32,
+    max_buffer_size: u32,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let mut data = INIT_TREE_DISCRIMINATOR.to_vec();
+    max_depth.serialize(&mut data)?;
+    max_buffer_size.serialize(&mut data)?;
+    invoke_tree(tree, data, &[], authority_seeds)
+}
+
+/// Append `leaf` to the tree
+pub fn append(tree: &TreeAccounts, leaf: [u8; 32], authority_seeds: &[&[u8]]) -> Result<()> {
+    let mut data = APPEND_DISCRIMINATOR.to_vec();
+    leaf.serialize(&mut data)?;
+    invoke_tree(tree, data, &[], authority_seeds)
+}
+
+/// Replace `previous_leaf` at `index` with `new_leaf`, proven by `proof`
+pub fn replace_leaf<'info>(
+    tree: &TreeAccounts<'_, 'info>,
+    proof: &[AccountInfo<'info>],
+    root: [u8; 32],
+    previous_leaf: [u8; 32],
+    new_leaf: [u8; 32],
+    index: u32,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let mut data = REPLACE_LEAF_DISCRIMINATOR.to_vec();
+    root.serialize(&mut data)?;
+    previous_leaf.serialize(&mut data)?;
+    new_leaf.serialize(&mut data)?;
+    index.serialize(&mut data)?;
+    invoke_tree(tree, data, proof, authority_seeds)
+}
+
+/// Log raw bytes through the noop program so they land in transaction history
+pub fn log_leaf_data<'info>(noop_program: &AccountInfo<'info>, data: Vec<u8>) -> Result<()> {
+    invoke(
+        &Instruction {
+            program_id: NOOP_PROGRAM_ID,
+            accounts: vec![],
+            data,
+        },
+        std::slice::from_ref(noop_program),
+    )?;
+    Ok(())
+}
+
+fn invoke_tree<'info>(
+    tree: &TreeAccounts<'_, 'info>,
+    data: Vec<u8>,
+    proof: &[AccountInfo<'info>],
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let mut accounts = vec![
+        AccountMeta::new(tree.merkle_tree.key(), false),
+        AccountMeta::new_readonly(tree.authority.key(), true),
+        AccountMeta::new_readonly(tree.noop_program.key(), false),
+    ];
+    accounts.extend(proof.iter().map(|node| AccountMeta::new_readonly(node.key(), false)));
+
+    let mut infos = vec![
+        tree.merkle_tree.clone(),
+        tree.authority.clone(),
+        tree.noop_program.clone(),
+    ];
+    infos.extend_from_slice(proof);
+    infos.push(tree.compression_program.clone());
+
+    invoke_signed(
+        &Instruction {
+            program_id: COMPRESSION_PROGRAM_ID,
+            accounts,
+            data,
+        },
+        &infos,
+        &[authority_seeds],
+    )?;
+    Ok(())
+}
@@ -1,7 +1,269 @@
+#![allow(clippy::too_many_arguments)]
+
 use anchor_lang::prelude::*;
+use anchor_spl::token_2022::{self, spl_token_2022::extension::ExtensionType, Token2022};
+use anchor_spl::token_2022_extensions::{
+    non_transferable_mint_initialize, permanent_delegate_initialize, NonTransferableMintInitialize,
+    PermanentDelegateInitialize,
+};
+use anchor_spl::token_interface::{self, find_mint_account_size, Mint, TokenAccount, TokenInterface};
+use automation::ThreadInstruction;
+use oracle_math::{Direction, Outcome};
+
+pub mod automation;
+pub mod collectibles;
+pub mod compression;
+pub mod passes;
+pub mod pools;
+pub mod sns;
+pub mod vault;
 
 declare_id!("AL9bxB2BUHnPptqzospgwyeet8RwBbd4NmYmxuiNNzXd"); // Will be replaced after deployment
 
+/// Decimals of on-chain prices (quote per token, scaled by 10^PRICE_DECIMALS)
+#[constant]
+pub const PRICE_DECIMALS: u8 = 9;
+/// Max allowed gap between a claimed price and the pool spot price
+#[constant]
+pub const MAX_PRICE_DEVIATION_BPS: u64 = 500;
+/// How long after publish the entry price can still be checked against a pool
+#[constant]
+pub const PRICE_CHECK_WINDOW_SECS: i64 = 120;
+/// Least quote-mint reserve, in whole units, a pool needs to be priced against
+#[constant]
+pub const MIN_POOL_LIQUIDITY: u64 = 500;
+/// Number of risk levels a signal can carry (0 = lowest risk)
+pub const RISK_LEVEL_COUNT: usize = 4;
+/// Default per-risk-level max swap slippage for vault executions
+#[constant]
+pub const DEFAULT_MAX_SLIPPAGE_BPS: [u16; RISK_LEVEL_COUNT] = [50, 100, 300, 800];
+/// Default per-risk-level max leverage for perp executions (10_000 = 1x)
+#[constant]
+pub const DEFAULT_MAX_LEVERAGE_BPS: [u32; RISK_LEVEL_COUNT] = [30_000, 20_000, 10_000, 10_000];
+/// Highest per-risk-level max leverage the config accepts (20x)
+#[constant]
+pub const MAX_LEVERAGE_BPS: u32 = 200_000;
+/// Default per-risk-level cap on one signal's share of the vault
+#[constant]
+pub const DEFAULT_MAX_ALLOCATION_BPS: [u16; RISK_LEVEL_COUNT] = [1_000, 500, 300, 200];
+/// Default drift from target weight, in bps of the vault, before a position can be rebalanced
+#[constant]
+pub const DEFAULT_REBALANCE_BAND_BPS: u16 = 100;
+/// Default largest single rebalance trade, in bps of the vault
+#[constant]
+pub const DEFAULT_MAX_REBALANCE_BPS: u16 = 200;
+/// Default per-risk-level ROI a closed signal needs to count as a win
+#[constant]
+pub const DEFAULT_WIN_THRESHOLD_BPS: [u32; RISK_LEVEL_COUNT] = [oracle_math::WIN_THRESHOLD_BPS; RISK_LEVEL_COUNT];
+/// Default share of vault base that must stay liquid (not lent out)
+#[constant]
+pub const DEFAULT_VAULT_RESERVE_BPS: u16 = 2_000;
+/// Default widest gap between a reported vault NAV and the vault's
+/// measured value
+#[constant]
+pub const DEFAULT_VAULT_NAV_TOLERANCE_BPS: u16 = 100;
+/// Default largest share price move between vault NAV reports
+#[constant]
+pub const DEFAULT_VAULT_MAX_NAV_CHANGE_BPS: u16 = 2_000;
+/// Number of subscription tiers (0 = basic)
+pub const TIER_COUNT: usize = 3;
+/// Number of buckets in the settled-ROI histogram
+pub const ROI_BUCKET_COUNT: usize = oracle_math::ROI_BUCKET_COUNT;
+/// Length of one paid subscription period
+#[constant]
+pub const SUBSCRIPTION_PERIOD_SECS: i64 = 30 * 24 * 60 * 60;
+/// Times one subscription can be paused
+#[constant]
+pub const MAX_SUBSCRIPTION_PAUSES: u8 = 2;
+/// Longest a pause freezes the remaining time for
+#[constant]
+pub const MAX_PAUSE_SECS: i64 = 60 * 24 * 60 * 60;
+/// Default price of one subscription period per tier
+#[constant]
+pub const DEFAULT_TIER_PRICE_LAMPORTS: [u64; TIER_COUNT] = [100_000_000, 500_000_000, 2_000_000_000];
+/// Max length of a linked `.sol` domain (without the suffix)
+pub const MAX_DOMAIN_LEN: usize = 32;
+/// Default max symbol length; `Signal::INIT_SPACE` has room for this many bytes
+#[constant]
+pub const DEFAULT_MAX_SYMBOL_LEN: u8 = 10;
+/// Hard cap on the configurable max symbol length
+#[constant]
+pub const MAX_SYMBOL_LEN: u8 = 32;
+/// Zeroed bytes kept at the end of every new signal account, so fields can
+/// be appended without a realloc
+#[constant]
+pub const SIGNAL_RESERVED_BYTES: usize = 64;
+/// Score a signal needs to be classed at each tier (by tier index)
+#[constant]
+pub const ALERT_TIER_MIN_SCORE: [u8; TIER_COUNT] = [0, 70, 85];
+/// Default minimum score for a signal to be minted as a collectible asset
+#[constant]
+pub const DEFAULT_ELITE_SCORE: u8 = 90;
+/// Default resale royalty paid to the publisher of a signal asset
+#[constant]
+pub const DEFAULT_ASSET_ROYALTY_BPS: u16 = 500;
+/// Default age at which a signal's effective score has halved
+#[constant]
+pub const DEFAULT_SCORE_HALF_LIFE_SECS: i64 = 4 * 60 * 60;
+/// Max length of one signal note
+#[constant]
+pub const MAX_NOTE_LEN: usize = 140;
+/// Max number of notes appended to one signal
+#[constant]
+pub const MAX_SIGNAL_NOTES: usize = 32;
+/// Ratings run from 1 to this many stars
+#[constant]
+pub const MAX_RATING: u8 = 5;
+/// Number of featured slots in the discovery account
+#[constant]
+pub const FEATURED_SLOTS: usize = 8;
+/// Smallest accepted boost payment
+#[constant]
+pub const MIN_BOOST_LAMPORTS: u64 = 1_000_000;
+/// How long a boost keeps a signal eligible for a featured slot
+#[constant]
+pub const BOOST_DURATION_SECS: i64 = 24 * 60 * 60;
+/// Length of one featured-slot auction epoch
+#[constant]
+pub const AUCTION_EPOCH_SECS: i64 = 24 * 60 * 60;
+/// A bid that displaces another must beat it by this much
+#[constant]
+pub const MIN_BID_INCREMENT_BPS: u64 = 500;
+/// Most tokens a basket signal can hold
+#[constant]
+pub const MAX_BASKET_MEMBERS: usize = 8;
+/// Longest basket name in bytes
+#[constant]
+pub const MAX_BASKET_NAME_LEN: usize = 32;
+/// Default gap between a claimed and a verified market cap that gets flagged
+#[constant]
+pub const DEFAULT_MAX_MCAP_DISCREPANCY_BPS: u16 = 2_000;
+/// Default mint supply change over a signal's life that gets flagged
+#[constant]
+pub const DEFAULT_MAX_SUPPLY_CHANGE_BPS: u16 = 1_000;
+/// Default ROI band either side of zero that settles as break-even; 0
+/// disables it
+#[constant]
+pub const DEFAULT_BREAK_EVEN_BPS: u16 = 0;
+/// Default wait after a token's signal closes before it can be signalled
+/// again without linking the closed one; 0 disables the cooldown
+#[constant]
+pub const DEFAULT_REPUBLISH_COOLDOWN_SECS: i64 = 0;
+/// Most entries a signal can scale into after publish
+#[constant]
+pub const MAX_SIGNAL_ENTRIES: usize = 8;
+/// Most tranches a DCA plan can split an entry into
+#[constant]
+pub const MAX_DCA_TRANCHES: u8 = 16;
+/// Most take-profit levels a signal can ladder its exit over
+#[constant]
+pub const MAX_TAKE_PROFIT_LEVELS: usize = 5;
+/// Most positions the model portfolio can hold at once
+#[constant]
+pub const MAX_PORTFOLIO_POSITIONS: usize = 20;
+/// Benchmarks signals can be measured against (0 = SOL, 1 = BTC)
+#[constant]
+pub const BENCHMARK_COUNT: usize = 2;
+/// Benchmark whose pool values the vault's SOL in its base mint
+pub const SOL_BENCHMARK: usize = 0;
+/// Length of a vault epoch; queued deposits and withdrawals are priced
+/// together at its end
+#[constant]
+pub const VAULT_EPOCH_SECS: i64 = 24 * 60 * 60;
+/// Longest the vault's LST valuation can go unsynced before NAV reports
+/// stop accepting it
+#[constant]
+pub const MAX_LST_VALUE_AGE_SECS: i64 = 60 * 60;
+
+// PDA seeds
+#[constant]
+pub const ORACLE_STATE_SEED: &[u8] = b"oracle_state";
+#[constant]
+pub const SIGNAL_SEED: &[u8] = b"signal";
+#[constant]
+pub const ORACLE_CONFIG_SEED: &[u8] = b"oracle_config";
+#[constant]
+pub const VAULT_SEED: &[u8] = b"vault";
+#[constant]
+pub const POSITION_SEED: &[u8] = b"position";
+#[constant]
+pub const TICKET_SEED: &[u8] = b"ticket";
+#[constant]
+pub const SUBSCRIPTION_SEED: &[u8] = b"subscription";
+#[constant]
+pub const TREASURY_SEED: &[u8] = b"treasury";
+#[constant]
+pub const PASS_MINT_SEED: &[u8] = b"pass_mint";
+#[constant]
+pub const PUBLISHER_SEED: &[u8] = b"publisher";
+#[constant]
+pub const TREE_AUTHORITY_SEED: &[u8] = b"tree_authority";
+#[constant]
+pub const SIGNAL_ASSET_SEED: &[u8] = b"signal_asset";
+#[constant]
+pub const PUBLISHER_STATS_SEED: &[u8] = b"publisher_stats";
+#[constant]
+pub const SIGNAL_NOTES_SEED: &[u8] = b"signal_notes";
+#[constant]
+pub const SIGNAL_RATING_SEED: &[u8] = b"signal_rating";
+#[constant]
+pub const RATING_RECEIPT_SEED: &[u8] = b"rating_receipt";
+#[constant]
+pub const SIGNAL_BOOST_SEED: &[u8] = b"signal_boost";
+#[constant]
+pub const DISCOVERY_SEED: &[u8] = b"discovery";
+#[constant]
+pub const AUCTION_SEED: &[u8] = b"auction";
+#[constant]
+pub const BASKET_SEED: &[u8] = b"basket";
+#[constant]
+pub const PAIR_SIGNAL_SEED: &[u8] = b"pair_signal";
+#[constant]
+pub const PERP_PARAMS_SEED: &[u8] = b"perp_params";
+#[constant]
+pub const SIGNAL_SIZING_SEED: &[u8] = b"signal_sizing";
+#[constant]
+pub const SIGNAL_MARKET_CAP_SEED: &[u8] = b"signal_market_cap";
+#[constant]
+pub const SUPPLY_SNAPSHOT_SEED: &[u8] = b"supply_snapshot";
+#[constant]
+pub const HOLDER_COMMITMENT_SEED: &[u8] = b"holder_commitment";
+#[constant]
+pub const TOKEN_HISTORY_SEED: &[u8] = b"token_history";
+#[constant]
+pub const SIGNAL_LINEAGE_SEED: &[u8] = b"signal_lineage";
+#[constant]
+pub const SIGNAL_ENTRIES_SEED: &[u8] = b"signal_entries";
+#[constant]
+pub const DCA_PLAN_SEED: &[u8] = b"dca_plan";
+#[constant]
+pub const TAKE_PROFIT_SEED: &[u8] = b"take_profit";
+#[constant]
+pub const INVALIDATION_SEED: &[u8] = b"invalidation";
+#[constant]
+pub const MODEL_PORTFOLIO_SEED: &[u8] = b"model_portfolio";
+#[constant]
+pub const SIGNAL_BENCHMARK_SEED: &[u8] = b"signal_benchmark";
+#[constant]
+pub const ACCESS_CLAIM_SEED: &[u8] = b"access_claim";
+#[constant]
+pub const TIER_ALLOWLIST_SEED: &[u8] = b"tier_allowlist";
+#[constant]
+pub const ALLOWLIST_ENTRY_SEED: &[u8] = b"allowlist_entry";
+#[constant]
+pub const VAULT_DEPOSITOR_SEED: &[u8] = b"vault_depositor";
+#[constant]
+pub const VAULT_EPOCH_SEED: &[u8] = b"vault_epoch";
+#[constant]
+pub const VAULT_SOL_SEED: &[u8] = b"vault_sol";
+
+/// Offset of `Signal::id` in account data (after the discriminator)
+#[constant]
+pub const SIGNAL_ID_OFFSET: u32 = 8;
+/// Offset of `Signal::token`; fields after `symbol` have no fixed offset
+#[constant]
+pub const SIGNAL_TOKEN_OFFSET: u32 = 16;
+
 #[program]
 pub mod oracle {
     use super::*;
@@ -13,6 +275,14 @@ pub mod oracle {
         oracle_state.total_signals = 0;
         oracle_state.total_wins = 0;
         oracle_state.total_losses = 0;
+        oracle_state.compressed_signals = 0;
+        oracle_state.streaks = Streaks::default();
+        oracle_state.roi_moments = RoiMoments::default();
+        oracle_state.roi_histogram = [0; ROI_BUCKET_COUNT];
+        oracle_state.roi_annualized_moments = RoiMoments::default();
+        oracle_state.unrealized_roi_bps = 0;
+        oracle_state.marked_at = 0;
+        oracle_state.performance_index = oracle_math::INDEX_BASE;
         oracle_state.bump = ctx.bumps.oracle_state;
         
         msg!("ORACLE initialized with authority: {}", oracle_state.authority);
@@ -29,38 +299,57 @@ pub mod oracle {
         sources_bitmap: u8,
         mcap: u64,
         entry_price: u64,
+        direction: SignalDirection,
+        quote_mint: Pubkey,
     ) -> Result<()> {
-        require!(symbol.len() <= 10, OracleError::SymbolTooLong);
-        require!(score <= 100, OracleError::InvalidScore);
+        require!(ctx.accounts.config.storage_mode == StorageMode::Accounts, OracleError::WrongStorageMode);
+        
+        let history = &mut ctx.accounts.token_history;
+        init_token_history(history, token, ctx.bumps.token_history);
+        let parent = check_republish(
+            history,
+            ctx.accounts.parent_signal.as_ref(),
+            &token,
+            ctx.accounts.config.republish_cooldown_secs,
+        )?;
         
         let signal = &mut ctx.accounts.signal;
         let oracle_state = &mut ctx.accounts.oracle_state;
         
-        signal.id = oracle_state.total_signals;
-        signal.token = token;
-        signal.symbol = symbol;
-        signal.score = score;
-        signal.risk_level = risk_level;
-        signal.sources_bitmap = sources_bitmap;
-        signal.mcap_at_signal = mcap;
-        signal.entry_price = entry_price;
-        signal.timestamp = Clock::get()?.unix_timestamp;
-        signal.status = SignalStatus::Open;
-        signal.ath_price = entry_price;
-        signal.exit_price = 0;
-        signal.roi_bps = 0;
-        signal.reasoning_hash = [0u8; 32]; // Empty initially
-        signal.reasoning_revealed = false;
+        init_signal(
+            signal,
+            oracle_state,
+            token,
+            symbol,
+            score,
+            risk_level,
+            sources_bitmap,
+            mcap,
+            entry_price,
+            direction,
+            [0u8; 32], // Empty initially,
+            quote_mint,
+            ctx.accounts.authority.key(),
+            ctx.accounts.config.max_symbol_len,
+        )?;
         signal.bump = ctx.bumps.signal;
-        
-        oracle_state.total_signals += 1;
+        record_sizing(&mut ctx.accounts.sizing, signal, oracle_state, ctx.bumps.sizing);
         
         emit!(SignalPublished {
             id: signal.id,
             token,
             score,
             timestamp: signal.timestamp,
+            publisher_domain: publisher_domain(&ctx.accounts.publisher),
         });
+        if let Some(parent) = parent {
+            emit!(SignalRepublished {
+                id: signal.id,
+                token,
+                parent,
+            });
+        }
+        emit_alert(signal);
         
         msg!("Signal #{} published: {} with score {}", signal.id, signal.symbol, score);
         Ok(())
@@ -76,32 +365,42 @@ pub mod oracle {
         sources_bitmap: u8,
         mcap: u64,
         entry_price: u64,
+        direction: SignalDirection,
         reasoning_hash: [u8; 32],
+        quote_mint: Pubkey,
     ) -> Result<()> {
-        require!(symbol.len() <= 10, OracleError::SymbolTooLong);
-        require!(score <= 100, OracleError::InvalidScore);
+        require!(ctx.accounts.config.storage_mode == StorageMode::Accounts, OracleError::WrongStorageMode);
+        
+        let history = &mut ctx.accounts.token_history;
+        init_token_history(history, token, ctx.bumps.token_history);
+        let parent = check_republish(
+            history,
+            ctx.accounts.parent_signal.as_ref(),
+            &token,
+            ctx.accounts.config.republish_cooldown_secs,
+        )?;
         
         let signal = &mut ctx.accounts.signal;
         let oracle_state = &mut ctx.accounts.oracle_state;
         
-        signal.id = oracle_state.total_signals;
-        signal.token = token;
-        signal.symbol = symbol;
-        signal.score = score;
-        signal.risk_level = risk_level;
-        signal.sources_bitmap = sources_bitmap;
-        signal.mcap_at_signal = mcap;
-        signal.entry_price = entry_price;
-        signal.timestamp = Clock::get()?.unix_timestamp;
-        signal.status = SignalStatus::Open;
-        signal.ath_price = entry_price;
-        signal.exit_price = 0;
-        signal.roi_bps = 0;
-        signal.reasoning_hash = reasoning_hash;
-        signal.reasoning_revealed = false;
+        init_signal(
+            signal,
+            oracle_state,
+            token,
+            symbol,
+            score,
+            risk_level,
+            sources_bitmap,
+            mcap,
+            entry_price,
+            direction,
+            reasoning_hash,
+            quote_mint,
+            ctx.accounts.authority.key(),
+            ctx.accounts.config.max_symbol_len,
+        )?;
         signal.bump = ctx.bumps.signal;
-        
-        oracle_state.total_signals += 1;
+        record_sizing(&mut ctx.accounts.sizing, signal, oracle_state, ctx.bumps.sizing);
         
         emit!(SignalPublishedWithProof {
             id: signal.id,
@@ -109,7 +408,16 @@ pub mod oracle {
             score,
             reasoning_hash,
             timestamp: signal.timestamp,
+            publisher_domain: publisher_domain(&ctx.accounts.publisher),
         });
+        if let Some(parent) = parent {
+            emit!(SignalRepublished {
+                id: signal.id,
+                token,
+                parent,
+            });
+        }
+        emit_alert(signal);
         
         msg!("Signal #{} published with reasoning proof: {} (score {})", signal.id, signal.symbol, score);
         Ok(())
@@ -140,10 +448,13 @@ pub mod oracle {
     pub fn update_ath(
         ctx: Context<UpdateSignal>,
         new_ath: u64,
+        quote_mint: Pubkey,
     ) -> Result<()> {
         let signal = &mut ctx.accounts.signal;
+        check_quote(signal, &quote_mint)?;
         
-        if new_ath > signal.ath_price {
+        // For shorts the best price is the lowest one
+        if oracle_math::is_new_best(signal.ath_price, new_ath, signal.direction.into()) {
             signal.ath_price = new_ath;
             msg!("Signal #{} ATH updated to {}", signal.id, new_ath);
         }
@@ -153,153 +464,7723 @@ pub mod oracle {
 
     /// Close a signal (mark as win/loss)
     pub fn close_signal(
-        ctx: Context<UpdateSignal>,
+        ctx: Context<CloseSignal>,
         exit_price: u64,
+        quote_mint: Pubkey,
     ) -> Result<()> {
         let signal = &mut ctx.accounts.signal;
         let oracle_state = &mut ctx.accounts.oracle_state;
+        check_quote(signal, &quote_mint)?;
+        
+        let config = &ctx.accounts.config;
+        let ladder = take_profit_ladder(&ctx.accounts.ladder)?;
+        let stats = &mut ctx.accounts.publisher_stats;
+        start_publisher_stats(stats, signal.publisher, ctx.bumps.publisher_stats);
+        settle_signal(signal, oracle_state, config, stats, ladder.as_ref(), exit_price)?;
+        record_token_close(&mut ctx.accounts.token_history, signal, ctx.bumps.token_history)?;
+        snapshot_exit_supply(&ctx.accounts.supply_snapshot, ctx.accounts.mint.as_ref(), signal, config)
+    }
+
+    /// Check a freshly published signal's entry price against a DEX pool
+    pub fn verify_entry_price(ctx: Context<VerifyPoolPrice>) -> Result<()> {
+        let signal = &mut ctx.accounts.signal;
         
         require!(signal.status == SignalStatus::Open, OracleError::SignalAlreadyClosed);
+        require!(
+            Clock::get()?.unix_timestamp - signal.timestamp <= PRICE_CHECK_WINDOW_SECS,
+            OracleError::PriceCheckWindowElapsed
+        );
+        
+        let pool_price = pools::pool_price(
+            &ctx.accounts.pool,
+            &ctx.accounts.mint_a,
+            &ctx.accounts.mint_b,
+            &ctx.accounts.vault_a,
+            &ctx.accounts.vault_b,
+            &signal.token,
+        )?;
+        check_quote(signal, &pool_price.quote_mint)?;
+        check_price(signal, &ctx.accounts.pool.key(), signal.entry_price, pool_price.price)?;
         
-        signal.exit_price = exit_price;
+        signal.price_pool = ctx.accounts.pool.key();
+        signal.entry_verified = true;
+        
+        msg!("Signal #{} entry price verified against pool {}", signal.id, signal.price_pool);
+        Ok(())
+    }
+
+    /// Close a signal after checking the exit price against a DEX pool. A
+    /// verified entry pins the exit to the same pool, even once added
+    /// entries have unset `entry_verified`.
+    pub fn close_signal_verified(
+        ctx: Context<CloseSignalVerified>,
+        exit_price: u64,
+    ) -> Result<()> {
+        let signal = &mut ctx.accounts.signal;
+        let oracle_state = &mut ctx.accounts.oracle_state;
         
-        // Calculate ROI in basis points (1 bps = 0.01%)
-        if signal.entry_price > 0 {
-            let roi = ((exit_price as i128 - signal.entry_price as i128) * 10000) 
-                / signal.entry_price as i128;
-            signal.roi_bps = roi as i64;
+        require!(signal.status == SignalStatus::Open, OracleError::SignalAlreadyClosed);
+        if signal.price_pool != Pubkey::default() {
+            require_keys_eq!(ctx.accounts.pool.key(), signal.price_pool, OracleError::ExitPoolMismatch);
         }
         
-        // Determine win/loss (win = 50%+ gain)
-        if signal.roi_bps >= 5000 {
-            signal.status = SignalStatus::Win;
-            oracle_state.total_wins += 1;
-        } else if signal.roi_bps < 0 {
-            signal.status = SignalStatus::Loss;
-            oracle_state.total_losses += 1;
+        let pool_price = pools::pool_price(
+            &ctx.accounts.pool,
+            &ctx.accounts.mint_a,
+            &ctx.accounts.mint_b,
+            &ctx.accounts.vault_a,
+            &ctx.accounts.vault_b,
+            &signal.token,
+        )?;
+        check_quote(signal, &pool_price.quote_mint)?;
+        check_price(signal, &ctx.accounts.pool.key(), exit_price, pool_price.price)?;
+        
+        signal.price_pool = ctx.accounts.pool.key();
+        signal.exit_verified = true;
+        
+        let config = &ctx.accounts.config;
+        let ladder = take_profit_ladder(&ctx.accounts.ladder)?;
+        let stats = &mut ctx.accounts.publisher_stats;
+        start_publisher_stats(stats, signal.publisher, ctx.bumps.publisher_stats);
+        settle_signal(signal, oracle_state, config, stats, ladder.as_ref(), exit_price)?;
+        record_token_close(&mut ctx.accounts.token_history, signal, ctx.bumps.token_history)?;
+        let mint = token_mint(&ctx.accounts.mint_a, &ctx.accounts.mint_b, &signal.token);
+        snapshot_exit_supply(&ctx.accounts.supply_snapshot, Some(mint), signal, config)
+    }
+
+    /// Create the oracle's tunable configuration
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        config.max_slippage_bps = DEFAULT_MAX_SLIPPAGE_BPS;
+        config.max_leverage_bps = DEFAULT_MAX_LEVERAGE_BPS;
+        config.max_allocation_bps = DEFAULT_MAX_ALLOCATION_BPS;
+        config.rebalance_band_bps = DEFAULT_REBALANCE_BAND_BPS;
+        config.max_rebalance_bps = DEFAULT_MAX_REBALANCE_BPS;
+        config.vault_performance_fee_bps = 0;
+        config.vault_nav_tolerance_bps = DEFAULT_VAULT_NAV_TOLERANCE_BPS;
+        config.vault_max_nav_change_bps = DEFAULT_VAULT_MAX_NAV_CHANGE_BPS;
+        config.vault_reserve_bps = DEFAULT_VAULT_RESERVE_BPS;
+        config.access_pass_tree = Pubkey::default();
+        config.tier_price_lamports = DEFAULT_TIER_PRICE_LAMPORTS;
+        config.storage_mode = StorageMode::Accounts;
+        config.signal_tree = Pubkey::default();
+        config.elite_score = DEFAULT_ELITE_SCORE;
+        config.asset_royalty_bps = DEFAULT_ASSET_ROYALTY_BPS;
+        config.score_half_life_secs = DEFAULT_SCORE_HALF_LIFE_SECS;
+        config.max_symbol_len = DEFAULT_MAX_SYMBOL_LEN;
+        config.max_mcap_discrepancy_bps = DEFAULT_MAX_MCAP_DISCREPANCY_BPS;
+        config.max_supply_change_bps = DEFAULT_MAX_SUPPLY_CHANGE_BPS;
+        config.republish_cooldown_secs = DEFAULT_REPUBLISH_COOLDOWN_SECS;
+        config.break_even_bps = DEFAULT_BREAK_EVEN_BPS;
+        config.win_threshold_bps = DEFAULT_WIN_THRESHOLD_BPS;
+        config.friction = Friction::default();
+        config.benchmark_pools = [Pubkey::default(); BENCHMARK_COUNT];
+        config.vault_max_drawdown_bps = 0;
+        config.vault_derisk_bps = 0;
+        config.subscription_transfer_cooldown_secs = 0;
+        config.vault_stake_pool = Pubkey::default();
+        config.bump = ctx.bumps.config;
+        
+        msg!("ORACLE config initialized");
+        Ok(())
+    }
+
+    /// Update configuration values (only fields that are set are changed)
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        update: ConfigUpdate,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        
+        if let Some(max_slippage_bps) = update.max_slippage_bps {
+            require!(max_slippage_bps.iter().all(|bps| *bps <= 10_000), OracleError::InvalidBps);
+            config.max_slippage_bps = max_slippage_bps;
+        }
+        if let Some(max_leverage_bps) = update.max_leverage_bps {
+            require!(
+                max_leverage_bps.iter().all(|bps| (10_000..=MAX_LEVERAGE_BPS).contains(bps)),
+                OracleError::InvalidBps
+            );
+            config.max_leverage_bps = max_leverage_bps;
+        }
+        if let Some(max_allocation_bps) = update.max_allocation_bps {
+            require!(max_allocation_bps.iter().all(|bps| *bps <= 10_000), OracleError::InvalidBps);
+            config.max_allocation_bps = max_allocation_bps;
+        }
+        if let Some(rebalance_band_bps) = update.rebalance_band_bps {
+            require!(rebalance_band_bps <= 10_000, OracleError::InvalidBps);
+            config.rebalance_band_bps = rebalance_band_bps;
+        }
+        if let Some(max_rebalance_bps) = update.max_rebalance_bps {
+            require!(max_rebalance_bps <= 10_000, OracleError::InvalidBps);
+            config.max_rebalance_bps = max_rebalance_bps;
+        }
+        if let Some(fee_bps) = update.vault_performance_fee_bps {
+            require!(fee_bps <= 10_000, OracleError::InvalidBps);
+            config.vault_performance_fee_bps = fee_bps;
+        }
+        if let Some(tolerance_bps) = update.vault_nav_tolerance_bps {
+            config.vault_nav_tolerance_bps = tolerance_bps;
+        }
+        if let Some(max_change_bps) = update.vault_max_nav_change_bps {
+            config.vault_max_nav_change_bps = max_change_bps;
+        }
+        if let Some(vault_reserve_bps) = update.vault_reserve_bps {
+            require!(vault_reserve_bps <= 10_000, OracleError::InvalidBps);
+            config.vault_reserve_bps = vault_reserve_bps;
+        }
+        if let Some(access_pass_tree) = update.access_pass_tree {
+            config.access_pass_tree = access_pass_tree;
+        }
+        if let Some(tier_price_lamports) = update.tier_price_lamports {
+            config.tier_price_lamports = tier_price_lamports;
+        }
+        if let Some(storage_mode) = update.storage_mode {
+            require!(
+                storage_mode == StorageMode::Accounts || config.signal_tree != Pubkey::default(),
+                OracleError::InvalidSignalTree
+            );
+            config.storage_mode = storage_mode;
+        }
+        if let Some(elite_score) = update.elite_score {
+            require!(elite_score <= 100, OracleError::InvalidScore);
+            config.elite_score = elite_score;
+        }
+        if let Some(asset_royalty_bps) = update.asset_royalty_bps {
+            require!(asset_royalty_bps <= 10_000, OracleError::InvalidBps);
+            config.asset_royalty_bps = asset_royalty_bps;
+        }
+        if let Some(score_half_life_secs) = update.score_half_life_secs {
+            require!(score_half_life_secs >= 0, OracleError::InvalidHalfLife);
+            config.score_half_life_secs = score_half_life_secs;
+        }
+        if let Some(max_symbol_len) = update.max_symbol_len {
+            require!(
+                (DEFAULT_MAX_SYMBOL_LEN..=MAX_SYMBOL_LEN).contains(&max_symbol_len),
+                OracleError::InvalidSymbolLimit
+            );
+            config.max_symbol_len = max_symbol_len;
+        }
+        if let Some(max_mcap_discrepancy_bps) = update.max_mcap_discrepancy_bps {
+            config.max_mcap_discrepancy_bps = max_mcap_discrepancy_bps;
+        }
+        if let Some(max_supply_change_bps) = update.max_supply_change_bps {
+            config.max_supply_change_bps = max_supply_change_bps;
+        }
+        if let Some(republish_cooldown_secs) = update.republish_cooldown_secs {
+            require!(republish_cooldown_secs >= 0, OracleError::InvalidCooldown);
+            config.republish_cooldown_secs = republish_cooldown_secs;
+        }
+        if let Some(break_even_bps) = update.break_even_bps {
+            require!(break_even_bps <= 10_000, OracleError::InvalidBps);
+            config.break_even_bps = break_even_bps;
+        }
+        if let Some(win_threshold_bps) = update.win_threshold_bps {
+            require!(win_threshold_bps.iter().all(|bps| *bps > 0), OracleError::InvalidWinThreshold);
+            config.win_threshold_bps = win_threshold_bps;
+        }
+        if let Some(friction) = update.friction {
+            require!(friction.is_valid(), OracleError::InvalidBps);
+            config.friction = friction;
+        }
+        if let Some(benchmark_pools) = update.benchmark_pools {
+            config.benchmark_pools = benchmark_pools;
+        }
+        if let Some(vault_max_drawdown_bps) = update.vault_max_drawdown_bps {
+            require!(vault_max_drawdown_bps <= 10_000, OracleError::InvalidBps);
+            config.vault_max_drawdown_bps = vault_max_drawdown_bps;
+        }
+        if let Some(vault_derisk_bps) = update.vault_derisk_bps {
+            require!(vault_derisk_bps <= 10_000, OracleError::InvalidBps);
+            config.vault_derisk_bps = vault_derisk_bps;
+        }
+        if let Some(cooldown_secs) = update.subscription_transfer_cooldown_secs {
+            require!(cooldown_secs >= 0, OracleError::InvalidCooldown);
+            config.subscription_transfer_cooldown_secs = cooldown_secs;
+        }
+        if let Some(vault_stake_pool) = update.vault_stake_pool {
+            config.vault_stake_pool = vault_stake_pool;
+        }
+        
+        emit!(ConfigUpdated {
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        msg!("ORACLE config updated");
+        Ok(())
+    }
+
+    /// Create the copy-vault that mirrors signals with real swaps
+    pub fn initialize_vault(
+        ctx: Context<InitializeVault>,
+        executor: Pubkey,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        vault.authority = ctx.accounts.authority.key();
+        vault.executor = executor;
+        vault.base_mint = ctx.accounts.base_mint.key();
+        vault.total_executions = 0;
+        vault.lent_amount = 0;
+        vault.nav = 0;
+        vault.peak_nav = 0;
+        vault.derisked = false;
+        vault.nav_at = 0;
+        vault.total_shares = 0;
+        vault.epoch = 0;
+        vault.epoch_started_at = Clock::get()?.unix_timestamp;
+        vault.queued_deposits = 0;
+        vault.queued_withdrawal_shares = 0;
+        vault.owed_withdrawals = 0;
+        vault.bump = ctx.bumps.vault;
+        
+        msg!("Vault initialized with executor: {}", executor);
+        Ok(())
+    }
+
+    /// Enter or exit a signal's position through a Jupiter route.
+    ///
+    /// The swap must deliver at least the signal's verified pool's spot
+    /// output (or `quoted_out`, if higher) minus the risk level's max
+    /// slippage into the vault, or the whole execution reverts. This
+    /// never touches the signal itself, so settlement is unaffected. The
+    /// side, amount and salt must open the signal's scheduled ticket, and
+    /// the current slot must be inside the ticket's window.
+    pub fn execute_signal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteSignal<'info>>,
+        side: ExecutionSide,
+        amount_in: u64,
+        quoted_out: u64,
+        salt: [u8; 32],
+        route_data: Vec<u8>,
+    ) -> Result<()> {
+        let signal = &ctx.accounts.signal;
+        let ticket = &mut ctx.accounts.ticket;
+        
+        let slot = Clock::get()?.slot;
+        require!(!ticket.executed, OracleError::TicketAlreadyExecuted);
+        require!(
+            slot >= ticket.valid_from_slot && slot <= ticket.valid_until_slot,
+            OracleError::OutsideSlotWindow
+        );
+        require!(
+            vault::execution_commitment(&signal.key(), side, amount_in, &salt) == ticket.commit_hash,
+            OracleError::CommitmentMismatch
+        );
+        ticket.executed = true;
+        
+        require!(amount_in > 0, OracleError::InvalidAmount);
+        if side == ExecutionSide::Entry {
+            require!(signal.status == SignalStatus::Open, OracleError::SignalAlreadyClosed);
+            require!(signal.direction == SignalDirection::Long, OracleError::NotALongSignal);
+            require!(!ctx.accounts.vault.emergency, OracleError::VaultInEmergency);
+            let max_allocation_bps = *ctx.accounts.config.max_allocation_bps
+                .get(signal.risk_level as usize)
+                .ok_or(OracleError::InvalidRiskLevel)?;
+            let vault_value = ctx.accounts.vault.allocation_base(ctx.accounts.vault_base_account.amount);
+            let position = &ctx.accounts.position;
+            let allocated = u128::from(position.cost_basis - position.exited_cost) + u128::from(amount_in);
+            require!(
+                allocated * 10_000 <= u128::from(vault_value) * u128::from(max_allocation_bps),
+                OracleError::AllocationCapExceeded
+            );
+            if ctx.accounts.vault.derisked {
+                let scale_bps = ctx.accounts.vault.entry_scale_bps(&ctx.accounts.config);
+                require!(scale_bps > 0, OracleError::VaultHalted);
+                let cap = u128::from(ctx.accounts.vault_base_account.amount) * u128::from(scale_bps) / 10_000;
+                require!(u128::from(amount_in) <= cap, OracleError::DeriskCapExceeded);
+            }
+            if let Some(plan) = ctx.accounts.dca_plan.as_mut() {
+                let now = Clock::get()?.unix_timestamp;
+                let tranche = plan.next_due(now).ok_or(OracleError::NoDcaTrancheDue)?;
+                plan.executed |= 1 << tranche;
+                emit!(DcaTrancheExecuted {
+                    id: signal.id,
+                    tranche,
+                    timestamp: now,
+                });
+            }
         } else {
-            signal.status = SignalStatus::Closed;
+            require!(amount_in <= ctx.accounts.position.token_amount, OracleError::InsufficientPosition);
+        }
+        
+        let max_slippage_bps = *ctx.accounts.config.max_slippage_bps
+            .get(signal.risk_level as usize)
+            .ok_or(OracleError::InvalidRiskLevel)?;
+        let (price, token_decimals, base_decimals) = vault_pool_price(
+            &ctx.accounts.pool,
+            &ctx.accounts.mint_a,
+            &ctx.accounts.mint_b,
+            &ctx.accounts.vault_a,
+            &ctx.accounts.vault_b,
+            &signal.token,
+            &ctx.accounts.vault.base_mint,
+        )?;
+        let min_out =
+            swap_min_out(side, amount_in, quoted_out, price, token_decimals, base_decimals, max_slippage_bps)?;
+        
+        let (spent, received) = swap_as_vault(
+            &ctx.accounts.jupiter_program,
+            ctx.remaining_accounts,
+            route_data,
+            &ctx.accounts.vault,
+            side,
+            &mut ctx.accounts.vault_base_account,
+            &mut ctx.accounts.vault_token_account,
+        )?;
+        
+        require!(spent <= amount_in, OracleError::SwapInputExceeded);
+        require!(received >= min_out, OracleError::SlippageExceeded);
+        if side == ExecutionSide::Entry {
+            let reserved = ctx.accounts.vault.queued_deposits + ctx.accounts.vault.owed_withdrawals;
+            require!(ctx.accounts.vault_base_account.amount >= reserved, OracleError::VaultFundsReserved);
         }
         
-        emit!(SignalClosed {
+        let position = &mut ctx.accounts.position;
+        position.signal = signal.key();
+        position.bump = ctx.bumps.position;
+        let vault_state = &mut ctx.accounts.vault;
+        let (entered_per_share, realized_pnl_per_share) = position.book(vault_state, side, spent, received);
+        vault_state.total_executions += 1;
+        
+        emit!(SignalExecuted {
             id: signal.id,
-            status: signal.status,
-            roi_bps: signal.roi_bps,
+            side,
+            amount_in: spent,
+            amount_out: received,
+            min_amount_out: min_out,
+        });
+        emit!(ExecutionAttributed {
+            id: signal.id,
+            side,
+            total_shares: vault_state.total_shares,
+            entered_per_share,
+            realized_pnl_per_share,
         });
         
-        msg!("Signal #{} closed with ROI: {}bps", signal.id, signal.roi_bps);
+        msg!("Signal #{} executed: {} in, {} out (min {})", signal.id, spent, received, min_out);
         Ok(())
     }
-}
-
-// === ACCOUNTS ===
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + OracleState::INIT_SPACE,
-        seeds = [b"oracle_state"],
-        bump
-    )]
-    pub oracle_state: Account<'info, OracleState>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
+    /// Open or close a short on Drift for a `Short` signal, on the market
+    /// set in its perp params.
+    ///
+    /// The reference price is the signal's verified pool's spot price; the
+    /// order's worst acceptable fill is derived from it with the risk
+    /// level's max slippage, and entries are capped by the risk level's max
+    /// leverage (or the signal's recommended leverage, if lower) against
+    /// the vault's base balance. Exits close on the market the short was
+    /// opened on.
+    pub fn execute_short<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteShort<'info>>,
+        side: ExecutionSide,
+        base_asset_amount: u64,
+    ) -> Result<()> {
+        let signal = &ctx.accounts.signal;
+        let config = &ctx.accounts.config;
+        let params = &ctx.accounts.perp_params;
+        let position = &mut ctx.accounts.position;
+        
+        require!(signal.direction == SignalDirection::Short, OracleError::NotAShortSignal);
+        let (reference_price, _, _) = vault_pool_price(
+            &ctx.accounts.pool,
+            &ctx.accounts.mint_a,
+            &ctx.accounts.mint_b,
+            &ctx.accounts.vault_a,
+            &ctx.accounts.vault_b,
+            &signal.token,
+            &ctx.accounts.vault.base_mint,
+        )?;
+        require!(base_asset_amount > 0 && reference_price > 0, OracleError::InvalidAmount);
+        
+        let risk = signal.risk_level as usize;
+        let max_slippage_bps = *config.max_slippage_bps.get(risk).ok_or(OracleError::InvalidRiskLevel)?;
+        let mut max_leverage_bps = *config.max_leverage_bps.get(risk).ok_or(OracleError::InvalidRiskLevel)?;
+        if params.leverage_bps > 0 {
+            max_leverage_bps = max_leverage_bps.min(params.leverage_bps);
+        }
+        
+        let notional = vault::perp_notional(base_asset_amount, reference_price, ctx.accounts.base_mint.decimals)
+            .ok_or(OracleError::PriceOverflow)?;
+        
+        // Shorts sell on entry (worst fill is lower) and buy back on exit (worst fill is higher)
+        let slippage = u128::from(reference_price) * u128::from(max_slippage_bps) / 10_000;
+        let was_open = position.is_open();
+        let market_index = match side {
+            ExecutionSide::Entry => params.market_index,
+            ExecutionSide::Exit => position.market_index,
+        };
+        let limit_price = match side {
+            ExecutionSide::Entry => {
+                require!(signal.status == SignalStatus::Open, OracleError::SignalAlreadyClosed);
+                require!(!ctx.accounts.vault.emergency, OracleError::VaultInEmergency);
+                require!(
+                    position.short_base_amount == 0 || position.market_index == market_index,
+                    OracleError::MarketMismatch
+                );
+                
+                let scale_bps = ctx.accounts.vault.entry_scale_bps(config);
+                require!(scale_bps > 0, OracleError::VaultHalted);
+                let collateral = ctx.accounts.vault_base_account.amount as u128;
+                let max_notional = collateral * u128::from(max_leverage_bps) / 10_000 * u128::from(scale_bps) / 10_000;
+                require!(
+                    u128::from(position.short_notional) + u128::from(notional) <= max_notional,
+                    OracleError::LeverageExceeded
+                );
+                
+                position.short_base_amount += base_asset_amount;
+                position.short_notional += notional;
+                u128::from(reference_price) - slippage
+            }
+            ExecutionSide::Exit => {
+                require!(base_asset_amount <= position.short_base_amount, OracleError::InsufficientPosition);
+                
+                let closed_notional = u128::from(position.short_notional) * u128::from(base_asset_amount)
+                    / u128::from(position.short_base_amount);
+                position.short_base_amount -= base_asset_amount;
+                position.short_notional -= closed_notional as u64;
+                u128::from(reference_price) + slippage
+            }
+        };
+        let limit_price = (limit_price * u128::from(vault::DRIFT_PRICE_PRECISION)
+            / 10u128.pow(u32::from(PRICE_DECIMALS))) as u64;
+        
+        position.signal = signal.key();
+        position.market_index = market_index;
+        position.bump = ctx.bumps.position;
+        ctx.accounts.vault.track_position(was_open, position.is_open());
+        
+        let vault_key = ctx.accounts.vault.key();
+        let seeds: &[&[u8]] = &[VAULT_SEED, &[ctx.accounts.vault.bump]];
+        vault::drift_perp_order(
+            &ctx.accounts.drift_program,
+            ctx.remaining_accounts,
+            market_index,
+            side == ExecutionSide::Entry,
+            side == ExecutionSide::Exit,
+            base_asset_amount,
+            limit_price,
+            &vault_key,
+            seeds,
+        )?;
+        ctx.accounts.vault.total_executions += 1;
+        
+        emit!(ShortExecuted {
+            id: signal.id,
+            side,
+            market_index,
+            base_asset_amount,
+            limit_price,
+            notional,
+        });
+        
+        msg!("Signal #{} short {:?}: {} base on market {}", signal.id, side, base_asset_amount, market_index);
+        Ok(())
+    }
 
-#[derive(Accounts)]
-#[instruction(token: Pubkey)]
-pub struct PublishSignal<'info> {
-    #[account(
-        mut,
-        seeds = [b"oracle_state"],
-        bump = oracle_state.bump
-    )]
-    pub oracle_state: Account<'info, OracleState>,
-    
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + Signal::INIT_SPACE,
-        seeds = [b"signal", oracle_state.total_signals.to_le_bytes().as_ref()],
-        bump
-    )]
-    pub signal: Account<'info, Signal>,
-    
-    #[account(
-        mut,
-        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
-    )]
-    pub authority: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
+    /// Park idle vault base in marginfi, keeping the configured reserve liquid
+    pub fn lend_idle<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ManageLending<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, OracleError::InvalidAmount);
+        require!(!ctx.accounts.vault.emergency, OracleError::VaultInEmergency);
+        
+        let before = ctx.accounts.vault_base_account.amount;
+        let vault_key = ctx.accounts.vault.key();
+        let seeds: &[&[u8]] = &[VAULT_SEED, &[ctx.accounts.vault.bump]];
+        vault::marginfi_deposit(
+            &ctx.accounts.marginfi_program,
+            ctx.remaining_accounts,
+            amount,
+            &vault_key,
+            seeds,
+        )?;
+        
+        let vault = &mut ctx.accounts.vault;
+        let base_account = &mut ctx.accounts.vault_base_account;
+        base_account.reload()?;
+        // The deposit must come out of the vault's own base account, in full
+        let deposited = before.saturating_sub(base_account.amount);
+        require!(deposited == amount, OracleError::LendingAmountMismatch);
+        vault.lent_amount += deposited;
+        
+        // Liquid base must still cover the reserve share of everything the vault holds
+        let total = u128::from(base_account.amount) + u128::from(vault.lent_amount);
+        let reserve = total * u128::from(ctx.accounts.config.vault_reserve_bps) / 10_000;
+        require!(u128::from(base_account.amount) >= reserve, OracleError::ReserveRatioBreached);
+        require!(
+            base_account.amount >= vault.queued_deposits + vault.owed_withdrawals,
+            OracleError::VaultFundsReserved
+        );
+        
+        emit!(VaultLendingChanged {
+            deposited,
+            withdrawn: 0,
+            lent_amount: vault.lent_amount,
+            liquid_amount: base_account.amount,
+        });
+        
+        msg!("Vault lent {} (total lent {})", deposited, vault.lent_amount);
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct UpdateSignal<'info> {
-    #[account(
-        mut,
-        seeds = [b"oracle_state"],
-        bump = oracle_state.bump
-    )]
-    pub oracle_state: Account<'info, OracleState>,
-    
-    #[account(mut)]
-    pub signal: Account<'info, Signal>,
-    
-    #[account(
-        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
-    )]
-    pub authority: Signer<'info>,
-}
+    /// Pull base back from marginfi when an execution needs liquidity
+    pub fn recall_lent<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ManageLending<'info>>,
+        amount: u64,
+        withdraw_all: bool,
+    ) -> Result<()> {
+        require!(amount > 0 || withdraw_all, OracleError::InvalidAmount);
+        
+        let before = ctx.accounts.vault_base_account.amount;
+        let vault_key = ctx.accounts.vault.key();
+        let seeds: &[&[u8]] = &[VAULT_SEED, &[ctx.accounts.vault.bump]];
+        vault::marginfi_withdraw(
+            &ctx.accounts.marginfi_program,
+            ctx.remaining_accounts,
+            amount,
+            withdraw_all,
+            &vault_key,
+            seeds,
+        )?;
+        
+        let vault = &mut ctx.accounts.vault;
+        let base_account = &mut ctx.accounts.vault_base_account;
+        base_account.reload()?;
+        let withdrawn = base_account.amount.saturating_sub(before);
+        // Anything above principal is interest earned
+        vault.lent_amount = if withdraw_all { 0 } else { vault.lent_amount.saturating_sub(withdrawn) };
+        
+        emit!(VaultLendingChanged {
+            deposited: 0,
+            withdrawn,
+            lent_amount: vault.lent_amount,
+            liquid_amount: base_account.amount,
+        });
+        
+        msg!("Vault recalled {} from lending (total lent {})", withdrawn, vault.lent_amount);
+        Ok(())
+    }
 
-// === STATE ===
+    /// Prove the signer holds a compressed access pass from the oracle's pass tree
+    pub fn verify_access_pass<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VerifyAccessPass<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        nonce: u64,
+        index: u32,
+    ) -> Result<()> {
+        let tree = ctx.accounts.merkle_tree.key();
+        let owner = ctx.accounts.owner.key();
+        
+        let asset_id = passes::asset_id(&tree, nonce);
+        let leaf = passes::leaf_hash(
+            &asset_id,
+            &owner,
+            &ctx.accounts.delegate.key(),
+            nonce,
+            &data_hash,
+            &creator_hash,
+        );
+        compression::verify_leaf(
+            &ctx.accounts.compression_program,
+            &ctx.accounts.merkle_tree,
+            ctx.remaining_accounts,
+            root,
+            leaf,
+            index,
+        )?;
+        
+        emit!(AccessPassVerified {
+            owner,
+            asset_id,
+            tree,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        msg!("Access pass {} verified for {}", asset_id, owner);
+        Ok(())
+    }
 
-#[account]
-#[derive(InitSpace)]
-pub struct OracleState {
-    pub authority: Pubkey,
-    pub total_signals: u64,
-    pub total_wins: u64,
-    pub total_losses: u64,
-    pub bump: u8,
-}
+    /// Buy or extend a subscription; payment goes to the treasury. Once the
+    /// pass mint exists, a subscriber without a pass is minted one.
+    pub fn subscribe(
+        ctx: Context<Subscribe>,
+        tier: u8,
+        periods: u32,
+    ) -> Result<()> {
+        require!((tier as usize) < TIER_COUNT, OracleError::InvalidTier);
+        require!(periods > 0, OracleError::InvalidAmount);
+        
+        let subscription = &mut ctx.accounts.subscription;
+        let now = Clock::get()?.unix_timestamp;
+        let active = subscription.expires_at > now;
+        
+        // An active subscription can only be extended on its own tier
+        require!(!active || subscription.tier == tier, OracleError::TierChangeWhileActive);
+        require!(subscription.paused_at == 0, OracleError::SubscriptionPaused);
+        
+        // Allowlisted tiers also need the subscriber's entry
+        require!(
+            ctx.accounts.tier_allowlist.data_is_empty() || ctx.accounts.allowlist_entry.is_some(),
+            OracleError::NotAllowlisted
+        );
+        
+        let price = ctx.accounts.config.tier_price_lamports[tier as usize]
+            .checked_mul(periods as u64)
+            .ok_or(OracleError::InvalidAmount)?;
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.subscriber.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            price,
+        )?;
+        
+        let starts_at = if active { subscription.expires_at } else { now };
+        if !active {
+            subscription.pauses = 0;
+        }
+        subscription.subscriber = ctx.accounts.subscriber.key();
+        subscription.tier = tier;
+        subscription.expires_at = starts_at + SUBSCRIPTION_PERIOD_SECS * periods as i64;
+        subscription.bump = ctx.bumps.subscription;
+        
+        emit!(Subscribed {
+            subscriber: subscription.subscriber,
+            tier,
+            expires_at: subscription.expires_at,
+            paid_lamports: price,
+        });
+        
+        // Once passes exist, subscribing mints one unless it's already held
+        let pass = pass_accounts(&ctx.accounts.pass_mint, &ctx.accounts.subscriber_pass, &ctx.accounts.token_program)?;
+        if let Some((subscriber_pass, token_program)) = pass {
+            if subscriber_pass.amount == 0 {
+                mint_pass(
+                    token_program,
+                    &ctx.accounts.pass_mint,
+                    &subscriber_pass.to_account_info(),
+                    ctx.bumps.pass_mint,
+                )?;
+                emit!(SubscriberPassChanged {
+                    subscriber: subscription.subscriber,
+                    minted: true,
+                    timestamp: now,
+                });
+            }
+        }
+        
+        msg!("{} subscribed to tier {} until {}", subscription.subscriber, tier, subscription.expires_at);
+        Ok(())
+    }
 
-#[account]
-#[derive(InitSpace)]
-pub struct Signal {
-    pub id: u64,
-    pub token: Pubkey,
-    #[max_len(10)]
-    pub symbol: String,
-    pub score: u8,
-    pub risk_level: u8,
-    pub sources_bitmap: u8,      // Bitmap of signal sources
-    pub mcap_at_signal: u64,
-    pub entry_price: u64,
-    pub ath_price: u64,
-    pub exit_price: u64,
-    pub roi_bps: i64,            // ROI in basis points (can be negative)
-    pub timestamp: i64,
-    pub status: SignalStatus,
-    pub reasoning_hash: [u8; 32], // SHA256 of reasoning committed BEFORE outcome
-    pub reasoning_revealed: bool, // Whether reasoning has been revealed publicly
-    pub bump: u8,
-}
+    /// Create the non-transferable Token-2022 mint for subscriber passes
+    pub fn initialize_pass_mint(ctx: Context<InitializePassMint>) -> Result<()> {
+        let mint = ctx.accounts.pass_mint.to_account_info();
+        let token_program = ctx.accounts.token_program.to_account_info();
+        let seeds: &[&[u8]] = &[PASS_MINT_SEED, &[ctx.bumps.pass_mint]];
+        
+        let space = find_mint_account_size(Some(&vec![
+            ExtensionType::NonTransferable,
+            ExtensionType::PermanentDelegate,
+        ]))?;
+        anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::CreateAccount {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: mint.clone(),
+                },
+                &[seeds],
+            ),
+            Rent::get()?.minimum_balance(space),
+            space as u64,
+            &token_program.key(),
+        )?;
+        
+        // Extensions must be initialized before the mint itself. The mint is
+        // its own permanent delegate so expired passes can be burned.
+        non_transferable_mint_initialize(CpiContext::new(
+            token_program.clone(),
+            NonTransferableMintInitialize {
+                token_program_id: token_program.clone(),
+                mint: mint.clone(),
+            },
+        ))?;
+        permanent_delegate_initialize(
+            CpiContext::new(
+                token_program.clone(),
+                PermanentDelegateInitialize {
+                    token_program_id: token_program.clone(),
+                    mint: mint.clone(),
+                },
+            ),
+            &mint.key(),
+        )?;
+        token_2022::initialize_mint2(
+            CpiContext::new(token_program, token_2022::InitializeMint2 { mint: mint.clone() }),
+            0,
+            &mint.key(),
+            None,
+        )?;
+        
+        msg!("Subscriber pass mint created: {}", mint.key());
+        Ok(())
+    }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
-pub enum SignalStatus {
-    Open,
-    Win,
-    Loss,
-    Closed,
-}
+    /// Mint a soulbound pass to an active subscriber who doesn't hold one,
+    /// e.g. one who subscribed before the pass mint existed
+    pub fn mint_subscriber_pass(ctx: Context<MintSubscriberPass>) -> Result<()> {
+        let subscription = &ctx.accounts.subscription;
+        
+        require!(subscription.expires_at > Clock::get()?.unix_timestamp, OracleError::SubscriptionExpired);
+        require!(ctx.accounts.subscriber_pass.amount == 0, OracleError::PassAlreadyHeld);
+        
+        mint_pass(
+            &ctx.accounts.token_program,
+            &ctx.accounts.pass_mint.to_account_info(),
+            &ctx.accounts.subscriber_pass.to_account_info(),
+            ctx.bumps.pass_mint,
+        )?;
+        
+        emit!(SubscriberPassChanged {
+            subscriber: subscription.subscriber,
+            minted: true,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        msg!("Subscriber pass minted to {}", subscription.subscriber);
+        Ok(())
+    }
 
-// === EVENTS ===
+    /// Burn the pass of a lapsed subscriber (permissionless)
+    pub fn burn_expired_pass(ctx: Context<BurnExpiredPass>) -> Result<()> {
+        let subscription = &ctx.accounts.subscription;
+        
+        require!(subscription.expires_at <= Clock::get()?.unix_timestamp, OracleError::SubscriptionStillActive);
+        
+        burn_pass(
+            &ctx.accounts.token_program,
+            &ctx.accounts.pass_mint.to_account_info(),
+            &ctx.accounts.subscriber_pass,
+            ctx.bumps.pass_mint,
+        )?;
+        
+        emit!(SubscriberPassChanged {
+            subscriber: subscription.subscriber,
+            minted: false,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        msg!("Expired subscriber pass burned for {}", subscription.subscriber);
+        Ok(())
+    }
+
+    /// Create a publisher profile for the signing wallet
+    pub fn register_publisher(ctx: Context<RegisterPublisher>) -> Result<()> {
+        let publisher = &mut ctx.accounts.publisher;
+        publisher.wallet = ctx.accounts.wallet.key();
+        publisher.domain = String::new();
+        publisher.domain_account = Pubkey::default();
+        publisher.created_at = Clock::get()?.unix_timestamp;
+        publisher.bump = ctx.bumps.publisher;
+        
+        msg!("Publisher registered: {}", publisher.wallet);
+        Ok(())
+    }
+
+    /// Link a `.sol` domain owned by the publisher's wallet to its profile
+    pub fn link_domain(
+        ctx: Context<LinkDomain>,
+        domain: String,
+    ) -> Result<()> {
+        require!(!domain.is_empty() && domain.len() <= MAX_DOMAIN_LEN, OracleError::DomainTooLong);
+        
+        let publisher = &mut ctx.accounts.publisher;
+        sns::verify_domain_owner(&ctx.accounts.name_account, &domain, &publisher.wallet)?;
+        
+        publisher.domain = domain;
+        publisher.domain_account = ctx.accounts.name_account.key();
+        
+        emit!(PublisherDomainLinked {
+            publisher: publisher.wallet,
+            domain: publisher.domain.clone(),
+            domain_account: publisher.domain_account,
+        });
+        
+        msg!("Publisher {} linked {}.sol", publisher.wallet, publisher.domain);
+        Ok(())
+    }
+
+    /// Create a treasury-funded automation thread that cranks this program.
+    /// Only permissionless instructions can run from a thread, so none of
+    /// their accounts may be a signer.
+    pub fn create_thread(
+        ctx: Context<ManageThread>,
+        thread_id: Vec<u8>,
+        instructions: Vec<ThreadInstruction>,
+        schedule: String,
+        amount: u64,
+    ) -> Result<()> {
+        // Treasury funds may only pay for cranks of this program that need no signer
+        require!(
+            !instructions.is_empty()
+                && instructions
+                    .iter()
+                    .all(|ix| ix.program_id == crate::ID && ix.accounts.iter().all(|acc| !acc.is_signer)),
+            OracleError::InvalidThreadInstruction
+        );
+        
+        let treasury = ctx.accounts.treasury.to_account_info();
+        require_keys_eq!(
+            ctx.accounts.thread.key(),
+            automation::thread_address(&treasury.key(), &thread_id),
+            OracleError::InvalidThreadAccount
+        );
+        
+        let seeds: &[&[u8]] = &[TREASURY_SEED, &[ctx.bumps.treasury]];
+        automation::thread_create(
+            &ctx.accounts.thread_program,
+            &treasury,
+            &ctx.accounts.thread,
+            &ctx.accounts.system_program.to_account_info(),
+            thread_id,
+            instructions,
+            schedule,
+            amount,
+            seeds,
+        )?;
+        
+        emit!(ThreadManaged {
+            thread: ctx.accounts.thread.key(),
+            action: ThreadAction::Created,
+            lamports: amount,
+        });
+        
+        msg!("Automation thread {} created", ctx.accounts.thread.key());
+        Ok(())
+    }
+
+    /// Top up an automation thread's fee balance from the treasury
+    pub fn fund_thread(
+        ctx: Context<ManageThread>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, OracleError::InvalidAmount);
+        require_keys_eq!(*ctx.accounts.thread.owner, automation::THREAD_PROGRAM_ID, OracleError::InvalidThreadAccount);
+        
+        let seeds: &[&[u8]] = &[TREASURY_SEED, &[ctx.bumps.treasury]];
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.treasury.to_account_info(),
+                    to: ctx.accounts.thread.to_account_info(),
+                },
+                &[seeds],
+            ),
+            amount,
+        )?;
+        
+        emit!(ThreadManaged {
+            thread: ctx.accounts.thread.key(),
+            action: ThreadAction::Funded,
+            lamports: amount,
+        });
+        
+        msg!("Automation thread {} funded with {} lamports", ctx.accounts.thread.key(), amount);
+        Ok(())
+    }
+
+    /// Delete an automation thread, returning its lamports to the treasury
+    pub fn close_thread(ctx: Context<ManageThread>) -> Result<()> {
+        let lamports = ctx.accounts.thread.lamports();
+        let seeds: &[&[u8]] = &[TREASURY_SEED, &[ctx.bumps.treasury]];
+        automation::thread_delete(
+            &ctx.accounts.thread_program,
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.thread,
+            seeds,
+        )?;
+        
+        emit!(ThreadManaged {
+            thread: ctx.accounts.thread.key(),
+            action: ThreadAction::Closed,
+            lamports,
+        });
+        
+        msg!("Automation thread {} closed", ctx.accounts.thread.key());
+        Ok(())
+    }
+
+    /// Commit to a vault execution for a signal within a slot window.
+    ///
+    /// Only the hash of (signal, side, amount, salt) is stored; the keeper
+    /// must reveal matching parameters inside the window, so executions
+    /// can't be resized, reordered or held back to trade against followers.
+    pub fn schedule_execution(
+        ctx: Context<ScheduleExecution>,
+        commit_hash: [u8; 32],
+        valid_from_slot: u64,
+        valid_until_slot: u64,
+    ) -> Result<()> {
+        require!(valid_from_slot <= valid_until_slot, OracleError::InvalidSlotWindow);
+        
+        let ticket = &mut ctx.accounts.ticket;
+        let slot = Clock::get()?.slot;
+        // A pending ticket can only be replaced once its window has passed
+        require!(
+            ticket.executed || ticket.valid_until_slot < slot,
+            OracleError::ExecutionAlreadyScheduled
+        );
+        
+        ticket.signal = ctx.accounts.signal.key();
+        ticket.commit_hash = commit_hash;
+        ticket.valid_from_slot = valid_from_slot;
+        ticket.valid_until_slot = valid_until_slot;
+        ticket.executed = false;
+        ticket.bump = ctx.bumps.ticket;
+        
+        emit!(ExecutionScheduled {
+            id: ctx.accounts.signal.id,
+            commit_hash,
+            valid_from_slot,
+            valid_until_slot,
+        });
+        
+        msg!("Signal #{} execution scheduled for slots {}-{}", ctx.accounts.signal.id, valid_from_slot, valid_until_slot);
+        Ok(())
+    }
+
+    /// Set up the Merkle tree backing compressed-mode signals.
+    ///
+    /// The tree account must already be allocated (owned by the compression
+    /// program) for the requested depth and buffer size.
+    pub fn init_signal_tree(
+        ctx: Context<InitSignalTree>,
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> Result<()> {
+        let seeds: &[&[u8]] = &[TREE_AUTHORITY_SEED, &[ctx.bumps.tree_authority]];
+        compression::init_tree(
+            &compression::TreeAccounts {
+                compression_program: &ctx.accounts.compression_program,
+                merkle_tree: &ctx.accounts.merkle_tree,
+                authority: &ctx.accounts.tree_authority,
+                noop_program: &ctx.accounts.noop_program,
+            },
+            max_depth,
+            max_buffer_size,
+            seeds,
+        )?;
+        
+        ctx.accounts.config.signal_tree = ctx.accounts.merkle_tree.key();
+        
+        msg!("Signal tree initialized: {}", ctx.accounts.merkle_tree.key());
+        Ok(())
+    }
+
+    /// Publish a signal as a compressed leaf instead of a PDA.
+    ///
+    /// The full signal is logged through the noop program and only its hash
+    /// is stored, in the oracle's signal tree at the next leaf index.
+    pub fn publish_signal_compressed(
+        ctx: Context<CompressedSignalTree>,
+        token: Pubkey,
+        symbol: String,
+        score: u8,
+        risk_level: u8,
+        sources_bitmap: u8,
+        mcap: u64,
+        entry_price: u64,
+        direction: SignalDirection,
+        reasoning_hash: [u8; 32],
+        quote_mint: Pubkey,
+    ) -> Result<()> {
+        require!(ctx.accounts.config.storage_mode == StorageMode::Compressed, OracleError::WrongStorageMode);
+        
+        let oracle_state = &mut ctx.accounts.oracle_state;
+        let mut signal = Signal::default();
+        init_signal(
+            &mut signal,
+            oracle_state,
+            token,
+            symbol,
+            score,
+            risk_level,
+            sources_bitmap,
+            mcap,
+            entry_price,
+            direction,
+            reasoning_hash,
+            quote_mint,
+            ctx.accounts.authority.key(),
+            ctx.accounts.config.max_symbol_len,
+        )?;
+        // Compressed signals have no account to store the size in
+        size_signal(&signal, oracle_state);
+        
+        let leaf_index = oracle_state.compressed_signals;
+        oracle_state.compressed_signals += 1;
+        
+        let tree = compressed_tree(ctx.accounts);
+        let seeds: &[&[u8]] = &[TREE_AUTHORITY_SEED, &[ctx.bumps.tree_authority]];
+        let leaf = write_compressed_signal(&tree, &signal)?;
+        compression::append(&tree, leaf, seeds)?;
+        
+        emit!(SignalPublishedWithProof {
+            id: signal.id,
+            token,
+            score,
+            reasoning_hash,
+            timestamp: signal.timestamp,
+            publisher_domain: String::new(),
+        });
+        emit!(CompressedSignalWritten {
+            id: signal.id,
+            leaf_index: leaf_index as u32,
+            leaf,
+        });
+        emit_alert(&signal);
+        
+        msg!("Signal #{} published compressed at leaf {}", signal.id, leaf_index);
+        Ok(())
+    }
+
+    /// Close a compressed signal, proving its current leaf with `proof`.
+    ///
+    /// `signal` is the current leaf data (as logged at publish); the settled
+    /// signal replaces it in the tree. The proof nodes are passed as
+    /// remaining accounts.
+    pub fn close_compressed_signal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseCompressedSignal<'info>>,
+        signal: Signal,
+        root: [u8; 32],
+        leaf_index: u32,
+        exit_price: u64,
+        quote_mint: Pubkey,
+    ) -> Result<()> {
+        check_quote(&signal, &quote_mint)?;
+        let previous_leaf = compressed_leaf(&signal)?;
+        let mut signal = signal;
+        let stats = &mut ctx.accounts.publisher_stats;
+        start_publisher_stats(stats, signal.publisher, ctx.bumps.publisher_stats);
+        settle_signal(&mut signal, &mut ctx.accounts.oracle_state, &ctx.accounts.config, stats, None, exit_price)?;
+        
+        let tree = compression::TreeAccounts {
+            compression_program: &ctx.accounts.compression_program,
+            merkle_tree: &ctx.accounts.merkle_tree,
+            authority: &ctx.accounts.tree_authority,
+            noop_program: &ctx.accounts.noop_program,
+        };
+        let seeds: &[&[u8]] = &[TREE_AUTHORITY_SEED, &[ctx.bumps.tree_authority]];
+        let leaf = write_compressed_signal(&tree, &signal)?;
+        compression::replace_leaf(
+            &tree,
+            ctx.remaining_accounts,
+            root,
+            previous_leaf,
+            leaf,
+            leaf_index,
+            seeds,
+        )?;
+        
+        emit!(CompressedSignalWritten {
+            id: signal.id,
+            leaf_index,
+            leaf,
+        });
+        Ok(())
+    }
+
+    /// Mint a Metaplex Core asset for an elite signal, owned by the publisher
+    pub fn mint_signal_asset(
+        ctx: Context<MintSignalAsset>,
+        uri: String,
+    ) -> Result<()> {
+        let signal = &ctx.accounts.signal;
+        let config = &ctx.accounts.config;
+        require!(signal.score >= config.elite_score, OracleError::ScoreBelowElite);
+        
+        let signal_key = signal.key();
+        let seeds: &[&[u8]] = &[SIGNAL_ASSET_SEED, signal_key.as_ref(), &[ctx.bumps.asset]];
+        collectibles::create_asset(
+            &collectibles::AssetAccounts {
+                core_program: &ctx.accounts.core_program,
+                asset: &ctx.accounts.asset,
+                payer: &ctx.accounts.authority,
+                owner: &ctx.accounts.authority,
+                update_authority: &ctx.accounts.oracle_state.to_account_info(),
+                system_program: &ctx.accounts.system_program,
+            },
+            format!("{} #{}", signal.symbol, signal.id),
+            uri,
+            config.asset_royalty_bps,
+            seeds,
+        )?;
+        
+        emit!(SignalAssetMinted {
+            id: signal.id,
+            asset: ctx.accounts.asset.key(),
+            owner: ctx.accounts.authority.key(),
+            royalty_bps: config.asset_royalty_bps,
+        });
+        
+        msg!("Signal #{} minted as asset {}", signal.id, ctx.accounts.asset.key());
+        Ok(())
+    }
+
+    /// Rewrite a signal created before `direction` and the price-check
+    /// fields existed into the current layout, growing the account and
+    /// topping up its rent from the authority
+    pub fn migrate_signal(ctx: Context<MigrateSignal>) -> Result<()> {
+        let info = ctx.accounts.signal.to_account_info();
+        require!(info.data_len() == LegacySignal::SPACE, OracleError::AlreadyMigrated);
+        
+        let legacy = {
+            let data = info.try_borrow_data()?;
+            require!(data[..8] == *Signal::DISCRIMINATOR, OracleError::InvalidSignalAccount);
+            LegacySignal::deserialize(&mut &data[8..])?
+        };
+        let (address, _) = Pubkey::find_program_address(&[SIGNAL_SEED, &legacy.id.to_le_bytes()], &crate::ID);
+        require_keys_eq!(address, info.key(), OracleError::InvalidSignalAccount);
+        
+        let space = signal_space(legacy.symbol.len());
+        let shortfall = Rent::get()?.minimum_balance(space).saturating_sub(info.lamports());
+        if shortfall > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        info.resize(space)?;
+        
+        // Legacy signals were all published by the oracle authority
+        let signal = legacy.upgrade(ctx.accounts.authority.key());
+        signal.serialize(&mut &mut info.try_borrow_mut_data()?[8..])?;
+        
+        msg!("Signal #{} migrated", signal.id);
+        Ok(())
+    }
+
+    /// Create the statistics account the signing publisher's settled
+    /// signals are recorded in
+    pub fn init_publisher_stats(ctx: Context<InitPublisherStats>) -> Result<()> {
+        let stats = &mut ctx.accounts.publisher_stats;
+        stats.wallet = ctx.accounts.wallet.key();
+        stats.signals = 0;
+        stats.wins = 0;
+        stats.losses = 0;
+        stats.cumulative_roi_bps = 0;
+        stats.last_active = Clock::get()?.unix_timestamp;
+        stats.streaks = Streaks::default();
+        stats.cumulative_roi_annualized_bps = 0;
+        stats.bump = ctx.bumps.publisher_stats;
+        
+        msg!("Publisher stats created for {}", stats.wallet);
+        Ok(())
+    }
+
+    /// Score of a signal decayed by its age against the configured
+    /// half-life; a view, returned as instruction return data
+    pub fn effective_score(ctx: Context<ViewSignal>) -> Result<u8> {
+        let signal = &ctx.accounts.signal;
+        let age = Clock::get()?.unix_timestamp - signal.timestamp;
+        let score = oracle_math::effective_score(signal.score, age, ctx.accounts.config.score_half_life_secs);
+        
+        msg!("Signal #{} effective score {} (published {}, {}s ago)", signal.id, score, signal.score, age);
+        Ok(score)
+    }
+
+    /// Grow a signal account to fit `symbol` (plus the reserved tail) and
+    /// store it, e.g. to restore a ticker cut short before the symbol
+    /// limit was raised. Accounts never shrink.
+    pub fn realloc_signal(
+        ctx: Context<ReallocSignal>,
+        symbol: String,
+    ) -> Result<()> {
+        require!(symbol.len() <= ctx.accounts.config.max_symbol_len as usize, OracleError::SymbolTooLong);
+        
+        let signal = &mut ctx.accounts.signal;
+        signal.symbol = symbol;
+        
+        msg!("Signal #{} symbol set to {}", signal.id, signal.symbol);
+        Ok(())
+    }
+
+    /// Create the (empty) notes account of a signal
+    pub fn init_signal_notes(ctx: Context<InitSignalNotes>) -> Result<()> {
+        let notes = &mut ctx.accounts.signal_notes;
+        notes.signal = ctx.accounts.signal.key();
+        notes.notes = Vec::new();
+        notes.bump = ctx.bumps.signal_notes;
+        
+        msg!("Notes opened for signal #{}", ctx.accounts.signal.id);
+        Ok(())
+    }
+
+    /// Append a timestamped note to a signal, growing its notes account
+    pub fn append_signal_note(
+        ctx: Context<AppendSignalNote>,
+        text: String,
+    ) -> Result<()> {
+        require!(!text.is_empty() && text.len() <= MAX_NOTE_LEN, OracleError::NoteTooLong);
+        
+        let notes = &mut ctx.accounts.signal_notes;
+        require!(notes.notes.len() < MAX_SIGNAL_NOTES, OracleError::TooManyNotes);
+        
+        let note = SignalNote {
+            timestamp: Clock::get()?.unix_timestamp,
+            text,
+        };
+        emit!(SignalNoteAdded {
+            id: ctx.accounts.signal.id,
+            index: notes.notes.len() as u32,
+            timestamp: note.timestamp,
+            text: note.text.clone(),
+        });
+        notes.notes.push(note);
+        
+        msg!("Note #{} added to signal #{}", notes.notes.len(), ctx.accounts.signal.id);
+        Ok(())
+    }
+
+    /// Rate a closed signal as an active subscriber; the receipt PDA makes
+    /// a second rating from the same subscriber fail
+    pub fn rate_signal(
+        ctx: Context<RateSignal>,
+        rating: u8,
+    ) -> Result<()> {
+        require!((1..=MAX_RATING).contains(&rating), OracleError::InvalidRating);
+        require!(ctx.accounts.signal.status != SignalStatus::Open, OracleError::SignalStillOpen);
+        
+        let now = Clock::get()?.unix_timestamp;
+        require!(ctx.accounts.subscription.expires_at > now, OracleError::SubscriptionExpired);
+        
+        let receipt = &mut ctx.accounts.receipt;
+        receipt.signal = ctx.accounts.signal.key();
+        receipt.subscriber = ctx.accounts.subscriber.key();
+        receipt.rating = rating;
+        receipt.timestamp = now;
+        receipt.bump = ctx.bumps.receipt;
+        
+        let ratings = &mut ctx.accounts.signal_rating;
+        ratings.signal = ctx.accounts.signal.key();
+        ratings.count += 1;
+        ratings.sum += rating as u64;
+        ratings.counts[(rating - 1) as usize] += 1;
+        ratings.bump = ctx.bumps.signal_rating;
+        
+        emit!(SignalRated {
+            id: ctx.accounts.signal.id,
+            subscriber: receipt.subscriber,
+            rating,
+        });
+        
+        msg!("Signal #{} rated {} by {}", ctx.accounts.signal.id, rating, receipt.subscriber);
+        Ok(())
+    }
+
+    /// Pay `lamports` into the treasury to heat up an open signal. Each
+    /// boost keeps it eligible for a free featured slot for
+    /// `BOOST_DURATION_SECS`; slots are otherwise sold by `bid_featured`.
+    pub fn boost_signal(
+        ctx: Context<BoostSignal>,
+        lamports: u64,
+    ) -> Result<()> {
+        require!(lamports >= MIN_BOOST_LAMPORTS, OracleError::InvalidAmount);
+        require!(ctx.accounts.signal.status == SignalStatus::Open, OracleError::SignalAlreadyClosed);
+        
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.booster.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            lamports,
+        )?;
+        
+        let now = Clock::get()?.unix_timestamp;
+        let boost = &mut ctx.accounts.signal_boost;
+        boost.signal = ctx.accounts.signal.key();
+        boost.heat = boost.heat.saturating_add(lamports);
+        boost.expires_at = now + BOOST_DURATION_SECS;
+        boost.bump = ctx.bumps.signal_boost;
+        
+        let discovery = &mut ctx.accounts.discovery;
+        discovery.bump = ctx.bumps.discovery;
+        let featured = discovery.feature(boost.signal, boost.heat, boost.expires_at, now);
+        
+        emit!(SignalBoosted {
+            id: ctx.accounts.signal.id,
+            booster: ctx.accounts.booster.key(),
+            lamports,
+            heat: boost.heat,
+            featured,
+        });
+        
+        msg!("Signal #{} boosted by {} lamports (heat {})", ctx.accounts.signal.id, lamports, boost.heat);
+        Ok(())
+    }
+
+    /// Bid `lamports` for a featured slot during `epoch`, which is open for
+    /// bids throughout the epoch before it. The top `FEATURED_SLOTS` bids
+    /// are held in escrow; a bid pushed out (or raised for the same
+    /// signal) is refunded to its bidder, passed as `outbid`.
+    pub fn bid_featured(
+        ctx: Context<BidFeatured>,
+        epoch: u64,
+        lamports: u64,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(epoch as i64 == now / AUCTION_EPOCH_SECS + 1, OracleError::AuctionClosed);
+        require!(lamports >= MIN_BOOST_LAMPORTS, OracleError::InvalidAmount);
+        require!(ctx.accounts.signal.status == SignalStatus::Open, OracleError::SignalAlreadyClosed);
+        
+        let auction = &mut ctx.accounts.auction;
+        auction.epoch = epoch;
+        auction.bump = ctx.bumps.auction;
+        let bid = AuctionBid {
+            signal: ctx.accounts.signal.key(),
+            bidder: ctx.accounts.bidder.key(),
+            lamports,
+        };
+        let displaced = auction.place(bid)?;
+        
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.bidder.to_account_info(),
+                    to: ctx.accounts.auction.to_account_info(),
+                },
+            ),
+            lamports,
+        )?;
+        
+        let mut refunded = None;
+        if displaced.lamports > 0 {
+            // Raising one's own bid refunds the signer; anyone else is passed
+            let outbid = if displaced.bidder == ctx.accounts.bidder.key() {
+                ctx.accounts.bidder.to_account_info()
+            } else {
+                let outbid = ctx.accounts.outbid.as_ref().ok_or(OracleError::InvalidRefundAccount)?;
+                require!(outbid.key() == displaced.bidder, OracleError::InvalidRefundAccount);
+                outbid.to_account_info()
+            };
+            let auction = ctx.accounts.auction.to_account_info();
+            **auction.try_borrow_mut_lamports()? -= displaced.lamports;
+            **outbid.try_borrow_mut_lamports()? += displaced.lamports;
+            refunded = Some(displaced.bidder);
+        }
+        
+        emit!(FeaturedBidPlaced {
+            epoch,
+            signal: bid.signal,
+            bidder: bid.bidder,
+            lamports,
+            refunded,
+        });
+        
+        msg!("Bid {} lamports for a featured slot in epoch {}", lamports, epoch);
+        Ok(())
+    }
+    
+    /// Once `epoch` has begun, install its winning bids in the featured
+    /// slots and pay the escrowed proceeds to the treasury. Anyone can call.
+    pub fn settle_auction(
+        ctx: Context<SettleAuction>,
+        epoch: u64,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(now >= epoch as i64 * AUCTION_EPOCH_SECS, OracleError::AuctionStillOpen);
+        require!(!ctx.accounts.auction.settled, OracleError::AuctionAlreadySettled);
+        
+        let auction = &mut ctx.accounts.auction;
+        auction.settled = true;
+        let proceeds: u64 = auction.bids.iter().map(|bid| bid.lamports).sum();
+        let winners = auction.bids.iter().filter(|bid| bid.lamports > 0).count() as u8;
+        
+        let discovery = &mut ctx.accounts.discovery;
+        discovery.bump = ctx.bumps.discovery;
+        discovery.install(&auction.bids, epoch);
+        
+        let auction = ctx.accounts.auction.to_account_info();
+        **auction.try_borrow_mut_lamports()? -= proceeds;
+        **ctx.accounts.treasury.try_borrow_mut_lamports()? += proceeds;
+        
+        emit!(FeaturedAuctionSettled {
+            epoch,
+            winners,
+            proceeds,
+        });
+        
+        msg!("Featured auction {} settled: {} winners, {} lamports", epoch, winners, proceeds);
+        Ok(())
+    }
+
+    /// Publish a basket signal on up to `MAX_BASKET_MEMBERS` tokens whose
+    /// weights sum to 10_000 bps. Baskets are numbered separately from
+    /// signals and stay out of the single-token track record.
+    pub fn publish_basket(
+        ctx: Context<PublishBasket>,
+        name: String,
+        members: Vec<BasketMemberParams>,
+    ) -> Result<()> {
+        require!(!name.is_empty() && name.len() <= MAX_BASKET_NAME_LEN, OracleError::BasketNameTooLong);
+        require!(!members.is_empty() && members.len() <= MAX_BASKET_MEMBERS, OracleError::InvalidBasket);
+        let weights: u64 = members.iter().map(|member| u64::from(member.weight_bps)).sum();
+        require!(weights == 10_000, OracleError::InvalidBasket);
+        for (i, member) in members.iter().enumerate() {
+            require!(member.weight_bps > 0 && member.entry_price > 0, OracleError::InvalidBasket);
+            require!(members[..i].iter().all(|other| other.token != member.token), OracleError::InvalidBasket);
+        }
+        
+        let oracle_state = &mut ctx.accounts.oracle_state;
+        let basket = &mut ctx.accounts.basket;
+        basket.id = oracle_state.total_baskets;
+        basket.name = name;
+        basket.members = members
+            .into_iter()
+            .map(|member| BasketMember {
+                token: member.token,
+                weight_bps: member.weight_bps,
+                entry_price: member.entry_price,
+                exit_price: 0,
+                roi_bps: 0,
+            })
+            .collect();
+        basket.timestamp = Clock::get()?.unix_timestamp;
+        basket.status = SignalStatus::Open;
+        basket.roi_bps = 0;
+        basket.bump = ctx.bumps.basket;
+        oracle_state.total_baskets += 1;
+        
+        emit!(BasketPublished {
+            id: basket.id,
+            name: basket.name.clone(),
+            members: basket.members.len() as u8,
+            timestamp: basket.timestamp,
+        });
+        
+        msg!("Basket #{} published: {} ({} tokens)", basket.id, basket.name, basket.members.len());
+        Ok(())
+    }
+    
+    /// Close a basket with one exit price per member, in publish order;
+    /// it settles on the weighted ROI of its members
+    pub fn close_basket(
+        ctx: Context<CloseBasket>,
+        exit_prices: Vec<u64>,
+    ) -> Result<()> {
+        let basket = &mut ctx.accounts.basket;
+        require!(basket.status == SignalStatus::Open, OracleError::SignalAlreadyClosed);
+        require!(exit_prices.len() == basket.members.len(), OracleError::InvalidBasket);
+        
+        for (member, exit_price) in basket.members.iter_mut().zip(exit_prices) {
+            member.exit_price = exit_price;
+            member.roi_bps = oracle_math::roi_bps(member.entry_price, exit_price, Direction::Long).unwrap_or(0);
+        }
+        basket.roi_bps = oracle_math::weighted_roi_bps(basket.members.iter().map(|m| (m.weight_bps, m.roi_bps)));
+        // Baskets and pairs carry no risk level, so they keep the default bar
+        let break_even_bps = ctx.accounts.config.break_even_bps;
+        let outcome = oracle_math::classify(basket.roi_bps, oracle_math::WIN_THRESHOLD_BPS, break_even_bps);
+        basket.status = match outcome {
+            Outcome::Win => SignalStatus::Win,
+            Outcome::Loss => SignalStatus::Loss,
+            Outcome::Closed => SignalStatus::Closed,
+            Outcome::BreakEven => SignalStatus::BreakEven,
+        };
+        
+        emit!(BasketClosed {
+            id: basket.id,
+            status: basket.status,
+            roi_bps: basket.roi_bps,
+        });
+        
+        msg!("Basket #{} closed: {} bps", basket.id, basket.roi_bps);
+        Ok(())
+    }
+
+    /// Publish a relative-value call that `long_token` will outperform
+    /// `short_token`. Pairs are numbered separately from signals and stay
+    /// out of the single-token track record.
+    pub fn publish_pair(
+        ctx: Context<PublishPair>,
+        long_token: Pubkey,
+        long_entry_price: u64,
+        short_token: Pubkey,
+        short_entry_price: u64,
+    ) -> Result<()> {
+        require!(long_token != short_token, OracleError::InvalidPair);
+        require!(long_entry_price > 0 && short_entry_price > 0, OracleError::InvalidPair);
+        
+        let oracle_state = &mut ctx.accounts.oracle_state;
+        let pair = &mut ctx.accounts.pair;
+        pair.id = oracle_state.total_pairs;
+        pair.long = PairLeg {
+            token: long_token,
+            entry_price: long_entry_price,
+            exit_price: 0,
+        };
+        pair.short = PairLeg {
+            token: short_token,
+            entry_price: short_entry_price,
+            exit_price: 0,
+        };
+        pair.timestamp = Clock::get()?.unix_timestamp;
+        pair.status = SignalStatus::Open;
+        pair.roi_bps = 0;
+        pair.bump = ctx.bumps.pair;
+        oracle_state.total_pairs += 1;
+        
+        emit!(PairPublished {
+            id: pair.id,
+            long_token,
+            short_token,
+            timestamp: pair.timestamp,
+        });
+        
+        msg!("Pair #{} published: long {} / short {}", pair.id, long_token, short_token);
+        Ok(())
+    }
+    
+    /// Close a pair at both legs' exit prices; it settles on the spread
+    /// ROI, the long leg's return minus the short leg's
+    pub fn close_pair(
+        ctx: Context<ClosePair>,
+        long_exit_price: u64,
+        short_exit_price: u64,
+    ) -> Result<()> {
+        let pair = &mut ctx.accounts.pair;
+        require!(pair.status == SignalStatus::Open, OracleError::SignalAlreadyClosed);
+        
+        pair.long.exit_price = long_exit_price;
+        pair.short.exit_price = short_exit_price;
+        pair.roi_bps = oracle_math::spread_roi_bps(
+            (pair.long.entry_price, long_exit_price),
+            (pair.short.entry_price, short_exit_price),
+        );
+        let break_even_bps = ctx.accounts.config.break_even_bps;
+        let outcome = oracle_math::classify(pair.roi_bps, oracle_math::WIN_THRESHOLD_BPS, break_even_bps);
+        pair.status = match outcome {
+            Outcome::Win => SignalStatus::Win,
+            Outcome::Loss => SignalStatus::Loss,
+            Outcome::Closed => SignalStatus::Closed,
+            Outcome::BreakEven => SignalStatus::BreakEven,
+        };
+        
+        emit!(PairClosed {
+            id: pair.id,
+            status: pair.status,
+            roi_bps: pair.roi_bps,
+        });
+        
+        msg!("Pair #{} closed: {} bps", pair.id, pair.roi_bps);
+        Ok(())
+    }
+
+    /// Recommend leverage for executing an open signal on perps, and name
+    /// the Drift market the vault shorts it on; the implied liquidation
+    /// price is derived from the leverage and the entry price. Leverage is
+    /// capped by the signal's risk level; 0 clears it.
+    pub fn set_perp_params(
+        ctx: Context<SetPerpParams>,
+        leverage_bps: u32,
+        market_index: u16,
+    ) -> Result<()> {
+        let signal = &ctx.accounts.signal;
+        require!(signal.status == SignalStatus::Open, OracleError::SignalAlreadyClosed);
+        let max_leverage_bps = *ctx
+            .accounts
+            .config
+            .max_leverage_bps
+            .get(signal.risk_level as usize)
+            .ok_or(OracleError::InvalidRiskLevel)?;
+        require!(
+            leverage_bps == 0 || (10_000..=max_leverage_bps).contains(&leverage_bps),
+            OracleError::LeverageExceeded
+        );
+        
+        let params = &mut ctx.accounts.perp_params;
+        params.signal = signal.key();
+        params.leverage_bps = leverage_bps;
+        params.liquidation_price = if leverage_bps == 0 {
+            0
+        } else {
+            oracle_math::liquidation_price(signal.entry_price, leverage_bps, signal.direction.into())
+        };
+        params.market_index = market_index;
+        params.bump = ctx.bumps.perp_params;
+        
+        emit!(PerpParamsSet {
+            id: signal.id,
+            leverage_bps,
+            liquidation_price: params.liquidation_price,
+            market_index,
+        });
+        
+        msg!("Signal #{} perp leverage {} bps, liquidation at {}", signal.id, leverage_bps, params.liquidation_price);
+        Ok(())
+    }
+
+    /// Compute a fresh signal's market cap from its mint's supply and the
+    /// spot price of the pool its entry was verified against, next to the
+    /// claimed `mcap_at_signal`, once. A gap above
+    /// `config.max_mcap_discrepancy_bps` is flagged, not rejected.
+    pub fn verify_market_cap(ctx: Context<VerifyMarketCap>) -> Result<()> {
+        let signal = &ctx.accounts.signal;
+        
+        require!(signal.status == SignalStatus::Open, OracleError::SignalAlreadyClosed);
+        require!(
+            Clock::get()?.unix_timestamp - signal.timestamp <= PRICE_CHECK_WINDOW_SECS,
+            OracleError::PriceCheckWindowElapsed
+        );
+        
+        let pool_price = pools::pool_price(
+            &ctx.accounts.pool,
+            &ctx.accounts.mint_a,
+            &ctx.accounts.mint_b,
+            &ctx.accounts.vault_a,
+            &ctx.accounts.vault_b,
+            &signal.token,
+        )?;
+        let token_mint = if ctx.accounts.mint_a.key() == signal.token {
+            &ctx.accounts.mint_a
+        } else {
+            &ctx.accounts.mint_b
+        };
+        let verified = pools::market_cap(token_mint.supply, token_mint.decimals, pool_price.price)
+            .ok_or(OracleError::PriceOverflow)?;
+        let discrepancy_bps = pools::deviation_bps(signal.mcap_at_signal, verified);
+        
+        let market_cap = &mut ctx.accounts.market_cap;
+        market_cap.signal = signal.key();
+        market_cap.claimed_mcap = signal.mcap_at_signal;
+        market_cap.verified_mcap = verified;
+        market_cap.supply = token_mint.supply;
+        market_cap.price = pool_price.price;
+        market_cap.quote_mint = pool_price.quote_mint;
+        market_cap.pool = ctx.accounts.pool.key();
+        market_cap.discrepancy_bps = discrepancy_bps;
+        market_cap.flagged = discrepancy_bps > u64::from(ctx.accounts.config.max_mcap_discrepancy_bps);
+        market_cap.bump = ctx.bumps.market_cap;
+        
+        emit!(MarketCapVerified {
+            id: signal.id,
+            claimed_mcap: market_cap.claimed_mcap,
+            verified_mcap: verified,
+            discrepancy_bps,
+            flagged: market_cap.flagged,
+        });
+        
+        msg!("Signal #{} mcap verified: {} ({} bps from the claim)", signal.id, verified, discrepancy_bps);
+        Ok(())
+    }
+
+    /// Record the token's mint supply as a signal is published, so its
+    /// return can later be told apart from dilution
+    pub fn snapshot_entry_supply(ctx: Context<SnapshotEntrySupply>) -> Result<()> {
+        let signal = &ctx.accounts.signal;
+        
+        require!(signal.status == SignalStatus::Open, OracleError::SignalAlreadyClosed);
+        require!(
+            Clock::get()?.unix_timestamp - signal.timestamp <= PRICE_CHECK_WINDOW_SECS,
+            OracleError::PriceCheckWindowElapsed
+        );
+        
+        let snapshot = &mut ctx.accounts.snapshot;
+        snapshot.signal = signal.key();
+        snapshot.entry_supply = ctx.accounts.mint.supply;
+        snapshot.entry_timestamp = Clock::get()?.unix_timestamp;
+        snapshot.exit_supply = 0;
+        snapshot.exit_timestamp = 0;
+        snapshot.supply_change_bps = 0;
+        snapshot.flagged = false;
+        snapshot.bump = ctx.bumps.snapshot;
+        
+        msg!("Signal #{} entry supply {}", signal.id, snapshot.entry_supply);
+        Ok(())
+    }
+    
+    /// Commit the hash of the token's top-holder distribution (an
+    /// `oracle_screening::HolderSnapshot`) while the signal is fresh, so
+    /// concentration disputes can be settled against the committed snapshot
+    pub fn commit_holder_distribution(
+        ctx: Context<CommitHolderDistribution>,
+        snapshot_hash: [u8; 32],
+        top_holders_bps: u16,
+    ) -> Result<()> {
+        let signal = &ctx.accounts.signal;
+        let now = Clock::get()?.unix_timestamp;
+        
+        require!(signal.status == SignalStatus::Open, OracleError::SignalAlreadyClosed);
+        require!(now - signal.timestamp <= PRICE_CHECK_WINDOW_SECS, OracleError::PriceCheckWindowElapsed);
+        require!(
+            snapshot_hash != [0u8; 32] && top_holders_bps <= 10_000,
+            OracleError::InvalidHolderCommitment
+        );
+        
+        let commitment = &mut ctx.accounts.commitment;
+        commitment.signal = signal.key();
+        commitment.snapshot_hash = snapshot_hash;
+        commitment.top_holders_bps = top_holders_bps;
+        commitment.timestamp = now;
+        commitment.bump = ctx.bumps.commitment;
+        
+        emit!(HolderDistributionCommitted {
+            id: signal.id,
+            snapshot_hash,
+            top_holders_bps,
+            timestamp: now,
+        });
+        
+        msg!("Signal #{} holder distribution committed: top holders {} bps", signal.id, top_holders_bps);
+        Ok(())
+    }
+
+    /// Re-enter a token after its last signal closed, as the next attempt
+    /// of that signal's chain rather than a fresh signal. The new signal's
+    /// lineage carries the attempt count and the compounded ROI of every
+    /// earlier attempt.
+    pub fn reopen_signal(
+        ctx: Context<ReopenSignal>,
+        symbol: String,
+        score: u8,
+        risk_level: u8,
+        sources_bitmap: u8,
+        mcap: u64,
+        entry_price: u64,
+        direction: SignalDirection,
+        reasoning_hash: [u8; 32],
+        quote_mint: Pubkey,
+    ) -> Result<()> {
+        require!(ctx.accounts.config.storage_mode == StorageMode::Accounts, OracleError::WrongStorageMode);
+        
+        let parent = &ctx.accounts.parent_signal;
+        let token = parent.token;
+        let history = &mut ctx.accounts.token_history;
+        init_token_history(history, token, ctx.bumps.token_history);
+        require!(
+            history.last_closed_at == 0 || history.last_signal == parent.key(),
+            OracleError::InvalidParentSignal
+        );
+        check_republish(history, Some(parent), &token, ctx.accounts.config.republish_cooldown_secs)?;
+        
+        // A parent without a lineage is the first attempt of its chain
+        let parent_lineage = &ctx.accounts.parent_lineage;
+        let (root, attempt, prior_roi_bps) = if parent_lineage.data_is_empty() {
+            (parent.key(), 2, parent.roi_bps)
+        } else {
+            let lineage = SignalLineage::try_deserialize(&mut &parent_lineage.try_borrow_data()?[..])?;
+            (
+                lineage.root,
+                lineage.attempt.saturating_add(1),
+                oracle_math::compound_roi_bps(lineage.prior_roi_bps, parent.roi_bps),
+            )
+        };
+        let parent = parent.key();
+        
+        let signal = &mut ctx.accounts.signal;
+        let oracle_state = &mut ctx.accounts.oracle_state;
+        init_signal(
+            signal,
+            oracle_state,
+            token,
+            symbol,
+            score,
+            risk_level,
+            sources_bitmap,
+            mcap,
+            entry_price,
+            direction,
+            reasoning_hash,
+            quote_mint,
+            ctx.accounts.authority.key(),
+            ctx.accounts.config.max_symbol_len,
+        )?;
+        signal.bump = ctx.bumps.signal;
+        record_sizing(&mut ctx.accounts.sizing, signal, oracle_state, ctx.bumps.sizing);
+        
+        let lineage = &mut ctx.accounts.lineage;
+        lineage.signal = signal.key();
+        lineage.parent = parent;
+        lineage.root = root;
+        lineage.attempt = attempt;
+        lineage.prior_roi_bps = prior_roi_bps;
+        lineage.bump = ctx.bumps.lineage;
+        
+        emit!(SignalPublishedWithProof {
+            id: signal.id,
+            token,
+            score,
+            reasoning_hash,
+            timestamp: signal.timestamp,
+            publisher_domain: publisher_domain(&ctx.accounts.publisher),
+        });
+        emit!(SignalReopened {
+            id: signal.id,
+            parent,
+            root,
+            attempt,
+            prior_roi_bps,
+        });
+        emit_alert(signal);
+        
+        msg!("Signal #{} reopens {} as attempt {}", signal.id, signal.symbol, attempt);
+        Ok(())
+    }
+
+    /// Record a further entry the strategy scaled into at `price`, sized at
+    /// `weight_bps` of the final position. The publish entry keeps whatever
+    /// weight the added entries leave, and the signal's entry price becomes
+    /// the weighted average, so ROI at close reflects the real cost basis.
+    pub fn add_entry(
+        ctx: Context<AddEntry>,
+        price: u64,
+        weight_bps: u16,
+    ) -> Result<()> {
+        let signal = &mut ctx.accounts.signal;
+        let entries = &mut ctx.accounts.entries;
+        let now = Clock::get()?.unix_timestamp;
+        
+        require!(signal.status == SignalStatus::Open, OracleError::SignalAlreadyClosed);
+        require!(entries.entries.len() < MAX_SIGNAL_ENTRIES, OracleError::TooManyEntries);
+        if entries.signal == Pubkey::default() {
+            entries.signal = signal.key();
+            entries.initial_price = signal.entry_price;
+            entries.bump = ctx.bumps.entries;
+        }
+        require!(
+            price > 0 && weight_bps > 0 && weight_bps <= entries.initial_weight_bps(),
+            OracleError::InvalidEntry
+        );
+        
+        entries.entries.push(SignalEntry {
+            price,
+            weight_bps,
+            timestamp: now,
+        });
+        signal.entry_price = entries.average_price();
+        // The averaged price was never checked against the pool
+        signal.entry_verified = false;
+        // The entry was a price the token traded at, so it may be a new best
+        if oracle_math::is_new_best(signal.ath_price, price, signal.direction.into()) {
+            signal.ath_price = price;
+        }
+        
+        emit!(EntryAdded {
+            id: signal.id,
+            price,
+            weight_bps,
+            entry_price: signal.entry_price,
+            timestamp: now,
+        });
+        
+        msg!("Signal #{} entry added at {} ({} bps), entry now {}", signal.id, price, weight_bps, signal.entry_price);
+        Ok(())
+    }
+
+    /// Declare that a signal's entry is bought in `tranches` tranches of
+    /// `tranche_bps` of the position each, one every `interval_secs` from
+    /// now. The copy-vault fills the next due tranche with each entry it
+    /// executes, so followers can see which tranches ran.
+    pub fn set_dca_plan(
+        ctx: Context<SetDcaPlan>,
+        tranches: u8,
+        interval_secs: i64,
+        tranche_bps: u16,
+    ) -> Result<()> {
+        let signal = &ctx.accounts.signal;
+        let now = Clock::get()?.unix_timestamp;
+        
+        require!(signal.status == SignalStatus::Open, OracleError::SignalAlreadyClosed);
+        require!(
+            (2..=MAX_DCA_TRANCHES).contains(&tranches)
+                && interval_secs > 0
+                && tranche_bps > 0
+                && tranches as u32 * tranche_bps as u32 <= 10_000,
+            OracleError::InvalidDcaPlan
+        );
+        
+        let plan = &mut ctx.accounts.dca_plan;
+        plan.signal = signal.key();
+        plan.tranches = tranches;
+        plan.tranche_bps = tranche_bps;
+        plan.interval_secs = interval_secs;
+        plan.start = now;
+        plan.executed = 0;
+        plan.bump = ctx.bumps.dca_plan;
+        
+        emit!(DcaPlanSet {
+            id: signal.id,
+            tranches,
+            tranche_bps,
+            interval_secs,
+            start: now,
+        });
+        
+        msg!("Signal #{} DCA plan: {} x {} bps every {}s", signal.id, tranches, tranche_bps, interval_secs);
+        Ok(())
+    }
+
+    /// Ladder a signal's exit over up to `MAX_TAKE_PROFIT_LEVELS` prices,
+    /// each exiting `exit_bps` of the position. Levels must lie beyond the
+    /// entry in the signal's favour, each further out than the last.
+    pub fn set_take_profits(
+        ctx: Context<SetTakeProfits>,
+        levels: Vec<TakeProfitParams>,
+    ) -> Result<()> {
+        let signal = &ctx.accounts.signal;
+        let direction: Direction = signal.direction.into();
+        
+        require!(signal.status == SignalStatus::Open, OracleError::SignalAlreadyClosed);
+        require!(!levels.is_empty() && levels.len() <= MAX_TAKE_PROFIT_LEVELS, OracleError::InvalidTakeProfits);
+        let mut previous = signal.entry_price;
+        let mut exit_bps = 0u32;
+        for level in &levels {
+            require!(
+                level.exit_bps > 0 && oracle_math::is_new_best(previous, level.price, direction),
+                OracleError::InvalidTakeProfits
+            );
+            previous = level.price;
+            exit_bps += level.exit_bps as u32;
+        }
+        require!(exit_bps <= 10_000, OracleError::InvalidTakeProfits);
+        
+        let ladder = &mut ctx.accounts.ladder;
+        ladder.signal = signal.key();
+        ladder.levels = levels
+            .iter()
+            .map(|level| TakeProfitLevel {
+                price: level.price,
+                exit_bps: level.exit_bps,
+                settled_at: 0,
+            })
+            .collect();
+        ladder.realized_roi_bps = 0;
+        ladder.exited_bps = 0;
+        ladder.bump = ctx.bumps.ladder;
+        
+        emit!(TakeProfitsSet {
+            id: signal.id,
+            levels: ladder.levels.clone(),
+        });
+        
+        msg!("Signal #{} take-profit ladder set: {} level(s)", signal.id, ladder.levels.len());
+        Ok(())
+    }
+
+    /// Settle take-profit `level` once the signal's verified pool trades at
+    /// or through it. Permissionless: the pool price is read on-chain, so
+    /// any keeper can crank it. The level exits at its own price and adds
+    /// its share of the position's ROI to the ladder's realized ROI; the
+    /// level that exits the whole position closes the signal at it.
+    pub fn settle_take_profit(
+        ctx: Context<SettleTakeProfit>,
+        level: u8,
+    ) -> Result<()> {
+        let signal = &mut ctx.accounts.signal;
+        let ladder = &mut ctx.accounts.ladder;
+        let (pool, mint_a, mint_b) = (&ctx.accounts.pool, &ctx.accounts.mint_a, &ctx.accounts.mint_b);
+        let (vault_a, vault_b) = (&ctx.accounts.vault_a, &ctx.accounts.vault_b);
+        let direction: Direction = signal.direction.into();
+        let now = Clock::get()?.unix_timestamp;
+        
+        require!(signal.status == SignalStatus::Open, OracleError::SignalAlreadyClosed);
+        let pool_price = pools::pool_price(pool, mint_a, mint_b, vault_a, vault_b, &signal.token)?;
+        check_quote(signal, &pool_price.quote_mint)?;
+        
+        let take_profit = ladder.levels.get_mut(level as usize).ok_or(OracleError::InvalidTakeProfits)?;
+        require!(take_profit.settled_at == 0, OracleError::TakeProfitSettled);
+        require!(
+            oracle_math::reaches(pool_price.price, take_profit.price, direction),
+            OracleError::TakeProfitNotReached
+        );
+        take_profit.settled_at = now;
+        
+        let roi_bps = oracle_math::roi_bps(signal.entry_price, take_profit.price, direction).unwrap_or(0);
+        let exit_bps = take_profit.exit_bps;
+        let price = take_profit.price;
+        ladder.realized_roi_bps = ladder
+            .realized_roi_bps
+            .saturating_add(oracle_math::weighted_roi_bps([(exit_bps, roi_bps)]));
+        ladder.exited_bps += exit_bps;
+        
+        emit!(TakeProfitHit {
+            id: signal.id,
+            level,
+            price,
+            pool_price: pool_price.price,
+            realized_roi_bps: ladder.realized_roi_bps,
+            exited_bps: ladder.exited_bps,
+        });
+        
+        msg!("Signal #{} take-profit {} hit at {}: realized {} bps", signal.id, level, price, ladder.realized_roi_bps);
+        
+        if ladder.exited_bps >= 10_000 {
+            signal.exit_verified = true;
+            let (oracle_state, config) = (&mut ctx.accounts.oracle_state, &ctx.accounts.config);
+            let stats = &mut ctx.accounts.publisher_stats;
+            start_publisher_stats(stats, signal.publisher, ctx.bumps.publisher_stats);
+            settle_signal(signal, oracle_state, config, stats, Some(&**ladder), price)?;
+            record_token_close(&mut ctx.accounts.token_history, signal, ctx.bumps.token_history)?;
+            let mint = token_mint(&ctx.accounts.mint_a, &ctx.accounts.mint_b, &signal.token);
+            snapshot_exit_supply(&ctx.accounts.supply_snapshot, Some(mint), signal, config)?;
+        }
+        Ok(())
+    }
+
+    /// Set the price past which a signal is invalidated: below the entry
+    /// for longs, above it for shorts, far enough out to close as a loss.
+    pub fn set_invalidation_price(
+        ctx: Context<SetInvalidationPrice>,
+        price: u64,
+    ) -> Result<()> {
+        let signal = &ctx.accounts.signal;
+        let config = &ctx.accounts.config;
+        
+        require!(signal.status == SignalStatus::Open, OracleError::SignalAlreadyClosed);
+        let roi_bps = oracle_math::roi_bps(signal.entry_price, price, signal.direction.into())
+            .ok_or(OracleError::InvalidInvalidationPrice)?;
+        let win_threshold_bps = *config.win_threshold_bps
+            .get(signal.risk_level as usize)
+            .ok_or(OracleError::InvalidRiskLevel)?;
+        require!(
+            price > 0 && oracle_math::classify(roi_bps, win_threshold_bps, config.break_even_bps) == Outcome::Loss,
+            OracleError::InvalidInvalidationPrice
+        );
+        
+        let invalidation = &mut ctx.accounts.invalidation;
+        invalidation.signal = signal.key();
+        invalidation.price = price;
+        invalidation.bump = ctx.bumps.invalidation;
+        
+        emit!(InvalidationPriceSet {
+            id: signal.id,
+            price,
+        });
+        
+        msg!("Signal #{} invalidation price set to {}", signal.id, price);
+        Ok(())
+    }
+
+    /// Close a signal whose verified pool price has crossed its invalidation
+    /// price, settling it at the pool price; anyone can call it
+    pub fn invalidate_signal(ctx: Context<InvalidateSignal>) -> Result<()> {
+        let signal = &mut ctx.accounts.signal;
+        let oracle_state = &mut ctx.accounts.oracle_state;
+        let invalidation_price = ctx.accounts.invalidation.price;
+        let (pool, mint_a, mint_b) = (&ctx.accounts.pool, &ctx.accounts.mint_a, &ctx.accounts.mint_b);
+        let (vault_a, vault_b) = (&ctx.accounts.vault_a, &ctx.accounts.vault_b);
+        
+        require!(signal.status == SignalStatus::Open, OracleError::SignalAlreadyClosed);
+        let pool_price = pools::pool_price(pool, mint_a, mint_b, vault_a, vault_b, &signal.token)?;
+        check_quote(signal, &pool_price.quote_mint)?;
+        require!(
+            oracle_math::reaches(invalidation_price, pool_price.price, signal.direction.into()),
+            OracleError::InvalidationNotReached
+        );
+        
+        signal.exit_verified = true;
+        
+        let config = &ctx.accounts.config;
+        let ladder = take_profit_ladder(&ctx.accounts.ladder)?;
+        let stats = &mut ctx.accounts.publisher_stats;
+        start_publisher_stats(stats, signal.publisher, ctx.bumps.publisher_stats);
+        settle_signal(signal, oracle_state, config, stats, ladder.as_ref(), pool_price.price)?;
+        record_token_close(&mut ctx.accounts.token_history, signal, ctx.bumps.token_history)?;
+        let mint = token_mint(&ctx.accounts.mint_a, &ctx.accounts.mint_b, &signal.token);
+        snapshot_exit_supply(&ctx.accounts.supply_snapshot, Some(mint), signal, config)?;
+        
+        emit!(SignalInvalidated {
+            id: signal.id,
+            invalidation_price,
+            pool_price: pool_price.price,
+        });
+        Ok(())
+    }
+
+    /// Mark open signals to their verified pools' prices, storing each one's
+    /// unrealized ROI and the portfolio total. Remaining accounts come in
+    /// groups of `[signal (writable), pool, mint_a, mint_b, vault_a,
+    /// vault_b]`, each pool the signal's verified `price_pool`; anyone can
+    /// call it.
+    pub fn mark_signals<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MarkSignals<'info>>,
+    ) -> Result<()> {
+        let oracle_state = &mut ctx.accounts.oracle_state;
+        let groups = ctx.remaining_accounts.chunks_exact(6);
+        require!(
+            !ctx.remaining_accounts.is_empty() && groups.remainder().is_empty(),
+            OracleError::InvalidMarkAccounts
+        );
+        let marked = groups.len();
+        
+        for group in groups {
+            let [signal, pool, mint_a, mint_b, vault_a, vault_b] = group else {
+                return err!(OracleError::InvalidMarkAccounts);
+            };
+            require!(signal.is_writable, OracleError::InvalidMarkAccounts);
+            let mut signal = Account::<Signal>::try_from(signal)?;
+            require!(signal.status == SignalStatus::Open, OracleError::SignalAlreadyClosed);
+            require!(
+                signal.price_pool != Pubkey::default() && pool.key() == signal.price_pool,
+                OracleError::PoolNotVerified
+            );
+            let mint_a = InterfaceAccount::<Mint>::try_from(mint_a)?;
+            let mint_b = InterfaceAccount::<Mint>::try_from(mint_b)?;
+            let pool_price = pools::pool_price(pool, &mint_a, &mint_b, vault_a, vault_b, &signal.token)?;
+            check_quote(&signal, &pool_price.quote_mint)?;
+            
+            let direction = signal.direction.into();
+            let roi_bps = oracle_math::roi_bps(signal.entry_price, pool_price.price, direction).unwrap_or(0);
+            oracle_state.unrealized_roi_bps = oracle_state
+                .unrealized_roi_bps
+                .saturating_sub(signal.unrealized_roi_bps)
+                .saturating_add(roi_bps);
+            signal.unrealized_roi_bps = roi_bps;
+            signal.exit(&crate::ID)?;
+            
+            emit!(SignalMarked {
+                id: signal.id,
+                price: pool_price.price,
+                unrealized_roi_bps: roi_bps,
+            });
+        }
+        oracle_state.marked_at = Clock::get()?.unix_timestamp;
+        
+        msg!("Marked {} signal(s): portfolio {} bps", marked, oracle_state.unrealized_roi_bps);
+        Ok(())
+    }
+
+    /// Start the model portfolio with a virtual `bankroll` split across at
+    /// most `max_positions` concurrent signals. It follows every signal
+    /// published from now on, in id order.
+    pub fn init_model_portfolio(
+        ctx: Context<InitModelPortfolio>,
+        bankroll: u64,
+        max_positions: u8,
+    ) -> Result<()> {
+        require!(
+            bankroll > 0 && max_positions > 0 && max_positions as usize <= MAX_PORTFOLIO_POSITIONS,
+            OracleError::InvalidPortfolio
+        );
+        
+        let portfolio = &mut ctx.accounts.portfolio;
+        portfolio.max_positions = max_positions;
+        portfolio.next_id = ctx.accounts.oracle_state.total_signals;
+        portfolio.cash = bankroll;
+        portfolio.nav = bankroll;
+        portfolio.peak_nav = bankroll;
+        portfolio.max_drawdown_bps = 0;
+        portfolio.positions = Vec::new();
+        portfolio.skipped = 0;
+        portfolio.bump = ctx.bumps.portfolio;
+        
+        msg!("Model portfolio started: {} across {} position(s)", bankroll, max_positions);
+        Ok(())
+    }
+
+    /// Consider the next signal in sequence for the model portfolio: it
+    /// takes a position of NAV / max_positions if the signal is still open
+    /// and a slot is free, otherwise the signal is skipped. Anyone can call
+    /// it.
+    pub fn portfolio_enter(ctx: Context<PortfolioEnter>) -> Result<()> {
+        let portfolio = &mut ctx.accounts.portfolio;
+        let id = portfolio.next_id;
+        require!(id < ctx.accounts.oracle_state.total_signals, OracleError::PortfolioUpToDate);
+        
+        // Compressed signals have no account to follow and are skipped too
+        let info = &ctx.accounts.signal;
+        let open = !info.data_is_empty() && {
+            require_keys_eq!(*info.owner, crate::ID, OracleError::InvalidSignalAccount);
+            Signal::try_deserialize(&mut &info.try_borrow_data()?[..])?.status == SignalStatus::Open
+        };
+        let size = if open { portfolio.open_position(info.key()) } else { None };
+        if size.is_none() {
+            portfolio.skipped += 1;
+        }
+        portfolio.next_id += 1;
+        
+        emit!(PortfolioEntered {
+            id,
+            size: size.unwrap_or(0),
+            cash: portfolio.cash,
+            exposure_bps: portfolio.exposure_bps(),
+        });
+        Ok(())
+    }
+
+    /// Close the model portfolio's position in a settled signal at its net
+    /// ROI. Anyone can call it.
+    pub fn portfolio_exit(ctx: Context<PortfolioExit>) -> Result<()> {
+        let signal = &ctx.accounts.signal;
+        let portfolio = &mut ctx.accounts.portfolio;
+        require!(signal.status != SignalStatus::Open, OracleError::SignalStillOpen);
+        
+        let proceeds = portfolio.close_position(&signal.key(), signal.net_roi_bps)?;
+        
+        emit!(PortfolioExited {
+            id: signal.id,
+            proceeds,
+            nav: portfolio.nav,
+            max_drawdown_bps: portfolio.max_drawdown_bps,
+            exposure_bps: portfolio.exposure_bps(),
+        });
+        
+        msg!("Model portfolio exited signal #{}: NAV {}", signal.id, portfolio.nav);
+        Ok(())
+    }
+
+    /// Record benchmark `benchmark`'s price from its configured pool next
+    /// to a signal: as the entry within the price check window after
+    /// publish, as the exit within the window after settlement. The price
+    /// is taken in the signal's quote mint. Anyone can call it.
+    pub fn record_benchmark(
+        ctx: Context<RecordBenchmark>,
+        benchmark: u8,
+    ) -> Result<()> {
+        let signal = &ctx.accounts.signal;
+        let (pool, mint_a, mint_b) = (&ctx.accounts.pool, &ctx.accounts.mint_a, &ctx.accounts.mint_b);
+        let (vault_a, vault_b) = (&ctx.accounts.vault_a, &ctx.accounts.vault_b);
+        let benchmark_pool = *ctx.accounts.config.benchmark_pools
+            .get(benchmark as usize)
+            .ok_or(OracleError::InvalidBenchmark)?;
+        require!(
+            benchmark_pool != Pubkey::default() && pool.key() == benchmark_pool,
+            OracleError::InvalidBenchmark
+        );
+        
+        let asset = if mint_a.key() == signal.quote_mint { mint_b.key() } else { mint_a.key() };
+        let pool_price = pools::pool_price(pool, mint_a, mint_b, vault_a, vault_b, &asset)?;
+        check_quote(signal, &pool_price.quote_mint)?;
+        
+        let at_exit = signal.status != SignalStatus::Open;
+        let since = if at_exit { signal.closed_at } else { signal.timestamp };
+        require!(
+            Clock::get()?.unix_timestamp - since <= PRICE_CHECK_WINDOW_SECS,
+            OracleError::PriceCheckWindowElapsed
+        );
+        
+        let record = &mut ctx.accounts.benchmark;
+        record.signal = signal.key();
+        record.bump = ctx.bumps.benchmark;
+        let prices = if at_exit { &mut record.exit_prices } else { &mut record.entry_prices };
+        let price = &mut prices[benchmark as usize];
+        require!(*price == 0, OracleError::BenchmarkRecorded);
+        *price = pool_price.price;
+        
+        emit!(BenchmarkRecorded {
+            id: signal.id,
+            benchmark,
+            price: pool_price.price,
+            at_exit,
+        });
+        Ok(())
+    }
+
+    /// Report the vault's NAV in base mint units, valued off-chain by the
+    /// executor. It must land within `config.vault_nav_tolerance_bps` of
+    /// what the program measures itself: base on hand and lent, idle and
+    /// staked SOL at the SOL benchmark pool, and every open position at
+    /// its signal's verified pool. The share price it implies can move at
+    /// most `config.vault_max_nav_change_bps` from the last report.
+    /// `remaining_accounts` are one group per open position, in position
+    /// address order: position, signal, pool, mint A, mint B, vault A,
+    /// vault B.
+    ///
+    /// Once NAV has fallen the configured drawdown from its peak, the
+    /// vault is de-risked: new entries halt, or are capped at the
+    /// configured share of liquid base, until the authority resets it.
+    pub fn report_vault_nav<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReportVaultNav<'info>>,
+        nav: u64,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        // Staked SOL is only part of NAV at a recent exchange rate
+        require!(
+            ctx.accounts.vault.lst_amount == 0 || now - ctx.accounts.vault.lst_synced_at <= MAX_LST_VALUE_AGE_SECS,
+            OracleError::StaleLstValue
+        );
+        let measured = ctx.accounts.measured_nav(ctx.remaining_accounts)?;
+        let config = &ctx.accounts.config;
+        require!(
+            nav == measured || pools::deviation_bps(nav, measured) <= u64::from(config.vault_nav_tolerance_bps),
+            OracleError::NavOutOfRange
+        );
+        
+        let vault = &mut ctx.accounts.vault;
+        let equity = nav.saturating_sub(vault.queued_deposits + vault.owed_withdrawals);
+        let share_price = vault::share_price(equity, vault.total_shares);
+        // Flows don't move the share price, so a jump in it is a jump in what the vault holds
+        let max_change_bps = u64::from(config.vault_max_nav_change_bps);
+        if max_change_bps > 0 && vault.total_shares > 0 && vault.nav_share_price > 0 {
+            require!(
+                vault::change_bps(share_price, vault.nav_share_price) <= max_change_bps,
+                OracleError::NavChangeTooLarge
+            );
+        }
+        vault.nav_share_price = share_price;
+        vault.nav = nav;
+        vault.nav_at = now;
+        vault.peak_nav = vault.peak_nav.max(nav);
+        
+        let drawdown_bps = oracle_math::drawdown_bps(vault.peak_nav, nav, Direction::Long);
+        let threshold_bps = u64::from(config.vault_max_drawdown_bps);
+        if !vault.derisked && threshold_bps > 0 && drawdown_bps >= threshold_bps {
+            vault.derisked = true;
+            emit!(VaultDerisked {
+                nav,
+                peak_nav: vault.peak_nav,
+                drawdown_bps: drawdown_bps as u16,
+            });
+            msg!("Vault de-risked at {}bps drawdown", drawdown_bps);
+        }
+        Ok(())
+    }
+
+    /// Lift the vault's de-risking and restart its drawdown from the
+    /// current NAV
+    pub fn reset_vault_derisk(ctx: Context<ResetVaultDerisk>) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        require!(vault.derisked, OracleError::VaultNotDerisked);
+        vault.derisked = false;
+        vault.peak_nav = vault.nav;
+        
+        emit!(VaultDeriskReset { nav: vault.nav });
+        msg!("Vault de-risking reset at NAV {}", vault.nav);
+        Ok(())
+    }
+
+    /// Record that `subscriber` held signal `signal` at the current slot,
+    /// before its reasoning is revealed. The claim proves when the
+    /// subscriber had the signal, not whether they traded on it.
+    pub fn claim_access(ctx: Context<ClaimAccess>) -> Result<()> {
+        let signal = &ctx.accounts.signal;
+        require!(!signal.reasoning_revealed, OracleError::ReasoningAlreadyRevealed);
+        
+        let clock = Clock::get()?;
+        require!(ctx.accounts.subscription.expires_at > clock.unix_timestamp, OracleError::SubscriptionExpired);
+        
+        let claim = &mut ctx.accounts.claim;
+        claim.signal = signal.key();
+        claim.subscriber = ctx.accounts.subscriber.key();
+        claim.slot = clock.slot;
+        claim.timestamp = clock.unix_timestamp;
+        claim.bump = ctx.bumps.claim;
+        
+        emit!(AccessClaimed {
+            id: signal.id,
+            subscriber: claim.subscriber,
+            slot: claim.slot,
+        });
+        
+        msg!("Signal #{} access claimed by {} at slot {}", signal.id, claim.subscriber, claim.slot);
+        Ok(())
+    }
+
+    /// Log the release of a signal's reasoning document to an
+    /// institutional subscriber, for their audit trail. `subscriber_hash`
+    /// identifies the subscriber without naming them (e.g. SHA256 of their
+    /// internal account id); only subscribers who opted in are logged.
+    pub fn log_access(ctx: Context<LogAccess>, subscriber_hash: [u8; 32]) -> Result<()> {
+        let signal = &ctx.accounts.signal;
+        require!(signal.reasoning_hash != [0u8; 32], OracleError::NoReasoningCommitment);
+        
+        emit!(AccessLogged {
+            id: signal.id,
+            subscriber_hash,
+            reasoning_hash: signal.reasoning_hash,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Put `tier` in allowlist mode, or change its capacity: only wallets
+    /// the authority has added can subscribe to it, at most `capacity` of
+    /// them
+    pub fn set_tier_allowlist(
+        ctx: Context<SetTierAllowlist>,
+        tier: u8,
+        capacity: u32,
+    ) -> Result<()> {
+        require!((tier as usize) < TIER_COUNT, OracleError::InvalidTier);
+        let allowlist = &mut ctx.accounts.allowlist;
+        require!(capacity > 0 && capacity >= allowlist.count, OracleError::InvalidAllowlistCapacity);
+        allowlist.tier = tier;
+        allowlist.capacity = capacity;
+        allowlist.bump = ctx.bumps.allowlist;
+        
+        emit!(TierAllowlistSet {
+            tier,
+            capacity,
+            count: allowlist.count,
+        });
+        Ok(())
+    }
+
+    /// Let `wallet` subscribe to allowlisted `tier`
+    pub fn allowlist_add(
+        ctx: Context<AllowlistAdd>,
+        tier: u8,
+        wallet: Pubkey,
+    ) -> Result<()> {
+        let allowlist = &mut ctx.accounts.allowlist;
+        require!(allowlist.count < allowlist.capacity, OracleError::AllowlistFull);
+        allowlist.count += 1;
+        
+        let entry = &mut ctx.accounts.entry;
+        entry.tier = tier;
+        entry.wallet = wallet;
+        entry.bump = ctx.bumps.entry;
+        
+        emit!(AllowlistChanged {
+            tier,
+            wallet,
+            added: true,
+            count: allowlist.count,
+        });
+        Ok(())
+    }
+
+    /// Take `wallet` off allowlisted `tier`; an active subscription runs
+    /// out but can't be renewed
+    pub fn allowlist_remove(
+        ctx: Context<AllowlistRemove>,
+        tier: u8,
+        wallet: Pubkey,
+    ) -> Result<()> {
+        let allowlist = &mut ctx.accounts.allowlist;
+        allowlist.count -= 1;
+        
+        emit!(AllowlistChanged {
+            tier,
+            wallet,
+            added: false,
+            count: allowlist.count,
+        });
+        Ok(())
+    }
+
+    /// Move the signer's remaining subscription time to `recipient`, who
+    /// must not hold an active subscription. The moved subscription can't
+    /// be transferred again until the configured cooldown has passed, so a
+    /// pass can't be rapidly shared around; a cooldown of 0 disables
+    /// transfers. Once passes exist the owner's pass is burned, and one is
+    /// minted to the recipient's pass account if it is passed.
+    pub fn transfer_subscription(ctx: Context<TransferSubscription>) -> Result<()> {
+        let cooldown_secs = ctx.accounts.config.subscription_transfer_cooldown_secs;
+        require!(cooldown_secs > 0, OracleError::TransfersDisabled);
+        let recipient = ctx.accounts.recipient.key();
+        require_keys_neq!(recipient, ctx.accounts.owner.key(), OracleError::InvalidRecipient);
+        
+        let now = Clock::get()?.unix_timestamp;
+        let from = &mut ctx.accounts.from;
+        require!(from.expires_at > now, OracleError::SubscriptionExpired);
+        require!(now >= from.transferable_at, OracleError::TransferCooldown);
+        require!(
+            ctx.accounts.tier_allowlist.data_is_empty() || ctx.accounts.recipient_entry.is_some(),
+            OracleError::NotAllowlisted
+        );
+        
+        let to = &mut ctx.accounts.to;
+        require!(to.expires_at <= now && to.paused_at == 0, OracleError::RecipientSubscribed);
+        to.subscriber = recipient;
+        to.tier = from.tier;
+        to.expires_at = from.expires_at;
+        to.transferable_at = now + cooldown_secs;
+        to.pauses = from.pauses;
+        to.bump = ctx.bumps.to;
+        from.expires_at = now;
+        
+        emit!(SubscriptionTransferred {
+            from: from.subscriber,
+            to: recipient,
+            tier: to.tier,
+            expires_at: to.expires_at,
+        });
+        
+        // The pass is soulbound, so it moves by burn and re-mint
+        let pass = pass_accounts(&ctx.accounts.pass_mint, &ctx.accounts.owner_pass, &ctx.accounts.token_program)?;
+        if let Some((owner_pass, token_program)) = pass {
+            if owner_pass.amount > 0 {
+                burn_pass(token_program, &ctx.accounts.pass_mint, owner_pass, ctx.bumps.pass_mint)?;
+                emit!(SubscriberPassChanged {
+                    subscriber: from.subscriber,
+                    minted: false,
+                    timestamp: now,
+                });
+            }
+            if let Some(recipient_pass) = &ctx.accounts.recipient_pass {
+                require_keys_eq!(recipient_pass.mint, ctx.accounts.pass_mint.key(), OracleError::PassAccountRequired);
+                if recipient_pass.amount == 0 {
+                    mint_pass(
+                        token_program,
+                        &ctx.accounts.pass_mint,
+                        &recipient_pass.to_account_info(),
+                        ctx.bumps.pass_mint,
+                    )?;
+                    emit!(SubscriberPassChanged {
+                        subscriber: recipient,
+                        minted: true,
+                        timestamp: now,
+                    });
+                }
+            }
+        }
+        
+        msg!("Subscription of {} transferred to {} until {}", from.subscriber, recipient, to.expires_at);
+        Ok(())
+    }
+
+    /// Freeze the signer's remaining subscription time. Access lapses
+    /// until `resume_subscription`, and once passes exist the pass is
+    /// burned until then; a subscription can be paused
+    /// MAX_SUBSCRIPTION_PAUSES times.
+    pub fn pause_subscription(ctx: Context<ManageSubscription>) -> Result<()> {
+        let subscription = &mut ctx.accounts.subscription;
+        let now = Clock::get()?.unix_timestamp;
+        require!(subscription.paused_at == 0, OracleError::SubscriptionPaused);
+        require!(subscription.expires_at > now, OracleError::SubscriptionExpired);
+        require!(subscription.pauses < MAX_SUBSCRIPTION_PAUSES, OracleError::PauseLimitReached);
+        
+        subscription.paused_at = now;
+        subscription.paused_remaining_secs = subscription.expires_at - now;
+        subscription.expires_at = now;
+        subscription.pauses += 1;
+        
+        emit!(SubscriptionPauseChanged {
+            subscriber: subscription.subscriber,
+            paused: true,
+            expires_at: subscription.expires_at,
+            remaining_secs: subscription.paused_remaining_secs,
+        });
+        
+        let pass = pass_accounts(&ctx.accounts.pass_mint, &ctx.accounts.subscriber_pass, &ctx.accounts.token_program)?;
+        if let Some((subscriber_pass, token_program)) = pass {
+            if subscriber_pass.amount > 0 {
+                burn_pass(token_program, &ctx.accounts.pass_mint, subscriber_pass, ctx.bumps.pass_mint)?;
+                emit!(SubscriberPassChanged {
+                    subscriber: subscription.subscriber,
+                    minted: false,
+                    timestamp: now,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Restart a paused subscription with the time it had left, minting
+    /// the pass back once passes exist. A pause only stays free for
+    /// MAX_PAUSE_SECS; time paused beyond that is used up.
+    pub fn resume_subscription(ctx: Context<ManageSubscription>) -> Result<()> {
+        let subscription = &mut ctx.accounts.subscription;
+        require!(subscription.paused_at != 0, OracleError::SubscriptionNotPaused);
+        
+        let now = Clock::get()?.unix_timestamp;
+        let resumed_at = now.min(subscription.paused_at + MAX_PAUSE_SECS);
+        subscription.expires_at = resumed_at + subscription.paused_remaining_secs;
+        subscription.paused_at = 0;
+        subscription.paused_remaining_secs = 0;
+        
+        emit!(SubscriptionPauseChanged {
+            subscriber: subscription.subscriber,
+            paused: false,
+            expires_at: subscription.expires_at,
+            remaining_secs: (subscription.expires_at - now).max(0),
+        });
+        
+        // A pause that ran out the remaining time leaves nothing to hold a pass for
+        let pass = pass_accounts(&ctx.accounts.pass_mint, &ctx.accounts.subscriber_pass, &ctx.accounts.token_program)?;
+        if let Some((subscriber_pass, token_program)) = pass {
+            if subscriber_pass.amount == 0 && subscription.expires_at > now {
+                mint_pass(
+                    token_program,
+                    &ctx.accounts.pass_mint,
+                    &subscriber_pass.to_account_info(),
+                    ctx.bumps.pass_mint,
+                )?;
+                emit!(SubscriberPassChanged {
+                    subscriber: subscription.subscriber,
+                    minted: true,
+                    timestamp: now,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Queue `amount` of base for the vault. It is moved into the vault now
+    /// but only buys shares at the end of the epoch, at that epoch's NAV,
+    /// so it can't dilute followers mid-trade.
+    pub fn request_vault_deposit(ctx: Context<RequestVaultDeposit>, amount: u64) -> Result<()> {
+        require!(amount > 0, OracleError::InvalidAmount);
+        let vault = &mut ctx.accounts.vault;
+        require!(!vault.emergency, OracleError::VaultInEmergency);
+        vault.queue_flow(Clock::get()?.unix_timestamp)?;
+        let depositor = &mut ctx.accounts.depositor;
+        depositor.queue(vault.epoch)?;
+        depositor.accrue(vault.entered_per_share, vault.realized_pnl_per_share);
+        depositor.owner = ctx.accounts.owner.key();
+        depositor.bump = ctx.bumps.depositor;
+        
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: ctx.accounts.owner_base_account.to_account_info(),
+                    mint: ctx.accounts.base_mint.to_account_info(),
+                    to: ctx.accounts.vault_base_account.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            amount,
+            ctx.accounts.base_mint.decimals,
+        )?;
+        depositor.queued_deposit += amount;
+        vault.queued_deposits += amount;
+        
+        emit!(VaultFlowQueued {
+            owner: depositor.owner,
+            epoch: vault.epoch,
+            deposit: amount,
+            withdrawal_shares: 0,
+        });
+        Ok(())
+    }
+
+    /// Queue `shares` for redemption at the end of the epoch, at that
+    /// epoch's NAV
+    pub fn request_vault_withdrawal(ctx: Context<RequestVaultWithdrawal>, shares: u64) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let depositor = &mut ctx.accounts.depositor;
+        require!(shares > 0 && shares <= depositor.shares, OracleError::InsufficientShares);
+        vault.queue_flow(Clock::get()?.unix_timestamp)?;
+        depositor.queue(vault.epoch)?;
+        depositor.accrue(vault.entered_per_share, vault.realized_pnl_per_share);
+        depositor.shares -= shares;
+        depositor.queued_withdrawal_shares += shares;
+        vault.queued_withdrawal_shares += shares;
+        
+        emit!(VaultFlowQueued {
+            owner: depositor.owner,
+            epoch: vault.epoch,
+            deposit: 0,
+            withdrawal_shares: shares,
+        });
+        Ok(())
+    }
+
+    /// Close the vault's epoch once it has run VAULT_EPOCH_SECS and the
+    /// executor has reported NAV since: every queued deposit and
+    /// withdrawal is priced at that one NAV, which `report_vault_nav` has
+    /// held to the vault's measured balances. Anyone can call it. With a
+    /// performance fee configured, the epoch's fees must be crystallized
+    /// first. If the settled shares are worth nothing, the epoch's deposits
+    /// are handed back instead of buying into them.
+    pub fn process_vault_epoch(ctx: Context<ProcessVaultEpoch>) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let now = Clock::get()?.unix_timestamp;
+        vault.check_closing_nav(now)?;
+        
+        // Queued deposits and unclaimed payouts sit in the vault but belong to no share
+        let equity = vault.nav.saturating_sub(vault.queued_deposits + vault.owed_withdrawals);
+        if vault.next_fee_epoch <= vault.epoch {
+            // Without a fee there is nothing to crystallize, but the high-water mark still moves
+            require!(ctx.accounts.config.vault_performance_fee_bps == 0, OracleError::FeesNotCrystallized);
+            (_, vault.high_water_mark) = vault::performance_fee(equity, vault.total_shares, vault.high_water_mark, 0);
+            vault.next_fee_epoch = vault.epoch + 1;
+        }
+        let (minted_shares, refunded_deposits) =
+            match vault::shares_for_deposit(vault.queued_deposits, vault.total_shares, equity) {
+                Some(shares) => (shares, 0),
+                None => (0, vault.queued_deposits),
+            };
+        let payout = vault::share_value(vault.queued_withdrawal_shares, vault.total_shares, equity);
+        
+        let record = &mut ctx.accounts.epoch;
+        record.epoch = vault.epoch;
+        record.equity = equity;
+        record.total_shares = vault.total_shares;
+        record.deposits = vault.queued_deposits;
+        record.minted_shares = minted_shares;
+        record.withdrawal_shares = vault.queued_withdrawal_shares;
+        record.payout = payout;
+        record.entered_per_share = vault.entered_per_share;
+        record.realized_pnl_per_share = vault.realized_pnl_per_share;
+        record.refunded_deposits = refunded_deposits;
+        record.bump = ctx.bumps.epoch;
+        
+        vault.total_shares = vault.total_shares - vault.queued_withdrawal_shares + minted_shares;
+        vault.owed_withdrawals += payout + refunded_deposits;
+        vault.queued_deposits = 0;
+        vault.queued_withdrawal_shares = 0;
+        vault.epoch += 1;
+        vault.epoch_started_at = now;
+        
+        emit!(VaultEpochProcessed {
+            epoch: record.epoch,
+            equity,
+            deposits: record.deposits,
+            minted_shares,
+            withdrawal_shares: record.withdrawal_shares,
+            payout,
+            total_shares: vault.total_shares,
+            refunded_deposits,
+        });
+        Ok(())
+    }
+
+    /// Turn a depositor's flows queued in a processed epoch into shares and
+    /// paid-out base. Anyone can call it.
+    pub fn settle_vault_depositor(ctx: Context<SettleVaultDepositor>) -> Result<()> {
+        let record = &ctx.accounts.epoch;
+        let depositor = &mut ctx.accounts.depositor;
+        require!(depositor.has_queued(), OracleError::NothingQueued);
+        
+        let shares = vault::pro_rata(record.minted_shares, depositor.queued_deposit, record.deposits);
+        let payout = vault::pro_rata(record.payout, depositor.queued_withdrawal_shares, record.withdrawal_shares);
+        let refund = vault::pro_rata(record.refunded_deposits, depositor.queued_deposit, record.deposits);
+        // What was held through the epoch earns up to its close, what is held after from there on
+        depositor.accrue(record.entered_per_share, record.realized_pnl_per_share);
+        depositor.shares += shares;
+        depositor.queued_deposit = 0;
+        depositor.queued_withdrawal_shares = 0;
+        let vault = &ctx.accounts.vault;
+        depositor.accrue(vault.entered_per_share, vault.realized_pnl_per_share);
+        
+        if payout + refund > 0 {
+            let seeds: &[&[u8]] = &[VAULT_SEED, &[ctx.accounts.vault.bump]];
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token_interface::TransferChecked {
+                        from: ctx.accounts.vault_base_account.to_account_info(),
+                        mint: ctx.accounts.base_mint.to_account_info(),
+                        to: ctx.accounts.owner_base_account.to_account_info(),
+                        authority: ctx.accounts.vault.to_account_info(),
+                    },
+                    &[seeds],
+                ),
+                payout + refund,
+                ctx.accounts.base_mint.decimals,
+            )?;
+        }
+        let vault = &mut ctx.accounts.vault;
+        vault.owed_withdrawals = vault.owed_withdrawals.saturating_sub(payout + refund);
+        
+        emit!(VaultDepositorSettled {
+            owner: depositor.owner,
+            epoch: record.epoch,
+            shares,
+            payout,
+            refund,
+        });
+        Ok(())
+    }
+
+    /// Bring a follower's entry and realized-PnL totals up to date with the
+    /// vault's executions. Anyone can call it; flows from a processed epoch
+    /// must be settled first.
+    pub fn accrue_vault_depositor(ctx: Context<AccrueVaultDepositor>) -> Result<()> {
+        let vault = &ctx.accounts.vault;
+        let depositor = &mut ctx.accounts.depositor;
+        require!(
+            !depositor.has_queued() || depositor.queued_epoch == vault.epoch,
+            OracleError::UnsettledVaultFlows
+        );
+        depositor.accrue(vault.entered_per_share, vault.realized_pnl_per_share);
+        
+        emit!(VaultDepositorAccrued {
+            owner: depositor.owner,
+            shares: depositor.held_shares(),
+            entered: depositor.entered,
+            realized_pnl: depositor.realized_pnl,
+        });
+        Ok(())
+    }
+
+    /// Stake `lamports` of the vault's idle SOL into the configured SPL
+    /// stake pool for its LST, so capital between signals earns staking
+    /// yield. `remaining_accounts` are the pool's `DepositSol` accounts.
+    pub fn stake_idle_sol<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ManageStaking<'info>>,
+        lamports: u64,
+    ) -> Result<()> {
+        require!(lamports > 0, OracleError::InvalidAmount);
+        require!(!ctx.accounts.vault.emergency, OracleError::VaultInEmergency);
+        ctx.accounts.check_pool_accounts(ctx.remaining_accounts)?;
+        
+        let vault_sol = ctx.accounts.vault_sol.key();
+        let seeds: &[&[u8]] = &[VAULT_SOL_SEED, &[ctx.bumps.vault_sol]];
+        vault::stake_pool_deposit_sol(
+            &ctx.accounts.stake_pool_program,
+            ctx.remaining_accounts,
+            lamports,
+            &vault_sol,
+            seeds,
+        )?;
+        
+        ctx.accounts.sync(lamports, 0)
+    }
+
+    /// Redeem `pool_tokens` of the vault's LST back to idle SOL through the
+    /// pool's reserve. `remaining_accounts` are the pool's `WithdrawSol`
+    /// accounts.
+    pub fn unstake_lst<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ManageStaking<'info>>,
+        pool_tokens: u64,
+    ) -> Result<()> {
+        require!(pool_tokens > 0, OracleError::InvalidAmount);
+        ctx.accounts.check_pool_accounts(ctx.remaining_accounts)?;
+        
+        let vault_key = ctx.accounts.vault.key();
+        let seeds: &[&[u8]] = &[VAULT_SEED, &[ctx.accounts.vault.bump]];
+        vault::stake_pool_withdraw_sol(
+            &ctx.accounts.stake_pool_program,
+            ctx.remaining_accounts,
+            pool_tokens,
+            &vault_key,
+            seeds,
+        )?;
+        
+        ctx.accounts.sync(0, pool_tokens)
+    }
+
+    /// Re-value the vault's LST at the stake pool's current exchange rate.
+    /// Anyone can call it; NAV reports need it done recently.
+    pub fn sync_vault_lst(ctx: Context<SyncVaultLst>) -> Result<()> {
+        let rate = read_stake_pool(&ctx.accounts.stake_pool, &ctx.accounts.vault_lst_account)?;
+        let vault = &mut ctx.accounts.vault;
+        vault.value_lst(ctx.accounts.vault_lst_account.amount, &rate)?;
+        
+        emit!(VaultStakingChanged {
+            staked_lamports: 0,
+            unstaked_pool_tokens: 0,
+            lst_amount: vault.lst_amount,
+            lst_lamports: vault.lst_lamports,
+        });
+        Ok(())
+    }
+
+    /// Put the vault into emergency exit mode for an incident such as an
+    /// exploited integration or a compromised publisher: entries, lending
+    /// and new deposits stop, open positions are unwound with
+    /// `emergency_unwind`, and once the executor has reported NAV,
+    /// followers can leave through `emergency_withdraw` instead of waiting
+    /// for the epoch.
+    pub fn declare_vault_emergency(ctx: Context<DeclareVaultEmergency>) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        require!(!vault.emergency, OracleError::VaultInEmergency);
+        vault.emergency = true;
+        vault.emergency_at = Clock::get()?.unix_timestamp;
+        
+        emit!(VaultEmergencyDeclared {
+            authority: ctx.accounts.authority.key(),
+            timestamp: vault.emergency_at,
+        });
+        msg!("Vault emergency exit declared");
+        Ok(())
+    }
+
+    /// Sell `amount_in` of a signal's position back to base through a
+    /// Jupiter route, without a scheduled ticket. Only in emergency mode;
+    /// the fill is still held to the risk level's max slippage from the
+    /// verified pool's spot price. Shorts unwind through `execute_short`'s
+    /// exit side.
+    pub fn emergency_unwind<'info>(
+        ctx: Context<'_, '_, 'info, 'info, EmergencyUnwind<'info>>,
+        amount_in: u64,
+        quoted_out: u64,
+        route_data: Vec<u8>,
+    ) -> Result<()> {
+        let signal = &ctx.accounts.signal;
+        require!(ctx.accounts.vault.emergency, OracleError::VaultNotInEmergency);
+        require!(amount_in > 0, OracleError::InvalidAmount);
+        require!(amount_in <= ctx.accounts.position.token_amount, OracleError::InsufficientPosition);
+        
+        let max_slippage_bps = *ctx.accounts.config.max_slippage_bps
+            .get(signal.risk_level as usize)
+            .ok_or(OracleError::InvalidRiskLevel)?;
+        let (price, token_decimals, base_decimals) = vault_pool_price(
+            &ctx.accounts.pool,
+            &ctx.accounts.mint_a,
+            &ctx.accounts.mint_b,
+            &ctx.accounts.vault_a,
+            &ctx.accounts.vault_b,
+            &signal.token,
+            &ctx.accounts.vault.base_mint,
+        )?;
+        let min_out = swap_min_out(
+            ExecutionSide::Exit,
+            amount_in,
+            quoted_out,
+            price,
+            token_decimals,
+            base_decimals,
+            max_slippage_bps,
+        )?;
+        let (spent, received) = swap_as_vault(
+            &ctx.accounts.jupiter_program,
+            ctx.remaining_accounts,
+            route_data,
+            &ctx.accounts.vault,
+            ExecutionSide::Exit,
+            &mut ctx.accounts.vault_base_account,
+            &mut ctx.accounts.vault_token_account,
+        )?;
+        require!(spent <= amount_in, OracleError::SwapInputExceeded);
+        require!(received >= min_out, OracleError::SlippageExceeded);
+        
+        let vault_state = &mut ctx.accounts.vault;
+        let (entered_per_share, realized_pnl_per_share) =
+            ctx.accounts.position.book(vault_state, ExecutionSide::Exit, spent, received);
+        vault_state.total_executions += 1;
+        
+        emit!(SignalExecuted {
+            id: signal.id,
+            side: ExecutionSide::Exit,
+            amount_in: spent,
+            amount_out: received,
+            min_amount_out: min_out,
+        });
+        emit!(ExecutionAttributed {
+            id: signal.id,
+            side: ExecutionSide::Exit,
+            total_shares: vault_state.total_shares,
+            entered_per_share,
+            realized_pnl_per_share,
+        });
+        
+        msg!("Signal #{} unwound: {} in, {} out (min {})", signal.id, spent, received, min_out);
+        Ok(())
+    }
+
+    /// Leave an emergency-mode vault without waiting for the epoch and
+    /// without a fee: flows queued this epoch are handed back, and `shares`
+    /// are redeemed at the NAV reported since the emergency was declared,
+    /// bounded like any report by what the vault measurably holds.
+    /// What liquid base can't cover yet, while positions unwind and lending
+    /// is recalled, stays owed to the follower and is paid out by later
+    /// calls, with `shares` of 0, as base comes in.
+    pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>, shares: u64) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let depositor = &mut ctx.accounts.depositor;
+        require!(vault.emergency, OracleError::VaultNotInEmergency);
+        require!(
+            !depositor.has_queued() || depositor.queued_epoch == vault.epoch,
+            OracleError::UnsettledVaultFlows
+        );
+        depositor.accrue(vault.entered_per_share, vault.realized_pnl_per_share);
+        
+        // Flows queued this epoch come back out first
+        let refund = depositor.queued_deposit;
+        vault.queued_deposits -= refund;
+        vault.nav = vault.nav.saturating_sub(refund);
+        vault.queued_withdrawal_shares -= depositor.queued_withdrawal_shares;
+        depositor.shares += depositor.queued_withdrawal_shares;
+        depositor.queued_deposit = 0;
+        depositor.queued_withdrawal_shares = 0;
+        require!(shares <= depositor.shares, OracleError::InsufficientShares);
+        require!(shares > 0 || refund > 0 || depositor.emergency_owed > 0, OracleError::InvalidAmount);
+        
+        // Shares are valued with what hasn't been unwound yet, and owed until liquid base covers them
+        if shares > 0 {
+            require!(vault.nav_at >= vault.emergency_at, OracleError::StaleEmergencyNav);
+            let equity = vault.nav.saturating_sub(vault.queued_deposits + vault.owed_withdrawals);
+            let value = vault::share_value(shares, vault.total_shares, equity);
+            depositor.shares -= shares;
+            depositor.emergency_owed += value;
+            vault.total_shares -= shares;
+            vault.owed_withdrawals += value;
+        }
+        let reserved = refund + vault.queued_deposits + vault.owed_withdrawals - depositor.emergency_owed;
+        let liquid = ctx.accounts.vault_base_account.amount.saturating_sub(reserved);
+        let payout = depositor.emergency_owed.min(liquid);
+        depositor.emergency_owed -= payout;
+        vault.owed_withdrawals = vault.owed_withdrawals.saturating_sub(payout);
+        vault.nav = vault.nav.saturating_sub(payout);
+        
+        let seeds: &[&[u8]] = &[VAULT_SEED, &[vault.bump]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: ctx.accounts.vault_base_account.to_account_info(),
+                    mint: ctx.accounts.base_mint.to_account_info(),
+                    to: ctx.accounts.owner_base_account.to_account_info(),
+                    authority: vault.to_account_info(),
+                },
+                &[seeds],
+            ),
+            refund + payout,
+            ctx.accounts.base_mint.decimals,
+        )?;
+        
+        emit!(VaultEmergencyWithdrawal {
+            owner: depositor.owner,
+            shares,
+            payout,
+            refund,
+            owed: depositor.emergency_owed,
+        });
+        Ok(())
+    }
+
+    /// Trim a position that has grown past its target weight, or top up
+    /// one that has fallen behind it, through a Jupiter route. The target
+    /// is the signal's suggested size capped by its risk level's allocation
+    /// cap, and the position is valued at the price of the pool its entry
+    /// was verified on, so the executor can't pick a thin pool to move it.
+    /// Its weight has to be off target by more than the configured band,
+    /// and one call trades at most the configured share of the vault and
+    /// never past the target.
+    pub fn rebalance<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Rebalance<'info>>,
+        side: ExecutionSide,
+        amount_in: u64,
+        quoted_out: u64,
+        route_data: Vec<u8>,
+    ) -> Result<()> {
+        let config = &ctx.accounts.config;
+        let signal = &ctx.accounts.signal;
+        let vault_state = &ctx.accounts.vault;
+        require!(amount_in > 0, OracleError::InvalidAmount);
+        
+        let risk = signal.risk_level as usize;
+        let max_allocation_bps = *config.max_allocation_bps.get(risk).ok_or(OracleError::InvalidRiskLevel)?;
+        let max_slippage_bps = *config.max_slippage_bps.get(risk).ok_or(OracleError::InvalidRiskLevel)?;
+        let target_bps = ctx.accounts.sizing.size_bps.min(max_allocation_bps);
+        
+        let (price, token_decimals, base_decimals) = vault_pool_price(
+            &ctx.accounts.pool,
+            &ctx.accounts.mint_a,
+            &ctx.accounts.mint_b,
+            &ctx.accounts.vault_a,
+            &ctx.accounts.vault_b,
+            &signal.token,
+            &vault_state.base_mint,
+        )?;
+        let value = |amount| {
+            vault::token_value(amount, price, token_decimals, base_decimals).ok_or(OracleError::PriceOverflow)
+        };
+        
+        let position_value = u128::from(value(ctx.accounts.position.token_amount)?);
+        let vault_value = u128::from(vault_state.allocation_base(ctx.accounts.vault_base_account.amount)).max(1);
+        let weight_bps = (position_value * 10_000 / vault_value) as u64;
+        let target = vault_value * u128::from(target_bps) / 10_000;
+        let band = vault_value * u128::from(config.rebalance_band_bps) / 10_000;
+        let max_trade = vault_value * u128::from(config.max_rebalance_bps) / 10_000;
+        match side {
+            ExecutionSide::Exit => {
+                require!(position_value > target + band, OracleError::WithinRebalanceBand);
+                require!(amount_in <= ctx.accounts.position.token_amount, OracleError::InsufficientPosition);
+                let trade = u128::from(value(amount_in)?);
+                require!(trade <= (position_value - target).min(max_trade), OracleError::RebalanceTooLarge);
+            }
+            ExecutionSide::Entry => {
+                require!(signal.status == SignalStatus::Open, OracleError::SignalAlreadyClosed);
+                require!(!vault_state.emergency, OracleError::VaultInEmergency);
+                require!(!vault_state.derisked, OracleError::VaultHalted);
+                require!(position_value + band < target, OracleError::WithinRebalanceBand);
+                let trade = u128::from(amount_in);
+                require!(trade <= (target - position_value).min(max_trade), OracleError::RebalanceTooLarge);
+            }
+        }
+        
+        let min_out =
+            swap_min_out(side, amount_in, quoted_out, price, token_decimals, base_decimals, max_slippage_bps)?;
+        let (spent, received) = swap_as_vault(
+            &ctx.accounts.jupiter_program,
+            ctx.remaining_accounts,
+            route_data,
+            &ctx.accounts.vault,
+            side,
+            &mut ctx.accounts.vault_base_account,
+            &mut ctx.accounts.vault_token_account,
+        )?;
+        require!(spent <= amount_in, OracleError::SwapInputExceeded);
+        require!(received >= min_out, OracleError::SlippageExceeded);
+        if side == ExecutionSide::Entry {
+            let reserved = ctx.accounts.vault.queued_deposits + ctx.accounts.vault.owed_withdrawals;
+            require!(ctx.accounts.vault_base_account.amount >= reserved, OracleError::VaultFundsReserved);
+        }
+        
+        let vault_state = &mut ctx.accounts.vault;
+        let (entered_per_share, realized_pnl_per_share) =
+            ctx.accounts.position.book(vault_state, side, spent, received);
+        vault_state.total_executions += 1;
+        
+        emit!(SignalExecuted {
+            id: signal.id,
+            side,
+            amount_in: spent,
+            amount_out: received,
+            min_amount_out: min_out,
+        });
+        emit!(ExecutionAttributed {
+            id: signal.id,
+            side,
+            total_shares: vault_state.total_shares,
+            entered_per_share,
+            realized_pnl_per_share,
+        });
+        emit!(VaultRebalanced {
+            id: signal.id,
+            side,
+            weight_bps,
+            target_bps,
+            amount_in: spent,
+            amount_out: received,
+        });
+        
+        msg!("Signal #{} rebalanced from {}bps toward {}bps", signal.id, weight_bps, target_bps);
+        Ok(())
+    }
+
+    /// Charge the epoch's performance fee, once the epoch is over and NAV
+    /// has been reported since, and before its flows are processed: the
+    /// treasury is paid the configured share of what equity per share
+    /// gained above the high-water mark, and the mark moves to the post-fee
+    /// price. Flows can't shift the fee: they only price after it, and
+    /// none can be queued once the epoch is over. Nor can the executor
+    /// inflate it, as the NAV it is charged on is checked against the
+    /// vault's own valuation. Anyone can call it.
+    pub fn crystallize_vault_fees(ctx: Context<CrystallizeVaultFees>) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        vault.check_closing_nav(Clock::get()?.unix_timestamp)?;
+        require!(vault.next_fee_epoch <= vault.epoch, OracleError::FeesAlreadyCrystallized);
+        
+        let equity = vault.nav.saturating_sub(vault.queued_deposits + vault.owed_withdrawals);
+        let fee_bps = ctx.accounts.config.vault_performance_fee_bps;
+        let (fee, high_water_mark) = vault::performance_fee(equity, vault.total_shares, vault.high_water_mark, fee_bps);
+        vault.high_water_mark = high_water_mark;
+        vault.next_fee_epoch = vault.epoch + 1;
+        
+        if fee > 0 {
+            let reserved = vault.queued_deposits + vault.owed_withdrawals;
+            require!(ctx.accounts.vault_base_account.amount >= reserved + fee, OracleError::VaultFundsReserved);
+            let seeds: &[&[u8]] = &[VAULT_SEED, &[vault.bump]];
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token_interface::TransferChecked {
+                        from: ctx.accounts.vault_base_account.to_account_info(),
+                        mint: ctx.accounts.base_mint.to_account_info(),
+                        to: ctx.accounts.treasury_base_account.to_account_info(),
+                        authority: vault.to_account_info(),
+                    },
+                    &[seeds],
+                ),
+                fee,
+                ctx.accounts.base_mint.decimals,
+            )?;
+            vault.nav -= fee;
+            vault.fees_paid += fee;
+        }
+        
+        emit!(VaultFeesCrystallized {
+            epoch: vault.epoch,
+            equity,
+            fee,
+            high_water_mark,
+        });
+        msg!("Vault epoch {} fee: {}", vault.epoch, fee);
+        Ok(())
+    }
+}
+
+/// Fill in a newly published signal and assign it the next id
+fn init_signal(
+    signal: &mut Signal,
+    oracle_state: &mut OracleState,
+    token: Pubkey,
+    symbol: String,
+    score: u8,
+    risk_level: u8,
+    sources_bitmap: u8,
+    mcap: u64,
+    entry_price: u64,
+    direction: SignalDirection,
+    reasoning_hash: [u8; 32],
+    quote_mint: Pubkey,
+    publisher: Pubkey,
+    max_symbol_len: u8,
+) -> Result<()> {
+    require!(symbol.len() <= max_symbol_len as usize, OracleError::SymbolTooLong);
+    require!(quote_mint != Pubkey::default() && quote_mint != token, OracleError::InvalidQuoteMint);
+    require!(score <= 100, OracleError::InvalidScore);
+    require!((risk_level as usize) < RISK_LEVEL_COUNT, OracleError::InvalidRiskLevel);
+    
+    signal.id = oracle_state.total_signals;
+    signal.token = token;
+    signal.symbol = symbol;
+    signal.score = score;
+    signal.risk_level = risk_level;
+    signal.sources_bitmap = sources_bitmap;
+    signal.mcap_at_signal = mcap;
+    signal.entry_price = entry_price;
+    signal.direction = direction;
+    signal.timestamp = Clock::get()?.unix_timestamp;
+    signal.status = SignalStatus::Open;
+    signal.ath_price = entry_price;
+    signal.exit_price = 0;
+    signal.roi_bps = 0;
+    signal.reasoning_hash = reasoning_hash;
+    signal.reasoning_revealed = false;
+    signal.price_pool = Pubkey::default();
+    signal.entry_verified = false;
+    signal.exit_verified = false;
+    signal.quote_mint = quote_mint;
+    signal.publisher = publisher;
+    
+    oracle_state.total_signals += 1;
+    Ok(())
+}
+
+/// Record the exit price, compute ROI and classify the outcome, counting
+/// it in the publisher's stats
+fn settle_signal(
+    signal: &mut Signal,
+    oracle_state: &mut OracleState,
+    config: &OracleConfig,
+    publisher_stats: &mut PublisherStats,
+    ladder: Option<&TakeProfitLadder>,
+    exit_price: u64,
+) -> Result<()> {
+    require!(signal.status == SignalStatus::Open, OracleError::SignalAlreadyClosed);
+    
+    signal.exit_price = exit_price;
+    oracle_state.unrealized_roi_bps = oracle_state.unrealized_roi_bps.saturating_sub(signal.unrealized_roi_bps);
+    signal.unrealized_roi_bps = 0;
+    
+    // ROI in basis points (1 bps = 0.01%), shorts profit from a falling price
+    if let Some(roi_bps) = oracle_math::roi_bps(signal.entry_price, exit_price, signal.direction.into()) {
+        signal.roi_bps = roi_bps;
+    }
+    let friction = config.friction.into();
+    signal.net_roi_bps = oracle_math::net_roi_bps(signal.entry_price, exit_price, signal.direction.into(), friction)
+        .unwrap_or(signal.roi_bps);
+    // Settled take-profit levels already exited their share at their own
+    // prices; only what they left exits at `exit_price`
+    if let Some(ladder) = ladder.filter(|ladder| ladder.exited_bps > 0) {
+        let remaining_bps = 10_000u16.saturating_sub(ladder.exited_bps);
+        let settled = ladder.levels.iter().filter(|level| level.settled_at != 0);
+        let laddered_net_bps = oracle_math::weighted_roi_bps(settled.map(|level| {
+            let net = oracle_math::net_roi_bps(signal.entry_price, level.price, signal.direction.into(), friction);
+            (level.exit_bps, net.unwrap_or(0))
+        }));
+        signal.roi_bps = ladder
+            .realized_roi_bps
+            .saturating_add(oracle_math::weighted_roi_bps([(remaining_bps, signal.roi_bps)]));
+        signal.net_roi_bps =
+            laddered_net_bps.saturating_add(oracle_math::weighted_roi_bps([(remaining_bps, signal.net_roi_bps)]));
+    }
+    let now = Clock::get()?.unix_timestamp;
+    signal.closed_at = now;
+    signal.roi_annualized_bps = oracle_math::annualized_roi_bps(signal.roi_bps, now - signal.timestamp);
+    
+    // Determine win/loss (win at the risk level's threshold, break-even within the band)
+    let win_threshold_bps = *config.win_threshold_bps
+        .get(signal.risk_level as usize)
+        .ok_or(OracleError::InvalidRiskLevel)?;
+    let outcome = oracle_math::classify(signal.roi_bps, win_threshold_bps, config.break_even_bps);
+    signal.status = match outcome {
+        Outcome::Win => {
+            oracle_state.total_wins += 1;
+            SignalStatus::Win
+        }
+        Outcome::Loss => {
+            oracle_state.total_losses += 1;
+            SignalStatus::Loss
+        }
+        Outcome::Closed => SignalStatus::Closed,
+        Outcome::BreakEven => SignalStatus::BreakEven,
+    };
+    
+    oracle_state.streaks.record(outcome);
+    oracle_state.roi_moments.record(signal.roi_bps);
+    oracle_state.roi_annualized_moments.record(signal.roi_annualized_bps);
+    oracle_state.performance_index = oracle_math::PerformanceIndex(oracle_state.performance_index)
+        .after(signal.roi_bps)
+        .0;
+    let bucket = &mut oracle_state.roi_histogram[oracle_math::roi_bucket(signal.roi_bps)];
+    *bucket = bucket.saturating_add(1);
+    publisher_stats.record(outcome, signal.roi_bps, signal.roi_annualized_bps, now);
+    
+    emit!(SignalClosed {
+        id: signal.id,
+        status: signal.status,
+        roi_bps: signal.roi_bps,
+    });
+    
+    msg!("Signal #{} closed with ROI: {}bps", signal.id, signal.roi_bps);
+    Ok(())
+}
+
+/// The signal's take-profit ladder, if one was set
+fn take_profit_ladder(ladder: &AccountInfo) -> Result<Option<TakeProfitLadder>> {
+    if ladder.data_is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(TakeProfitLadder::try_deserialize(&mut &ladder.try_borrow_data()?[..])?))
+}
+
+/// Verified domain of an optional publisher profile, empty if none
+fn publisher_domain(publisher: &Option<Account<Publisher>>) -> String {
+    publisher.as_ref().map(|p| p.domain.clone()).unwrap_or_default()
+}
+
+/// Reject a price quoted in anything but the signal's quote mint; signals
+/// published before quote mints existed accept any
+fn check_quote(signal: &Signal, quote_mint: &Pubkey) -> Result<()> {
+    require!(
+        signal.quote_mint == Pubkey::default() || signal.quote_mint == *quote_mint,
+        OracleError::QuoteMismatch
+    );
+    Ok(())
+}
+
+/// Reject a claimed price that strays too far from the pool spot price
+fn check_price(signal: &Signal, pool: &Pubkey, claimed: u64, pool_price: u64) -> Result<()> {
+    let deviation_bps = pools::deviation_bps(claimed, pool_price);
+    
+    emit!(PriceChecked {
+        id: signal.id,
+        pool: *pool,
+        claimed_price: claimed,
+        pool_price,
+        deviation_bps,
+    });
+    
+    require!(deviation_bps <= MAX_PRICE_DEVIATION_BPS, OracleError::PriceDeviationTooLarge);
+    Ok(())
+}
+
+/// Highest tier whose score threshold the signal clears
+pub fn alert_tier(score: u8) -> u8 {
+    ALERT_TIER_MIN_SCORE.iter().rposition(|min| score >= *min).unwrap_or(0) as u8
+}
+
+/// Compact alert for notification bridges, separate from the indexing events
+fn emit_alert(signal: &Signal) {
+    emit!(SignalAlert {
+        id: signal.id,
+        token: signal.token,
+        symbol: signal.symbol.clone(),
+        score: signal.score,
+        direction: signal.direction,
+        tier: alert_tier(signal.score),
+    });
+}
+
+/// Data of a signal stored as a compressed leaf. Trailing tail fields that
+/// are still unset are left out, so leaves written before they existed
+/// still match.
+fn leaf_data(signal: &Signal) -> Result<Vec<u8>> {
+    let mut data = signal.try_to_vec()?;
+    let tail = [
+        (signal.publisher == Pubkey::default(), 32),
+        (signal.closed_at == 0, 8),
+        (signal.unrealized_roi_bps == 0, 8),
+        (signal.net_roi_bps == 0, 8),
+        (signal.roi_annualized_bps == 0, 8),
+        (signal.quote_mint == Pubkey::default(), 32),
+    ];
+    for (unset, len) in tail {
+        if !unset {
+            break;
+        }
+        data.truncate(data.len() - len);
+    }
+    Ok(data)
+}
+
+/// Hash of a signal stored as a compressed leaf
+fn compressed_leaf(signal: &Signal) -> Result<[u8; 32]> {
+    Ok(solana_keccak_hasher::hash(&leaf_data(signal)?).to_bytes())
+}
+
+/// Log a compressed signal's data and return its leaf hash
+fn write_compressed_signal(tree: &compression::TreeAccounts, signal: &Signal) -> Result<[u8; 32]> {
+    let data = leaf_data(signal)?;
+    let leaf = solana_keccak_hasher::hash(&data).to_bytes();
+    compression::log_leaf_data(tree.noop_program, data)?;
+    Ok(leaf)
+}
+
+fn compressed_tree<'a, 'info>(accounts: &'a CompressedSignalTree<'info>) -> compression::TreeAccounts<'a, 'info> {
+    compression::TreeAccounts {
+        compression_program: &accounts.compression_program,
+        merkle_tree: &accounts.merkle_tree,
+        authority: &accounts.tree_authority,
+        noop_program: &accounts.noop_program,
+    }
+}
+
+/// Account size of a signal whose symbol is `symbol_len` bytes, including
+/// the reserved tail
+pub fn signal_space(symbol_len: usize) -> usize {
+    8 + Signal::INIT_SPACE + symbol_len.saturating_sub(DEFAULT_MAX_SYMBOL_LEN as usize) + SIGNAL_RESERVED_BYTES
+}
+
+/// Store the suggested position size of a freshly published signal
+fn record_sizing(sizing: &mut SignalSizing, signal: &Account<Signal>, oracle_state: &OracleState, bump: u8) {
+    sizing.signal = signal.key();
+    sizing.size_bps = size_signal(signal, oracle_state);
+    sizing.win_rate_bps = oracle_math::win_rate_bps(oracle_state.total_wins, oracle_state.total_losses) as u16;
+    sizing.bump = bump;
+}
+
+/// Suggested position size of a freshly published signal, from the
+/// oracle's record so far (see `oracle_math::position_size_bps`)
+fn size_signal(signal: &Signal, oracle_state: &OracleState) -> u16 {
+    let size_bps = oracle_math::position_size_bps(
+        oracle_state.total_wins,
+        oracle_state.total_losses,
+        signal.score,
+        signal.risk_level,
+    );
+    emit!(SignalSized {
+        id: signal.id,
+        size_bps: size_bps as u16,
+    });
+    size_bps as u16
+}
+
+/// Signed change from `entry` to `exit` supply in basis points of `entry`
+fn supply_change_bps(entry: u64, exit: u64) -> i64 {
+    if entry == 0 {
+        return 0;
+    }
+    let change = (exit as i128 - entry as i128) * 10_000 / entry as i128;
+    change.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+/// Reject a signal on a token whose last signal closed within the
+/// cooldown, unless `parent` links that closed signal. Returns the linked
+/// parent, if any.
+fn check_republish(
+    history: &TokenHistory,
+    parent: Option<&Account<Signal>>,
+    token: &Pubkey,
+    cooldown_secs: i64,
+) -> Result<Option<Pubkey>> {
+    if let Some(parent) = parent {
+        require!(
+            parent.token == *token && parent.status != SignalStatus::Open,
+            OracleError::InvalidParentSignal
+        );
+    }
+    
+    let since_close = Clock::get()?.unix_timestamp - history.last_closed_at;
+    if cooldown_secs > 0 && history.last_closed_at != 0 && since_close < cooldown_secs {
+        require!(
+            parent.is_some_and(|parent| parent.key() == history.last_signal),
+            OracleError::RepublishCooldown
+        );
+    }
+    Ok(parent.map(|parent| parent.key()))
+}
+
+/// Note a signal's close in its token's history, starting the history if
+/// the token has none yet
+fn record_token_close(history: &mut TokenHistory, signal: &Account<Signal>, bump: u8) -> Result<()> {
+    init_token_history(history, signal.token, bump);
+    history.last_signal = signal.key();
+    history.last_closed_at = Clock::get()?.unix_timestamp;
+    Ok(())
+}
+
+/// Start a publisher's stats on their first settled signal, unless
+/// `init_publisher_stats` already created them
+fn start_publisher_stats(stats: &mut PublisherStats, wallet: Pubkey, bump: u8) {
+    if stats.wallet == Pubkey::default() {
+        stats.wallet = wallet;
+        stats.bump = bump;
+    }
+}
+
+/// Of a pool's two mints, the one that is `token`
+fn token_mint<'a, 'info>(
+    mint_a: &'a InterfaceAccount<'info, Mint>,
+    mint_b: &'a InterfaceAccount<'info, Mint>,
+    token: &Pubkey,
+) -> &'a InterfaceAccount<'info, Mint> {
+    if mint_a.key() == *token {
+        mint_a
+    } else {
+        mint_b
+    }
+}
+
+/// Record the mint supply of a settling signal's token next to the entry
+/// supply, when one was snapshotted, and flag it when supply moved more
+/// than `config.max_supply_change_bps`. `mint` is required once there is
+/// an entry supply.
+fn snapshot_exit_supply(
+    account: &AccountInfo,
+    mint: Option<&InterfaceAccount<Mint>>,
+    signal: &Account<Signal>,
+    config: &OracleConfig,
+) -> Result<()> {
+    if account.data_is_empty() {
+        return Ok(());
+    }
+    let mut snapshot = SupplySnapshot::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    require!(snapshot.exit_timestamp == 0, OracleError::SupplySnapshotTaken);
+    let mint = mint.ok_or(OracleError::SupplyMintMissing)?;
+    
+    snapshot.exit_supply = mint.supply;
+    snapshot.exit_timestamp = Clock::get()?.unix_timestamp;
+    snapshot.supply_change_bps = supply_change_bps(snapshot.entry_supply, snapshot.exit_supply);
+    snapshot.flagged = snapshot.supply_change_bps.unsigned_abs() > u64::from(config.max_supply_change_bps);
+    
+    emit!(SupplyChanged {
+        id: signal.id,
+        entry_supply: snapshot.entry_supply,
+        exit_supply: snapshot.exit_supply,
+        supply_change_bps: snapshot.supply_change_bps,
+        flagged: snapshot.flagged,
+    });
+    
+    msg!("Signal #{} supply changed {} bps", signal.id, snapshot.supply_change_bps);
+    snapshot.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])
+}
+
+/// Start a token's history on its first signal
+fn init_token_history(history: &mut TokenHistory, token: Pubkey, bump: u8) {
+    if history.token == Pubkey::default() {
+        history.token = token;
+        history.bump = bump;
+    }
+}
+
+/// Read `stake_pool`'s exchange rate, checking `lst_account` holds its LST
+/// and the pool has been updated this epoch
+fn read_stake_pool(stake_pool: &AccountInfo, lst_account: &TokenAccount) -> Result<vault::StakePoolRate> {
+    let rate = vault::stake_pool_rate(&stake_pool.try_borrow_data()?).ok_or(OracleError::InvalidStakePool)?;
+    require_keys_eq!(rate.pool_mint, lst_account.mint, OracleError::InvalidStakePool);
+    require!(rate.last_update_epoch == Clock::get()?.epoch, OracleError::StakePoolNotUpdated);
+    Ok(rate)
+}
+
+/// A subscriber's pass account and the Token-2022 program, which an
+/// instruction needs once the pass mint exists; `None` until then
+fn pass_accounts<'a, 'info>(
+    pass_mint: &AccountInfo<'info>,
+    pass: &'a Option<InterfaceAccount<'info, TokenAccount>>,
+    token_program: &'a Option<Program<'info, Token2022>>,
+) -> Result<Option<(&'a InterfaceAccount<'info, TokenAccount>, &'a Program<'info, Token2022>)>> {
+    if pass_mint.data_is_empty() {
+        return Ok(None);
+    }
+    let (Some(pass), Some(token_program)) = (pass, token_program) else {
+        return err!(OracleError::PassAccountRequired);
+    };
+    require_keys_eq!(pass.mint, pass_mint.key(), OracleError::PassAccountRequired);
+    Ok(Some((pass, token_program)))
+}
+
+/// Burn every pass in `from` through the mint's permanent delegate, the
+/// mint itself
+fn burn_pass<'info>(
+    token_program: &AccountInfo<'info>,
+    pass_mint: &AccountInfo<'info>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    bump: u8,
+) -> Result<()> {
+    let seeds: &[&[u8]] = &[PASS_MINT_SEED, &[bump]];
+    token_2022::burn(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            token_2022::Burn {
+                mint: pass_mint.clone(),
+                from: from.to_account_info(),
+                authority: pass_mint.clone(),
+            },
+            &[seeds],
+        ),
+        from.amount,
+    )
+}
+
+/// Mint one subscriber pass to `to`; the pass mint is its own authority
+fn mint_pass<'info>(
+    token_program: &AccountInfo<'info>,
+    pass_mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    bump: u8,
+) -> Result<()> {
+    let seeds: &[&[u8]] = &[PASS_MINT_SEED, &[bump]];
+    token_2022::mint_to(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            token_2022::MintTo {
+                mint: pass_mint.clone(),
+                to: to.clone(),
+                authority: pass_mint.clone(),
+            },
+            &[seeds],
+        ),
+        1,
+    )
+}
+
+/// Spot price of `token` in the vault's `base_mint` on a signal's pool,
+/// with the token's and the base's decimals
+fn vault_pool_price<'info>(
+    pool: &AccountInfo,
+    mint_a: &InterfaceAccount<Mint>,
+    mint_b: &InterfaceAccount<Mint>,
+    vault_a: &AccountInfo<'info>,
+    vault_b: &AccountInfo<'info>,
+    token: &Pubkey,
+    base_mint: &Pubkey,
+) -> Result<(u64, u8, u8)> {
+    let pool_price = pools::pool_price(pool, mint_a, mint_b, vault_a, vault_b, token)?;
+    require_keys_eq!(pool_price.quote_mint, *base_mint, OracleError::PoolMintMismatch);
+    let (token_decimals, base_decimals) = if mint_a.key() == *token {
+        (mint_a.decimals, mint_b.decimals)
+    } else {
+        (mint_b.decimals, mint_a.decimals)
+    };
+    Ok((pool_price.price, token_decimals, base_decimals))
+}
+
+/// Least a swap of `amount_in` on `side` may return: the pool's spot
+/// output at `price`, or `quoted_out` if higher, less `max_slippage_bps`.
+/// Anchoring on the pool means a low quote can't waive the check.
+fn swap_min_out(
+    side: ExecutionSide,
+    amount_in: u64,
+    quoted_out: u64,
+    price: u64,
+    token_decimals: u8,
+    base_decimals: u8,
+    max_slippage_bps: u16,
+) -> Result<u64> {
+    let spot =
+        vault::spot_out(side, amount_in, price, token_decimals, base_decimals).ok_or(OracleError::PriceOverflow)?;
+    Ok(vault::min_amount_out(quoted_out.max(spot), max_slippage_bps))
+}
+
+/// Swap the vault's base for `token` (entries) or back (exits) through a
+/// Jupiter route, measuring what was spent and received on the vault's
+/// own token accounts
+fn swap_as_vault<'info>(
+    jupiter_program: &AccountInfo<'info>,
+    route_accounts: &[AccountInfo<'info>],
+    route_data: Vec<u8>,
+    vault: &Account<'info, Vault>,
+    side: ExecutionSide,
+    base: &mut InterfaceAccount<'info, TokenAccount>,
+    token: &mut InterfaceAccount<'info, TokenAccount>,
+) -> Result<(u64, u64)> {
+    let (input, output) = match side {
+        ExecutionSide::Entry => (base, token),
+        ExecutionSide::Exit => (token, base),
+    };
+    let input_before = input.amount;
+    let output_before = output.amount;
+    
+    let seeds: &[&[u8]] = &[VAULT_SEED, &[vault.bump]];
+    vault::jupiter_swap(jupiter_program, route_accounts, route_data, &vault.key(), seeds)?;
+    
+    input.reload()?;
+    output.reload()?;
+    Ok((input_before.saturating_sub(input.amount), output.amount.saturating_sub(output_before)))
+}
+
+// === ACCOUNTS ===
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + OracleState::INIT_SPACE,
+        seeds = [ORACLE_STATE_SEED],
+        bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(token: Pubkey, symbol: String)]
+pub struct PublishSignal<'info> {
+    #[account(
+        mut,
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = signal_space(symbol.len()),
+        seeds = [SIGNAL_SEED, oracle_state.total_signals.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SignalSizing::INIT_SPACE,
+        seeds = [SIGNAL_SIZING_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub sizing: Account<'info, SignalSizing>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + TokenHistory::INIT_SPACE,
+        seeds = [TOKEN_HISTORY_SEED, token.as_ref()],
+        bump
+    )]
+    pub token_history: Account<'info, TokenHistory>,
+    
+    /// Closed signal on the same token this one follows up on; lets it
+    /// through the republish cooldown when it is the token's last signal
+    pub parent_signal: Option<Account<'info, Signal>>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// Publisher profile, when present its verified domain is included in events
+    #[account(
+        seeds = [PUBLISHER_SEED, authority.key().as_ref()],
+        bump = publisher.bump
+    )]
+    pub publisher: Option<Account<'info, Publisher>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateSignal<'info> {
+    #[account(
+        mut,
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(mut)]
+    pub signal: Account<'info, Signal>,
+    
+    /// CHECK: the signal's take-profit ladder, empty unless one was set
+    #[account(
+        seeds = [TAKE_PROFIT_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub ladder: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyPoolPrice<'info> {
+    #[account(
+        mut,
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(mut)]
+    pub signal: Account<'info, Signal>,
+    
+    /// CHECK: the signal's take-profit ladder, empty unless one was set
+    #[account(
+        seeds = [TAKE_PROFIT_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub ladder: UncheckedAccount<'info>,
+    
+    /// CHECK: owner and layout are validated by the pool reader
+    pub pool: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = mint_a.key() == signal.token || mint_b.key() == signal.token @ OracleError::PoolMintMismatch
+    )]
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    
+    pub mint_b: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: checked against the pool's mint A reserve by the pool reader
+    pub vault_a: UncheckedAccount<'info>,
+    
+    /// CHECK: checked against the pool's mint B reserve by the pool reader
+    pub vault_b: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseSignal<'info> {
+    #[account(
+        mut,
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(mut)]
+    pub signal: Account<'info, Signal>,
+    
+    /// CHECK: the signal's take-profit ladder, empty unless one was set
+    #[account(
+        seeds = [TAKE_PROFIT_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub ladder: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// The signal's publisher's stats, updated when it settles
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PublisherStats::INIT_SPACE,
+        seeds = [PUBLISHER_STATS_SEED, signal.publisher.as_ref()],
+        bump
+    )]
+    pub publisher_stats: Account<'info, PublisherStats>,
+    
+    /// The signal's token history, recording the close for the republish cooldown
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + TokenHistory::INIT_SPACE,
+        seeds = [TOKEN_HISTORY_SEED, signal.token.as_ref()],
+        bump
+    )]
+    pub token_history: Account<'info, TokenHistory>,
+    
+    /// CHECK: the signal's supply snapshot, empty unless its entry supply
+    /// was recorded; the exit supply is taken as the signal settles
+    #[account(
+        mut,
+        seeds = [SUPPLY_SNAPSHOT_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub supply_snapshot: UncheckedAccount<'info>,
+    
+    /// The signal's token mint, read for the exit supply snapshot; required
+    /// once the entry supply was snapshotted
+    #[account(address = signal.token)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseSignalVerified<'info> {
+    #[account(
+        mut,
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(mut)]
+    pub signal: Account<'info, Signal>,
+    
+    /// CHECK: the signal's take-profit ladder, empty unless one was set
+    #[account(
+        seeds = [TAKE_PROFIT_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub ladder: UncheckedAccount<'info>,
+    
+    /// CHECK: owner and layout are validated by the pool reader
+    pub pool: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = mint_a.key() == signal.token || mint_b.key() == signal.token @ OracleError::PoolMintMismatch
+    )]
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    
+    pub mint_b: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: checked against the pool's mint A reserve by the pool reader
+    pub vault_a: UncheckedAccount<'info>,
+    
+    /// CHECK: checked against the pool's mint B reserve by the pool reader
+    pub vault_b: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// The signal's publisher's stats, updated when it settles
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PublisherStats::INIT_SPACE,
+        seeds = [PUBLISHER_STATS_SEED, signal.publisher.as_ref()],
+        bump
+    )]
+    pub publisher_stats: Account<'info, PublisherStats>,
+    
+    /// The signal's token history, recording the close for the republish cooldown
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + TokenHistory::INIT_SPACE,
+        seeds = [TOKEN_HISTORY_SEED, signal.token.as_ref()],
+        bump
+    )]
+    pub token_history: Account<'info, TokenHistory>,
+    
+    /// CHECK: the signal's supply snapshot, empty unless its entry supply
+    /// was recorded; the exit supply is taken as the signal settles
+    #[account(
+        mut,
+        seeds = [SUPPLY_SNAPSHOT_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub supply_snapshot: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + OracleConfig::INIT_SPACE,
+        seeds = [ORACLE_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        mut,
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeVault<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Vault::INIT_SPACE,
+        seeds = [VAULT_SEED],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    /// Quote asset the vault holds between signals (e.g. USDC)
+    pub base_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteSignal<'info> {
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        mut,
+        seeds = [TICKET_SEED, signal.key().as_ref()],
+        bump = ticket.bump
+    )]
+    pub ticket: Account<'info, ExecutionTicket>,
+    
+    /// The signal's DCA plan, if it has one; entries then fill its next due tranche
+    #[account(
+        mut,
+        seeds = [DCA_PLAN_SEED, signal.key().as_ref()],
+        bump = dca_plan.bump
+    )]
+    pub dca_plan: Option<Account<'info, DcaPlan>>,
+    
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + VaultPosition::INIT_SPACE,
+        seeds = [POSITION_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub position: Account<'info, VaultPosition>,
+    
+    #[account(
+        mut,
+        token::mint = vault.base_mint,
+        token::authority = vault
+    )]
+    pub vault_base_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = signal.token,
+        token::authority = vault
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: must be the pool the signal's entry was verified against;
+    /// owner and layout are validated by the pool reader
+    #[account(
+        constraint = signal.price_pool != Pubkey::default() && pool.key() == signal.price_pool
+            @ OracleError::PoolNotVerified
+    )]
+    pub pool: UncheckedAccount<'info>,
+    
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    
+    pub mint_b: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: checked against the pool's mint A reserve by the pool reader
+    pub vault_a: UncheckedAccount<'info>,
+    
+    /// CHECK: checked against the pool's mint B reserve by the pool reader
+    pub vault_b: UncheckedAccount<'info>,
+    
+    /// CHECK: address is pinned to the Jupiter aggregator
+    #[account(address = vault::JUPITER_PROGRAM_ID)]
+    pub jupiter_program: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = executor.key() == vault.executor @ OracleError::Unauthorized
+    )]
+    pub executor: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteShort<'info> {
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + VaultPosition::INIT_SPACE,
+        seeds = [POSITION_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub position: Account<'info, VaultPosition>,
+    
+    #[account(address = vault.base_mint)]
+    pub base_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        token::mint = vault.base_mint,
+        token::authority = vault
+    )]
+    pub vault_base_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// The Drift market to short on, and the recommended leverage, which
+    /// tightens the risk level's cap when set
+    #[account(
+        seeds = [PERP_PARAMS_SEED, signal.key().as_ref()],
+        bump = perp_params.bump
+    )]
+    pub perp_params: Account<'info, PerpParams>,
+    
+    /// CHECK: must be the pool the signal's entry was verified against;
+    /// owner and layout are validated by the pool reader
+    #[account(
+        constraint = signal.price_pool != Pubkey::default() && pool.key() == signal.price_pool
+            @ OracleError::PoolNotVerified
+    )]
+    pub pool: UncheckedAccount<'info>,
+    
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    
+    pub mint_b: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: checked against the pool's mint A reserve by the pool reader
+    pub vault_a: UncheckedAccount<'info>,
+    
+    /// CHECK: checked against the pool's mint B reserve by the pool reader
+    pub vault_b: UncheckedAccount<'info>,
+    
+    /// CHECK: address is pinned to the Drift program
+    #[account(address = vault::DRIFT_PROGRAM_ID)]
+    pub drift_program: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = executor.key() == vault.executor @ OracleError::Unauthorized
+    )]
+    pub executor: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManageLending<'info> {
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    #[account(
+        mut,
+        token::mint = vault.base_mint,
+        token::authority = vault
+    )]
+    pub vault_base_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: address is pinned to the marginfi program
+    #[account(address = vault::MARGINFI_PROGRAM_ID)]
+    pub marginfi_program: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = executor.key() == vault.executor @ OracleError::Unauthorized
+    )]
+    pub executor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyAccessPass<'info> {
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    /// CHECK: must be the oracle's pass tree; contents are checked by the compression program
+    #[account(
+        constraint = merkle_tree.key() == config.access_pass_tree @ OracleError::InvalidPassTree
+    )]
+    pub merkle_tree: UncheckedAccount<'info>,
+    
+    pub owner: Signer<'info>,
+    
+    /// CHECK: only hashed into the leaf; usually the owner
+    pub delegate: UncheckedAccount<'info>,
+    
+    /// CHECK: address is pinned to the account compression program
+    #[account(address = compression::COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(tier: u8)]
+pub struct Subscribe<'info> {
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    /// CHECK: the tier's allowlist address; empty unless the tier is in
+    /// allowlist mode
+    #[account(
+        seeds = [TIER_ALLOWLIST_SEED, &[tier]],
+        bump
+    )]
+    pub tier_allowlist: UncheckedAccount<'info>,
+    
+    /// Subscriber's approval, needed for allowlisted tiers
+    #[account(
+        seeds = [ALLOWLIST_ENTRY_SEED, &[tier], subscriber.key().as_ref()],
+        bump = allowlist_entry.bump
+    )]
+    pub allowlist_entry: Option<Account<'info, AllowlistEntry>>,
+    
+    #[account(
+        init_if_needed,
+        payer = subscriber,
+        space = 8 + Subscription::INIT_SPACE,
+        seeds = [SUBSCRIPTION_SEED, subscriber.key().as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+    
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    
+    /// CHECK: the pass mint's address; empty until `initialize_pass_mint`
+    #[account(
+        mut,
+        seeds = [PASS_MINT_SEED],
+        bump
+    )]
+    pub pass_mint: UncheckedAccount<'info>,
+    
+    /// Subscriber's pass account, needed once the pass mint exists
+    #[account(
+        mut,
+        token::authority = subscriber,
+        token::token_program = token_program
+    )]
+    pub subscriber_pass: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token2022>>,
+    
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializePassMint<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    /// CHECK: created and initialized in the handler (extensions need manual setup)
+    #[account(
+        mut,
+        seeds = [PASS_MINT_SEED],
+        bump
+    )]
+    pub pass_mint: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MintSubscriberPass<'info> {
+    #[account(
+        seeds = [SUBSCRIPTION_SEED, subscriber.key().as_ref()],
+        bump = subscription.bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+    
+    #[account(
+        mut,
+        seeds = [PASS_MINT_SEED],
+        bump
+    )]
+    pub pass_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        token::mint = pass_mint,
+        token::authority = subscriber,
+        token::token_program = token_program
+    )]
+    pub subscriber_pass: InterfaceAccount<'info, TokenAccount>,
+    
+    pub subscriber: Signer<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct BurnExpiredPass<'info> {
+    #[account(
+        seeds = [SUBSCRIPTION_SEED, subscription.subscriber.as_ref()],
+        bump = subscription.bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+    
+    #[account(
+        mut,
+        seeds = [PASS_MINT_SEED],
+        bump
+    )]
+    pub pass_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        token::mint = pass_mint,
+        token::authority = subscription.subscriber,
+        token::token_program = token_program
+    )]
+    pub subscriber_pass: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterPublisher<'info> {
+    #[account(
+        init,
+        payer = wallet,
+        space = 8 + Publisher::INIT_SPACE,
+        seeds = [PUBLISHER_SEED, wallet.key().as_ref()],
+        bump
+    )]
+    pub publisher: Account<'info, Publisher>,
+    
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct LinkDomain<'info> {
+    #[account(
+        mut,
+        seeds = [PUBLISHER_SEED, wallet.key().as_ref()],
+        bump = publisher.bump
+    )]
+    pub publisher: Account<'info, Publisher>,
+    
+    /// CHECK: verified against the SNS derivation and owner in the handler
+    pub name_account: UncheckedAccount<'info>,
+    
+    pub wallet: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ManageThread<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    
+    /// CHECK: derivation/owner checked in the handlers and by the thread program
+    #[account(mut)]
+    pub thread: UncheckedAccount<'info>,
+    
+    /// CHECK: address is pinned to the thread program
+    #[account(address = automation::THREAD_PROGRAM_ID)]
+    pub thread_program: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ScheduleExecution<'info> {
+    #[account(
+        seeds = [VAULT_SEED],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ExecutionTicket::INIT_SPACE,
+        seeds = [TICKET_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub ticket: Account<'info, ExecutionTicket>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == vault.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitSignalTree<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        mut,
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    /// CHECK: pre-allocated tree, initialized by the compression program
+    #[account(
+        mut,
+        owner = compression::COMPRESSION_PROGRAM_ID
+    )]
+    pub merkle_tree: UncheckedAccount<'info>,
+    
+    /// CHECK: PDA that owns the signal tree
+    #[account(
+        seeds = [TREE_AUTHORITY_SEED],
+        bump
+    )]
+    pub tree_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: address is pinned to the compression program
+    #[account(address = compression::COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    
+    /// CHECK: address is pinned to the noop program
+    #[account(address = compression::NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CompressedSignalTree<'info> {
+    #[account(
+        mut,
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    /// CHECK: must be the oracle's signal tree; contents are managed by the compression program
+    #[account(
+        mut,
+        address = config.signal_tree @ OracleError::InvalidSignalTree
+    )]
+    pub merkle_tree: UncheckedAccount<'info>,
+    
+    /// CHECK: PDA that owns the signal tree
+    #[account(
+        seeds = [TREE_AUTHORITY_SEED],
+        bump
+    )]
+    pub tree_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: address is pinned to the compression program
+    #[account(address = compression::COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    
+    /// CHECK: address is pinned to the noop program
+    #[account(address = compression::NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(signal: Signal)]
+pub struct CloseCompressedSignal<'info> {
+    #[account(
+        mut,
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    /// CHECK: must be the oracle's signal tree; contents are managed by the compression program
+    #[account(
+        mut,
+        address = config.signal_tree @ OracleError::InvalidSignalTree
+    )]
+    pub merkle_tree: UncheckedAccount<'info>,
+    
+    /// CHECK: PDA that owns the signal tree
+    #[account(
+        seeds = [TREE_AUTHORITY_SEED],
+        bump
+    )]
+    pub tree_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: address is pinned to the compression program
+    #[account(address = compression::COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    
+    /// CHECK: address is pinned to the noop program
+    #[account(address = compression::NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// The signal's publisher's stats, updated when it settles
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PublisherStats::INIT_SPACE,
+        seeds = [PUBLISHER_STATS_SEED, signal.publisher.as_ref()],
+        bump
+    )]
+    pub publisher_stats: Account<'info, PublisherStats>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MintSignalAsset<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    pub signal: Account<'info, Signal>,
+    
+    /// CHECK: created by the Core program; one asset per signal
+    #[account(
+        mut,
+        seeds = [SIGNAL_ASSET_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub asset: UncheckedAccount<'info>,
+    
+    /// CHECK: address is pinned to the Core program
+    #[account(address = collectibles::CORE_PROGRAM_ID)]
+    pub core_program: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateSignal<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    /// CHECK: legacy layout that `Account<Signal>` can't decode; owner is
+    /// checked here, discriminator and address in the handler
+    #[account(mut, owner = crate::ID)]
+    pub signal: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitPublisherStats<'info> {
+    #[account(
+        seeds = [PUBLISHER_SEED, wallet.key().as_ref()],
+        bump = publisher.bump
+    )]
+    pub publisher: Account<'info, Publisher>,
+    
+    #[account(
+        init,
+        payer = wallet,
+        space = 8 + PublisherStats::INIT_SPACE,
+        seeds = [PUBLISHER_STATS_SEED, wallet.key().as_ref()],
+        bump
+    )]
+    pub publisher_stats: Account<'info, PublisherStats>,
+    
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ViewSignal<'info> {
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    pub signal: Account<'info, Signal>,
+}
+
+#[derive(Accounts)]
+#[instruction(symbol: String)]
+pub struct ReallocSignal<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(
+        mut,
+        realloc = signal_space(symbol.len()).max(signal.to_account_info().data_len()),
+        realloc::payer = authority,
+        realloc::zero = false
+    )]
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitSignalNotes<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = SignalNotes::BASE_SPACE,
+        seeds = [SIGNAL_NOTES_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub signal_notes: Account<'info, SignalNotes>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(text: String)]
+pub struct AppendSignalNote<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        mut,
+        seeds = [SIGNAL_NOTES_SEED, signal.key().as_ref()],
+        bump = signal_notes.bump,
+        realloc = signal_notes.to_account_info().data_len() + SignalNote::space(&text),
+        realloc::payer = authority,
+        realloc::zero = false
+    )]
+    pub signal_notes: Account<'info, SignalNotes>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RateSignal<'info> {
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        seeds = [SUBSCRIPTION_SEED, subscriber.key().as_ref()],
+        bump = subscription.bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+    
+    #[account(
+        init_if_needed,
+        payer = subscriber,
+        space = 8 + SignalRating::INIT_SPACE,
+        seeds = [SIGNAL_RATING_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub signal_rating: Account<'info, SignalRating>,
+    
+    #[account(
+        init,
+        payer = subscriber,
+        space = 8 + RatingReceipt::INIT_SPACE,
+        seeds = [RATING_RECEIPT_SEED, signal.key().as_ref(), subscriber.key().as_ref()],
+        bump
+    )]
+    pub receipt: Account<'info, RatingReceipt>,
+    
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BoostSignal<'info> {
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        init_if_needed,
+        payer = booster,
+        space = 8 + SignalBoost::INIT_SPACE,
+        seeds = [SIGNAL_BOOST_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub signal_boost: Account<'info, SignalBoost>,
+    
+    #[account(
+        init_if_needed,
+        payer = booster,
+        space = 8 + Discovery::INIT_SPACE,
+        seeds = [DISCOVERY_SEED],
+        bump
+    )]
+    pub discovery: Account<'info, Discovery>,
+    
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub booster: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct BidFeatured<'info> {
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + FeaturedAuction::INIT_SPACE,
+        seeds = [AUCTION_SEED, epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub auction: Account<'info, FeaturedAuction>,
+    
+    /// CHECK: bidder of the replaced bid, checked in the handler; omit when
+    /// nothing is displaced or the signer outbids themselves
+    #[account(mut)]
+    pub outbid: Option<UncheckedAccount<'info>>,
+    
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct SettleAuction<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, epoch.to_le_bytes().as_ref()],
+        bump = auction.bump
+    )]
+    pub auction: Account<'info, FeaturedAuction>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + Discovery::INIT_SPACE,
+        seeds = [DISCOVERY_SEED],
+        bump
+    )]
+    pub discovery: Account<'info, Discovery>,
+    
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PublishBasket<'info> {
+    #[account(
+        mut,
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Basket::INIT_SPACE,
+        seeds = [BASKET_SEED, oracle_state.total_baskets.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub basket: Account<'info, Basket>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseBasket<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(
+        mut,
+        seeds = [BASKET_SEED, basket.id.to_le_bytes().as_ref()],
+        bump = basket.bump
+    )]
+    pub basket: Account<'info, Basket>,
+    
+    #[account(
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PublishPair<'info> {
+    #[account(
+        mut,
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PairSignal::INIT_SPACE,
+        seeds = [PAIR_SIGNAL_SEED, oracle_state.total_pairs.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pair: Account<'info, PairSignal>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClosePair<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(
+        mut,
+        seeds = [PAIR_SIGNAL_SEED, pair.id.to_le_bytes().as_ref()],
+        bump = pair.bump
+    )]
+    pub pair: Account<'info, PairSignal>,
+    
+    #[account(
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetPerpParams<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PerpParams::INIT_SPACE,
+        seeds = [PERP_PARAMS_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub perp_params: Account<'info, PerpParams>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyMarketCap<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SignalMarketCap::INIT_SPACE,
+        seeds = [SIGNAL_MARKET_CAP_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub market_cap: Account<'info, SignalMarketCap>,
+    
+    /// CHECK: must be the pool the signal's entry was verified against;
+    /// owner and layout are validated by the pool reader
+    #[account(
+        constraint = signal.price_pool != Pubkey::default() && pool.key() == signal.price_pool
+            @ OracleError::PoolNotVerified
+    )]
+    pub pool: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = mint_a.key() == signal.token || mint_b.key() == signal.token @ OracleError::PoolMintMismatch
+    )]
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    
+    pub mint_b: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: checked against the pool's mint A reserve by the pool reader
+    pub vault_a: UncheckedAccount<'info>,
+    
+    /// CHECK: checked against the pool's mint B reserve by the pool reader
+    pub vault_b: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SnapshotEntrySupply<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    pub signal: Account<'info, Signal>,
+    
+    #[account(address = signal.token)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SupplySnapshot::INIT_SPACE,
+        seeds = [SUPPLY_SNAPSHOT_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub snapshot: Account<'info, SupplySnapshot>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CommitHolderDistribution<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + HolderCommitment::INIT_SPACE,
+        seeds = [HOLDER_COMMITMENT_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub commitment: Account<'info, HolderCommitment>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(symbol: String)]
+pub struct ReopenSignal<'info> {
+    #[account(
+        mut,
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = signal_space(symbol.len()),
+        seeds = [SIGNAL_SEED, oracle_state.total_signals.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SignalSizing::INIT_SPACE,
+        seeds = [SIGNAL_SIZING_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub sizing: Account<'info, SignalSizing>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + TokenHistory::INIT_SPACE,
+        seeds = [TOKEN_HISTORY_SEED, parent_signal.token.as_ref()],
+        bump
+    )]
+    pub token_history: Account<'info, TokenHistory>,
+    
+    /// The token's last closed signal, which this one re-enters
+    pub parent_signal: Account<'info, Signal>,
+    
+    /// CHECK: the parent's lineage, empty when the parent was a first attempt
+    #[account(
+        seeds = [SIGNAL_LINEAGE_SEED, parent_signal.key().as_ref()],
+        bump
+    )]
+    pub parent_lineage: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SignalLineage::INIT_SPACE,
+        seeds = [SIGNAL_LINEAGE_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub lineage: Account<'info, SignalLineage>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    /// Publisher profile, when present its verified domain is included in events
+    #[account(
+        seeds = [PUBLISHER_SEED, authority.key().as_ref()],
+        bump = publisher.bump
+    )]
+    pub publisher: Option<Account<'info, Publisher>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddEntry<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(mut)]
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + SignalEntries::INIT_SPACE,
+        seeds = [SIGNAL_ENTRIES_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub entries: Account<'info, SignalEntries>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetDcaPlan<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + DcaPlan::INIT_SPACE,
+        seeds = [DCA_PLAN_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub dca_plan: Account<'info, DcaPlan>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetTakeProfits<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + TakeProfitLadder::INIT_SPACE,
+        seeds = [TAKE_PROFIT_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub ladder: Account<'info, TakeProfitLadder>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SettleTakeProfit<'info> {
+    #[account(
+        mut,
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(mut)]
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        mut,
+        seeds = [TAKE_PROFIT_SEED, signal.key().as_ref()],
+        bump = ladder.bump
+    )]
+    pub ladder: Account<'info, TakeProfitLadder>,
+    
+    /// CHECK: must be the pool the signal's entry was verified against;
+    /// owner and layout are validated by the pool reader
+    #[account(
+        constraint = signal.price_pool != Pubkey::default() && pool.key() == signal.price_pool
+            @ OracleError::PoolNotVerified
+    )]
+    pub pool: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = mint_a.key() == signal.token || mint_b.key() == signal.token @ OracleError::PoolMintMismatch
+    )]
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    
+    pub mint_b: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: checked against the pool's mint A reserve by the pool reader
+    pub vault_a: UncheckedAccount<'info>,
+    
+    /// CHECK: checked against the pool's mint B reserve by the pool reader
+    pub vault_b: UncheckedAccount<'info>,
+    
+    /// The signal's publisher's stats, updated if the level closes it
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + PublisherStats::INIT_SPACE,
+        seeds = [PUBLISHER_STATS_SEED, signal.publisher.as_ref()],
+        bump
+    )]
+    pub publisher_stats: Account<'info, PublisherStats>,
+    
+    /// The signal's token history, recording the close for the republish cooldown
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + TokenHistory::INIT_SPACE,
+        seeds = [TOKEN_HISTORY_SEED, signal.token.as_ref()],
+        bump
+    )]
+    pub token_history: Account<'info, TokenHistory>,
+    
+    /// CHECK: the signal's supply snapshot, empty unless its entry supply
+    /// was recorded; the exit supply is taken as the signal settles
+    #[account(
+        mut,
+        seeds = [SUPPLY_SNAPSHOT_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub supply_snapshot: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetInvalidationPrice<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Invalidation::INIT_SPACE,
+        seeds = [INVALIDATION_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub invalidation: Account<'info, Invalidation>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InvalidateSignal<'info> {
+    #[account(
+        mut,
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(mut)]
+    pub signal: Account<'info, Signal>,
+    
+    /// CHECK: the signal's take-profit ladder, empty unless one was set
+    #[account(
+        seeds = [TAKE_PROFIT_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub ladder: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [INVALIDATION_SEED, signal.key().as_ref()],
+        bump = invalidation.bump
+    )]
+    pub invalidation: Account<'info, Invalidation>,
+    
+    /// CHECK: must be the pool the signal's entry was verified against;
+    /// owner and layout are validated by the pool reader
+    #[account(
+        constraint = signal.price_pool != Pubkey::default() && pool.key() == signal.price_pool
+            @ OracleError::PoolNotVerified
+    )]
+    pub pool: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = mint_a.key() == signal.token || mint_b.key() == signal.token @ OracleError::PoolMintMismatch
+    )]
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    
+    pub mint_b: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: checked against the pool's mint A reserve by the pool reader
+    pub vault_a: UncheckedAccount<'info>,
+    
+    /// CHECK: checked against the pool's mint B reserve by the pool reader
+    pub vault_b: UncheckedAccount<'info>,
+    
+    /// The signal's publisher's stats, updated with the loss
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + PublisherStats::INIT_SPACE,
+        seeds = [PUBLISHER_STATS_SEED, signal.publisher.as_ref()],
+        bump
+    )]
+    pub publisher_stats: Account<'info, PublisherStats>,
+    
+    /// The signal's token history, recording the close for the republish cooldown
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + TokenHistory::INIT_SPACE,
+        seeds = [TOKEN_HISTORY_SEED, signal.token.as_ref()],
+        bump
+    )]
+    pub token_history: Account<'info, TokenHistory>,
+    
+    /// CHECK: the signal's supply snapshot, empty unless its entry supply
+    /// was recorded; the exit supply is taken as the signal settles
+    #[account(
+        mut,
+        seeds = [SUPPLY_SNAPSHOT_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub supply_snapshot: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MarkSignals<'info> {
+    #[account(
+        mut,
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+}
+
+#[derive(Accounts)]
+pub struct InitModelPortfolio<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ModelPortfolio::INIT_SPACE,
+        seeds = [MODEL_PORTFOLIO_SEED],
+        bump
+    )]
+    pub portfolio: Account<'info, ModelPortfolio>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PortfolioEnter<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        mut,
+        seeds = [MODEL_PORTFOLIO_SEED],
+        bump = portfolio.bump
+    )]
+    pub portfolio: Account<'info, ModelPortfolio>,
+    
+    /// CHECK: the address of signal `portfolio.next_id`; empty for
+    /// compressed signals, otherwise decoded in the handler
+    #[account(
+        seeds = [SIGNAL_SEED, portfolio.next_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub signal: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PortfolioExit<'info> {
+    #[account(
+        mut,
+        seeds = [MODEL_PORTFOLIO_SEED],
+        bump = portfolio.bump
+    )]
+    pub portfolio: Account<'info, ModelPortfolio>,
+    
+    pub signal: Account<'info, Signal>,
+}
+
+#[derive(Accounts)]
+pub struct RecordBenchmark<'info> {
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + SignalBenchmark::INIT_SPACE,
+        seeds = [SIGNAL_BENCHMARK_SEED, signal.key().as_ref()],
+        bump
+    )]
+    pub benchmark: Account<'info, SignalBenchmark>,
+    
+    /// CHECK: must be the configured benchmark pool; owner and layout are
+    /// validated by the pool reader
+    pub pool: UncheckedAccount<'info>,
+    
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    
+    pub mint_b: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: checked against the pool's mint A reserve by the pool reader
+    pub vault_a: UncheckedAccount<'info>,
+    
+    /// CHECK: checked against the pool's mint B reserve by the pool reader
+    pub vault_b: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReportVaultNav<'info> {
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    #[account(
+        token::mint = vault.base_mint,
+        token::authority = vault
+    )]
+    pub vault_base_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Holds the vault's idle SOL
+    #[account(
+        seeds = [VAULT_SOL_SEED],
+        bump
+    )]
+    pub vault_sol: SystemAccount<'info>,
+    
+    /// CHECK: the SOL benchmark pool, needed while the vault holds SOL;
+    /// owner and layout are validated by the pool reader
+    #[account(address = config.benchmark_pools[SOL_BENCHMARK] @ OracleError::InvalidBenchmark)]
+    pub sol_pool: Option<UncheckedAccount<'info>>,
+    
+    pub sol_mint_a: Option<InterfaceAccount<'info, Mint>>,
+    
+    pub sol_mint_b: Option<InterfaceAccount<'info, Mint>>,
+    
+    /// CHECK: checked against the pool's mint A reserve by the pool reader
+    pub sol_vault_a: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: checked against the pool's mint B reserve by the pool reader
+    pub sol_vault_b: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        constraint = executor.key() == vault.executor @ OracleError::Unauthorized
+    )]
+    pub executor: Signer<'info>,
+}
+
+impl<'info> ReportVaultNav<'info> {
+    /// What the vault holds by the program's own count: base on hand and
+    /// lent, its SOL, and each open position in `accounts` at its signal's
+    /// verified pool, shorts marked by how far their notional has moved
+    fn measured_nav(&self, accounts: &'info [AccountInfo<'info>]) -> Result<u64> {
+        let vault = &self.vault;
+        let groups = accounts.chunks_exact(7);
+        require!(
+            groups.remainder().is_empty() && groups.len() == vault.open_positions as usize,
+            OracleError::InvalidNavAccounts
+        );
+        let mut value = i128::from(self.vault_base_account.amount)
+            + i128::from(vault.lent_amount)
+            + i128::from(self.sol_value()?);
+        
+        // Ascending addresses, so no position is counted twice
+        let mut last = None;
+        for group in groups {
+            let [position, signal, pool, mint_a, mint_b, vault_a, vault_b] = group else {
+                return err!(OracleError::InvalidNavAccounts);
+            };
+            require!(last < Some(position.key()), OracleError::InvalidNavAccounts);
+            last = Some(position.key());
+            let position = Account::<VaultPosition>::try_from(position)?;
+            let signal = Account::<Signal>::try_from(signal)?;
+            require!(
+                position.signal == signal.key() && position.is_open(),
+                OracleError::InvalidNavAccounts
+            );
+            require!(
+                signal.price_pool != Pubkey::default() && pool.key() == signal.price_pool,
+                OracleError::PoolNotVerified
+            );
+            let mint_a = InterfaceAccount::<Mint>::try_from(mint_a)?;
+            let mint_b = InterfaceAccount::<Mint>::try_from(mint_b)?;
+            let (price, token_decimals, base_decimals) =
+                vault_pool_price(pool, &mint_a, &mint_b, vault_a, vault_b, &signal.token, &vault.base_mint)?;
+            let spot = vault::token_value(position.token_amount, price, token_decimals, base_decimals)
+                .ok_or(OracleError::PriceOverflow)?;
+            let short = vault::perp_notional(position.short_base_amount, price, base_decimals)
+                .ok_or(OracleError::PriceOverflow)?;
+            value += i128::from(spot) + i128::from(position.short_notional) - i128::from(short);
+        }
+        Ok(u64::try_from(value.max(0)).unwrap_or(u64::MAX))
+    }
+
+    /// The vault's idle and staked SOL in base, at the SOL benchmark pool
+    fn sol_value(&self) -> Result<u64> {
+        let lamports = self.vault_sol.lamports() + self.vault.lst_lamports;
+        if lamports == 0 {
+            return Ok(0);
+        }
+        let native_mint = anchor_spl::token::spl_token::native_mint::ID;
+        if self.vault.base_mint == native_mint {
+            return Ok(lamports);
+        }
+        let (Some(pool), Some(mint_a), Some(mint_b), Some(vault_a), Some(vault_b)) =
+            (&self.sol_pool, &self.sol_mint_a, &self.sol_mint_b, &self.sol_vault_a, &self.sol_vault_b)
+        else {
+            return err!(OracleError::SolPoolRequired);
+        };
+        let (price, sol_decimals, base_decimals) =
+            vault_pool_price(pool, mint_a, mint_b, vault_a, vault_b, &native_mint, &self.vault.base_mint)?;
+        Ok(vault::token_value(lamports, price, sol_decimals, base_decimals).ok_or(OracleError::PriceOverflow)?)
+    }
+}
+
+#[derive(Accounts)]
+pub struct ResetVaultDerisk<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    #[account(
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimAccess<'info> {
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        seeds = [SUBSCRIPTION_SEED, subscriber.key().as_ref()],
+        bump = subscription.bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+    
+    #[account(
+        init,
+        payer = subscriber,
+        space = 8 + AccessClaim::INIT_SPACE,
+        seeds = [ACCESS_CLAIM_SEED, signal.key().as_ref(), subscriber.key().as_ref()],
+        bump
+    )]
+    pub claim: Account<'info, AccessClaim>,
+    
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct LogAccess<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(tier: u8)]
+pub struct SetTierAllowlist<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + TierAllowlist::INIT_SPACE,
+        seeds = [TIER_ALLOWLIST_SEED, &[tier]],
+        bump
+    )]
+    pub allowlist: Account<'info, TierAllowlist>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(tier: u8, wallet: Pubkey)]
+pub struct AllowlistAdd<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        mut,
+        seeds = [TIER_ALLOWLIST_SEED, &[tier]],
+        bump = allowlist.bump
+    )]
+    pub allowlist: Account<'info, TierAllowlist>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AllowlistEntry::INIT_SPACE,
+        seeds = [ALLOWLIST_ENTRY_SEED, &[tier], wallet.as_ref()],
+        bump
+    )]
+    pub entry: Account<'info, AllowlistEntry>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(tier: u8, wallet: Pubkey)]
+pub struct AllowlistRemove<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        mut,
+        seeds = [TIER_ALLOWLIST_SEED, &[tier]],
+        bump = allowlist.bump
+    )]
+    pub allowlist: Account<'info, TierAllowlist>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [ALLOWLIST_ENTRY_SEED, &[tier], wallet.as_ref()],
+        bump = entry.bump
+    )]
+    pub entry: Account<'info, AllowlistEntry>,
+    
+    #[account(
+        mut,
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TransferSubscription<'info> {
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(
+        mut,
+        seeds = [SUBSCRIPTION_SEED, owner.key().as_ref()],
+        bump = from.bump
+    )]
+    pub from: Account<'info, Subscription>,
+    
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + Subscription::INIT_SPACE,
+        seeds = [SUBSCRIPTION_SEED, recipient.key().as_ref()],
+        bump
+    )]
+    pub to: Account<'info, Subscription>,
+    
+    /// CHECK: the allowlist address of the subscription's tier; empty
+    /// unless the tier is in allowlist mode
+    #[account(
+        seeds = [TIER_ALLOWLIST_SEED, &[from.tier]],
+        bump
+    )]
+    pub tier_allowlist: UncheckedAccount<'info>,
+    
+    /// Recipient's approval, needed for allowlisted tiers
+    #[account(
+        seeds = [ALLOWLIST_ENTRY_SEED, &[from.tier], recipient.key().as_ref()],
+        bump = recipient_entry.bump
+    )]
+    pub recipient_entry: Option<Account<'info, AllowlistEntry>>,
+    
+    /// CHECK: any wallet; only its key is used
+    pub recipient: UncheckedAccount<'info>,
+    
+    /// CHECK: the pass mint's address; empty until `initialize_pass_mint`
+    #[account(
+        mut,
+        seeds = [PASS_MINT_SEED],
+        bump
+    )]
+    pub pass_mint: UncheckedAccount<'info>,
+    
+    /// Owner's pass account, needed once the pass mint exists
+    #[account(
+        mut,
+        token::authority = owner,
+        token::token_program = token_program
+    )]
+    pub owner_pass: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Recipient's pass account, minted a pass when given
+    #[account(
+        mut,
+        token::authority = recipient,
+        token::token_program = token_program
+    )]
+    pub recipient_pass: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token2022>>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManageSubscription<'info> {
+    #[account(
+        mut,
+        seeds = [SUBSCRIPTION_SEED, subscriber.key().as_ref()],
+        bump = subscription.bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+    
+    /// CHECK: the pass mint's address; empty until `initialize_pass_mint`
+    #[account(
+        mut,
+        seeds = [PASS_MINT_SEED],
+        bump
+    )]
+    pub pass_mint: UncheckedAccount<'info>,
+    
+    /// Subscriber's pass account, needed once the pass mint exists
+    #[account(
+        mut,
+        token::authority = subscriber,
+        token::token_program = token_program
+    )]
+    pub subscriber_pass: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token2022>>,
+    
+    pub subscriber: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RequestVaultDeposit<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + VaultDepositor::INIT_SPACE,
+        seeds = [VAULT_DEPOSITOR_SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub depositor: Account<'info, VaultDepositor>,
+    
+    #[account(address = vault.base_mint)]
+    pub base_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        token::mint = base_mint,
+        token::authority = owner
+    )]
+    pub owner_base_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = base_mint,
+        token::authority = vault
+    )]
+    pub vault_base_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RequestVaultWithdrawal<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_DEPOSITOR_SEED, owner.key().as_ref()],
+        bump = depositor.bump
+    )]
+    pub depositor: Account<'info, VaultDepositor>,
+    
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProcessVaultEpoch<'info> {
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + VaultEpoch::INIT_SPACE,
+        seeds = [VAULT_EPOCH_SEED, vault.epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub epoch: Account<'info, VaultEpoch>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SettleVaultDepositor<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_DEPOSITOR_SEED, depositor.owner.as_ref()],
+        bump = depositor.bump
+    )]
+    pub depositor: Account<'info, VaultDepositor>,
+    
+    /// The processed epoch the depositor's flows were queued in
+    #[account(
+        seeds = [VAULT_EPOCH_SEED, depositor.queued_epoch.to_le_bytes().as_ref()],
+        bump = epoch.bump
+    )]
+    pub epoch: Account<'info, VaultEpoch>,
+    
+    #[account(address = vault.base_mint)]
+    pub base_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        token::mint = base_mint,
+        token::authority = depositor.owner
+    )]
+    pub owner_base_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = base_mint,
+        token::authority = vault
+    )]
+    pub vault_base_account: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct AccrueVaultDepositor<'info> {
+    #[account(
+        seeds = [VAULT_SEED],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_DEPOSITOR_SEED, depositor.owner.as_ref()],
+        bump = depositor.bump
+    )]
+    pub depositor: Account<'info, VaultDepositor>,
+}
+
+#[derive(Accounts)]
+pub struct ManageStaking<'info> {
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.vault_stake_pool != Pubkey::default() @ OracleError::StakingDisabled
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    /// Holds the vault's idle SOL; system-owned so it can fund deposits
+    #[account(
+        mut,
+        seeds = [VAULT_SOL_SEED],
+        bump
+    )]
+    pub vault_sol: SystemAccount<'info>,
+    
+    #[account(
+        mut,
+        token::authority = vault
+    )]
+    pub vault_lst_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: pinned to the configured pool and owned by the stake pool program
+    #[account(
+        address = config.vault_stake_pool,
+        owner = vault::STAKE_POOL_PROGRAM_ID
+    )]
+    pub stake_pool: UncheckedAccount<'info>,
+    
+    /// CHECK: address is pinned to the stake pool program
+    #[account(address = vault::STAKE_POOL_PROGRAM_ID)]
+    pub stake_pool_program: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = executor.key() == vault.executor @ OracleError::Unauthorized
+    )]
+    pub executor: Signer<'info>,
+}
+
+impl ManageStaking<'_> {
+    /// The forwarded accounts must act on the configured pool
+    fn check_pool_accounts(&self, accounts: &[AccountInfo]) -> Result<()> {
+        let pool = accounts.first().ok_or(OracleError::InvalidStakePool)?;
+        require_keys_eq!(pool.key(), self.stake_pool.key(), OracleError::InvalidStakePool);
+        Ok(())
+    }
+
+    /// Re-value the LST after a conversion and report it
+    fn sync(&mut self, staked_lamports: u64, unstaked_pool_tokens: u64) -> Result<()> {
+        self.vault_lst_account.reload()?;
+        let rate = read_stake_pool(&self.stake_pool, &self.vault_lst_account)?;
+        self.vault.value_lst(self.vault_lst_account.amount, &rate)?;
+        
+        emit!(VaultStakingChanged {
+            staked_lamports,
+            unstaked_pool_tokens,
+            lst_amount: self.vault.lst_amount,
+            lst_lamports: self.vault.lst_lamports,
+        });
+        msg!("Vault holds {} LST worth {} lamports", self.vault.lst_amount, self.vault.lst_lamports);
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct SyncVaultLst<'info> {
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    #[account(token::authority = vault)]
+    pub vault_lst_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: pinned to the configured pool and owned by the stake pool program
+    #[account(
+        address = config.vault_stake_pool @ OracleError::InvalidStakePool,
+        owner = vault::STAKE_POOL_PROGRAM_ID
+    )]
+    pub stake_pool: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DeclareVaultEmergency<'info> {
+    #[account(
+        seeds = [ORACLE_STATE_SEED],
+        bump = oracle_state.bump
+    )]
+    pub oracle_state: Account<'info, OracleState>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    #[account(
+        constraint = authority.key() == oracle_state.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct EmergencyUnwind<'info> {
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub signal: Account<'info, Signal>,
+    
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, signal.key().as_ref()],
+        bump = position.bump
+    )]
+    pub position: Account<'info, VaultPosition>,
+    
+    #[account(
+        mut,
+        token::mint = vault.base_mint,
+        token::authority = vault
+    )]
+    pub vault_base_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = signal.token,
+        token::authority = vault
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: must be the pool the signal's entry was verified against;
+    /// owner and layout are validated by the pool reader
+    #[account(
+        constraint = signal.price_pool != Pubkey::default() && pool.key() == signal.price_pool
+            @ OracleError::PoolNotVerified
+    )]
+    pub pool: UncheckedAccount<'info>,
+    
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    
+    pub mint_b: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: checked against the pool's mint A reserve by the pool reader
+    pub vault_a: UncheckedAccount<'info>,
+    
+    /// CHECK: checked against the pool's mint B reserve by the pool reader
+    pub vault_b: UncheckedAccount<'info>,
+    
+    /// CHECK: address is pinned to the Jupiter aggregator
+    #[account(address = vault::JUPITER_PROGRAM_ID)]
+    pub jupiter_program: UncheckedAccount<'info>,
+    
+    /// The vault's executor or its authority
+    #[account(
+        constraint = operator.key() == vault.executor || operator.key() == vault.authority @ OracleError::Unauthorized
+    )]
+    pub operator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct EmergencyWithdraw<'info> {
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_DEPOSITOR_SEED, owner.key().as_ref()],
+        bump = depositor.bump
+    )]
+    pub depositor: Account<'info, VaultDepositor>,
+    
+    #[account(address = vault.base_mint)]
+    pub base_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        token::mint = base_mint,
+        token::authority = owner
+    )]
+    pub owner_base_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = base_mint,
+        token::authority = vault
+    )]
+    pub vault_base_account: InterfaceAccount<'info, TokenAccount>,
+    
+    pub owner: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct Rebalance<'info> {
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    pub signal: Account<'info, Signal>,
+    
+    /// Holds the signal's suggested size, the position's target weight
+    #[account(
+        seeds = [SIGNAL_SIZING_SEED, signal.key().as_ref()],
+        bump = sizing.bump
+    )]
+    pub sizing: Account<'info, SignalSizing>,
+    
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, signal.key().as_ref()],
+        bump = position.bump
+    )]
+    pub position: Account<'info, VaultPosition>,
+    
+    #[account(
+        mut,
+        token::mint = vault.base_mint,
+        token::authority = vault
+    )]
+    pub vault_base_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = signal.token,
+        token::authority = vault
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: must be the pool the signal's entry was verified against;
+    /// owner and layout are validated by the pool reader
+    #[account(
+        constraint = signal.price_pool != Pubkey::default() && pool.key() == signal.price_pool
+            @ OracleError::PoolNotVerified
+    )]
+    pub pool: UncheckedAccount<'info>,
+    
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    
+    pub mint_b: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: checked against the pool's mint A reserve by the pool reader
+    pub vault_a: UncheckedAccount<'info>,
+    
+    /// CHECK: checked against the pool's mint B reserve by the pool reader
+    pub vault_b: UncheckedAccount<'info>,
+    
+    /// CHECK: address is pinned to the Jupiter aggregator
+    #[account(address = vault::JUPITER_PROGRAM_ID)]
+    pub jupiter_program: UncheckedAccount<'info>,
+    
+    #[account(
+        constraint = executor.key() == vault.executor @ OracleError::Unauthorized
+    )]
+    pub executor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CrystallizeVaultFees<'info> {
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, OracleConfig>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    
+    #[account(address = vault.base_mint)]
+    pub base_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        token::mint = base_mint,
+        token::authority = vault
+    )]
+    pub vault_base_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    
+    #[account(
+        mut,
+        token::mint = base_mint,
+        token::authority = treasury
+    )]
+    pub treasury_base_account: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// === STATE ===
+
+#[account]
+#[derive(InitSpace)]
+pub struct OracleState {
+    pub authority: Pubkey,
+    pub total_signals: u64,
+    pub total_wins: u64,
+    pub total_losses: u64,
+    pub compressed_signals: u64, // Leaves appended to the signal tree
+    pub streaks: Streaks,
+    pub roi_moments: RoiMoments, // Running mean/variance of settled ROI
+    pub roi_histogram: [u32; ROI_BUCKET_COUNT], // Settlements per oracle_math::ROI_BUCKET_FLOORS_BPS bucket
+    pub total_baskets: u64,
+    pub total_pairs: u64,
+    pub roi_annualized_moments: RoiMoments, // Running mean/variance of settled annualized ROI
+    pub unrealized_roi_bps: i64, // Sum of open signals' marked ROI, one unit position each
+    pub marked_at: i64,          // Last mark_signals run
+    pub performance_index: u64,  // Equity curve over settled ROI, see oracle_math::PerformanceIndex
+    pub bump: u8,
+}
+
+/// Current and longest runs of consecutive wins and losses
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub struct Streaks {
+    pub win: u32,
+    pub loss: u32,
+    pub max_win: u32,
+    pub max_loss: u32,
+}
+
+impl Streaks {
+    /// Extend or break the runs with a settlement's outcome
+    pub fn record(&mut self, outcome: Outcome) {
+        *self = oracle_math::Streaks::from(*self).after(outcome).into();
+    }
+}
+
+impl From<Streaks> for oracle_math::Streaks {
+    fn from(streaks: Streaks) -> Self {
+        oracle_math::Streaks {
+            win: streaks.win,
+            loss: streaks.loss,
+            max_win: streaks.max_win,
+            max_loss: streaks.max_loss,
+        }
+    }
+}
+
+impl From<oracle_math::Streaks> for Streaks {
+    fn from(streaks: oracle_math::Streaks) -> Self {
+        Streaks {
+            win: streaks.win,
+            loss: streaks.loss,
+            max_win: streaks.max_win,
+            max_loss: streaks.max_loss,
+        }
+    }
+}
+
+/// Welford accumulators for settled ROI, see `oracle_math::RoiMoments`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub struct RoiMoments {
+    pub count: u64,
+    pub mean: i64,               // Mean ROI in bps, scaled by MOMENT_SCALE
+    pub m2: u128,                // Sum of squared deviations, in bps²
+}
+
+impl RoiMoments {
+    /// Fold in one settlement's ROI
+    pub fn record(&mut self, roi_bps: i64) {
+        *self = oracle_math::RoiMoments::from(*self).after(roi_bps).into();
+    }
+}
+
+impl From<RoiMoments> for oracle_math::RoiMoments {
+    fn from(moments: RoiMoments) -> Self {
+        oracle_math::RoiMoments {
+            count: moments.count,
+            mean: moments.mean,
+            m2: moments.m2,
+        }
+    }
+}
+
+impl From<oracle_math::RoiMoments> for RoiMoments {
+    fn from(moments: oracle_math::RoiMoments) -> Self {
+        RoiMoments {
+            count: moments.count,
+            mean: moments.mean,
+            m2: moments.m2,
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace, Default)]
+pub struct Signal {
+    pub id: u64,
+    pub token: Pubkey,
+    #[max_len(10)]
+    pub symbol: String,          // Up to config.max_symbol_len, see signal_space
+    pub score: u8,
+    pub risk_level: u8,
+    pub sources_bitmap: u8,      // Bitmap of signal sources
+    pub mcap_at_signal: u64,
+    pub entry_price: u64,
+    pub direction: SignalDirection,
+    pub ath_price: u64,          // Best price seen (lowest for shorts)
+    pub exit_price: u64,
+    pub roi_bps: i64,            // ROI in basis points (can be negative)
+    pub timestamp: i64,
+    pub status: SignalStatus,
+    pub reasoning_hash: [u8; 32], // SHA256 of reasoning committed BEFORE outcome
+    pub reasoning_revealed: bool, // Whether reasoning has been revealed publicly
+    pub price_pool: Pubkey,       // DEX pool the entry/exit prices were checked against
+    pub entry_verified: bool,
+    pub exit_verified: bool,
+    pub bump: u8,
+    // Appended into the reserved tail; older signals read it as default
+    pub quote_mint: Pubkey,       // Mint every price is quoted in
+    pub roi_annualized_bps: i64,  // roi_bps scaled to a year of holding, set at settlement
+    pub net_roi_bps: i64,         // roi_bps after config.friction, set at settlement
+    pub unrealized_roi_bps: i64,  // ROI at the last mark while open, 0 once settled
+    pub closed_at: i64,           // Settlement time, 0 while open
+    pub publisher: Pubkey,        // Wallet whose stats the signal settles into
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, InitSpace)]
+pub enum SignalStatus {
+    #[default]
+    Open,
+    Win,
+    Loss,
+    Closed,
+    BreakEven,   // Within config.break_even_bps of zero, neither win nor loss
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct OracleConfig {
+    pub max_slippage_bps: [u16; RISK_LEVEL_COUNT], // Per risk level, for vault swaps
+    pub max_leverage_bps: [u32; RISK_LEVEL_COUNT], // Per risk level, for perp shorts (10_000 = 1x)
+    pub vault_reserve_bps: u16,  // Share of vault base kept liquid when lending
+    pub access_pass_tree: Pubkey, // Bubblegum tree holding cNFT access passes
+    pub tier_price_lamports: [u64; TIER_COUNT], // Price of one subscription period
+    pub storage_mode: StorageMode,
+    pub signal_tree: Pubkey,     // Merkle tree holding compressed-mode signals
+    pub elite_score: u8,         // Min score to mint a signal asset
+    pub asset_royalty_bps: u16,  // Resale royalty paid to the publisher
+    pub score_half_life_secs: i64, // Effective-score decay; 0 disables it
+    pub max_symbol_len: u8,      // Longest symbol a signal can be published with
+    pub max_mcap_discrepancy_bps: u16, // Claimed/verified mcap gap that gets flagged
+    pub max_supply_change_bps: u16, // Mint supply change over a signal's life that gets flagged
+    pub republish_cooldown_secs: i64, // Wait after a token's signal closes before another; 0 disables it
+    pub break_even_bps: u16,     // ROI band either side of zero that settles as break-even
+    pub win_threshold_bps: [u32; RISK_LEVEL_COUNT], // Per risk level, ROI a close needs to count as a win
+    pub friction: Friction,      // Follower costs net_roi_bps deducts from the gross ROI
+    pub benchmark_pools: [Pubkey; BENCHMARK_COUNT], // Pools benchmark prices are read from, default if unset
+    pub vault_max_drawdown_bps: u16, // Vault NAV drawdown that de-risks it; 0 disables it
+    pub vault_derisk_bps: u16,   // Entry size cap while de-risked, as a share of liquid base; 0 halts entries
+    pub subscription_transfer_cooldown_secs: i64, // Wait before a subscription moves again; 0 disables transfers
+    pub vault_stake_pool: Pubkey, // SPL stake pool idle vault SOL is staked into, default if unset
+    pub max_allocation_bps: [u16; RISK_LEVEL_COUNT], // Per risk level, most of the vault one signal can hold
+    pub rebalance_band_bps: u16, // Weight drift from target, in bps of the vault, that allows a rebalance
+    pub max_rebalance_bps: u16,  // Largest single rebalance trade, in bps of the vault
+    pub vault_performance_fee_bps: u16, // Share of vault gains above the high-water mark paid to the treasury
+    pub vault_nav_tolerance_bps: u16, // Widest gap between a reported NAV and the vault's measured value
+    pub vault_max_nav_change_bps: u16, // Largest share price move between NAV reports; 0 disables it
+    pub bump: u8,
+}
+
+/// Partial config change; `None` fields are left untouched
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ConfigUpdate {
+    pub max_slippage_bps: Option<[u16; RISK_LEVEL_COUNT]>,
+    pub max_leverage_bps: Option<[u32; RISK_LEVEL_COUNT]>,
+    pub vault_reserve_bps: Option<u16>,
+    pub access_pass_tree: Option<Pubkey>,
+    pub tier_price_lamports: Option<[u64; TIER_COUNT]>,
+    pub storage_mode: Option<StorageMode>,
+    pub elite_score: Option<u8>,
+    pub asset_royalty_bps: Option<u16>,
+    pub score_half_life_secs: Option<i64>,
+    pub max_symbol_len: Option<u8>,
+    pub max_mcap_discrepancy_bps: Option<u16>,
+    pub max_supply_change_bps: Option<u16>,
+    pub republish_cooldown_secs: Option<i64>,
+    pub break_even_bps: Option<u16>,
+    pub win_threshold_bps: Option<[u32; RISK_LEVEL_COUNT]>,
+    pub friction: Option<Friction>,
+    pub benchmark_pools: Option<[Pubkey; BENCHMARK_COUNT]>,
+    pub vault_max_drawdown_bps: Option<u16>,
+    pub vault_derisk_bps: Option<u16>,
+    pub subscription_transfer_cooldown_secs: Option<i64>,
+    pub vault_stake_pool: Option<Pubkey>,
+    pub max_allocation_bps: Option<[u16; RISK_LEVEL_COUNT]>,
+    pub rebalance_band_bps: Option<u16>,
+    pub max_rebalance_bps: Option<u16>,
+    pub vault_performance_fee_bps: Option<u16>,
+    pub vault_nav_tolerance_bps: Option<u16>,
+    pub vault_max_nav_change_bps: Option<u16>,
+}
+
+/// Slippage and fees a follower pays, see `oracle_math::Friction`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub struct Friction {
+    pub entry_slippage_bps: u16,
+    pub exit_slippage_bps: u16,
+    pub swap_fee_bps: u16,       // Charged on both the entry and the exit swap
+}
+
+impl Friction {
+    /// Whether each fill's slippage plus fee stays under 100%
+    pub fn is_valid(&self) -> bool {
+        let fee = self.swap_fee_bps as u32;
+        self.entry_slippage_bps as u32 + fee < 10_000 && self.exit_slippage_bps as u32 + fee < 10_000
+    }
+}
+
+impl From<Friction> for oracle_math::Friction {
+    fn from(friction: Friction) -> Self {
+        oracle_math::Friction {
+            entry_slippage_bps: friction.entry_slippage_bps,
+            exit_slippage_bps: friction.exit_slippage_bps,
+            swap_fee_bps: friction.swap_fee_bps,
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Vault {
+    pub authority: Pubkey,
+    pub executor: Pubkey,        // Keeper allowed to route executions
+    pub base_mint: Pubkey,       // Quote asset held between signals
+    pub total_executions: u64,
+    pub lent_amount: u64,        // Base principal currently parked in lending
+    pub nav: u64,                // Last NAV the executor reported, in base mint units, within the measured range
+    pub peak_nav: u64,           // Highest reported NAV since the last de-risk reset
+    pub derisked: bool,          // Set once drawdown crosses the configured threshold
+    pub nav_at: i64,             // When nav was last reported
+    pub total_shares: u64,       // Settled follower shares
+    pub epoch: u64,              // Epoch currently queueing flows
+    pub epoch_started_at: i64,
+    pub queued_deposits: u64,    // Base held for deposits queued this epoch
+    pub queued_withdrawal_shares: u64,
+    pub owed_withdrawals: u64,   // Base owed to processed withdrawals not yet settled
+    pub entered_per_share: i128, // Base spent on entries per share, scaled by PER_SHARE_SCALE
+    pub realized_pnl_per_share: i128, // Realized spot PnL per share, scaled by PER_SHARE_SCALE
+    pub lst_amount: u64,         // LST held for idle SOL, as of the last sync
+    pub lst_lamports: u64,       // Its SOL value at the stake pool's exchange rate
+    pub lst_synced_at: i64,
+    pub emergency: bool,         // Emergency exit declared; entries stop and withdrawals are immediate
+    pub emergency_at: i64,
+    pub high_water_mark: u128,   // Highest post-fee equity per share, scaled by PER_SHARE_SCALE; 0 until first set
+    pub next_fee_epoch: u64,     // First epoch whose fees haven't been crystallized
+    pub fees_paid: u64,          // Performance fees paid to the treasury, in base
+    pub last_flow_at: i64,       // When a deposit or withdrawal was last queued
+    pub open_positions: u32,     // Positions holding tokens or a short; NAV reports value each one
+    pub nav_share_price: u128,   // Equity per share at the last NAV report, scaled by PER_SHARE_SCALE
+    pub bump: u8,
+}
+
+impl Vault {
+    /// When the epoch queueing flows has run VAULT_EPOCH_SECS
+    pub fn epoch_ends_at(&self) -> i64 {
+        self.epoch_started_at + VAULT_EPOCH_SECS
+    }
+
+    /// Note a flow queued into the current epoch. Once it has run its
+    /// length the epoch only waits to be priced, so no flow can land after
+    /// the NAV it will be priced at.
+    fn queue_flow(&mut self, now: i64) -> Result<()> {
+        require!(now < self.epoch_ends_at(), OracleError::VaultEpochClosing);
+        self.last_flow_at = now;
+        Ok(())
+    }
+
+    /// The epoch is over and NAV was reported since, and after its last flow
+    fn check_closing_nav(&self, now: i64) -> Result<()> {
+        require!(now >= self.epoch_ends_at(), OracleError::EpochNotOver);
+        require!(
+            self.nav_at >= self.epoch_ends_at() && self.nav_at > self.last_flow_at,
+            OracleError::StaleVaultNav
+        );
+        Ok(())
+    }
+
+    /// Share of a normal entry allowed right now: all of it, or the
+    /// configured de-risk share (0 halts entries) while de-risked
+    pub fn entry_scale_bps(&self, config: &OracleConfig) -> u16 {
+        if self.derisked { config.vault_derisk_bps } else { 10_000 }
+    }
+
+    /// What allocation caps are measured against: the last reported NAV,
+    /// or the `liquid` base balance until one is reported
+    pub fn allocation_base(&self, liquid: u64) -> u64 {
+        if self.nav_at > 0 { self.nav } else { liquid }
+    }
+
+    /// Keep `open_positions` in step with a position opening or closing
+    fn track_position(&mut self, was_open: bool, is_open: bool) {
+        match (was_open, is_open) {
+            (false, true) => self.open_positions += 1,
+            (true, false) => self.open_positions -= 1,
+            _ => {}
+        }
+    }
+
+    /// Value `lst_amount` of LST at the stake pool's exchange rate
+    fn value_lst(&mut self, lst_amount: u64, rate: &vault::StakePoolRate) -> Result<()> {
+        self.lst_amount = lst_amount;
+        self.lst_lamports = vault::pro_rata(rate.total_lamports, lst_amount, rate.pool_token_supply);
+        self.lst_synced_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct VaultPosition {
+    pub signal: Pubkey,
+    pub token_amount: u64,       // Signal token currently held
+    pub cost_basis: u64,         // Base spent on entries
+    pub proceeds: u64,           // Base received from exits
+    pub market_index: u16,       // Drift perp market for shorts
+    pub short_base_amount: u64,  // Open short size (Drift base precision)
+    pub short_notional: u64,     // Open short notional in base mint units
+    pub exited_cost: u64,        // Share of cost_basis already sold
+    pub entered_per_share: i128, // This position's part of the vault's accumulators
+    pub realized_pnl_per_share: i128,
+    pub bump: u8,
+}
+
+impl VaultPosition {
+    /// Still holds tokens or a short
+    pub fn is_open(&self) -> bool {
+        self.token_amount > 0 || self.short_base_amount > 0
+    }
+
+    /// Book a spot swap of `spent` for `received` and attribute it to the
+    /// vault shares outstanding right now; returns the per-share entry and
+    /// realized-PnL increments
+    fn book(&mut self, vault: &mut Vault, side: ExecutionSide, spent: u64, received: u64) -> (i128, i128) {
+        let was_open = self.is_open();
+        let (entered, realized_pnl) = match side {
+            ExecutionSide::Entry => {
+                self.token_amount += received;
+                self.cost_basis += spent;
+                (spent, 0)
+            }
+            ExecutionSide::Exit => {
+                // Tokens sold carry the position's average entry cost
+                let open_cost = self.cost_basis - self.exited_cost;
+                let cost = vault::pro_rata(open_cost, spent, self.token_amount);
+                self.token_amount -= spent;
+                self.proceeds += received;
+                self.exited_cost += cost;
+                (0, i128::from(received) - i128::from(cost))
+            }
+        };
+        
+        let entered_per_share = vault::per_share(i128::from(entered), vault.total_shares);
+        let realized_pnl_per_share = vault::per_share(realized_pnl, vault.total_shares);
+        self.entered_per_share += entered_per_share;
+        self.realized_pnl_per_share += realized_pnl_per_share;
+        vault.entered_per_share += entered_per_share;
+        vault.realized_pnl_per_share += realized_pnl_per_share;
+        vault.track_position(was_open, self.is_open());
+        (entered_per_share, realized_pnl_per_share)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub enum SignalDirection {
+    #[default]
+    Long,
+    Short,
+}
+
+impl From<SignalDirection> for Direction {
+    fn from(direction: SignalDirection) -> Self {
+        match direction {
+            SignalDirection::Long => Direction::Long,
+            SignalDirection::Short => Direction::Short,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum ExecutionSide {
+    Entry,
+    Exit,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Subscription {
+    pub subscriber: Pubkey,
+    pub tier: u8,
+    pub expires_at: i64,
+    pub transferable_at: i64,    // Earliest time the subscription can be transferred on
+    pub paused_at: i64,          // 0 unless paused
+    pub paused_remaining_secs: i64, // Time left when paused, restored on resume
+    pub pauses: u8,              // Pauses used by the current subscription
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Publisher {
+    pub wallet: Pubkey,
+    #[max_len(32)]
+    pub domain: String,          // Verified `.sol` name, without the suffix
+    pub domain_account: Pubkey,  // SNS name account the domain was verified against
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+/// Where an oracle keeps its signals
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum StorageMode {
+    Accounts,    // One PDA per signal
+    Compressed,  // Leaf hashes in a concurrent Merkle tree
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ThreadAction {
+    Created,
+    Funded,
+    Closed,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ExecutionTicket {
+    pub signal: Pubkey,
+    pub commit_hash: [u8; 32],   // sha256(signal, side, amount_in, salt)
+    pub valid_from_slot: u64,
+    pub valid_until_slot: u64,
+    pub executed: bool,
+    pub bump: u8,
+}
+
+/// Signal layout before `direction` and the price-check fields were added;
+/// only read by `migrate_signal`
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacySignal {
+    pub id: u64,
+    pub token: Pubkey,
+    #[max_len(10)]
+    pub symbol: String,
+    pub score: u8,
+    pub risk_level: u8,
+    pub sources_bitmap: u8,
+    pub mcap_at_signal: u64,
+    pub entry_price: u64,
+    pub ath_price: u64,
+    pub exit_price: u64,
+    pub roi_bps: i64,
+    pub timestamp: i64,
+    pub status: SignalStatus,
+    pub reasoning_hash: [u8; 32],
+    pub reasoning_revealed: bool,
+    pub bump: u8,
+}
+
+impl LegacySignal {
+    /// Size of a legacy signal account
+    pub const SPACE: usize = 8 + LegacySignal::INIT_SPACE;
+    
+    /// The same signal in the current layout, settling into `publisher`'s
+    /// stats; legacy signals were all longs and were never checked against
+    /// a pool
+    pub fn upgrade(self, publisher: Pubkey) -> Signal {
+        Signal {
+            id: self.id,
+            token: self.token,
+            symbol: self.symbol,
+            score: self.score,
+            risk_level: self.risk_level,
+            sources_bitmap: self.sources_bitmap,
+            mcap_at_signal: self.mcap_at_signal,
+            entry_price: self.entry_price,
+            direction: SignalDirection::Long,
+            ath_price: self.ath_price,
+            exit_price: self.exit_price,
+            roi_bps: self.roi_bps,
+            timestamp: self.timestamp,
+            status: self.status,
+            reasoning_hash: self.reasoning_hash,
+            reasoning_revealed: self.reasoning_revealed,
+            price_pool: Pubkey::default(),
+            entry_verified: false,
+            exit_verified: false,
+            bump: self.bump,
+            quote_mint: Pubkey::default(),
+            roi_annualized_bps: 0,
+            net_roi_bps: 0,
+            unrealized_roi_bps: 0,
+            closed_at: 0,
+            publisher,
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PublisherStats {
+    pub wallet: Pubkey,
+    pub signals: u64,            // Signals settled
+    pub wins: u64,
+    pub losses: u64,
+    pub cumulative_roi_bps: i64, // Sum of settled ROI, saturating
+    pub last_active: i64,        // Last settlement (or creation)
+    pub streaks: Streaks,
+    pub cumulative_roi_annualized_bps: i64, // Sum of settled annualized ROI, saturating
+    pub bump: u8,
+}
+
+impl PublisherStats {
+    /// Count a settled signal
+    pub fn record(&mut self, outcome: Outcome, roi_bps: i64, roi_annualized_bps: i64, now: i64) {
+        self.signals += 1;
+        match outcome {
+            Outcome::Win => self.wins += 1,
+            Outcome::Loss => self.losses += 1,
+            Outcome::Closed | Outcome::BreakEven => {}
+        }
+        self.cumulative_roi_bps = self.cumulative_roi_bps.saturating_add(roi_bps);
+        self.cumulative_roi_annualized_bps = self.cumulative_roi_annualized_bps.saturating_add(roi_annualized_bps);
+        self.last_active = now;
+        self.streaks.record(outcome);
+    }
+}
+
+/// Publisher commentary appended to a signal after publish
+#[account]
+pub struct SignalNotes {
+    pub signal: Pubkey,
+    pub notes: Vec<SignalNote>,  // Oldest first; the account grows with each
+    pub bump: u8,
+}
+
+impl SignalNotes {
+    /// Size with no notes
+    pub const BASE_SPACE: usize = 8 + 32 + 4 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct SignalNote {
+    pub timestamp: i64,
+    pub text: String,
+}
+
+impl SignalNote {
+    /// Bytes a note holding `text` adds to the notes account
+    pub fn space(text: &str) -> usize {
+        8 + 4 + text.len()
+    }
+}
+
+/// Subscriber ratings of one signal
+#[account]
+#[derive(InitSpace)]
+pub struct SignalRating {
+    pub signal: Pubkey,
+    pub count: u32,
+    pub sum: u64,                // Mean rating = sum / count
+    pub counts: [u32; MAX_RATING as usize], // Ratings per star, 1 first
+    pub bump: u8,
+}
+
+/// Proof that `subscriber` has rated `signal`
+#[account]
+#[derive(InitSpace)]
+pub struct RatingReceipt {
+    pub signal: Pubkey,
+    pub subscriber: Pubkey,
+    pub rating: u8,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+/// Paid boosts of one signal
+#[account]
+#[derive(InitSpace)]
+pub struct SignalBoost {
+    pub signal: Pubkey,
+    pub heat: u64,               // Lamports paid in boosts, ever
+    pub expires_at: i64,         // Featured eligibility ends here
+    pub bump: u8,
+}
+
+/// Featured slots of the signal feed, held by the hottest active boosts
+#[account]
+#[derive(InitSpace)]
+pub struct Discovery {
+    pub featured: [FeaturedSlot; FEATURED_SLOTS],
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub struct FeaturedSlot {
+    pub signal: Pubkey,          // Default when empty
+    pub heat: u64,
+    pub expires_at: i64,
+}
+
+impl Discovery {
+    /// Feature a boosted `signal` in its own slot (extending it), else in
+    /// an empty or expired one. Slots won at auction are never displaced.
+    /// Returns whether it is featured.
+    pub fn feature(&mut self, signal: Pubkey, heat: u64, expires_at: i64, now: i64) -> bool {
+        if let Some(slot) = self.featured.iter_mut().find(|s| s.signal == signal) {
+            slot.heat = slot.heat.max(heat);
+            slot.expires_at = slot.expires_at.max(expires_at);
+            return true;
+        }
+        match self
+            .featured
+            .iter_mut()
+            .find(|s| s.signal == Pubkey::default() || s.expires_at <= now)
+        {
+            Some(slot) => {
+                *slot = FeaturedSlot {
+                    signal,
+                    heat,
+                    expires_at,
+                };
+                true
+            }
+            None => false,
+        }
+    }
+    
+    /// Replace every slot with the winning `bids`, highest first, until
+    /// the end of `epoch`; slots left unsold are freed for boosts
+    pub fn install(&mut self, bids: &[AuctionBid], epoch: u64) {
+        let mut bids = bids.to_vec();
+        bids.sort_by_key(|bid| std::cmp::Reverse(bid.lamports));
+        let expires_at = (epoch as i64 + 1) * AUCTION_EPOCH_SECS;
+        for (slot, bid) in self.featured.iter_mut().zip(bids) {
+            *slot = if bid.lamports == 0 {
+                FeaturedSlot::default()
+            } else {
+                FeaturedSlot {
+                    signal: bid.signal,
+                    heat: bid.lamports,
+                    expires_at,
+                }
+            };
+        }
+    }
+}
+
+/// Escrowed bids for the featured slots of one epoch
+#[account]
+#[derive(InitSpace)]
+pub struct FeaturedAuction {
+    pub epoch: u64,
+    pub bids: [AuctionBid; FEATURED_SLOTS], // Unordered; zero lamports when empty
+    pub settled: bool,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub struct AuctionBid {
+    pub signal: Pubkey,
+    pub bidder: Pubkey,
+    pub lamports: u64,
+}
+
+impl FeaturedAuction {
+    /// Hold `bid` in place of the signal's previous bid, an empty entry or
+    /// the lowest bid, whichever comes first, if it beats what it replaces
+    /// by `MIN_BID_INCREMENT_BPS`. Returns the replaced bid to refund.
+    pub fn place(&mut self, bid: AuctionBid) -> Result<AuctionBid> {
+        let index = self
+            .bids
+            .iter()
+            .position(|b| b.lamports > 0 && b.signal == bid.signal)
+            .or_else(|| self.bids.iter().position(|b| b.lamports == 0))
+            .or_else(|| self.bids.iter().enumerate().min_by_key(|(_, b)| b.lamports).map(|(i, _)| i))
+            .ok_or(OracleError::BidTooLow)?;
+        let displaced = self.bids[index];
+        let min = u128::from(displaced.lamports) * u128::from(10_000 + MIN_BID_INCREMENT_BPS) / 10_000;
+        require!(displaced.lamports == 0 || u128::from(bid.lamports) >= min, OracleError::BidTooLow);
+        self.bids[index] = bid;
+        Ok(displaced)
+    }
+}
+
+/// A signal on a weighted set of tokens
+#[account]
+#[derive(InitSpace)]
+pub struct Basket {
+    pub id: u64,
+    #[max_len(MAX_BASKET_NAME_LEN)]
+    pub name: String,            // Thesis, e.g. "AI-MEMES"
+    #[max_len(MAX_BASKET_MEMBERS)]
+    pub members: Vec<BasketMember>,
+    pub timestamp: i64,
+    pub status: SignalStatus,
+    pub roi_bps: i64,            // Weighted ROI of the members once closed
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct BasketMember {
+    pub token: Pubkey,
+    pub weight_bps: u16,
+    pub entry_price: u64,
+    pub exit_price: u64,
+    pub roi_bps: i64,
+}
+
+/// One token of a basket being published
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BasketMemberParams {
+    pub token: Pubkey,
+    pub weight_bps: u16,
+    pub entry_price: u64,
+}
+
+/// A call that one token will outperform another
+#[account]
+#[derive(InitSpace)]
+pub struct PairSignal {
+    pub id: u64,
+    pub long: PairLeg,
+    pub short: PairLeg,
+    pub timestamp: i64,
+    pub status: SignalStatus,
+    pub roi_bps: i64,            // Spread ROI once closed
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct PairLeg {
+    pub token: Pubkey,
+    pub entry_price: u64,
+    pub exit_price: u64,         // 0 while open
+}
+
+/// Perps execution guidance for a signal
+#[account]
+#[derive(InitSpace)]
+pub struct PerpParams {
+    pub signal: Pubkey,
+    pub leverage_bps: u32,       // Recommended leverage (10_000 = 1x); 0 when unset
+    pub liquidation_price: u64,  // Implied by leverage_bps and the entry price
+    pub market_index: u16,       // Drift perp market that tracks the signal's token
+    pub bump: u8,
+}
+
+/// Position sizing suggested when a signal was published
+#[account]
+#[derive(InitSpace)]
+pub struct SignalSizing {
+    pub signal: Pubkey,
+    pub size_bps: u16,           // Fraction of capital to commit
+    pub win_rate_bps: u16,       // Oracle win rate it was sized on
+    pub bump: u8,
+}
+
+/// A signal's claimed market cap next to one computed on-chain
+#[account]
+#[derive(InitSpace)]
+pub struct SignalMarketCap {
+    pub signal: Pubkey,
+    pub claimed_mcap: u64,       // Signal::mcap_at_signal
+    pub verified_mcap: u64,      // supply * price, in whole quote units
+    pub supply: u64,             // Mint supply (base units) it was computed from
+    pub price: u64,              // Pool spot price, PRICE_DECIMALS
+    pub quote_mint: Pubkey,      // What verified_mcap is denominated in
+    pub pool: Pubkey,
+    pub discrepancy_bps: u64,    // Gap of the claim from the verified value
+    pub flagged: bool,           // Gap above config.max_mcap_discrepancy_bps
+    pub bump: u8,
+}
+
+/// Mint supply of a signal's token at publish and at close. Only total
+/// supply is on-chain; circulating supply is left to off-chain analysis.
+#[account]
+#[derive(InitSpace)]
+pub struct SupplySnapshot {
+    pub signal: Pubkey,
+    pub entry_supply: u64,
+    pub entry_timestamp: i64,
+    pub exit_supply: u64,
+    pub exit_timestamp: i64,     // 0 until the exit snapshot
+    pub supply_change_bps: i64,  // Exit vs entry supply, positive when diluted
+    pub flagged: bool,           // Change above config.max_supply_change_bps
+    pub bump: u8,
+}
+
+/// Hash of a signal token's top-holder snapshot, taken at publish
+#[account]
+#[derive(InitSpace)]
+pub struct HolderCommitment {
+    pub signal: Pubkey,
+    pub snapshot_hash: [u8; 32], // SHA256 of the canonical snapshot text
+    pub top_holders_bps: u16,    // Share of supply the snapshotted holders held
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+/// Last close of any signal on a token, for the republish cooldown
+#[account]
+#[derive(InitSpace)]
+pub struct TokenHistory {
+    pub token: Pubkey,
+    pub last_signal: Pubkey,   // Most recently closed signal on the token
+    pub last_closed_at: i64,   // 0 until a signal on the token closes
+    pub bump: u8,
+}
+
+/// Place of a reopened signal in its token's chain of attempts
+#[account]
+#[derive(InitSpace)]
+pub struct SignalLineage {
+    pub signal: Pubkey,
+    pub parent: Pubkey,          // Attempt this one re-enters
+    pub root: Pubkey,            // First attempt of the chain
+    pub attempt: u16,            // 1-based; reopened signals start at 2
+    pub prior_roi_bps: i64,      // Compounded ROI of every earlier attempt
+    pub bump: u8,
+}
+
+/// Entries a signal scaled into after publish
+#[account]
+#[derive(InitSpace)]
+pub struct SignalEntries {
+    pub signal: Pubkey,
+    pub initial_price: u64,      // Entry price the signal was published with
+    #[max_len(MAX_SIGNAL_ENTRIES)]
+    pub entries: Vec<SignalEntry>, // Oldest first
+    pub bump: u8,
+}
+
+impl SignalEntries {
+    /// Share of the position left to the publish entry
+    pub fn initial_weight_bps(&self) -> u16 {
+        let added: u32 = self.entries.iter().map(|entry| entry.weight_bps as u32).sum();
+        10_000u32.saturating_sub(added) as u16
+    }
+    
+    /// Entry price averaged over the publish entry and every added one
+    pub fn average_price(&self) -> u64 {
+        let added = self.entries.iter().map(|entry| (entry.weight_bps, entry.price));
+        oracle_math::average_price(std::iter::once((self.initial_weight_bps(), self.initial_price)).chain(added))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct SignalEntry {
+    pub price: u64,
+    pub weight_bps: u16,         // Share of the final position bought at `price`
+    pub timestamp: i64,
+}
+
+/// Tranches a signal's entry is bought in, and which of them have run
+#[account]
+#[derive(InitSpace)]
+pub struct DcaPlan {
+    pub signal: Pubkey,
+    pub tranches: u8,
+    pub tranche_bps: u16,        // Share of the position each tranche buys
+    pub interval_secs: i64,      // Between consecutive tranches
+    pub start: i64,              // When tranche 0 comes due
+    pub executed: u16,           // Bit i set once tranche i ran
+    pub bump: u8,
+}
+
+impl DcaPlan {
+    /// Earliest tranche due at `now` that hasn't run
+    pub fn next_due(&self, now: i64) -> Option<u8> {
+        oracle_math::next_dca_tranche(self.start, self.interval_secs, self.tranches, self.executed, now)
+    }
+}
+
+/// Exit levels a signal takes profit at, settled one by one
+#[account]
+#[derive(InitSpace)]
+pub struct TakeProfitLadder {
+    pub signal: Pubkey,
+    #[max_len(MAX_TAKE_PROFIT_LEVELS)]
+    pub levels: Vec<TakeProfitLevel>, // Nearest the entry first
+    pub realized_roi_bps: i64,   // Position ROI locked in by settled levels
+    pub exited_bps: u16,         // Share of the position settled levels exited
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct TakeProfitLevel {
+    pub price: u64,
+    pub exit_bps: u16,           // Share of the position exited at `price`
+    pub settled_at: i64,         // 0 until the level is hit
+}
+
+/// One take-profit level being set
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TakeProfitParams {
+    pub price: u64,
+    pub exit_bps: u16,
+}
+
+/// Price past which anyone may close a signal as failed
+#[account]
+#[derive(InitSpace)]
+pub struct Invalidation {
+    pub signal: Pubkey,
+    pub price: u64,
+    pub bump: u8,
+}
+
+/// Simulated follower with a fixed bankroll and a cap on concurrent
+/// positions, so its NAV reflects sizing and overlap rather than a single
+/// compounding chain
+#[account]
+#[derive(InitSpace)]
+pub struct ModelPortfolio {
+    pub max_positions: u8,
+    pub next_id: u64,            // Next signal to consider entering
+    pub cash: u64,
+    pub nav: u64,                // Cash plus open positions at cost
+    pub peak_nav: u64,
+    pub max_drawdown_bps: u16,   // Deepest fall of NAV from its peak
+    #[max_len(MAX_PORTFOLIO_POSITIONS)]
+    pub positions: Vec<PortfolioPosition>,
+    pub skipped: u64,            // Signals passed over: full, already settled or compressed
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct PortfolioPosition {
+    pub signal: Pubkey,
+    pub size: u64,               // Cash committed at entry
+}
+
+impl ModelPortfolio {
+    /// Open a position in `signal` if a slot and cash are free, returning
+    /// its size
+    pub fn open_position(&mut self, signal: Pubkey) -> Option<u64> {
+        if self.positions.len() >= self.max_positions as usize {
+            return None;
+        }
+        let size = (self.nav / self.max_positions as u64).min(self.cash);
+        if size == 0 {
+            return None;
+        }
+        self.cash -= size;
+        self.positions.push(PortfolioPosition { signal, size });
+        Some(size)
+    }
+
+    /// Close the position in `signal` at `roi_bps`, returning the proceeds
+    pub fn close_position(&mut self, signal: &Pubkey, roi_bps: i64) -> Result<u64> {
+        let index = self
+            .positions
+            .iter()
+            .position(|position| position.signal == *signal)
+            .ok_or(OracleError::PositionNotHeld)?;
+        let size = self.positions.swap_remove(index).size;
+        let growth = (oracle_math::BPS as i128 + roi_bps as i128).max(0) as u128;
+        let proceeds = (size as u128 * growth / oracle_math::BPS as u128).min(u64::MAX as u128) as u64;
+        
+        self.cash = self.cash.saturating_add(proceeds);
+        self.nav = self.nav.saturating_sub(size).saturating_add(proceeds);
+        self.peak_nav = self.peak_nav.max(self.nav);
+        let drawdown_bps = oracle_math::drawdown_bps(self.peak_nav, self.nav, Direction::Long) as u16;
+        self.max_drawdown_bps = self.max_drawdown_bps.max(drawdown_bps);
+        Ok(proceeds)
+    }
+
+    /// Share of NAV committed to open positions, in basis points
+    pub fn exposure_bps(&self) -> u16 {
+        let committed: u128 = self.positions.iter().map(|position| position.size as u128).sum();
+        if self.nav == 0 {
+            return 0;
+        }
+        (committed * oracle_math::BPS as u128 / self.nav as u128).min(oracle_math::BPS as u128) as u16
+    }
+}
+
+/// Benchmark prices at a signal's entry and exit, by benchmark index, so
+/// its alpha can be recomputed from on-chain data
+#[account]
+#[derive(InitSpace)]
+pub struct SignalBenchmark {
+    pub signal: Pubkey,
+    pub entry_prices: [u64; BENCHMARK_COUNT], // 0 until recorded
+    pub exit_prices: [u64; BENCHMARK_COUNT],  // 0 until recorded
+    pub bump: u8,
+}
+
+/// Proof that `subscriber` held `signal` at `slot`, before its reveal
+#[account]
+#[derive(InitSpace)]
+pub struct AccessClaim {
+    pub signal: Pubkey,
+    pub subscriber: Pubkey,
+    pub slot: u64,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+/// Subscription tier restricted to approved wallets; its existence puts
+/// the tier in allowlist mode
+#[account]
+#[derive(InitSpace)]
+pub struct TierAllowlist {
+    pub tier: u8,
+    pub capacity: u32,           // Most wallets that can be allowlisted at once
+    pub count: u32,              // Wallets currently allowlisted
+    pub bump: u8,
+}
+
+/// Approval for `wallet` to subscribe to allowlisted `tier`
+#[account]
+#[derive(InitSpace)]
+pub struct AllowlistEntry {
+    pub tier: u8,
+    pub wallet: Pubkey,
+    pub bump: u8,
+}
+
+/// A follower's vault shares and the flows they have queued for the
+/// current epoch
+#[account]
+#[derive(InitSpace)]
+pub struct VaultDepositor {
+    pub owner: Pubkey,
+    pub shares: u64,             // Settled shares, excluding any queued for withdrawal
+    pub queued_deposit: u64,     // Base waiting to buy shares at queued_epoch's end
+    pub queued_withdrawal_shares: u64, // Shares waiting to be redeemed at queued_epoch's end
+    pub queued_epoch: u64,
+    pub entered: u64,            // This follower's part of the vault's entries, in base
+    pub realized_pnl: i64,       // This follower's part of the vault's realized spot PnL
+    pub entered_checkpoint: i128, // Vault accumulators as of the last accrual
+    pub realized_pnl_checkpoint: i128,
+    pub emergency_owed: u64,     // Redeemed in emergency but not yet paid out of liquid base
+    pub bump: u8,
+}
+
+impl VaultDepositor {
+    pub fn has_queued(&self) -> bool {
+        self.queued_deposit > 0 || self.queued_withdrawal_shares > 0
+    }
+
+    /// Shares counted in the vault's total: settled ones and those queued
+    /// for withdrawal, which stay in until their epoch closes
+    pub fn held_shares(&self) -> u64 {
+        self.shares + self.queued_withdrawal_shares
+    }
+
+    /// Credit held shares with the accumulators' growth since the last
+    /// checkpoint, then move the checkpoint up to them
+    fn accrue(&mut self, entered_per_share: i128, realized_pnl_per_share: i128) {
+        let held = self.held_shares();
+        self.entered += vault::attributed(held, entered_per_share - self.entered_checkpoint) as u64;
+        self.realized_pnl += vault::attributed(held, realized_pnl_per_share - self.realized_pnl_checkpoint);
+        self.entered_checkpoint = entered_per_share;
+        self.realized_pnl_checkpoint = realized_pnl_per_share;
+    }
+
+    /// Queue into `epoch`; flows from an earlier epoch must be settled first
+    fn queue(&mut self, epoch: u64) -> Result<()> {
+        require!(!self.has_queued() || self.queued_epoch == epoch, OracleError::UnsettledVaultFlows);
+        self.queued_epoch = epoch;
+        Ok(())
+    }
+}
+
+/// How a processed vault epoch priced its queued flows
+#[account]
+#[derive(InitSpace)]
+pub struct VaultEpoch {
+    pub epoch: u64,
+    pub equity: u64,             // NAV net of queued deposits and unclaimed payouts
+    pub total_shares: u64,       // Shares outstanding before the epoch's flows
+    pub deposits: u64,
+    pub minted_shares: u64,
+    pub withdrawal_shares: u64,
+    pub payout: u64,             // Base owed to the epoch's withdrawals
+    pub entered_per_share: i128, // Vault accumulators when the epoch closed
+    pub realized_pnl_per_share: i128,
+    pub refunded_deposits: u64,  // Deposits handed back because shares had no value to buy at
+    pub bump: u8,
+}
+
+// === EVENTS ===
 
 #[event]
 pub struct SignalPublished {
@@ -307,6 +8188,7 @@ pub struct SignalPublished {
     pub token: Pubkey,
     pub score: u8,
     pub timestamp: i64,
+    pub publisher_domain: String, // Verified `.sol` name, empty if none linked
 }
 
 #[event]
@@ -323,6 +8205,7 @@ pub struct SignalPublishedWithProof {
     pub score: u8,
     pub reasoning_hash: [u8; 32],
     pub timestamp: i64,
+    pub publisher_domain: String,
 }
 
 #[event]
@@ -332,20 +8215,728 @@ pub struct ReasoningRevealed {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct PriceChecked {
+    pub id: u64,
+    pub pool: Pubkey,
+    pub claimed_price: u64,
+    pub pool_price: u64,
+    pub deviation_bps: u64,
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SignalExecuted {
+    pub id: u64,
+    pub side: ExecutionSide,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub min_amount_out: u64,
+}
+
+#[event]
+pub struct ShortExecuted {
+    pub id: u64,
+    pub side: ExecutionSide,
+    pub market_index: u16,
+    pub base_asset_amount: u64,
+    pub limit_price: u64,
+    pub notional: u64,
+}
+
+#[event]
+pub struct VaultLendingChanged {
+    pub deposited: u64,
+    pub withdrawn: u64,
+    pub lent_amount: u64,
+    pub liquid_amount: u64,
+}
+
+#[event]
+pub struct AccessPassVerified {
+    pub owner: Pubkey,
+    pub asset_id: Pubkey,
+    pub tree: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Subscribed {
+    pub subscriber: Pubkey,
+    pub tier: u8,
+    pub expires_at: i64,
+    pub paid_lamports: u64,
+}
+
+#[event]
+pub struct SubscriberPassChanged {
+    pub subscriber: Pubkey,
+    pub minted: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PublisherDomainLinked {
+    pub publisher: Pubkey,
+    pub domain: String,
+    pub domain_account: Pubkey,
+}
+
+/// Lightweight alert payload for notification services (Dialect/Telegram bridges)
+#[event]
+pub struct SignalAlert {
+    pub id: u64,
+    pub token: Pubkey,
+    pub symbol: String,
+    pub score: u8,
+    pub direction: SignalDirection,
+    pub tier: u8,               // Minimum subscription tier entitled to this alert
+}
+
+#[event]
+pub struct ThreadManaged {
+    pub thread: Pubkey,
+    pub action: ThreadAction,
+    pub lamports: u64,
+}
+
+#[event]
+pub struct ExecutionScheduled {
+    pub id: u64,
+    pub commit_hash: [u8; 32],
+    pub valid_from_slot: u64,
+    pub valid_until_slot: u64,
+}
+
+#[event]
+pub struct CompressedSignalWritten {
+    pub id: u64,
+    pub leaf_index: u32,
+    pub leaf: [u8; 32],
+}
+
+#[event]
+pub struct SignalAssetMinted {
+    pub id: u64,
+    pub asset: Pubkey,
+    pub owner: Pubkey,
+    pub royalty_bps: u16,
+}
+
+#[event]
+pub struct SignalNoteAdded {
+    pub id: u64,
+    pub index: u32,
+    pub timestamp: i64,
+    pub text: String,
+}
+
+#[event]
+pub struct SignalRated {
+    pub id: u64,
+    pub subscriber: Pubkey,
+    pub rating: u8,
+}
+
+#[event]
+pub struct SignalBoosted {
+    pub id: u64,
+    pub booster: Pubkey,
+    pub lamports: u64,
+    pub heat: u64,
+    pub featured: bool,
+}
+
+#[event]
+pub struct FeaturedBidPlaced {
+    pub epoch: u64,
+    pub signal: Pubkey,
+    pub bidder: Pubkey,
+    pub lamports: u64,
+    pub refunded: Option<Pubkey>, // Bidder whose escrow was returned
+}
+
+#[event]
+pub struct FeaturedAuctionSettled {
+    pub epoch: u64,
+    pub winners: u8,
+    pub proceeds: u64,
+}
+
+#[event]
+pub struct BasketPublished {
+    pub id: u64,
+    pub name: String,
+    pub members: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BasketClosed {
+    pub id: u64,
+    pub status: SignalStatus,
+    pub roi_bps: i64,
+}
+
+#[event]
+pub struct PairPublished {
+    pub id: u64,
+    pub long_token: Pubkey,
+    pub short_token: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PairClosed {
+    pub id: u64,
+    pub status: SignalStatus,
+    pub roi_bps: i64,
+}
+
+#[event]
+pub struct PerpParamsSet {
+    pub id: u64,
+    pub leverage_bps: u32,
+    pub liquidation_price: u64,
+    pub market_index: u16,
+}
+
+#[event]
+pub struct SignalSized {
+    pub id: u64,
+    pub size_bps: u16,
+}
+
+#[event]
+pub struct MarketCapVerified {
+    pub id: u64,
+    pub claimed_mcap: u64,
+    pub verified_mcap: u64,
+    pub discrepancy_bps: u64,
+    pub flagged: bool,
+}
+
+#[event]
+pub struct SupplyChanged {
+    pub id: u64,
+    pub entry_supply: u64,
+    pub exit_supply: u64,
+    pub supply_change_bps: i64,
+    pub flagged: bool,
+}
+
+#[event]
+pub struct HolderDistributionCommitted {
+    pub id: u64,
+    pub snapshot_hash: [u8; 32],
+    pub top_holders_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SignalRepublished {
+    pub id: u64,
+    pub token: Pubkey,
+    pub parent: Pubkey,
+}
+
+#[event]
+pub struct SignalReopened {
+    pub id: u64,
+    pub parent: Pubkey,
+    pub root: Pubkey,
+    pub attempt: u16,
+    pub prior_roi_bps: i64,
+}
+
+#[event]
+pub struct EntryAdded {
+    pub id: u64,
+    pub price: u64,
+    pub weight_bps: u16,
+    pub entry_price: u64,        // Signal's averaged entry price after the add
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DcaPlanSet {
+    pub id: u64,
+    pub tranches: u8,
+    pub tranche_bps: u16,
+    pub interval_secs: i64,
+    pub start: i64,
+}
+
+#[event]
+pub struct DcaTrancheExecuted {
+    pub id: u64,
+    pub tranche: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TakeProfitsSet {
+    pub id: u64,
+    pub levels: Vec<TakeProfitLevel>,
+}
+
+#[event]
+pub struct TakeProfitHit {
+    pub id: u64,
+    pub level: u8,
+    pub price: u64,
+    pub pool_price: u64,
+    pub realized_roi_bps: i64,
+    pub exited_bps: u16,
+}
+
+#[event]
+pub struct InvalidationPriceSet {
+    pub id: u64,
+    pub price: u64,
+}
+
+#[event]
+pub struct SignalInvalidated {
+    pub id: u64,
+    pub invalidation_price: u64,
+    pub pool_price: u64,
+}
+
+#[event]
+pub struct SignalMarked {
+    pub id: u64,
+    pub price: u64,
+    pub unrealized_roi_bps: i64,
+}
+
+#[event]
+pub struct PortfolioEntered {
+    pub id: u64,
+    pub size: u64,               // 0 when the signal was skipped
+    pub cash: u64,
+    pub exposure_bps: u16,
+}
+
+#[event]
+pub struct PortfolioExited {
+    pub id: u64,
+    pub proceeds: u64,
+    pub nav: u64,
+    pub max_drawdown_bps: u16,
+    pub exposure_bps: u16,
+}
+
+#[event]
+pub struct BenchmarkRecorded {
+    pub id: u64,
+    pub benchmark: u8,
+    pub price: u64,
+    pub at_exit: bool,
+}
+
+#[event]
+pub struct VaultDerisked {
+    pub nav: u64,
+    pub peak_nav: u64,
+    pub drawdown_bps: u16,
+}
+
+#[event]
+pub struct VaultDeriskReset {
+    pub nav: u64,
+}
+
+#[event]
+pub struct AccessClaimed {
+    pub id: u64,
+    pub subscriber: Pubkey,
+    pub slot: u64,
+}
+
+/// Audit record of a reasoning document released to a subscriber
+#[event]
+pub struct AccessLogged {
+    pub id: u64,
+    pub subscriber_hash: [u8; 32],
+    pub reasoning_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TierAllowlistSet {
+    pub tier: u8,
+    pub capacity: u32,
+    pub count: u32,
+}
+
+#[event]
+pub struct AllowlistChanged {
+    pub tier: u8,
+    pub wallet: Pubkey,
+    pub added: bool,
+    pub count: u32,
+}
+
+#[event]
+pub struct SubscriptionTransferred {
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub tier: u8,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct SubscriptionPauseChanged {
+    pub subscriber: Pubkey,
+    pub paused: bool,
+    pub expires_at: i64,
+    pub remaining_secs: i64,
+}
+
+#[event]
+pub struct VaultFlowQueued {
+    pub owner: Pubkey,
+    pub epoch: u64,
+    pub deposit: u64,
+    pub withdrawal_shares: u64,
+}
+
+#[event]
+pub struct VaultEpochProcessed {
+    pub epoch: u64,
+    pub equity: u64,
+    pub deposits: u64,
+    pub minted_shares: u64,
+    pub withdrawal_shares: u64,
+    pub payout: u64,
+    pub total_shares: u64,
+    pub refunded_deposits: u64,
+}
+
+#[event]
+pub struct VaultDepositorSettled {
+    pub owner: Pubkey,
+    pub epoch: u64,
+    pub shares: u64,
+    pub payout: u64,
+    pub refund: u64, // Deposit handed back by an epoch that refunded its deposits
+}
+
+/// Per-share attribution of one spot execution; a follower's part of it is
+/// their held shares times these
+#[event]
+pub struct ExecutionAttributed {
+    pub id: u64,
+    pub side: ExecutionSide,
+    pub total_shares: u64,
+    pub entered_per_share: i128,
+    pub realized_pnl_per_share: i128,
+}
+
+#[event]
+pub struct VaultDepositorAccrued {
+    pub owner: Pubkey,
+    pub shares: u64,
+    pub entered: u64,
+    pub realized_pnl: i64,
+}
+
+#[event]
+pub struct VaultStakingChanged {
+    pub staked_lamports: u64,
+    pub unstaked_pool_tokens: u64,
+    pub lst_amount: u64,
+    pub lst_lamports: u64,       // LST value at the pool's exchange rate
+}
+
+#[event]
+pub struct VaultEmergencyDeclared {
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultEmergencyWithdrawal {
+    pub owner: Pubkey,
+    pub shares: u64,
+    pub payout: u64,
+    pub refund: u64,             // Queued deposit handed back
+    pub owed: u64,               // Still owed once this payout is made
+}
+
+#[event]
+pub struct VaultRebalanced {
+    pub id: u64,
+    pub side: ExecutionSide,
+    pub weight_bps: u64,         // Position's weight before the trade
+    pub target_bps: u16,
+    pub amount_in: u64,
+    pub amount_out: u64,
+}
+
+#[event]
+pub struct VaultFeesCrystallized {
+    pub epoch: u64,
+    pub equity: u64,             // Pre-fee equity the fee was charged on
+    pub fee: u64,
+    pub high_water_mark: u128,
+}
+
 // === ERRORS ===
 
+/// Codes are stable: new variants take the next number, existing ones are never renumbered
 #[error_code]
 pub enum OracleError {
     #[msg("Unauthorized")]
-    Unauthorized,
-    #[msg("Symbol too long (max 10 chars)")]
-    SymbolTooLong,
+    Unauthorized = 0,
+    #[msg("Symbol is longer than the configured max")]
+    SymbolTooLong = 1,
     #[msg("Invalid score (must be 0-100)")]
-    InvalidScore,
+    InvalidScore = 2,
     #[msg("Signal already closed")]
-    SignalAlreadyClosed,
+    SignalAlreadyClosed = 3,
     #[msg("Reasoning already revealed")]
-    ReasoningAlreadyRevealed,
+    ReasoningAlreadyRevealed = 4,
     #[msg("No reasoning commitment exists for this signal")]
-    NoReasoningCommitment,
+    NoReasoningCommitment = 5,
+    #[msg("Pool is not owned by a supported DEX program")]
+    UnsupportedPool = 6,
+    #[msg("Pool account data is invalid")]
+    InvalidPoolAccount = 7,
+    #[msg("Pool does not trade the signal's token")]
+    PoolMintMismatch = 8,
+    #[msg("Pool price overflowed")]
+    PriceOverflow = 9,
+    #[msg("Price deviates too far from the pool spot price")]
+    PriceDeviationTooLarge = 10,
+    #[msg("Entry price can no longer be checked against a pool")]
+    PriceCheckWindowElapsed = 11,
+    #[msg("Invalid risk level")]
+    InvalidRiskLevel = 12,
+    #[msg("Basis points value out of range")]
+    InvalidBps = 13,
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount = 14,
+    #[msg("Vault position is smaller than the requested exit")]
+    InsufficientPosition = 15,
+    #[msg("Swap spent more than the requested input")]
+    SwapInputExceeded = 16,
+    #[msg("Swap output is below the minimum allowed by slippage config")]
+    SlippageExceeded = 17,
+    #[msg("Signal is not a short signal")]
+    NotAShortSignal = 18,
+    #[msg("Perp market does not match the open position")]
+    MarketMismatch = 19,
+    #[msg("Position would exceed the max leverage for this risk level")]
+    LeverageExceeded = 20,
+    #[msg("Lending would leave the vault below its reserve ratio")]
+    ReserveRatioBreached = 21,
+    #[msg("Merkle tree is not the oracle's access pass tree")]
+    InvalidPassTree = 22,
+    #[msg("Invalid subscription tier")]
+    InvalidTier = 23,
+    #[msg("Cannot change tier while the subscription is active")]
+    TierChangeWhileActive = 24,
+    #[msg("Subscription has expired")]
+    SubscriptionExpired = 25,
+    #[msg("Subscription is still active")]
+    SubscriptionStillActive = 26,
+    #[msg("Subscriber already holds a pass")]
+    PassAlreadyHeld = 27,
+    #[msg("Domain must be 1-32 chars (without .sol)")]
+    DomainTooLong = 28,
+    #[msg("Account is not the SNS name account for this domain")]
+    InvalidDomainAccount = 29,
+    #[msg("Domain is not owned by the publisher's wallet")]
+    DomainNotOwned = 30,
+    #[msg("Thread instructions must be signer-free cranks of this program")]
+    InvalidThreadInstruction = 31,
+    #[msg("Thread account does not match the treasury's thread")]
+    InvalidThreadAccount = 32,
+    #[msg("Slot window is empty")]
+    InvalidSlotWindow = 33,
+    #[msg("An execution is already pending for this signal")]
+    ExecutionAlreadyScheduled = 34,
+    #[msg("Execution is outside its slot window")]
+    OutsideSlotWindow = 35,
+    #[msg("Execution parameters don't match the commitment")]
+    CommitmentMismatch = 36,
+    #[msg("Execution ticket was already used")]
+    TicketAlreadyExecuted = 37,
+    #[msg("Operation not available in this oracle's storage mode")]
+    WrongStorageMode = 38,
+    #[msg("Merkle tree is not the oracle's signal tree")]
+    InvalidSignalTree = 39,
+    #[msg("Signal score is below the elite threshold")]
+    ScoreBelowElite = 40,
+    #[msg("Account is already in the current layout")]
+    AlreadyMigrated = 41,
+    #[msg("Account is not a signal of this oracle")]
+    InvalidSignalAccount = 42,
+    #[msg("Score half-life cannot be negative")]
+    InvalidHalfLife = 43,
+    #[msg("Max symbol length is out of range")]
+    InvalidSymbolLimit = 44,
+    #[msg("Note must be 1 to 140 bytes")]
+    NoteTooLong = 45,
+    #[msg("Signal already has the maximum number of notes")]
+    TooManyNotes = 46,
+    #[msg("Rating must be 1 to 5")]
+    InvalidRating = 47,
+    #[msg("Signal has not been closed yet")]
+    SignalStillOpen = 48,
+    #[msg("Bids are only open for the next auction epoch")]
+    AuctionClosed = 49,
+    #[msg("Bid does not beat the bid it would replace")]
+    BidTooLow = 50,
+    #[msg("Refund account is not the outbid bidder")]
+    InvalidRefundAccount = 51,
+    #[msg("Auction epoch has not started yet")]
+    AuctionStillOpen = 52,
+    #[msg("Auction was already settled")]
+    AuctionAlreadySettled = 53,
+    #[msg("Basket needs 1 to 8 distinct priced tokens with weights summing to 10_000 bps")]
+    InvalidBasket = 54,
+    #[msg("Basket name must be 1 to 32 bytes")]
+    BasketNameTooLong = 55,
+    #[msg("Pair legs need distinct tokens and nonzero entry prices")]
+    InvalidPair = 56,
+    #[msg("Supply snapshot was already recorded")]
+    SupplySnapshotTaken = 57,
+    #[msg("Holder commitment needs a nonzero hash and at most 10000 bps")]
+    InvalidHolderCommitment = 58,
+    #[msg("Token's last signal closed within the republish cooldown")]
+    RepublishCooldown = 59,
+    #[msg("Parent signal must be a closed signal on the same token")]
+    InvalidParentSignal = 60,
+    #[msg("Cooldown must not be negative")]
+    InvalidCooldown = 61,
+    #[msg("Quote mint must be set and differ from the token")]
+    InvalidQuoteMint = 62,
+    #[msg("Price is quoted in a different mint than the signal")]
+    QuoteMismatch = 63,
+    #[msg("Win thresholds must all be above zero")]
+    InvalidWinThreshold = 64,
+    #[msg("Entry needs a nonzero price and a weight within the publish entry's share")]
+    InvalidEntry = 65,
+    #[msg("Signal already holds the most entries allowed")]
+    TooManyEntries = 66,
+    #[msg("DCA plan needs 2 to 16 tranches, a positive interval and at most 10000 bps in total")]
+    InvalidDcaPlan = 67,
+    #[msg("No DCA tranche is due")]
+    NoDcaTrancheDue = 68,
+    #[msg("Take-profit levels need 1 to 5 nonzero exits past the entry, each further out, at most 10000 bps in all")]
+    InvalidTakeProfits = 69,
+    #[msg("Take-profit level was already settled")]
+    TakeProfitSettled = 70,
+    #[msg("Pool price has not reached the take-profit level")]
+    TakeProfitNotReached = 71,
+    #[msg("Pool is not the signal's verified price pool")]
+    PoolNotVerified = 72,
+    #[msg("Invalidation price must be nonzero and far enough past the entry to close as a loss")]
+    InvalidInvalidationPrice = 73,
+    #[msg("Pool price has not crossed the invalidation price")]
+    InvalidationNotReached = 74,
+    #[msg("Mark accounts must come in groups of signal, pool, mint A and mint B, with writable signals")]
+    InvalidMarkAccounts = 75,
+    #[msg("Model portfolio needs a bankroll and 1 to 20 positions")]
+    InvalidPortfolio = 76,
+    #[msg("Model portfolio holds no position in this signal")]
+    PositionNotHeld = 77,
+    #[msg("Model portfolio has considered every published signal")]
+    PortfolioUpToDate = 78,
+    #[msg("Benchmark index is out of range or its pool is not configured")]
+    InvalidBenchmark = 79,
+    #[msg("Benchmark price was already recorded")]
+    BenchmarkRecorded = 80,
+    #[msg("Vault is de-risked and new entries are halted")]
+    VaultHalted = 81,
+    #[msg("Entry exceeds the de-risked vault's size cap")]
+    DeriskCapExceeded = 82,
+    #[msg("Vault is not de-risked")]
+    VaultNotDerisked = 83,
+    #[msg("Allowlist capacity must be positive and cover the wallets already on it")]
+    InvalidAllowlistCapacity = 84,
+    #[msg("Tier allowlist is at capacity")]
+    AllowlistFull = 85,
+    #[msg("Wallet is not on the tier's allowlist")]
+    NotAllowlisted = 86,
+    #[msg("Subscription transfers are disabled")]
+    TransfersDisabled = 87,
+    #[msg("Subscription was transferred too recently")]
+    TransferCooldown = 88,
+    #[msg("Recipient already has an active subscription")]
+    RecipientSubscribed = 89,
+    #[msg("Recipient must be another wallet")]
+    InvalidRecipient = 90,
+    #[msg("Subscription is paused")]
+    SubscriptionPaused = 91,
+    #[msg("Subscription is not paused")]
+    SubscriptionNotPaused = 92,
+    #[msg("Subscription has used all its pauses")]
+    PauseLimitReached = 93,
+    #[msg("Not enough vault shares")]
+    InsufficientShares = 94,
+    #[msg("Settle flows queued in an earlier epoch first")]
+    UnsettledVaultFlows = 95,
+    #[msg("Vault epoch has not run its length yet")]
+    EpochNotOver = 96,
+    #[msg("Vault NAV has not been reported since the epoch ended")]
+    StaleVaultNav = 97,
+    #[msg("Depositor has nothing queued")]
+    NothingQueued = 98,
+    #[msg("Entry would spend base reserved for queued deposits or payouts")]
+    VaultFundsReserved = 99,
+    #[msg("Vault staking is not configured")]
+    StakingDisabled = 100,
+    #[msg("Account is not the configured stake pool or its LST")]
+    InvalidStakePool = 101,
+    #[msg("Stake pool has not been updated this epoch")]
+    StakePoolNotUpdated = 102,
+    #[msg("Vault LST value is stale; sync it before reporting NAV")]
+    StaleLstValue = 103,
+    #[msg("Vault is in emergency exit mode")]
+    VaultInEmergency = 104,
+    #[msg("Vault is not in emergency exit mode")]
+    VaultNotInEmergency = 105,
+    #[msg("Entry would take the signal past its risk level's vault allocation cap")]
+    AllocationCapExceeded = 106,
+    #[msg("Position weight is within its rebalance band")]
+    WithinRebalanceBand = 107,
+    #[msg("Rebalance trade exceeds the gap to target or the per-call limit")]
+    RebalanceTooLarge = 108,
+    #[msg("Vault fees for this epoch are already crystallized")]
+    FeesAlreadyCrystallized = 109,
+    #[msg("Crystallize the epoch's performance fee before processing its flows")]
+    FeesNotCrystallized = 110,
+    #[msg("Pool holds too little quote liquidity to price against")]
+    PoolLiquidityTooLow = 111,
+    #[msg("Exit must be checked against the pool the entry was verified on")]
+    ExitPoolMismatch = 112,
+    #[msg("Lending deposit didn't move the requested amount out of the vault")]
+    LendingAmountMismatch = 113,
+    #[msg("Subscriber pass account is missing or for another mint")]
+    PassAccountRequired = 114,
+    #[msg("Vault epoch has run its length; flows reopen once it is processed")]
+    VaultEpochClosing = 115,
+    #[msg("Vault NAV has not been reported since the emergency was declared")]
+    StaleEmergencyNav = 116,
+    #[msg("Spot entries are for long signals; shorts execute on perps")]
+    NotALongSignal = 117,
+    #[msg("The signal's entry supply was snapshotted; pass its mint")]
+    SupplyMintMissing = 118,
+    #[msg("Reported NAV is too far from the vault's measured value")]
+    NavOutOfRange = 119,
+    #[msg("Reported NAV moves the share price more than allowed")]
+    NavChangeTooLarge = 120,
+    #[msg("NAV reports need each open vault position once, in address order")]
+    InvalidNavAccounts = 121,
+    #[msg("The vault holds SOL; pass the SOL benchmark pool")]
+    SolPoolRequired = 122,
 }
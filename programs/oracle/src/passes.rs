@@ -0,0 +1,40 @@
+//! Subscriber access passes.
+//!
+//! Passes are issued as Bubblegum compressed NFTs in a tree owned by the
+//! oracle. Holding one is proven by recomputing the cNFT's leaf and having
+//! the account-compression program check it against the tree's root (see
+//! [`crate::compression::verify_leaf`]).
+
+use anchor_lang::prelude::*;
+use solana_keccak_hasher::hashv;
+
+/// Metaplex Bubblegum
+pub const BUBBLEGUM_PROGRAM_ID: Pubkey = pubkey!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+/// Bubblegum `LeafSchema::V1`
+const LEAF_SCHEMA_V1: u8 = 1;
+
+/// Asset id Bubblegum assigns to the `nonce`-th leaf minted into `tree`
+pub fn asset_id(tree: &Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(&[b"asset", tree.as_ref(), &nonce.to_le_bytes()], &BUBBLEGUM_PROGRAM_ID).0
+}
+
+/// Leaf hash of a V1 Bubblegum cNFT
+pub fn leaf_hash(
+    asset_id: &Pubkey,
+    owner: &Pubkey,
+    delegate: &Pubkey,
+    nonce: u64,
+    data_hash: &[u8; 32],
+    creator_hash: &[u8; 32],
+) -> [u8; 32] {
+    hashv(&[
+        &[LEAF_SCHEMA_V1],
+        asset_id.as_ref(),
+        owner.as_ref(),
+        delegate.as_ref(),
+        &nonce.to_le_bytes(),
+        data_hash,
+        creator_hash,
+    ])
+    .to_bytes()
+}
@@ -0,0 +1,62 @@
+//! Meteora DLMM (discrete liquidity bins)
+//!
+//! A DLMM pair doesn't store a price directly: the price of token X in
+//! token Y is `(1 + bin_step / 10_000) ^ active_id`.
+
+use anchor_lang::prelude::*;
+
+use super::{mul_div, mul_q64, read_i32, read_pubkey, read_u16, PoolAdapter, RawPool, Q64};
+use crate::OracleError;
+
+const DISCRIMINATOR: [u8; 8] = [33, 11, 49, 98, 181, 101, 177, 13];
+const ACTIVE_ID_OFFSET: usize = 76;
+const BIN_STEP_OFFSET: usize = 80;
+const TOKEN_X_MINT_OFFSET: usize = 88;
+const TOKEN_Y_MINT_OFFSET: usize = 120;
+const RESERVE_X_OFFSET: usize = 152;
+const RESERVE_Y_OFFSET: usize = 184;
+
+pub struct MeteoraDlmm;
+
+impl PoolAdapter for MeteoraDlmm {
+    const PROGRAM_ID: Pubkey = pubkey!("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo");
+
+    fn decode(data: &[u8]) -> Result<RawPool> {
+        require!(
+            data.len() >= RESERVE_Y_OFFSET + 32 && data[..8] == DISCRIMINATOR,
+            OracleError::InvalidPoolAccount
+        );
+
+        let active_id = read_i32(data, ACTIVE_ID_OFFSET);
+        let bin_step = read_u16(data, BIN_STEP_OFFSET);
+        Ok(RawPool {
+            mint_a: read_pubkey(data, TOKEN_X_MINT_OFFSET),
+            mint_b: read_pubkey(data, TOKEN_Y_MINT_OFFSET),
+            vault_a: read_pubkey(data, RESERVE_X_OFFSET),
+            vault_b: read_pubkey(data, RESERVE_Y_OFFSET),
+            price_q64: bin_price(active_id, bin_step).ok_or(OracleError::PriceOverflow)?,
+        })
+    }
+}
+
+/// `(1 + bin_step / 10_000) ^ active_id` in Q64.64, by repeated squaring
+fn bin_price(active_id: i32, bin_step: u16) -> Option<u128> {
+    let mut base = Q64 + (u128::from(bin_step) << 64) / 10_000;
+    let mut exp = active_id.unsigned_abs();
+    let mut result = Q64;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_q64(result, base)?;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = mul_q64(base, base)?;
+        }
+    }
+
+    if active_id < 0 {
+        mul_div(Q64, Q64, result)
+    } else {
+        Some(result)
+    }
+}
@@ -0,0 +1,210 @@
+//! On-chain DEX pool readers used to sanity-check signal prices.
+//!
+//! Pool accounts are decoded straight from their raw data so the program
+//! doesn't need to depend on each DEX's crate. Each DEX lives in its own
+//! submodule implementing [`PoolAdapter`]; the account owner selects the
+//! adapter, and every adapter validates the discriminator before trusting
+//! any bytes.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::{OracleError, MIN_POOL_LIQUIDITY, PRICE_DECIMALS};
+
+pub mod meteora_dlmm;
+pub mod whirlpool;
+
+pub use meteora_dlmm::MeteoraDlmm;
+pub use whirlpool::Whirlpool;
+
+const Q64: u128 = 1 << 64;
+
+/// A DEX whose pool accounts can be decoded into a spot price.
+///
+/// Supporting a new DEX means adding a module that implements this trait
+/// and registering it in [`pool_price`].
+pub trait PoolAdapter {
+    /// Program that owns this DEX's pool accounts
+    const PROGRAM_ID: Pubkey;
+
+    /// Decode the pool's mints, reserve vaults and the price of mint A in
+    /// mint B base units
+    fn decode(data: &[u8]) -> Result<RawPool>;
+}
+
+/// Spot price of a signal's token, read from a pool
+pub struct PoolPrice {
+    /// The pool's other mint (what the price is denominated in)
+    pub quote_mint: Pubkey,
+    /// Quote per token, scaled by 10^PRICE_DECIMALS
+    pub price: u64,
+}
+
+/// Raw pool state: price of `mint_a` in `mint_b` base units, as Q64.64
+pub struct RawPool {
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    /// Token accounts holding the pool's mint A and mint B reserves
+    pub vault_a: Pubkey,
+    pub vault_b: Pubkey,
+    pub price_q64: u128,
+}
+
+/// Read the spot price of `token` from any supported pool.
+///
+/// `mint_a`/`mint_b` must be the pool's mint A and B (token X and Y for
+/// DLMM pairs), in that order, so their decimals can be applied, and
+/// `vault_a`/`vault_b` its reserves of each. A pool holding less than
+/// [`MIN_POOL_LIQUIDITY`] whole units of the quote mint is too thin to
+/// price against.
+pub fn pool_price<'info>(
+    pool: &AccountInfo,
+    mint_a: &InterfaceAccount<Mint>,
+    mint_b: &InterfaceAccount<Mint>,
+    vault_a: &AccountInfo<'info>,
+    vault_b: &AccountInfo<'info>,
+    token: &Pubkey,
+) -> Result<PoolPrice> {
+    let raw = decode_pool(pool.owner, &pool.try_borrow_data()?)?;
+
+    require_keys_eq!(raw.mint_a, mint_a.key(), OracleError::PoolMintMismatch);
+    require_keys_eq!(raw.mint_b, mint_b.key(), OracleError::PoolMintMismatch);
+    require_keys_eq!(raw.vault_a, vault_a.key(), OracleError::InvalidPoolAccount);
+    require_keys_eq!(raw.vault_b, vault_b.key(), OracleError::InvalidPoolAccount);
+
+    let price = token_price(&raw, token, mint_a.decimals, mint_b.decimals)?;
+    let (quote_vault, quote_decimals) = if price.quote_mint == raw.mint_b {
+        (vault_b, mint_b.decimals)
+    } else {
+        (vault_a, mint_a.decimals)
+    };
+    let reserve = TokenAccount::try_deserialize(&mut &quote_vault.try_borrow_data()?[..])?.amount;
+    let min_reserve = 10u128.pow(quote_decimals as u32) * MIN_POOL_LIQUIDITY as u128;
+    require!(reserve as u128 >= min_reserve, OracleError::PoolLiquidityTooLow);
+
+    Ok(price)
+}
+
+/// Decode a pool account with the adapter for its owning program
+pub fn decode_pool(owner: &Pubkey, data: &[u8]) -> Result<RawPool> {
+    if *owner == Whirlpool::PROGRAM_ID {
+        Whirlpool::decode(data)
+    } else if *owner == MeteoraDlmm::PROGRAM_ID {
+        MeteoraDlmm::decode(data)
+    } else {
+        err!(OracleError::UnsupportedPool)
+    }
+}
+
+/// Orient a raw pool price around `token` and scale it to PRICE_DECIMALS
+pub fn token_price(raw: &RawPool, token: &Pubkey, decimals_a: u8, decimals_b: u8) -> Result<PoolPrice> {
+    let (quote_mint, price_q64, base_decimals, quote_decimals) = if *token == raw.mint_a {
+        (raw.mint_b, raw.price_q64, decimals_a, decimals_b)
+    } else if *token == raw.mint_b {
+        require!(raw.price_q64 > 0, OracleError::InvalidPoolAccount);
+        let inverted = mul_div(Q64, Q64, raw.price_q64).ok_or(OracleError::PriceOverflow)?;
+        (raw.mint_a, inverted, decimals_b, decimals_a)
+    } else {
+        return err!(OracleError::PoolMintMismatch);
+    };
+
+    // human price = raw price * 10^(base_decimals - quote_decimals)
+    let exponent = PRICE_DECIMALS as i32 + base_decimals as i32 - quote_decimals as i32;
+    let scaled = if exponent >= 0 {
+        let factor = 10u128.checked_pow(exponent as u32).ok_or(OracleError::PriceOverflow)?;
+        mul_div(price_q64, factor, Q64)
+    } else {
+        let factor = 10u128.checked_pow((-exponent) as u32).ok_or(OracleError::PriceOverflow)?;
+        Q64.checked_mul(factor).and_then(|denom| mul_div(price_q64, 1, denom))
+    }
+    .ok_or(OracleError::PriceOverflow)?;
+
+    Ok(PoolPrice {
+        quote_mint,
+        price: u64::try_from(scaled).map_err(|_| OracleError::PriceOverflow)?,
+    })
+}
+
+/// Deviation of `claimed` from `reference` in basis points
+pub fn deviation_bps(claimed: u64, reference: u64) -> u64 {
+    if reference == 0 {
+        return u64::MAX;
+    }
+    let diff = (claimed as i128 - reference as i128).unsigned_abs();
+    u64::try_from(diff * 10_000 / reference as u128).unwrap_or(u64::MAX)
+}
+
+/// Market cap of `supply` base units of a token with `decimals` at
+/// `price` (PRICE_DECIMALS), in whole units of the price's quote mint
+pub fn market_cap(supply: u64, decimals: u8, price: u64) -> Option<u64> {
+    let scale = 10u128.checked_pow(decimals as u32 + PRICE_DECIMALS as u32)?;
+    u64::try_from(mul_div(supply as u128, price as u128, scale)?).ok()
+}
+
+/// `a * b / denom` with a 256-bit intermediate product
+pub fn mul_div(a: u128, b: u128, denom: u128) -> Option<u128> {
+    if denom == 0 {
+        return None;
+    }
+    let (hi, lo) = full_mul(a, b);
+    if hi >= denom {
+        return None;
+    }
+
+    // Schoolbook long division of hi:lo by denom, one bit at a time
+    let mut rem = hi;
+    let mut quot: u128 = 0;
+    for i in (0..128).rev() {
+        let carry = rem >> 127;
+        rem = (rem << 1) | ((lo >> i) & 1);
+        quot <<= 1;
+        if carry == 1 || rem >= denom {
+            rem = rem.wrapping_sub(denom);
+            quot |= 1;
+        }
+    }
+    Some(quot)
+}
+
+fn full_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+    let ll = a_lo * b_lo;
+    let lh = a_lo * b_hi;
+    let hl = a_hi * b_lo;
+    let hh = a_hi * b_hi;
+
+    let mid = (ll >> 64) + (lh & MASK) + (hl & MASK);
+    let lo = (ll & MASK) | (mid << 64);
+    let hi = hh + (lh >> 64) + (hl >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+/// Fixed-point multiply of two Q64.64 values
+fn mul_q64(a: u128, b: u128) -> Option<u128> {
+    mul_div(a, b, Q64)
+}
+
+fn read_u128(data: &[u8], offset: usize) -> u128 {
+    let mut buf = [0u8; 16];
+    buf.copy_from_slice(&data[offset..offset + 16]);
+    u128::from_le_bytes(buf)
+}
+
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([data[offset], data[offset + 1]])
+}
+
+fn read_i32(data: &[u8], offset: usize) -> i32 {
+    let mut buf = [0u8; 4];
+    buf.copy_from_slice(&data[offset..offset + 4]);
+    i32::from_le_bytes(buf)
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> Pubkey {
+    let mut buf = [0u8; 32];
+    buf.copy_from_slice(&data[offset..offset + 32]);
+    Pubkey::new_from_array(buf)
+}
@@ -0,0 +1,36 @@
+//! Orca Whirlpools (concentrated liquidity)
+
+use anchor_lang::prelude::*;
+
+use super::{mul_q64, read_pubkey, read_u128, PoolAdapter, RawPool};
+use crate::OracleError;
+
+const DISCRIMINATOR: [u8; 8] = [63, 149, 209, 12, 225, 128, 99, 9];
+const SQRT_PRICE_OFFSET: usize = 65;
+const MINT_A_OFFSET: usize = 101;
+const VAULT_A_OFFSET: usize = 133;
+const MINT_B_OFFSET: usize = 181;
+const VAULT_B_OFFSET: usize = 213;
+
+pub struct Whirlpool;
+
+impl PoolAdapter for Whirlpool {
+    const PROGRAM_ID: Pubkey = pubkey!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
+
+    fn decode(data: &[u8]) -> Result<RawPool> {
+        require!(
+            data.len() >= VAULT_B_OFFSET + 32 && data[..8] == DISCRIMINATOR,
+            OracleError::InvalidPoolAccount
+        );
+
+        // sqrt_price is Q64.64, so squaring it gives the Q64.64 price
+        let sqrt_price = read_u128(data, SQRT_PRICE_OFFSET);
+        Ok(RawPool {
+            mint_a: read_pubkey(data, MINT_A_OFFSET),
+            mint_b: read_pubkey(data, MINT_B_OFFSET),
+            vault_a: read_pubkey(data, VAULT_A_OFFSET),
+            vault_b: read_pubkey(data, VAULT_B_OFFSET),
+            price_q64: mul_q64(sqrt_price, sqrt_price).ok_or(OracleError::PriceOverflow)?,
+        })
+    }
+}
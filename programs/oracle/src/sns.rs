@@ -0,0 +1,39 @@
+//! Solana Name Service (.sol domain) ownership checks.
+
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+use crate::OracleError;
+
+/// SPL Name Service program
+pub const NAME_PROGRAM_ID: Pubkey = pubkey!("namesLPneVptA9Z5rqUDD9tMTWEJwofgaYwp8cawRkX");
+/// Root name account of the `.sol` TLD
+pub const SOL_TLD_ROOT: Pubkey = pubkey!("58PwtjSDuFHuUkYjH9BYnnQKHfwo9reZhC2zMJv9JPkx");
+
+const HASH_PREFIX: &str = "SPL Name Service";
+/// Offset of `owner` in the name record header (after `parent_name`)
+const OWNER_OFFSET: usize = 32;
+const HEADER_LEN: usize = 96;
+
+/// Name account for `<name>.sol` (without the `.sol` suffix)
+pub fn domain_account(name: &str) -> Pubkey {
+    let hashed_name = hashv(&[HASH_PREFIX.as_bytes(), name.as_bytes()]).to_bytes();
+    Pubkey::find_program_address(
+        &[&hashed_name, Pubkey::default().as_ref(), SOL_TLD_ROOT.as_ref()],
+        &NAME_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Check that `name_account` is `<name>.sol` and is owned by `owner`
+pub fn verify_domain_owner(name_account: &AccountInfo, name: &str, owner: &Pubkey) -> Result<()> {
+    require_keys_eq!(*name_account.owner, NAME_PROGRAM_ID, OracleError::InvalidDomainAccount);
+    require_keys_eq!(name_account.key(), domain_account(name), OracleError::InvalidDomainAccount);
+
+    let data = name_account.try_borrow_data()?;
+    require!(data.len() >= HEADER_LEN, OracleError::InvalidDomainAccount);
+    let domain_owner = Pubkey::try_from(&data[OWNER_OFFSET..OWNER_OFFSET + 32])
+        .map_err(|_| OracleError::InvalidDomainAccount)?;
+    require_keys_eq!(domain_owner, *owner, OracleError::DomainNotOwned);
+    Ok(())
+}
@@ -0,0 +1,385 @@
+//! Copy-vault execution helpers.
+//!
+//! The vault mirrors published signals with real swaps. Routes are built
+//! off-chain against Jupiter's API and forwarded here as opaque instruction
+//! data; the program never trusts the route itself, only the balance
+//! changes it produces in the vault's own token accounts.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+/// Jupiter aggregator v6
+pub const JUPITER_PROGRAM_ID: Pubkey = pubkey!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4");
+
+/// Minimum acceptable output for a quote after `max_slippage_bps`
+pub fn min_amount_out(quoted_out: u64, max_slippage_bps: u16) -> u64 {
+    let keep_bps = 10_000 - u128::from(max_slippage_bps.min(10_000));
+    (u128::from(quoted_out) * keep_bps / 10_000) as u64
+}
+
+/// Forward a Jupiter route, signing as the vault PDA.
+///
+/// `route_accounts` are passed through in order; the vault is marked as a
+/// signer wherever it appears so it can act as the swap authority.
+pub fn jupiter_swap<'info>(
+    jupiter_program: &AccountInfo<'info>,
+    route_accounts: &[AccountInfo<'info>],
+    route_data: Vec<u8>,
+    vault: &Pubkey,
+    vault_seeds: &[&[u8]],
+) -> Result<()> {
+    invoke_as_vault(jupiter_program, route_accounts, route_data, vault, vault_seeds)
+}
+
+/// CPI into `program` with `accounts` in order, signing as the vault PDA
+fn invoke_as_vault<'info>(
+    program: &AccountInfo<'info>,
+    accounts: &[AccountInfo<'info>],
+    data: Vec<u8>,
+    vault: &Pubkey,
+    vault_seeds: &[&[u8]],
+) -> Result<()> {
+    let metas = accounts
+        .iter()
+        .map(|acc| AccountMeta {
+            pubkey: *acc.key,
+            is_signer: acc.is_signer || acc.key == vault,
+            is_writable: acc.is_writable,
+        })
+        .collect();
+    let ix = Instruction {
+        program_id: *program.key,
+        accounts: metas,
+        data,
+    };
+
+    let mut infos = accounts.to_vec();
+    infos.push(program.clone());
+    invoke_signed(&ix, &infos, &[vault_seeds])?;
+    Ok(())
+}
+
+/// Drift protocol v2 (perpetuals)
+pub const DRIFT_PROGRAM_ID: Pubkey = pubkey!("dRiftyHA39MWEi3m9aunc5MzRF1JYuBsbn6VPcn33UH");
+
+/// Drift's base asset precision (perp position sizes)
+pub const DRIFT_BASE_PRECISION: u128 = 1_000_000_000;
+/// Drift's price precision
+pub const DRIFT_PRICE_PRECISION: u64 = 1_000_000;
+
+/// sha256("global:place_perp_order")[..8]
+const PLACE_PERP_ORDER_DISCRIMINATOR: [u8; 8] = [69, 161, 93, 202, 120, 126, 76, 185];
+
+/// Mirror of Drift's `OrderParams`, encoded the same way for the CPI
+#[derive(AnchorSerialize)]
+struct DriftOrderParams {
+    order_type: u8,     // 0 = Market
+    market_type: u8,    // 1 = Perp
+    direction: u8,      // 0 = Long, 1 = Short
+    user_order_id: u8,
+    base_asset_amount: u64,
+    price: u64,
+    market_index: u16,
+    reduce_only: bool,
+    post_only: u8,      // 0 = None
+    bit_flags: u8,
+    max_ts: Option<i64>,
+    trigger_price: Option<u64>,
+    trigger_condition: u8,
+    oracle_price_offset: Option<i32>,
+    auction_duration: Option<u8>,
+    auction_start_price: Option<i64>,
+    auction_end_price: Option<i64>,
+}
+
+/// Place a Drift perp market order with a worst-acceptable `limit_price`.
+///
+/// `drift_accounts` must start with Drift's `state`, `user` and `authority`
+/// (the vault) accounts, followed by the oracle/market accounts Drift needs
+/// for margin checks.
+pub fn drift_perp_order<'info>(
+    drift_program: &AccountInfo<'info>,
+    drift_accounts: &[AccountInfo<'info>],
+    market_index: u16,
+    short: bool,
+    reduce_only: bool,
+    base_asset_amount: u64,
+    limit_price: u64,
+    vault: &Pubkey,
+    vault_seeds: &[&[u8]],
+) -> Result<()> {
+    let params = DriftOrderParams {
+        order_type: 0,
+        market_type: 1,
+        direction: short as u8,
+        user_order_id: 0,
+        base_asset_amount,
+        price: limit_price,
+        market_index,
+        reduce_only,
+        post_only: 0,
+        bit_flags: 0,
+        max_ts: None,
+        trigger_price: None,
+        trigger_condition: 0,
+        oracle_price_offset: None,
+        auction_duration: None,
+        auction_start_price: None,
+        auction_end_price: None,
+    };
+
+    let mut data = PLACE_PERP_ORDER_DISCRIMINATOR.to_vec();
+    params.serialize(&mut data)?;
+
+    invoke_as_vault(drift_program, drift_accounts, data, vault, vault_seeds)
+}
+
+/// Notional of a Drift base amount at `price`, in the vault's base mint units
+pub fn perp_notional(base_asset_amount: u64, price: u64, base_decimals: u8) -> Option<u64> {
+    let scale = 10u128.checked_pow(u32::from(crate::PRICE_DECIMALS))?;
+    let notional = u128::from(base_asset_amount)
+        .checked_mul(u128::from(price))?
+        .checked_mul(10u128.checked_pow(u32::from(base_decimals))?)?
+        / DRIFT_BASE_PRECISION
+        / scale;
+    u64::try_from(notional).ok()
+}
+
+/// Value in base mint units of `amount` of a token priced at `price`
+/// (base per whole token, PRICE_DECIMALS)
+pub fn token_value(amount: u64, price: u64, token_decimals: u8, base_decimals: u8) -> Option<u64> {
+    let value = u128::from(amount)
+        .checked_mul(u128::from(price))?
+        .checked_mul(10u128.checked_pow(u32::from(base_decimals))?)?
+        / 10u128.checked_pow(u32::from(crate::PRICE_DECIMALS) + u32::from(token_decimals))?;
+    u64::try_from(value).ok()
+}
+
+/// What a swap of `amount_in` on `side` returns at spot `price` (base per
+/// whole token, PRICE_DECIMALS): tokens for base on entries, base for
+/// tokens on exits
+pub fn spot_out(
+    side: crate::ExecutionSide,
+    amount_in: u64,
+    price: u64,
+    token_decimals: u8,
+    base_decimals: u8,
+) -> Option<u64> {
+    if side == crate::ExecutionSide::Exit {
+        return token_value(amount_in, price, token_decimals, base_decimals);
+    }
+    let tokens = u128::from(amount_in)
+        .checked_mul(10u128.checked_pow(u32::from(crate::PRICE_DECIMALS) + u32::from(token_decimals))?)?
+        .checked_div(u128::from(price).checked_mul(10u128.checked_pow(u32::from(base_decimals))?)?)?;
+    u64::try_from(tokens).ok()
+}
+
+/// marginfi v2 (lending)
+pub const MARGINFI_PROGRAM_ID: Pubkey = pubkey!("MFv2hWf31Z9kbCa1snEPYctwafyhdvnV7FZnsebVacA");
+
+/// sha256("global:lending_account_deposit")[..8]
+const LENDING_DEPOSIT_DISCRIMINATOR: [u8; 8] = [171, 94, 235, 103, 82, 64, 212, 140];
+/// sha256("global:lending_account_withdraw")[..8]
+const LENDING_WITHDRAW_DISCRIMINATOR: [u8; 8] = [36, 72, 74, 19, 210, 210, 192, 192];
+
+/// Deposit `amount` of the vault's base into its marginfi account.
+///
+/// `lending_accounts` follow marginfi's `lending_account_deposit` order:
+/// group, marginfi account, authority (the vault), bank, source token
+/// account, bank liquidity vault, token program.
+pub fn marginfi_deposit<'info>(
+    marginfi_program: &AccountInfo<'info>,
+    lending_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    vault: &Pubkey,
+    vault_seeds: &[&[u8]],
+) -> Result<()> {
+    let mut data = LENDING_DEPOSIT_DISCRIMINATOR.to_vec();
+    amount.serialize(&mut data)?;
+    None::<bool>.serialize(&mut data)?; // deposit_up_to_limit
+    invoke_as_vault(marginfi_program, lending_accounts, data, vault, vault_seeds)
+}
+
+/// Withdraw `amount` (or everything) from the vault's marginfi account.
+///
+/// `lending_accounts` follow marginfi's `lending_account_withdraw` order,
+/// followed by the bank/oracle pairs marginfi needs for its health check.
+pub fn marginfi_withdraw<'info>(
+    marginfi_program: &AccountInfo<'info>,
+    lending_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    withdraw_all: bool,
+    vault: &Pubkey,
+    vault_seeds: &[&[u8]],
+) -> Result<()> {
+    let mut data = LENDING_WITHDRAW_DISCRIMINATOR.to_vec();
+    amount.serialize(&mut data)?;
+    Some(withdraw_all).serialize(&mut data)?;
+    invoke_as_vault(marginfi_program, lending_accounts, data, vault, vault_seeds)
+}
+
+/// SPL stake pool program (jitoSOL and most other LSTs)
+pub const STAKE_POOL_PROGRAM_ID: Pubkey = pubkey!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+
+/// `StakePoolInstruction::DepositSol`
+const DEPOSIT_SOL_INSTRUCTION: u8 = 14;
+/// `StakePoolInstruction::WithdrawSol`
+const WITHDRAW_SOL_INSTRUCTION: u8 = 16;
+
+/// Offsets into an SPL `StakePool` account
+const STAKE_POOL_MINT_OFFSET: usize = 162;
+const STAKE_POOL_TOTAL_LAMPORTS_OFFSET: usize = 258;
+const STAKE_POOL_TOKEN_SUPPLY_OFFSET: usize = 266;
+const STAKE_POOL_LAST_UPDATE_EPOCH_OFFSET: usize = 274;
+
+/// What an SPL stake pool's LST is worth, read from the pool account
+pub struct StakePoolRate {
+    pub pool_mint: Pubkey,
+    pub total_lamports: u64,
+    pub pool_token_supply: u64,
+    pub last_update_epoch: u64,
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(data.get(offset..offset + 8)?.try_into().ok()?))
+}
+
+/// Parse an SPL `StakePool` account's mint and exchange rate
+pub fn stake_pool_rate(data: &[u8]) -> Option<StakePoolRate> {
+    // AccountType::StakePool
+    if data.first() != Some(&1) {
+        return None;
+    }
+    let mint = data.get(STAKE_POOL_MINT_OFFSET..STAKE_POOL_MINT_OFFSET + 32)?;
+    Some(StakePoolRate {
+        pool_mint: Pubkey::try_from(mint).ok()?,
+        total_lamports: read_u64(data, STAKE_POOL_TOTAL_LAMPORTS_OFFSET)?,
+        pool_token_supply: read_u64(data, STAKE_POOL_TOKEN_SUPPLY_OFFSET)?,
+        last_update_epoch: read_u64(data, STAKE_POOL_LAST_UPDATE_EPOCH_OFFSET)?,
+    })
+}
+
+/// Stake `lamports` from `sol_account` into an SPL stake pool.
+///
+/// `pool_accounts` follow `DepositSol`'s order: stake pool, withdraw
+/// authority, reserve stake, lamports source (the vault's SOL account),
+/// destination LST account, manager fee account, referrer fee account,
+/// pool mint, system program, token program.
+pub fn stake_pool_deposit_sol<'info>(
+    stake_pool_program: &AccountInfo<'info>,
+    pool_accounts: &[AccountInfo<'info>],
+    lamports: u64,
+    sol_account: &Pubkey,
+    sol_account_seeds: &[&[u8]],
+) -> Result<()> {
+    let mut data = vec![DEPOSIT_SOL_INSTRUCTION];
+    lamports.serialize(&mut data)?;
+    invoke_as_vault(stake_pool_program, pool_accounts, data, sol_account, sol_account_seeds)
+}
+
+/// Redeem `pool_tokens` of LST for SOL from an SPL stake pool's reserve.
+///
+/// `pool_accounts` follow `WithdrawSol`'s order: stake pool, withdraw
+/// authority, transfer authority (the vault), source LST account, reserve
+/// stake, lamports destination, manager fee account, pool mint, clock and
+/// stake history sysvars, stake program, token program.
+pub fn stake_pool_withdraw_sol<'info>(
+    stake_pool_program: &AccountInfo<'info>,
+    pool_accounts: &[AccountInfo<'info>],
+    pool_tokens: u64,
+    vault: &Pubkey,
+    vault_seeds: &[&[u8]],
+) -> Result<()> {
+    let mut data = vec![WITHDRAW_SOL_INSTRUCTION];
+    pool_tokens.serialize(&mut data)?;
+    invoke_as_vault(stake_pool_program, pool_accounts, data, vault, vault_seeds)
+}
+
+/// Shares minted for `amount` of base into a vault with `total_shares`
+/// worth `equity`; the first deposit mints one share per base unit. None
+/// when the shares can't be priced: they are worth nothing, or too little
+/// for the count to fit in a u64.
+pub fn shares_for_deposit(amount: u64, total_shares: u64, equity: u64) -> Option<u64> {
+    if total_shares == 0 {
+        return Some(amount);
+    }
+    if equity == 0 {
+        return None;
+    }
+    u64::try_from(u128::from(amount) * u128::from(total_shares) / u128::from(equity)).ok()
+}
+
+/// Base that `shares` of a vault with `total_shares` worth `equity` redeem for
+pub fn share_value(shares: u64, total_shares: u64, equity: u64) -> u64 {
+    if total_shares == 0 {
+        return 0;
+    }
+    (u128::from(shares) * u128::from(equity) / u128::from(total_shares)) as u64
+}
+
+/// Equity per share, scaled by PER_SHARE_SCALE; 0 while there are no shares
+pub fn share_price(equity: u64, total_shares: u64) -> u128 {
+    if total_shares == 0 {
+        return 0;
+    }
+    u128::from(equity) * PER_SHARE_SCALE as u128 / u128::from(total_shares)
+}
+
+/// Move from share price `reference` to `price` in basis points
+pub fn change_bps(price: u128, reference: u128) -> u64 {
+    if reference == 0 {
+        return u64::MAX;
+    }
+    u64::try_from(price.abs_diff(reference).saturating_mul(10_000) / reference).unwrap_or(u64::MAX)
+}
+
+/// `part`'s share of `amount`, when `part` is out of `whole`
+pub fn pro_rata(amount: u64, part: u64, whole: u64) -> u64 {
+    if whole == 0 {
+        return 0;
+    }
+    (u128::from(amount) * u128::from(part) / u128::from(whole)) as u64
+}
+
+/// Fixed-point scale of the vault's per-share accumulators
+pub const PER_SHARE_SCALE: i128 = 1_000_000_000_000;
+
+/// `amount` spread over `total_shares`, scaled by PER_SHARE_SCALE; nothing
+/// is attributed while the vault has no shares
+pub fn per_share(amount: i128, total_shares: u64) -> i128 {
+    if total_shares == 0 {
+        return 0;
+    }
+    amount * PER_SHARE_SCALE / i128::from(total_shares)
+}
+
+/// What `shares` held while a per-share accumulator grew by `delta` comes to
+pub fn attributed(shares: u64, delta: i128) -> i64 {
+    (i128::from(shares) * delta / PER_SHARE_SCALE) as i64
+}
+
+/// Performance fee on `equity` spread over `total_shares`, charged on
+/// what the share price gained above `high_water_mark` (PER_SHARE_SCALE;
+/// 0 means the one base unit per share first deposits mint at). Returns
+/// the fee and the post-fee share price, the new high-water mark.
+pub fn performance_fee(equity: u64, total_shares: u64, high_water_mark: u128, fee_bps: u16) -> (u64, u128) {
+    let scale = PER_SHARE_SCALE as u128;
+    let high_water_mark = if high_water_mark == 0 { scale } else { high_water_mark };
+    if total_shares == 0 {
+        return (0, high_water_mark);
+    }
+    let price = u128::from(equity) * scale / u128::from(total_shares);
+    if price <= high_water_mark {
+        return (0, high_water_mark);
+    }
+    let gain = (price - high_water_mark) * u128::from(total_shares) / scale;
+    let fee = (gain * u128::from(fee_bps) / 10_000) as u64;
+    let post_fee_price = u128::from(equity - fee) * scale / u128::from(total_shares);
+    (fee, post_fee_price)
+}
+
+/// Commitment to an execution's parameters, revealed by the keeper at execution
+pub fn execution_commitment(signal: &Pubkey, side: crate::ExecutionSide, amount_in: u64, salt: &[u8; 32]) -> [u8; 32] {
+    solana_sha256_hasher::hashv(&[signal.as_ref(), &[side as u8], &amount_in.to_le_bytes(), salt]).to_bytes()
+}
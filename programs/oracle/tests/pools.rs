@@ -0,0 +1,30 @@
+//! Table tests for the pool readers' fixed-point helpers.
+
+use oracle::pools::{deviation_bps, mul_div};
+
+#[test]
+fn mul_div_keeps_the_full_product() {
+    const Q64: u128 = 1 << 64;
+    let cases = [
+        (6, 7, 3, Some(14)),
+        (u128::MAX, u128::MAX, u128::MAX, Some(u128::MAX)),
+        (u128::MAX, 2, 2, Some(u128::MAX)),
+        (1 << 127, 4, 8, Some(1 << 126)),
+        // The low bits of the product are floored away
+        (Q64 + 3, Q64 + 5, Q64, Some(Q64 + 8)),
+        (u128::MAX, 2, 1, None),
+        (u128::MAX, u128::MAX, 1 << 127, None),
+        (1, 1, 0, None),
+    ];
+    for (a, b, denom, expected) in cases {
+        assert_eq!(mul_div(a, b, denom), expected, "{a} * {b} / {denom}");
+    }
+}
+
+#[test]
+fn deviation_is_symmetric_in_basis_points() {
+    let cases = [(105, 100, 500), (95, 100, 500), (100, 100, 0), (1, 0, u64::MAX), (u64::MAX, 1, u64::MAX)];
+    for (claimed, reference, expected) in cases {
+        assert_eq!(deviation_bps(claimed, reference), expected, "{claimed} vs {reference}");
+    }
+}